@@ -0,0 +1,607 @@
+use std::cmp::Ordering;
+
+/// A link to a subtree, which is empty when `None`.
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+/// A node in the AVL tree.
+#[derive(Debug)]
+struct Node<K, V> {
+    /// The key the node is ordered by.
+    key: K,
+    /// The value stored under the key.
+    value: V,
+    /// The height of the subtree rooted at this node; a leaf has height 1.
+    height: usize,
+    /// The subtree of keys less than `key`.
+    left: Link<K, V>,
+    /// The subtree of keys greater than `key`.
+    right: Link<K, V>,
+}
+
+impl<K, V> Node<K, V> {
+    /// Creates a new leaf node with the given key and value.
+    fn new(key: K, value: V) -> Self {
+        Node {
+            key,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Recomputes the height from the heights of the two subtrees.
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    /// Returns the height of the left subtree minus the height of the right subtree.
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+/// Returns the height of the subtree behind `link`, which is 0 for an empty link.
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+/// A self-balancing binary search tree mapping keys to values.
+///
+/// After every insertion and removal the nodes on the changed path are rebalanced
+/// with single or double rotations, so the heights of any node's two subtrees
+/// differ by at most one. That bounds the height by about 1.44·log2(n), which
+/// makes every operation O(log n) even for sorted input.
+#[derive(Debug)]
+pub struct KolzoAvl<K: Ord, V> {
+    /// The root of the tree.
+    root: Link<K, V>,
+    /// The number of entries in the tree.
+    length: usize,
+}
+
+impl<K: Ord, V> KolzoAvl<K, V> {
+    /// Creates a new empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let tree: KolzoAvl<i32, &str> = KolzoAvl::new();
+    /// assert!(tree.is_empty());
+    /// assert_eq!(tree.height(), 0);
+    /// ```
+    pub fn new() -> Self {
+        KolzoAvl {
+            root: None,
+            length: 0,
+        }
+    }
+
+    /// Inserts a key-value pair into the tree and rebalances it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to insert.
+    /// * `value` - The value to store under the key.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(V)` containing the previous value if the key was already present; the
+    ///   value is replaced and the key is kept.
+    /// * `None` if the key is new.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let mut tree = KolzoAvl::new();
+    /// assert_eq!(tree.insert(1, "a"), None);
+    /// assert_eq!(tree.insert(1, "b"), Some("a"));
+    /// assert_eq!(tree.get(&1), Some(&"b"));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut previous = None;
+        self.root = Some(insert_into(self.root.take(), key, value, &mut previous));
+        if previous.is_none() {
+            self.length += 1;
+        }
+        previous
+    }
+
+    /// Returns a reference to the value stored under `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let mut tree = KolzoAvl::new();
+    /// tree.insert(2, "two");
+    /// assert_eq!(tree.get(&2), Some(&"two"));
+    /// assert_eq!(tree.get(&3), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// Removes `key` from the tree, rebalances it and returns the removed value.
+    ///
+    /// A node with two children is replaced by its in-order successor, the smallest
+    /// node of its right subtree.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(V)` containing the removed value if the key was present.
+    /// * `None` if the key was not in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let mut tree = KolzoAvl::new();
+    /// for key in 1..=3 {
+    ///     tree.insert(key, key * 10);
+    /// }
+    ///
+    /// assert_eq!(tree.remove(&2), Some(20));
+    /// assert_eq!(tree.remove(&2), None);
+    /// assert!(tree.validate().is_ok());
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let mut removed = None;
+        self.root = remove_from(self.root.take(), key, &mut removed);
+        if removed.is_some() {
+            self.length -= 1;
+        }
+        removed
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn min(&self) -> Option<(&K, &V)> {
+        let mut current = self.root.as_deref()?;
+        while let Some(left) = current.left.as_deref() {
+            current = left;
+        }
+        Some((&current.key, &current.value))
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn max(&self) -> Option<(&K, &V)> {
+        let mut current = self.root.as_deref()?;
+        while let Some(right) = current.right.as_deref() {
+            current = right;
+        }
+        Some((&current.key, &current.value))
+    }
+
+    /// Returns the number of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the tree has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the height of the tree: 0 when empty and 1 for a single node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let mut tree = KolzoAvl::new();
+    /// for key in 1..=7 {
+    ///     tree.insert(key, ());
+    /// }
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Checks the structure of every node in the tree.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if every key is between the keys of its ancestors, every stored
+    ///   height is correct, the subtree heights of every node differ by at most one,
+    ///   and the tree holds exactly `len()` nodes.
+    /// * `Err(String)` describing the first violation found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let mut tree = KolzoAvl::new();
+    /// for key in 0..100 {
+    ///     tree.insert(key, ());
+    /// }
+    /// assert_eq!(tree.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let mut count = 0;
+        validate_subtree(&self.root, None, None, &mut count)?;
+
+        if count != self.length {
+            return Err(format!(
+                "the tree holds {count} nodes but its length is {}",
+                self.length
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns an in-order iterator over the entries, which yields the keys sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use avl_tree::algorithm::KolzoAvl;
+    ///
+    /// let mut tree = KolzoAvl::new();
+    /// for key in [2, 3, 1] {
+    ///     tree.insert(key, ());
+    /// }
+    /// let keys: Vec<_> = tree.iter().map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.length,
+        };
+        iter.push_left_spine(self.root.as_deref());
+        iter
+    }
+}
+
+/// Inserts the pair into the subtree behind `link` and returns the rebalanced subtree.
+/// If the key is already present, its old value is stored in `previous`.
+fn insert_into<K: Ord, V>(
+    link: Link<K, V>,
+    key: K,
+    value: V,
+    previous: &mut Option<V>,
+) -> Box<Node<K, V>> {
+    let mut node = match link {
+        Some(node) => node,
+        None => return Box::new(Node::new(key, value)),
+    };
+
+    match key.cmp(&node.key) {
+        Ordering::Less => node.left = Some(insert_into(node.left.take(), key, value, previous)),
+        Ordering::Greater => {
+            node.right = Some(insert_into(node.right.take(), key, value, previous))
+        }
+        Ordering::Equal => {
+            *previous = Some(std::mem::replace(&mut node.value, value));
+            return node;
+        }
+    }
+
+    rebalance(node)
+}
+
+/// Removes `key` from the subtree behind `link` and returns the rebalanced subtree.
+/// The removed value, if any, is stored in `removed`.
+fn remove_from<K: Ord, V>(link: Link<K, V>, key: &K, removed: &mut Option<V>) -> Link<K, V> {
+    let mut node = link?;
+
+    match key.cmp(&node.key) {
+        Ordering::Less => node.left = remove_from(node.left.take(), key, removed),
+        Ordering::Greater => node.right = remove_from(node.right.take(), key, removed),
+        Ordering::Equal => {
+            let Node {
+                value, left, right, ..
+            } = *node;
+            *removed = Some(value);
+
+            return match (left, right) {
+                (None, child) | (child, None) => child,
+                (Some(left), Some(right)) => {
+                    let (mut successor, rest) = take_min(right);
+                    successor.left = Some(left);
+                    successor.right = rest;
+                    Some(rebalance(successor))
+                }
+            };
+        }
+    }
+
+    Some(rebalance(node))
+}
+
+/// Detaches the smallest node of the subtree rooted at `node`.
+///
+/// # Returns
+///
+/// The detached node, with both links cleared, and the rebalanced rest of the subtree.
+fn take_min<K, V>(mut node: Box<Node<K, V>>) -> (Box<Node<K, V>>, Link<K, V>) {
+    match node.left.take() {
+        None => {
+            let rest = node.right.take();
+            (node, rest)
+        }
+        Some(left) => {
+            let (min, rest) = take_min(left);
+            node.left = rest;
+            (min, Some(rebalance(node)))
+        }
+    }
+}
+
+/// Restores the AVL invariant at `node`, whose subtrees are already balanced and
+/// differ in height by at most two, and returns the new root of the subtree.
+fn rebalance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    node.update_height();
+    let balance = node.balance_factor();
+
+    if balance > 1 {
+        // Left-right case: turn it into a left-left case first.
+        if node
+            .left
+            .as_ref()
+            .is_some_and(|left| left.balance_factor() < 0)
+        {
+            node.left = node.left.take().map(rotate_left);
+        }
+        return rotate_right(node);
+    }
+
+    if balance < -1 {
+        // Right-left case: turn it into a right-right case first.
+        if node
+            .right
+            .as_ref()
+            .is_some_and(|right| right.balance_factor() > 0)
+        {
+            node.right = node.right.take().map(rotate_right);
+        }
+        return rotate_left(node);
+    }
+
+    node
+}
+
+/// Rotates the subtree right, making the left child the new root.
+fn rotate_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut new_root = node
+        .left
+        .take()
+        .expect("a right rotation needs a left child");
+    node.left = new_root.right.take();
+    node.update_height();
+    new_root.right = Some(node);
+    new_root.update_height();
+    new_root
+}
+
+/// Rotates the subtree left, making the right child the new root.
+fn rotate_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut new_root = node
+        .right
+        .take()
+        .expect("a left rotation needs a right child");
+    node.right = new_root.left.take();
+    node.update_height();
+    new_root.left = Some(node);
+    new_root.update_height();
+    new_root
+}
+
+/// Checks the subtree behind `link`, whose keys must lie strictly between `lower`
+/// and `upper`, and returns its height. Every visited node is added to `count`.
+fn validate_subtree<K: Ord, V>(
+    link: &Link<K, V>,
+    lower: Option<&K>,
+    upper: Option<&K>,
+    count: &mut usize,
+) -> Result<usize, String> {
+    let Some(node) = link else {
+        return Ok(0);
+    };
+    *count += 1;
+
+    if lower.is_some_and(|lower| node.key <= *lower)
+        || upper.is_some_and(|upper| node.key >= *upper)
+    {
+        return Err("a key is out of order with respect to an ancestor".to_string());
+    }
+
+    let left_height = validate_subtree(&node.left, lower, Some(&node.key), count)?;
+    let right_height = validate_subtree(&node.right, Some(&node.key), upper, count)?;
+
+    if left_height.abs_diff(right_height) > 1 {
+        return Err(format!(
+            "a node has subtrees of heights {left_height} and {right_height}"
+        ));
+    }
+
+    let expected = 1 + left_height.max(right_height);
+    if node.height != expected {
+        return Err(format!(
+            "a node stores height {} but its subtree has height {expected}",
+            node.height
+        ));
+    }
+
+    Ok(expected)
+}
+
+impl<K: Ord, V> Default for KolzoAvl<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a KolzoAvl<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An in-order iterator over the entries of a [`KolzoAvl`], in ascending key order.
+pub struct Iter<'a, K, V> {
+    /// The nodes whose left subtrees have been pushed but which are not yet yielded.
+    stack: Vec<&'a Node<K, V>>,
+    /// The number of entries not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    /// Pushes `node` and every node along its chain of left children.
+    fn push_left_spine(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_ascending_inserts_stay_balanced() {
+        let mut tree = KolzoAvl::new();
+        for key in 1..=10_000 {
+            tree.insert(key, key);
+        }
+
+        let bound = 1.44 * (tree.len() as f64).log2();
+        assert!(
+            (tree.height() as f64) <= bound,
+            "height {} exceeds {bound}",
+            tree.height()
+        );
+        assert_eq!(tree.validate(), Ok(()));
+        assert!(tree.iter().map(|(key, _)| *key).eq(1..=10_000));
+    }
+
+    #[test]
+    fn test_rotation_cases() {
+        // Left-left, right-right, left-right and right-left insertions of three
+        // keys all end with the middle key at the root.
+        for keys in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+            let mut tree = KolzoAvl::new();
+            for key in keys {
+                tree.insert(key, ());
+            }
+
+            assert_eq!(tree.height(), 2, "keys {keys:?}");
+            assert_eq!(tree.root.as_ref().map(|root| root.key), Some(2));
+            assert_eq!(tree.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_insert_replaces_duplicate() {
+        let mut tree = KolzoAvl::new();
+        tree.insert(1, 10);
+
+        assert_eq!(tree.insert(1, 100), Some(10));
+        assert_eq!(tree.get(&1), Some(&100));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn test_remove_rebalances() {
+        let mut tree = KolzoAvl::new();
+        for key in 1..=100 {
+            tree.insert(key, key * 10);
+        }
+
+        for key in (1..=100).filter(|key| key % 3 != 0) {
+            assert_eq!(tree.remove(&key), Some(key * 10));
+            assert_eq!(tree.validate(), Ok(()));
+        }
+        assert_eq!(tree.remove(&1), None);
+
+        assert_eq!(tree.len(), 33);
+        assert!(tree.iter().map(|(key, _)| *key).eq((3..=99).step_by(3)));
+        assert_eq!(tree.min(), Some((&3, &30)));
+        assert_eq!(tree.max(), Some((&99, &990)));
+    }
+
+    #[test]
+    fn test_validate_detects_broken_tree() {
+        let mut tree = KolzoAvl::new();
+        for key in 1..=3 {
+            tree.insert(key, ());
+        }
+
+        if let Some(root) = tree.root.as_mut() {
+            root.key = 10;
+        }
+        assert!(tree.validate().is_err());
+
+        let mut tree = KolzoAvl::new();
+        tree.insert(1, ());
+        tree.length = 2;
+        assert!(tree.validate().is_err());
+    }
+
+    #[test]
+    fn test_matches_btree_map() {
+        let mut rng = StdRng::seed_from_u64(219);
+        let mut tree = KolzoAvl::new();
+        let mut model = BTreeMap::new();
+
+        for _ in 0..10_000 {
+            let key = rng.gen_range(0..500);
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    let value: i32 = rng.gen();
+                    assert_eq!(tree.insert(key, value), model.insert(key, value));
+                }
+                2 => assert_eq!(tree.remove(&key), model.remove(&key)),
+                _ => assert_eq!(tree.get(&key), model.get(&key)),
+            }
+
+            assert_eq!(tree.validate(), Ok(()));
+            assert_eq!(tree.len(), model.len());
+        }
+
+        assert!(tree.iter().eq(model.iter()));
+    }
+}
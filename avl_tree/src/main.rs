@@ -0,0 +1,17 @@
+use avl_tree::algorithm::KolzoAvl;
+
+fn main() {
+    let mut tree = KolzoAvl::new();
+    for key in 1..=7 {
+        tree.insert(key, key * 10);
+    }
+    println!("IN-ORDER {:?}", tree.iter().collect::<Vec<_>>());
+    println!("HEIGHT {}", tree.height());
+    println!("GET 3 {:?}", tree.get(&3));
+    println!("REPLACE 3 {:?}", tree.insert(3, 33));
+    println!("MIN {:?} MAX {:?}", tree.min(), tree.max());
+    println!("REMOVE 4 {:?}", tree.remove(&4));
+    println!("IN-ORDER {:?}", tree.iter().collect::<Vec<_>>());
+    println!("VALID {:?}", tree.validate());
+    println!("LENGTH {}", tree.len());
+}
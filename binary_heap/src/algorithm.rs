@@ -0,0 +1,452 @@
+use std::cmp::Ordering;
+
+/// A binary heap backed by a vector, usable as a priority queue.
+///
+/// The element at index `i` has its children at `2i + 1` and `2i + 2`, and no
+/// child ranks above its parent. In a min-heap the smallest element is on top;
+/// in a max-heap, created with [`KolzoHeap::max_heap`], the largest is.
+/// `push` and `pop` are O(log n) and `peek` is O(1).
+#[derive(Debug, Clone)]
+pub struct KolzoHeap<T: Ord> {
+    /// The elements in heap order.
+    data: Vec<T>,
+    /// The result of comparing an element with one it ranks above: `Less` for a
+    /// min-heap and `Greater` for a max-heap.
+    order: Ordering,
+}
+
+impl<T: Ord> KolzoHeap<T> {
+    /// Creates a new empty min-heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let mut heap = KolzoHeap::new();
+    /// heap.push(2);
+    /// heap.push(1);
+    /// assert_eq!(heap.peek(), Some(&1));
+    /// ```
+    pub fn new() -> Self {
+        KolzoHeap {
+            data: Vec::new(),
+            order: Ordering::Less,
+        }
+    }
+
+    /// Creates a new empty max-heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let mut heap = KolzoHeap::max_heap();
+    /// heap.push(1);
+    /// heap.push(2);
+    /// assert_eq!(heap.peek(), Some(&2));
+    /// ```
+    pub fn max_heap() -> Self {
+        KolzoHeap {
+            data: Vec::new(),
+            order: Ordering::Greater,
+        }
+    }
+
+    /// Builds a min-heap from a vector in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The elements of the heap, in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let mut heap = KolzoHeap::from_vec(vec![3, 1, 2]);
+    /// assert_eq!(heap.pop(), Some(1));
+    /// ```
+    pub fn from_vec(values: Vec<T>) -> Self {
+        Self::heapify(values, Ordering::Less)
+    }
+
+    /// Builds a max-heap from a vector in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The elements of the heap, in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let mut heap = KolzoHeap::max_heap_from_vec(vec![3, 1, 2]);
+    /// assert_eq!(heap.pop(), Some(3));
+    /// ```
+    pub fn max_heap_from_vec(values: Vec<T>) -> Self {
+        Self::heapify(values, Ordering::Greater)
+    }
+
+    /// Sifts down every parent, from the last one up to the root. Most nodes sit
+    /// near the bottom and only move a few levels, which sums to O(n).
+    fn heapify(mut data: Vec<T>, order: Ordering) -> Self {
+        let length = data.len();
+        for index in (0..length / 2).rev() {
+            sift_down(&mut data, index, length, order);
+        }
+        KolzoHeap { data, order }
+    }
+
+    /// Adds a value to the heap in O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let mut heap = KolzoHeap::new();
+    /// heap.push(5);
+    /// heap.push(3);
+    /// assert_eq!(heap.len(), 2);
+    /// assert_eq!(heap.peek(), Some(&3));
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+        let last = self.data.len() - 1;
+        sift_up(&mut self.data, last, self.order);
+    }
+
+    /// Removes and returns the top of the heap in O(log n).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the smallest value of a min-heap, or the largest value
+    ///   of a max-heap, if the heap is not empty.
+    /// * `None` if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let mut heap = KolzoHeap::from_vec(vec![2, 1]);
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), Some(2));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let top = self.data.swap_remove(0);
+        let length = self.data.len();
+        sift_down(&mut self.data, 0, length, self.order);
+        Some(top)
+    }
+
+    /// Returns a reference to the top of the heap without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the heap has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Consumes the heap and returns its elements in ascending order, whatever the
+    /// kind of heap.
+    ///
+    /// The elements are sorted in place by repeatedly moving the top behind the
+    /// shrinking heap, so no extra memory is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_heap::algorithm::KolzoHeap;
+    ///
+    /// let heap = KolzoHeap::from_vec(vec![3, 1, 2]);
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3]);
+    ///
+    /// let heap = KolzoHeap::max_heap_from_vec(vec![3, 1, 2]);
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        for end in (1..self.data.len()).rev() {
+            self.data.swap(0, end);
+            sift_down(&mut self.data, 0, end, self.order);
+        }
+
+        // Moving the top behind the heap leaves a max-heap ascending and a min-heap descending.
+        if self.order == Ordering::Less {
+            self.data.reverse();
+        }
+        self.data
+    }
+}
+
+impl<T: Ord> Default for KolzoHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for KolzoHeap<T> {
+    /// Pushes every value of the iterator onto the heap.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for KolzoHeap<T> {
+    /// Builds a min-heap from the values with a single O(n) heapify.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+/// Sorts a slice in ascending order in place with heapsort, in O(n log n).
+///
+/// The slice is first arranged into a max-heap, then the top is repeatedly swapped
+/// behind the shrinking heap. The sort is not stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use binary_heap::algorithm::heapsort;
+///
+/// let mut values = [5, 2, 4, 1, 3];
+/// heapsort(&mut values);
+/// assert_eq!(values, [1, 2, 3, 4, 5]);
+/// ```
+pub fn heapsort<T: Ord>(values: &mut [T]) {
+    let length = values.len();
+    for index in (0..length / 2).rev() {
+        sift_down(values, index, length, Ordering::Greater);
+    }
+
+    for end in (1..length).rev() {
+        values.swap(0, end);
+        sift_down(values, 0, end, Ordering::Greater);
+    }
+}
+
+/// Moves the element at `index` up until its parent ranks above it.
+///
+/// # Arguments
+///
+/// * `data` - The heap.
+/// * `index` - The position of the element to move.
+/// * `order` - The comparison result of an element against one it ranks above.
+fn sift_up<T: Ord>(data: &mut [T], mut index: usize, order: Ordering) {
+    while index > 0 {
+        let parent = (index - 1) / 2;
+        if data[index].cmp(&data[parent]) != order {
+            break;
+        }
+        data.swap(index, parent);
+        index = parent;
+    }
+}
+
+/// Moves the element at `index` down until neither child inside `data[..end]`
+/// ranks above it.
+///
+/// # Arguments
+///
+/// * `data` - The heap, of which only the first `end` elements are considered.
+/// * `index` - The position of the element to move.
+/// * `end` - The length of the heap inside `data`.
+/// * `order` - The comparison result of an element against one it ranks above.
+fn sift_down<T: Ord>(data: &mut [T], mut index: usize, end: usize, order: Ordering) {
+    loop {
+        let left = 2 * index + 1;
+        let right = left + 1;
+        let mut top = index;
+
+        if left < end && data[left].cmp(&data[top]) == order {
+            top = left;
+        }
+        if right < end && data[right].cmp(&data[top]) == order {
+            top = right;
+        }
+        if top == index {
+            break;
+        }
+
+        data.swap(index, top);
+        index = top;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    fn random_values(count: usize, seed: u64) -> Vec<i32> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count).map(|_| rng.gen_range(-1000..1000)).collect()
+    }
+
+    /// Returns `true` if no child in `data` ranks above its parent.
+    fn is_heap<T: Ord>(data: &[T], order: Ordering) -> bool {
+        (1..data.len()).all(|index| data[index].cmp(&data[(index - 1) / 2]) != order)
+    }
+
+    #[test]
+    fn test_sift_up() {
+        let mut data = vec![1, 3, 5, 7, 9, 0];
+        sift_up(&mut data, 5, Ordering::Less);
+        assert_eq!(data, vec![0, 3, 1, 7, 9, 5]);
+
+        let mut data = vec![9, 7, 8, 10];
+        sift_up(&mut data, 3, Ordering::Greater);
+        assert_eq!(data, vec![10, 9, 8, 7]);
+    }
+
+    #[test]
+    fn test_sift_down() {
+        let mut data = vec![9, 1, 2, 3, 4];
+        sift_down(&mut data, 0, 5, Ordering::Less);
+        assert_eq!(data, vec![1, 3, 2, 9, 4]);
+
+        // Elements past `end` are left alone.
+        let mut data = vec![9, 1, 0];
+        sift_down(&mut data, 0, 2, Ordering::Less);
+        assert_eq!(data, vec![1, 9, 0]);
+    }
+
+    #[test]
+    fn test_pops_come_out_sorted() {
+        let values = random_values(1000, 220);
+        let mut heap = KolzoHeap::new();
+        heap.extend(values.iter().copied());
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+
+        let mut expected = values;
+        expected.sort();
+        assert_eq!(popped, expected);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_heapify_matches_pushes() {
+        let values = random_values(500, 1);
+
+        for (mut heapified, mut pushed) in [
+            (KolzoHeap::from_vec(values.clone()), KolzoHeap::new()),
+            (
+                KolzoHeap::max_heap_from_vec(values.clone()),
+                KolzoHeap::max_heap(),
+            ),
+        ] {
+            pushed.extend(values.iter().copied());
+            assert!(is_heap(&heapified.data, heapified.order));
+            assert!(is_heap(&pushed.data, pushed.order));
+            assert_eq!(heapified.len(), pushed.len());
+
+            while !pushed.is_empty() {
+                assert_eq!(heapified.pop(), pushed.pop());
+            }
+            assert_eq!(heapified.pop(), None);
+        }
+    }
+
+    #[test]
+    fn test_into_sorted_vec() {
+        let values = random_values(300, 2);
+        let mut expected = values.clone();
+        expected.sort();
+
+        assert_eq!(
+            KolzoHeap::from_vec(values.clone()).into_sorted_vec(),
+            expected
+        );
+        assert_eq!(
+            KolzoHeap::max_heap_from_vec(values).into_sorted_vec(),
+            expected
+        );
+        assert!(KolzoHeap::<i32>::new().into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn test_heapsort() {
+        for length in 0..50 {
+            let mut values = random_values(length, length as u64);
+            let mut expected = values.clone();
+            expected.sort();
+
+            heapsort(&mut values);
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    fn test_min_heap_matches_reversed_binary_heap() {
+        let mut rng = StdRng::seed_from_u64(220);
+        let mut heap = KolzoHeap::new();
+        let mut model = BinaryHeap::new();
+
+        for _ in 0..10_000 {
+            match rng.gen_range(0..3) {
+                0 | 1 => {
+                    let value: i32 = rng.gen_range(-100..100);
+                    heap.push(value);
+                    model.push(Reverse(value));
+                }
+                _ => assert_eq!(heap.pop(), model.pop().map(|Reverse(value)| value)),
+            }
+
+            assert_eq!(heap.peek(), model.peek().map(|Reverse(value)| value));
+            assert_eq!(heap.len(), model.len());
+        }
+    }
+
+    #[test]
+    fn test_max_heap_matches_binary_heap() {
+        let mut rng = StdRng::seed_from_u64(221);
+        let mut heap = KolzoHeap::max_heap();
+        let mut model = BinaryHeap::new();
+
+        for _ in 0..10_000 {
+            match rng.gen_range(0..3) {
+                0 | 1 => {
+                    let value: i32 = rng.gen_range(-100..100);
+                    heap.push(value);
+                    model.push(value);
+                }
+                _ => assert_eq!(heap.pop(), model.pop()),
+            }
+
+            assert_eq!(heap.peek(), model.peek());
+            assert_eq!(heap.len(), model.len());
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use binary_heap::algorithm::{heapsort, KolzoHeap};
+
+fn main() {
+    let mut heap = KolzoHeap::new();
+    for value in [5, 1, 4, 2, 3] {
+        heap.push(value);
+    }
+    println!("PEEK {:?}", heap.peek());
+    println!("POP {:?}", heap.pop());
+    println!("LENGTH {}", heap.len());
+    println!("SORTED {:?}", heap.into_sorted_vec());
+
+    let mut max_heap = KolzoHeap::max_heap_from_vec(vec![5, 1, 4, 2, 3]);
+    println!("MAX POP {:?}", max_heap.pop());
+
+    let mut values = vec![9, 7, 8, 1, 3];
+    heapsort(&mut values);
+    println!("HEAPSORT {:?}", values);
+}
@@ -0,0 +1,258 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Searches a sorted slice for `target`.
+///
+/// # Arguments
+///
+/// * `values` - A slice sorted in ascending order.
+/// * `target` - The value to look for.
+///
+/// # Returns
+///
+/// * `Ok(index)` with the index of the first value equal to `target`.
+/// * `Err(index)` with the index where `target` could be inserted to keep the slice
+///   sorted, if no value equals it.
+///
+/// Unlike `slice::binary_search`, which may return any of several equal values, the
+/// found index is always the first one.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::algorithm::binary_search;
+///
+/// let values = [1, 3, 3, 5];
+/// assert_eq!(binary_search(&values, &3), Ok(1));
+/// assert_eq!(binary_search(&values, &4), Err(3));
+/// assert_eq!(binary_search(&values, &9), Err(4));
+/// ```
+pub fn binary_search<T: Ord>(values: &[T], target: &T) -> Result<usize, usize> {
+    binary_search_by(values, |value| value.cmp(target))
+}
+
+/// Searches a slice with a comparator that tells how each value relates to the
+/// target.
+///
+/// # Arguments
+///
+/// * `values` - A slice ordered consistently with `compare`: every value for which
+///   it returns `Less` comes before every `Equal`, which come before every
+///   `Greater`.
+/// * `compare` - Returns how a value compares to the target.
+///
+/// # Returns
+///
+/// * `Ok(index)` with the index of the first value for which `compare` is `Equal`.
+/// * `Err(index)` with the number of values for which `compare` is `Less`, if no
+///   value compares `Equal`.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::algorithm::binary_search_by;
+///
+/// let words = ["fig", "apple", "banana"];
+/// // Sorted by length.
+/// assert_eq!(binary_search_by(&words, |word| word.len().cmp(&5)), Ok(1));
+/// assert_eq!(binary_search_by(&words, |word| word.len().cmp(&4)), Err(1));
+/// ```
+pub fn binary_search_by<T, F>(values: &[T], mut compare: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let index = partition_point(values, |value| compare(value) == Ordering::Less);
+    match values.get(index) {
+        Some(value) if compare(value) == Ordering::Equal => Ok(index),
+        _ => Err(index),
+    }
+}
+
+/// Returns the index of the first value that is not less than `target`, or the
+/// length of the slice if there is none.
+///
+/// # Arguments
+///
+/// * `values` - A slice sorted in ascending order.
+/// * `target` - The value to bound.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::algorithm::lower_bound;
+///
+/// let values = [1, 3, 3, 5];
+/// assert_eq!(lower_bound(&values, &3), 1);
+/// assert_eq!(lower_bound(&values, &4), 3);
+/// assert_eq!(lower_bound(&values, &0), 0);
+/// ```
+pub fn lower_bound<T: Ord>(values: &[T], target: &T) -> usize {
+    partition_point(values, |value| value < target)
+}
+
+/// Returns the index of the first value that is greater than `target`, or the
+/// length of the slice if there is none.
+///
+/// # Arguments
+///
+/// * `values` - A slice sorted in ascending order.
+/// * `target` - The value to bound.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::algorithm::upper_bound;
+///
+/// let values = [1, 3, 3, 5];
+/// assert_eq!(upper_bound(&values, &3), 3);
+/// assert_eq!(upper_bound(&values, &5), 4);
+/// ```
+pub fn upper_bound<T: Ord>(values: &[T], target: &T) -> usize {
+    partition_point(values, |value| value <= target)
+}
+
+/// Returns the range of indices whose values equal `target`. The range is empty,
+/// and starts at the insertion point, if no value does.
+///
+/// # Arguments
+///
+/// * `values` - A slice sorted in ascending order.
+/// * `target` - The value to look for.
+///
+/// # Examples
+///
+/// ```
+/// use binary_search::algorithm::equal_range;
+///
+/// let values = [1, 3, 3, 5];
+/// assert_eq!(equal_range(&values, &3), 1..3);
+/// assert_eq!(equal_range(&values, &4), 3..3);
+/// ```
+pub fn equal_range<T: Ord>(values: &[T], target: &T) -> Range<usize> {
+    let start = lower_bound(values, target);
+    let end = start + upper_bound(&values[start..], target);
+    start..end
+}
+
+/// Returns the number of leading values for which `before` holds, assuming it holds
+/// for a prefix of the slice and for nothing after it.
+///
+/// The midpoint is computed as `low + (high - low) / 2`, which cannot overflow even
+/// when the slice is close to `usize::MAX` long.
+fn partition_point<T, P>(values: &[T], mut before: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let (mut low, mut high) = (0, values.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if before(&values[middle]) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::ptr::NonNull;
+
+    #[test]
+    fn test_duplicates() {
+        let values = [2, 2, 2, 4, 4, 6, 6, 6, 6];
+
+        assert_eq!(binary_search(&values, &2), Ok(0));
+        assert_eq!(binary_search(&values, &4), Ok(3));
+        assert_eq!(binary_search(&values, &6), Ok(5));
+        assert_eq!(binary_search(&values, &5), Err(5));
+
+        assert_eq!(lower_bound(&values, &6), 5);
+        assert_eq!(upper_bound(&values, &6), 9);
+        assert_eq!(equal_range(&values, &2), 0..3);
+        assert_eq!(equal_range(&values, &4), 3..5);
+        assert_eq!(equal_range(&values, &3), 3..3);
+
+        let same = [7; 100];
+        assert_eq!(equal_range(&same, &7), 0..100);
+        assert_eq!(binary_search(&same, &7), Ok(0));
+    }
+
+    #[test]
+    fn test_empty_slice() {
+        let empty: [i32; 0] = [];
+        assert_eq!(binary_search(&empty, &1), Err(0));
+        assert_eq!(lower_bound(&empty, &1), 0);
+        assert_eq!(upper_bound(&empty, &1), 0);
+        assert_eq!(equal_range(&empty, &1), 0..0);
+    }
+
+    #[test]
+    fn test_targets_outside_the_range() {
+        let values = [10, 20, 30];
+
+        assert_eq!(binary_search(&values, &i32::MIN), Err(0));
+        assert_eq!(binary_search(&values, &i32::MAX), Err(3));
+        assert_eq!(equal_range(&values, &5), 0..0);
+        assert_eq!(equal_range(&values, &35), 3..3);
+        assert_eq!(upper_bound(&values, &30), 3);
+    }
+
+    #[test]
+    fn test_binary_search_by() {
+        let pairs = [(1, 'a'), (2, 'b'), (2, 'c'), (9, 'd')];
+        assert_eq!(binary_search_by(&pairs, |pair| pair.0.cmp(&2)), Ok(1));
+        assert_eq!(binary_search_by(&pairs, |pair| pair.0.cmp(&5)), Err(3));
+
+        let mut calls = 0;
+        let values: Vec<u32> = (0..1024).collect();
+        binary_search_by(&values, |value| {
+            calls += 1;
+            value.cmp(&700)
+        })
+        .unwrap();
+        // Ten halvings of 1024 values, plus the final equality check.
+        assert_eq!(calls, 11);
+    }
+
+    #[test]
+    fn test_huge_slice_does_not_overflow() {
+        // A slice of zero-sized values takes no memory at any length, so it can be
+        // as long as `usize::MAX`, where `(low + high) / 2` would overflow.
+        let huge: &[()] =
+            unsafe { std::slice::from_raw_parts(NonNull::dangling().as_ptr(), usize::MAX) };
+
+        assert_eq!(lower_bound(huge, &()), 0);
+        assert_eq!(upper_bound(huge, &()), usize::MAX);
+        assert_eq!(binary_search_by(huge, |_| Ordering::Less), Err(usize::MAX));
+        assert_eq!(binary_search_by(huge, |_| Ordering::Greater), Err(0));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_slice_binary_search(
+            mut values in proptest::collection::vec(-50i32..50, 0..100),
+            target in -60i32..60,
+        ) {
+            values.sort();
+            let expected = values.binary_search(&target);
+
+            match (binary_search(&values, &target), expected) {
+                // std may return any of several equal values; ours is the first.
+                (Ok(index), Ok(_)) => {
+                    prop_assert_eq!(values[index], target);
+                    prop_assert!(index == 0 || values[index - 1] < target);
+                }
+                (found, expected) => prop_assert_eq!(found, expected),
+            }
+
+            let range = equal_range(&values, &target);
+            prop_assert_eq!(range.start, values.partition_point(|value| *value < target));
+            prop_assert_eq!(range.end, values.partition_point(|value| *value <= target));
+            prop_assert_eq!(range.len(), values.iter().filter(|value| **value == target).count());
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use binary_search::algorithm::{binary_search, equal_range, lower_bound, upper_bound};
+
+fn main() {
+    let values = [1, 3, 3, 3, 5, 8, 13];
+    println!("VALUES {:?}", values);
+    println!("SEARCH 5 {:?}", binary_search(&values, &5));
+    println!("SEARCH 4 {:?}", binary_search(&values, &4));
+    println!("LOWER BOUND 3 {}", lower_bound(&values, &3));
+    println!("UPPER BOUND 3 {}", upper_bound(&values, &3));
+    println!("EQUAL RANGE 3 {:?}", equal_range(&values, &3));
+}
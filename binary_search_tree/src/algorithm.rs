@@ -0,0 +1,603 @@
+use std::cmp::Ordering;
+
+/// A link to a subtree, which is empty when `None`.
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+/// A node in the binary search tree.
+#[derive(Debug)]
+struct Node<K, V> {
+    /// The key the node is ordered by.
+    key: K,
+    /// The value stored under the key.
+    value: V,
+    /// The subtree of keys less than `key`.
+    left: Link<K, V>,
+    /// The subtree of keys greater than `key`.
+    right: Link<K, V>,
+}
+
+impl<K, V> Node<K, V> {
+    /// Creates a new leaf node with the given key and value.
+    fn new(key: K, value: V) -> Self {
+        Node {
+            key,
+            value,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// An unbalanced binary search tree mapping keys to values.
+///
+/// Every key in a node's left subtree is less than the node's key and every key in
+/// its right subtree is greater, so lookups follow a single path from the root.
+/// The tree does not rebalance itself, so operations are O(h) where `h` is the
+/// height: O(log n) for random insertions and O(n) for sorted ones.
+///
+/// All operations and traversals are iterative, so even a degenerate tree cannot
+/// overflow the stack.
+#[derive(Debug)]
+pub struct KolzoBst<K: Ord, V> {
+    /// The root of the tree.
+    root: Link<K, V>,
+    /// The number of entries in the tree.
+    length: usize,
+}
+
+impl<K: Ord, V> KolzoBst<K, V> {
+    /// Creates a new empty tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let tree: KolzoBst<i32, &str> = KolzoBst::new();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoBst {
+            root: None,
+            length: 0,
+        }
+    }
+
+    /// Inserts a key-value pair into the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to insert.
+    /// * `value` - The value to store under the key.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(V)` containing the previous value if the key was already present; the
+    ///   value is replaced and the key is kept.
+    /// * `None` if the key is new.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// assert_eq!(tree.insert(1, "a"), None);
+    /// assert_eq!(tree.insert(1, "b"), Some("a"));
+    /// assert_eq!(tree.get(&1), Some(&"b"));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let slot = self.slot_mut(&key);
+        match slot {
+            Some(node) => Some(std::mem::replace(&mut node.value, value)),
+            None => {
+                *slot = Some(Box::new(Node::new(key, value)));
+                self.length += 1;
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value stored under `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// tree.insert(2, "two");
+    /// assert_eq!(tree.get(&2), Some(&"two"));
+    /// assert_eq!(tree.get(&3), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// Removes `key` from the tree and returns its value.
+    ///
+    /// A leaf is simply unlinked and a node with one child is replaced by that
+    /// child. A node with two children is replaced by its in-order successor, the
+    /// smallest node of its right subtree.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(V)` containing the removed value if the key was present.
+    /// * `None` if the key was not in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// for key in [2, 1, 3] {
+    ///     tree.insert(key, key * 10);
+    /// }
+    ///
+    /// assert_eq!(tree.remove(&2), Some(20));
+    /// assert_eq!(tree.remove(&2), None);
+    /// assert_eq!(tree.iter().map(|(key, _)| *key).collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.slot_mut(key);
+        let mut node = slot.take()?;
+
+        *slot = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let (mut successor, rest) = Self::take_min(right);
+                successor.left = Some(left);
+                successor.right = rest;
+                Some(successor)
+            }
+        };
+
+        self.length -= 1;
+        Some(node.value)
+    }
+
+    /// Returns the entry with the smallest key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// assert_eq!(tree.min(), None);
+    ///
+    /// tree.insert(2, "b");
+    /// tree.insert(1, "a");
+    /// assert_eq!(tree.min(), Some((&1, &"a")));
+    /// ```
+    pub fn min(&self) -> Option<(&K, &V)> {
+        let mut current = self.root.as_deref()?;
+        while let Some(left) = current.left.as_deref() {
+            current = left;
+        }
+        Some((&current.key, &current.value))
+    }
+
+    /// Returns the entry with the largest key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// assert_eq!(tree.max(), None);
+    ///
+    /// tree.insert(1, "a");
+    /// tree.insert(2, "b");
+    /// assert_eq!(tree.max(), Some((&2, &"b")));
+    /// ```
+    pub fn max(&self) -> Option<(&K, &V)> {
+        let mut current = self.root.as_deref()?;
+        while let Some(right) = current.right.as_deref() {
+            current = right;
+        }
+        Some((&current.key, &current.value))
+    }
+
+    /// Returns the number of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the tree has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an in-order iterator over the entries, which yields the keys sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// for key in [2, 3, 1] {
+    ///     tree.insert(key, ());
+    /// }
+    /// let keys: Vec<_> = tree.iter().map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> InOrder<'_, K, V> {
+        let mut iter = InOrder {
+            stack: Vec::new(),
+            remaining: self.length,
+        };
+        iter.push_left_spine(self.root.as_deref());
+        iter
+    }
+
+    /// Returns a pre-order iterator, which yields each node before its subtrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// for key in [2, 3, 1] {
+    ///     tree.insert(key, ());
+    /// }
+    /// let keys: Vec<_> = tree.pre_order().map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, vec![2, 1, 3]);
+    /// ```
+    pub fn pre_order(&self) -> PreOrder<'_, K, V> {
+        PreOrder {
+            stack: self.root.as_deref().into_iter().collect(),
+            remaining: self.length,
+        }
+    }
+
+    /// Returns a post-order iterator, which yields each node after its subtrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binary_search_tree::algorithm::KolzoBst;
+    ///
+    /// let mut tree = KolzoBst::new();
+    /// for key in [2, 3, 1] {
+    ///     tree.insert(key, ());
+    /// }
+    /// let keys: Vec<_> = tree.post_order().map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, vec![1, 3, 2]);
+    /// ```
+    pub fn post_order(&self) -> PostOrder<'_, K, V> {
+        PostOrder {
+            stack: self
+                .root
+                .as_deref()
+                .map(|root| (root, false))
+                .into_iter()
+                .collect(),
+            remaining: self.length,
+        }
+    }
+
+    /// Returns the link that holds `key`, or the empty link where it would be inserted.
+    fn slot_mut(&mut self, key: &K) -> &mut Link<K, V> {
+        let mut slot = &mut self.root;
+        while slot.as_ref().is_some_and(|node| node.key != *key) {
+            let node = slot.as_mut().expect("the loop condition checked the link");
+            slot = if *key < node.key {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+        slot
+    }
+
+    /// Detaches the smallest node of the subtree rooted at `root`.
+    ///
+    /// # Returns
+    ///
+    /// The detached node, with both links cleared, and what remains of the subtree.
+    fn take_min(mut root: Box<Node<K, V>>) -> (Box<Node<K, V>>, Link<K, V>) {
+        if root.left.is_none() {
+            let rest = root.right.take();
+            return (root, rest);
+        }
+
+        let mut parent = &mut root;
+        while parent.left.as_ref().is_some_and(|left| left.left.is_some()) {
+            parent = parent
+                .left
+                .as_mut()
+                .expect("the loop condition checked the link");
+        }
+
+        let mut min = parent.left.take().expect("the root has a left child");
+        parent.left = min.right.take();
+        (min, Some(root))
+    }
+}
+
+impl<K: Ord, V> Default for KolzoBst<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> Drop for KolzoBst<K, V> {
+    /// Drops the nodes one at a time from an explicit stack so degenerate trees do
+    /// not overflow the call stack through the recursive drop of the child boxes.
+    fn drop(&mut self) {
+        let mut stack: Vec<_> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a KolzoBst<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = InOrder<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An in-order iterator over the entries of a [`KolzoBst`], in ascending key order.
+pub struct InOrder<'a, K, V> {
+    /// The nodes whose left subtrees have been pushed but which are not yet yielded.
+    stack: Vec<&'a Node<K, V>>,
+    /// The number of entries not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, K, V> InOrder<'a, K, V> {
+    /// Pushes `node` and every node along its chain of left children.
+    fn push_left_spine(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for InOrder<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for InOrder<'_, K, V> {}
+
+/// A pre-order iterator over the entries of a [`KolzoBst`].
+pub struct PreOrder<'a, K, V> {
+    /// The roots of the subtrees still to visit, the next one on top.
+    stack: Vec<&'a Node<K, V>>,
+    /// The number of entries not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for PreOrder<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.right.as_deref());
+        self.stack.extend(node.left.as_deref());
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for PreOrder<'_, K, V> {}
+
+/// A post-order iterator over the entries of a [`KolzoBst`].
+pub struct PostOrder<'a, K, V> {
+    /// The nodes still to visit, each paired with whether its children have
+    /// already been pushed.
+    stack: Vec<(&'a Node<K, V>, bool)>,
+    /// The number of entries not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for PostOrder<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, children_pushed)) = self.stack.pop() {
+            if children_pushed {
+                self.remaining -= 1;
+                return Some((&node.key, &node.value));
+            }
+
+            self.stack.push((node, true));
+            self.stack
+                .extend(node.right.as_deref().map(|right| (right, false)));
+            self.stack
+                .extend(node.left.as_deref().map(|left| (left, false)));
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for PostOrder<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    fn tree_from(keys: &[i32]) -> KolzoBst<i32, i32> {
+        let mut tree = KolzoBst::new();
+        for &key in keys {
+            tree.insert(key, key * 10);
+        }
+        tree
+    }
+
+    fn keys(tree: &KolzoBst<i32, i32>) -> Vec<i32> {
+        tree.iter().map(|(key, _)| *key).collect()
+    }
+
+    #[test]
+    fn test_insert_shuffled_iterates_sorted() {
+        let mut values: Vec<i32> = (0..1000).collect();
+        values.shuffle(&mut StdRng::seed_from_u64(218));
+        let tree = tree_from(&values);
+
+        assert_eq!(tree.len(), 1000);
+        assert_eq!(keys(&tree), (0..1000).collect::<Vec<_>>());
+        assert_eq!(tree.iter().len(), 1000);
+        assert_eq!(tree.min(), Some((&0, &0)));
+        assert_eq!(tree.max(), Some((&999, &9990)));
+    }
+
+    #[test]
+    fn test_insert_replaces_duplicate() {
+        let mut tree = tree_from(&[2, 1, 3]);
+
+        assert_eq!(tree.insert(1, 100), Some(10));
+        assert_eq!(tree.get(&1), Some(&100));
+        assert_eq!(tree.len(), 3);
+    }
+
+    #[test]
+    fn test_traversal_orders() {
+        //       4
+        //     /   \
+        //    2     6
+        //   / \   / \
+        //  1   3 5   7
+        let tree = tree_from(&[4, 2, 6, 1, 3, 5, 7]);
+
+        let pre: Vec<_> = tree.pre_order().map(|(key, _)| *key).collect();
+        let post: Vec<_> = tree.post_order().map(|(key, _)| *key).collect();
+        assert_eq!(pre, vec![4, 2, 1, 3, 6, 5, 7]);
+        assert_eq!(post, vec![1, 3, 2, 5, 7, 6, 4]);
+        assert_eq!(keys(&tree), vec![1, 2, 3, 4, 5, 6, 7]);
+
+        let empty: KolzoBst<i32, i32> = KolzoBst::new();
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.pre_order().next(), None);
+        assert_eq!(empty.post_order().next(), None);
+    }
+
+    #[test]
+    fn test_remove_leaf() {
+        let mut tree = tree_from(&[4, 2, 6, 1, 3]);
+
+        assert_eq!(tree.remove(&1), Some(10));
+        assert_eq!(keys(&tree), vec![2, 3, 4, 6]);
+        assert_eq!(tree.remove(&1), None);
+    }
+
+    #[test]
+    fn test_remove_one_child() {
+        let mut tree = tree_from(&[4, 2, 6, 1, 7]);
+
+        assert_eq!(tree.remove(&2), Some(20));
+        assert_eq!(tree.remove(&6), Some(60));
+        assert_eq!(keys(&tree), vec![1, 4, 7]);
+        assert_eq!(
+            tree.pre_order().map(|(key, _)| *key).collect::<Vec<_>>(),
+            vec![4, 1, 7]
+        );
+    }
+
+    #[test]
+    fn test_remove_two_children() {
+        let mut tree = tree_from(&[4, 2, 8, 6, 9, 5, 7]);
+
+        // The successor of 4 is 5, the leftmost node of the right subtree.
+        assert_eq!(tree.remove(&4), Some(40));
+        assert_eq!(
+            tree.pre_order().map(|(key, _)| *key).collect::<Vec<_>>(),
+            vec![5, 2, 8, 6, 7, 9]
+        );
+
+        // The successor of 8 is its right child 9, which has no left subtree.
+        assert_eq!(tree.remove(&8), Some(80));
+        assert_eq!(keys(&tree), vec![2, 5, 6, 7, 9]);
+    }
+
+    #[test]
+    fn test_remove_root() {
+        let mut tree = tree_from(&[1]);
+        assert_eq!(tree.remove(&1), Some(10));
+        assert!(tree.is_empty());
+        assert_eq!(tree.min(), None);
+
+        let mut tree = tree_from(&[1, 2, 3]);
+        assert_eq!(tree.remove(&1), Some(10));
+        assert_eq!(keys(&tree), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_matches_btree_map() {
+        let mut rng = StdRng::seed_from_u64(218);
+        let mut tree = KolzoBst::new();
+        let mut model = BTreeMap::new();
+
+        for _ in 0..10_000 {
+            let key = rng.gen_range(0..200);
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    let value: i32 = rng.gen();
+                    assert_eq!(tree.insert(key, value), model.insert(key, value));
+                }
+                2 => assert_eq!(tree.remove(&key), model.remove(&key)),
+                _ => assert_eq!(tree.get(&key), model.get(&key)),
+            }
+
+            assert_eq!(tree.len(), model.len());
+            assert_eq!(tree.min(), model.first_key_value());
+            assert_eq!(tree.max(), model.last_key_value());
+        }
+
+        assert!(tree.iter().eq(model.iter()));
+    }
+
+    #[test]
+    fn test_drop_degenerate_tree() {
+        let mut tree = KolzoBst::new();
+        for key in 0..20_000 {
+            tree.insert(key, ());
+        }
+        assert_eq!(tree.iter().count(), 20_000);
+        drop(tree);
+    }
+}
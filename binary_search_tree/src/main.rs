@@ -0,0 +1,17 @@
+use binary_search_tree::algorithm::KolzoBst;
+
+fn main() {
+    let mut tree = KolzoBst::new();
+    for key in [4, 2, 6, 1, 3, 5, 7] {
+        tree.insert(key, key * 10);
+    }
+    println!("IN-ORDER {:?}", tree.iter().collect::<Vec<_>>());
+    println!("PRE-ORDER {:?}", tree.pre_order().collect::<Vec<_>>());
+    println!("POST-ORDER {:?}", tree.post_order().collect::<Vec<_>>());
+    println!("GET 3 {:?}", tree.get(&3));
+    println!("REPLACE 3 {:?}", tree.insert(3, 33));
+    println!("MIN {:?} MAX {:?}", tree.min(), tree.max());
+    println!("REMOVE 4 {:?}", tree.remove(&4));
+    println!("IN-ORDER {:?}", tree.iter().collect::<Vec<_>>());
+    println!("LENGTH {}", tree.len());
+}
@@ -0,0 +1,44 @@
+use bit_set::algorithm::KolzoBitSet;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// The range of values, and the number of membership checks per run.
+const SIZE: usize = 1_000_000;
+
+/// Checks a million random values against sets holding every third value below a
+/// million. The bit set answers with one shift and mask on a 125 KB array, while
+/// the hash set hashes each value and probes a table many times that size.
+fn membership(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(246);
+    let queries: Vec<usize> = (0..SIZE).map(|_| rng.gen_range(0..SIZE)).collect();
+    let bit_set: KolzoBitSet = (0..SIZE).step_by(3).collect();
+    let hash_set: HashSet<usize> = (0..SIZE).step_by(3).collect();
+
+    let mut group = c.benchmark_group("contains_1m");
+    group.sample_size(20);
+
+    group.bench_function("bit_set", |b| {
+        b.iter(|| {
+            queries
+                .iter()
+                .filter(|&&value| bit_set.contains(black_box(value)))
+                .count()
+        })
+    });
+
+    group.bench_function("hash_set", |b| {
+        b.iter(|| {
+            queries
+                .iter()
+                .filter(|&&value| hash_set.contains(black_box(&value)))
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, membership);
+criterion_main!(benches);
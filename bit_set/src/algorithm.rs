@@ -0,0 +1,477 @@
+use std::ops::{Bound, RangeBounds};
+
+/// The number of bits in each block.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// Returns the block holding bit `index` and the mask selecting it in that block.
+fn locate(index: usize) -> (usize, u64) {
+    (index / BLOCK_BITS, 1 << (index % BLOCK_BITS))
+}
+
+/// Returns a block mask with the bits `low..high` set, for `low < high <= 64`.
+fn span_mask(low: usize, high: usize) -> u64 {
+    (u64::MAX >> (BLOCK_BITS - (high - low))) << low
+}
+
+/// A set of `usize` values stored as one bit per possible value.
+///
+/// Bit `i` lives in block `i / 64` at position `i % 64`. The set grows by whole
+/// blocks whenever a value beyond the current capacity is inserted or toggled, so
+/// the capacity is always a multiple of 64. Membership tests and updates are O(1),
+/// and the set algebra runs over whole blocks at a time.
+#[derive(Debug, Clone, Default)]
+pub struct KolzoBitSet {
+    /// The bits, 64 values per block, lowest values first.
+    blocks: Vec<u64>,
+}
+
+impl KolzoBitSet {
+    /// Creates a new empty set without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let set = KolzoBitSet::new();
+    /// assert!(set.is_empty());
+    /// assert_eq!(set.capacity(), 0);
+    /// ```
+    pub fn new() -> Self {
+        KolzoBitSet { blocks: Vec::new() }
+    }
+
+    /// Creates a new empty set with room for the values `0..bits` before it grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let set = KolzoBitSet::with_capacity(100);
+    /// assert_eq!(set.capacity(), 128);
+    /// ```
+    pub fn with_capacity(bits: usize) -> Self {
+        KolzoBitSet {
+            blocks: vec![0; bits.div_ceil(BLOCK_BITS)],
+        }
+    }
+
+    /// Returns the number of values the set can hold without growing, which is
+    /// always a multiple of 64.
+    pub fn capacity(&self) -> usize {
+        self.blocks.len() * BLOCK_BITS
+    }
+
+    /// Returns the number of values in the set, in O(capacity / 64).
+    pub fn len(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if the set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|&block| block == 0)
+    }
+
+    /// Adds a value to the set, growing it if the value is beyond the capacity.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value was not in the set before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set = KolzoBitSet::new();
+    /// assert!(set.insert(64));
+    /// assert!(!set.insert(64));
+    /// assert_eq!(set.capacity(), 128);
+    /// ```
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (block, mask) = locate(value);
+        self.grow_to(block + 1);
+        let absent = self.blocks[block] & mask == 0;
+        self.blocks[block] |= mask;
+        absent
+    }
+
+    /// Removes a value from the set. The capacity never shrinks.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value was in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set = KolzoBitSet::new();
+    /// set.insert(3);
+    /// assert!(set.remove(3));
+    /// assert!(!set.remove(3));
+    /// assert!(!set.remove(1_000));
+    /// ```
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (block, mask) = locate(value);
+        match self.blocks.get_mut(block) {
+            Some(bits) => {
+                let present = *bits & mask != 0;
+                *bits &= !mask;
+                present
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the set holds `value`. Values beyond the capacity are
+    /// never in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set = KolzoBitSet::new();
+    /// set.insert(63);
+    /// assert!(set.contains(63));
+    /// assert!(!set.contains(64));
+    /// ```
+    pub fn contains(&self, value: usize) -> bool {
+        let (block, mask) = locate(value);
+        self.blocks.get(block).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Adds every value of `other` to this set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set: KolzoBitSet = [1, 2].into_iter().collect();
+    /// set.union_with(&[2, 200].into_iter().collect());
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1, 2, 200]);
+    /// ```
+    pub fn union_with(&mut self, other: &KolzoBitSet) {
+        self.grow_to(other.blocks.len());
+        for (bits, other_bits) in self.blocks.iter_mut().zip(&other.blocks) {
+            *bits |= other_bits;
+        }
+    }
+
+    /// Keeps only the values that are also in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set: KolzoBitSet = [1, 2, 200].into_iter().collect();
+    /// set.intersect_with(&[2, 3].into_iter().collect());
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![2]);
+    /// ```
+    pub fn intersect_with(&mut self, other: &KolzoBitSet) {
+        for (index, bits) in self.blocks.iter_mut().enumerate() {
+            *bits &= other.blocks.get(index).copied().unwrap_or(0);
+        }
+    }
+
+    /// Removes every value that is in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set: KolzoBitSet = [1, 2, 200].into_iter().collect();
+    /// set.difference_with(&[2, 3].into_iter().collect());
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1, 200]);
+    /// ```
+    pub fn difference_with(&mut self, other: &KolzoBitSet) {
+        for (bits, other_bits) in self.blocks.iter_mut().zip(&other.blocks) {
+            *bits &= !other_bits;
+        }
+    }
+
+    /// Flips every value in `range`: values in the set are removed and the others
+    /// are added. The set grows to cover the end of the range.
+    ///
+    /// An unbounded end stops at the current capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let mut set: KolzoBitSet = [62, 63].into_iter().collect();
+    /// set.toggle_range(63..66);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![62, 64, 65]);
+    /// ```
+    pub fn toggle_range(&mut self, range: impl RangeBounds<usize>) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.capacity(),
+        };
+        if start >= end {
+            return;
+        }
+
+        let (first, last) = (start / BLOCK_BITS, (end - 1) / BLOCK_BITS);
+        self.grow_to(last + 1);
+        for block in first..=last {
+            // The first and last blocks may only be partly covered by the range.
+            let low = if block == first {
+                start % BLOCK_BITS
+            } else {
+                0
+            };
+            let high = if block == last {
+                (end - 1) % BLOCK_BITS + 1
+            } else {
+                BLOCK_BITS
+            };
+            self.blocks[block] ^= span_mask(low, high);
+        }
+    }
+
+    /// Returns an iterator over the values in the set, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bit_set::algorithm::KolzoBitSet;
+    ///
+    /// let set: KolzoBitSet = [130, 0, 64].into_iter().collect();
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 64, 130]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            blocks: &self.blocks,
+            block: 0,
+            remaining: self.blocks.first().copied().unwrap_or(0),
+        }
+    }
+
+    /// Adds zeroed blocks until there are at least `blocks` of them.
+    fn grow_to(&mut self, blocks: usize) {
+        if self.blocks.len() < blocks {
+            self.blocks.resize(blocks, 0);
+        }
+    }
+}
+
+impl Extend<usize> for KolzoBitSet {
+    /// Inserts every value of the iterator.
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl FromIterator<usize> for KolzoBitSet {
+    /// Builds a set holding every value of the iterator.
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = KolzoBitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a KolzoBitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`KolzoBitSet`], in increasing order.
+pub struct Iter<'a> {
+    /// Every block of the set.
+    blocks: &'a [u64],
+    /// The index of the block being read.
+    block: usize,
+    /// The bits of the current block that have not been yielded yet.
+    remaining: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining == 0 {
+            self.block += 1;
+            self.remaining = *self.blocks.get(self.block)?;
+        }
+
+        let offset = self.remaining.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.remaining &= self.remaining - 1;
+        Some(self.block * BLOCK_BITS + offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
+
+    fn values(set: &KolzoBitSet) -> Vec<usize> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_block_boundaries() {
+        let mut set = KolzoBitSet::with_capacity(64);
+        assert_eq!(set.capacity(), 64);
+
+        assert!(set.insert(63));
+        assert_eq!(set.capacity(), 64);
+        assert!(set.insert(64));
+        assert_eq!(set.capacity(), 128);
+        assert!(set.insert(65));
+        assert!(set.insert(0));
+
+        assert_eq!(values(&set), vec![0, 63, 64, 65]);
+        assert!(!set.contains(62) && !set.contains(66) && !set.contains(128));
+        assert_eq!(set.len(), 4);
+
+        assert!(set.remove(64));
+        assert_eq!(values(&set), vec![0, 63, 65]);
+        assert!(!set.remove(64));
+    }
+
+    #[test]
+    fn test_with_capacity_rounds_up_to_whole_blocks() {
+        assert_eq!(KolzoBitSet::with_capacity(0).capacity(), 0);
+        assert_eq!(KolzoBitSet::with_capacity(1).capacity(), 64);
+        assert_eq!(KolzoBitSet::with_capacity(65).capacity(), 128);
+        assert!(KolzoBitSet::with_capacity(1_000).is_empty());
+    }
+
+    #[test]
+    fn test_toggle_range_across_blocks() {
+        let mut set = KolzoBitSet::new();
+        set.toggle_range(60..200);
+        assert_eq!(values(&set), (60..200).collect::<Vec<_>>());
+        assert_eq!(set.capacity(), 256);
+
+        set.toggle_range(63..=128);
+        let expected: Vec<_> = (60..63).chain(129..200).collect();
+        assert_eq!(values(&set), expected);
+
+        // Whole blocks, a single bit, and empty ranges.
+        set.toggle_range(64..128);
+        set.toggle_range(0..1);
+        set.toggle_range(5..5);
+        let expected: Vec<_> = (0..1)
+            .chain(60..63)
+            .chain(64..128)
+            .chain(129..200)
+            .collect();
+        assert_eq!(values(&set), expected);
+
+        set.toggle_range(..);
+        assert_eq!(set.len(), 256 - expected.len());
+        assert!(set.contains(255) && !set.contains(0) && !set.contains(64));
+    }
+
+    #[test]
+    fn test_toggle_range_ending_on_block_edge() {
+        let mut set = KolzoBitSet::new();
+        set.toggle_range(0..64);
+        assert_eq!(set.capacity(), 64);
+        assert_eq!(set.len(), 64);
+
+        set.toggle_range(63..65);
+        assert_eq!(set.len(), 64);
+        assert!(!set.contains(63) && set.contains(64));
+    }
+
+    #[test]
+    fn test_set_algebra_with_different_capacities() {
+        let small: KolzoBitSet = [1, 63, 64].into_iter().collect();
+        let large: KolzoBitSet = [1, 64, 65, 300].into_iter().collect();
+
+        let mut union = small.clone();
+        union.union_with(&large);
+        assert_eq!(values(&union), vec![1, 63, 64, 65, 300]);
+
+        let mut intersection = large.clone();
+        intersection.intersect_with(&small);
+        assert_eq!(values(&intersection), vec![1, 64]);
+
+        let mut difference = large.clone();
+        difference.difference_with(&small);
+        assert_eq!(values(&difference), vec![65, 300]);
+
+        let mut difference = small;
+        difference.difference_with(&large);
+        assert_eq!(values(&difference), vec![63]);
+    }
+
+    #[test]
+    fn test_matches_hash_set() {
+        let mut rng = StdRng::seed_from_u64(246);
+        let mut set = KolzoBitSet::new();
+        let mut model = HashSet::new();
+
+        for _ in 0..5_000 {
+            let value = rng.gen_range(0..400);
+            match rng.gen_range(0..6) {
+                0 | 1 => assert_eq!(set.insert(value), model.insert(value)),
+                2 => assert_eq!(set.remove(value), model.remove(&value)),
+                3 => {
+                    let end = value + rng.gen_range(0..150);
+                    set.toggle_range(value..end);
+                    for toggled in value..end {
+                        if !model.remove(&toggled) {
+                            model.insert(toggled);
+                        }
+                    }
+                }
+                4 => {
+                    let other: HashSet<usize> = (0..20).map(|_| rng.gen_range(0..500)).collect();
+                    let other_set: KolzoBitSet = other.iter().copied().collect();
+                    match rng.gen_range(0..3) {
+                        0 => {
+                            set.union_with(&other_set);
+                            model.extend(&other);
+                        }
+                        1 => {
+                            set.intersect_with(&other_set);
+                            model.retain(|value| other.contains(value));
+                        }
+                        _ => {
+                            set.difference_with(&other_set);
+                            model.retain(|value| !other.contains(value));
+                        }
+                    }
+                }
+                _ => assert_eq!(set.contains(value), model.contains(&value)),
+            }
+
+            assert_eq!(set.len(), model.len());
+        }
+
+        let mut expected: Vec<_> = model.into_iter().collect();
+        expected.sort_unstable();
+        assert_eq!(values(&set), expected);
+    }
+}
@@ -0,0 +1,17 @@
+use bit_set::algorithm::KolzoBitSet;
+
+fn main() {
+    let mut evens: KolzoBitSet = (0..20).step_by(2).collect();
+    let small: KolzoBitSet = (0..8).collect();
+
+    println!("EVENS {:?}", evens.iter().collect::<Vec<_>>());
+    println!("CONTAINS 4 {}", evens.contains(4));
+    println!("LENGTH {}", evens.len());
+
+    evens.intersect_with(&small);
+    println!("EVENS BELOW 8 {:?}", evens.iter().collect::<Vec<_>>());
+
+    evens.toggle_range(60..70);
+    println!("TOGGLED {:?}", evens.iter().collect::<Vec<_>>());
+    println!("CAPACITY {}", evens.capacity());
+}
@@ -0,0 +1,492 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The error returned when a value cannot be pushed. It hands the value back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushError<T> {
+    /// The queue stayed full: immediately for `try_push`, or until the timeout for
+    /// `push_timeout`.
+    Full(T),
+    /// The queue has been closed.
+    Closed(T),
+}
+
+impl<T> PushError<T> {
+    /// Returns the value that could not be pushed.
+    pub fn into_inner(self) -> T {
+        match self {
+            PushError::Full(value) | PushError::Closed(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::Full(_) => write!(f, "the queue is full"),
+            PushError::Closed(_) => write!(f, "the queue is closed"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for PushError<T> {}
+
+/// The values and the closed flag, guarded together by the queue's mutex.
+struct State<T> {
+    /// The queued values, front first.
+    values: VecDeque<T>,
+    /// Whether `close` has been called.
+    closed: bool,
+}
+
+/// A first-in, first-out queue of fixed capacity for passing values between
+/// threads.
+///
+/// The values sit in a `VecDeque` behind a `Mutex`. Producers wait on the
+/// `not_full` condition variable while the queue is full, and consumers wait on
+/// `not_empty` while it is empty; every push signals `not_empty` and every pop
+/// signals `not_full`.
+///
+/// Closing the queue wakes every waiter. Pushes then fail, handing their value
+/// back, while pops keep draining the remaining values and return `None` once the
+/// queue is empty.
+pub struct KolzoBlockingQueue<T> {
+    /// The queued values and the closed flag.
+    state: Mutex<State<T>>,
+    /// Signalled when a value is pushed or the queue is closed.
+    not_empty: Condvar,
+    /// Signalled when a value is popped or the queue is closed.
+    not_full: Condvar,
+    /// The most values the queue holds at once.
+    capacity: usize,
+}
+
+impl<T> KolzoBlockingQueue<T> {
+    /// Creates a new empty, open queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The most values the queue holds at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blocking_queue::algorithm::KolzoBlockingQueue;
+    ///
+    /// let queue: KolzoBlockingQueue<i32> = KolzoBlockingQueue::new(8);
+    /// assert_eq!(queue.capacity(), 8);
+    /// assert!(queue.is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "a blocking queue needs a capacity of at least 1"
+        );
+        KolzoBlockingQueue {
+            state: Mutex::new(State {
+                values: VecDeque::with_capacity(capacity),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    /// Adds a value at the back of the queue, waiting as long as it takes for room.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the value is queued.
+    /// * `Err(value)` if the queue is closed, before or while waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blocking_queue::algorithm::KolzoBlockingQueue;
+    ///
+    /// let queue = KolzoBlockingQueue::new(1);
+    /// assert_eq!(queue.push(1), Ok(()));
+    /// queue.close();
+    /// assert_eq!(queue.push(2), Err(2));
+    /// ```
+    pub fn push(&self, value: T) -> Result<(), T> {
+        let mut state = self.lock();
+        while !state.closed && state.values.len() == self.capacity {
+            state = self
+                .not_full
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        self.push_locked(state, value)
+            .map_err(PushError::into_inner)
+    }
+
+    /// Adds a value at the back of the queue if there is room right now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blocking_queue::algorithm::{KolzoBlockingQueue, PushError};
+    ///
+    /// let queue = KolzoBlockingQueue::new(1);
+    /// assert_eq!(queue.try_push(1), Ok(()));
+    /// assert_eq!(queue.try_push(2), Err(PushError::Full(2)));
+    /// ```
+    pub fn try_push(&self, value: T) -> Result<(), PushError<T>> {
+        let state = self.lock();
+        self.push_locked(state, value)
+    }
+
+    /// Adds a value at the back of the queue, waiting at most `timeout` for room.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` once the value is queued.
+    /// * `Err(PushError::Full(value))` if the queue was still full at the deadline.
+    /// * `Err(PushError::Closed(value))` if the queue is closed.
+    pub fn push_timeout(&self, value: T, timeout: Duration) -> Result<(), PushError<T>> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.lock();
+        while !state.closed && state.values.len() == self.capacity {
+            let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                break;
+            };
+            state = self
+                .not_full
+                .wait_timeout(state, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+        self.push_locked(state, value)
+    }
+
+    /// Removes the value at the front of the queue, waiting as long as it takes
+    /// for one.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(value)` with the front value.
+    /// * `None` if the queue is closed and empty. Values queued before `close` are
+    ///   still returned first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use blocking_queue::algorithm::KolzoBlockingQueue;
+    ///
+    /// let queue = KolzoBlockingQueue::new(2);
+    /// queue.push(1).unwrap();
+    /// queue.close();
+    /// assert_eq!(queue.pop(), Some(1));
+    /// assert_eq!(queue.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self.lock();
+        while !state.closed && state.values.is_empty() {
+            state = self
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        self.pop_locked(state)
+    }
+
+    /// Removes the value at the front of the queue if there is one right now.
+    pub fn try_pop(&self) -> Option<T> {
+        let state = self.lock();
+        self.pop_locked(state)
+    }
+
+    /// Removes the value at the front of the queue, waiting at most `timeout` for
+    /// one.
+    ///
+    /// Returns `None` if the queue was still empty at the deadline, or is closed
+    /// and empty.
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.lock();
+        while !state.closed && state.values.is_empty() {
+            let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                break;
+            };
+            state = self
+                .not_empty
+                .wait_timeout(state, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+        self.pop_locked(state)
+    }
+
+    /// Closes the queue and wakes every waiting thread.
+    ///
+    /// Later pushes fail, and pops return the remaining values and then `None`.
+    /// Closing an already closed queue does nothing.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /// Returns `true` if the queue has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Returns the number of values in the queue. Other threads may change it as
+    /// soon as it is read.
+    pub fn len(&self) -> usize {
+        self.lock().values.len()
+    }
+
+    /// Returns `true` if the queue holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.lock().values.is_empty()
+    }
+
+    /// Returns the most values the queue holds at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Locks the state. A thread that panicked while holding the lock cannot have
+    /// left the state half updated, since no user code runs under it, so a
+    /// poisoned lock is used as is.
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Queues `value` if the queue is open and has room, and wakes one consumer.
+    fn push_locked(
+        &self,
+        mut state: MutexGuard<'_, State<T>>,
+        value: T,
+    ) -> Result<(), PushError<T>> {
+        if state.closed {
+            return Err(PushError::Closed(value));
+        }
+        if state.values.len() == self.capacity {
+            return Err(PushError::Full(value));
+        }
+
+        state.values.push_back(value);
+        drop(state);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Takes the front value, if any, and wakes one producer.
+    fn pop_locked(&self, mut state: MutexGuard<'_, State<T>>) -> Option<T> {
+        let value = state.values.pop_front()?;
+        drop(state);
+        self.not_full.notify_one();
+        Some(value)
+    }
+}
+
+impl<T> fmt::Debug for KolzoBlockingQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("KolzoBlockingQueue")
+            .field("len", &state.values.len())
+            .field("capacity", &self.capacity)
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    #[test]
+    fn test_fifo_order_and_capacity() {
+        let queue = KolzoBlockingQueue::new(3);
+        for value in 1..=3 {
+            assert_eq!(queue.try_push(value), Ok(()));
+        }
+        assert_eq!(queue.try_push(4), Err(PushError::Full(4)));
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.try_push(4), Ok(()));
+        assert_eq!(queue.try_pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(4));
+        assert_eq!(queue.try_pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity of at least 1")]
+    fn test_zero_capacity_panics() {
+        KolzoBlockingQueue::<i32>::new(0);
+    }
+
+    #[test]
+    fn test_producers_and_consumers_transfer_everything() {
+        const PRODUCERS: usize = 4;
+        const CONSUMERS: usize = 4;
+        const PER_PRODUCER: usize = 25_000;
+
+        let queue = KolzoBlockingQueue::new(64);
+        let producers_left = AtomicUsize::new(PRODUCERS);
+
+        let received: Vec<Vec<usize>> = thread::scope(|scope| {
+            for producer in 0..PRODUCERS {
+                let queue = &queue;
+                let producers_left = &producers_left;
+                scope.spawn(move || {
+                    for index in 0..PER_PRODUCER {
+                        queue.push(producer * PER_PRODUCER + index).unwrap();
+                    }
+                    // The last producer to finish closes the queue.
+                    if producers_left.fetch_sub(1, Ordering::AcqRel) == 1 {
+                        queue.close();
+                    }
+                });
+            }
+
+            let consumers: Vec<_> = (0..CONSUMERS)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut received = Vec::new();
+                        while let Some(value) = queue.pop() {
+                            received.push(value);
+                        }
+                        received
+                    })
+                })
+                .collect();
+            consumers
+                .into_iter()
+                .map(|consumer| consumer.join().unwrap())
+                .collect()
+        });
+
+        // Every value arrives exactly once, and each producer's values arrive in
+        // order at any one consumer.
+        let mut all: Vec<usize> = received.iter().flatten().copied().collect();
+        assert_eq!(all.len(), PRODUCERS * PER_PRODUCER);
+        all.sort_unstable();
+        assert!(all.iter().copied().eq(0..PRODUCERS * PER_PRODUCER));
+
+        for values in &received {
+            for producer in 0..PRODUCERS {
+                let from_producer = values
+                    .iter()
+                    .filter(|&&value| value / PER_PRODUCER == producer);
+                assert!(from_producer.is_sorted());
+            }
+        }
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_pop_timeout_times_out() {
+        let queue: KolzoBlockingQueue<i32> = KolzoBlockingQueue::new(1);
+        let timeout = Duration::from_millis(50);
+
+        let start = Instant::now();
+        assert_eq!(queue.pop_timeout(timeout), None);
+        assert!(start.elapsed() >= timeout);
+
+        queue.push(1).unwrap();
+        assert_eq!(queue.pop_timeout(timeout), Some(1));
+    }
+
+    #[test]
+    fn test_push_timeout_times_out() {
+        let queue = KolzoBlockingQueue::new(1);
+        let timeout = Duration::from_millis(50);
+        queue.push(1).unwrap();
+
+        let start = Instant::now();
+        assert_eq!(queue.push_timeout(2, timeout), Err(PushError::Full(2)));
+        assert!(start.elapsed() >= timeout);
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn test_push_timeout_succeeds_when_room_appears() {
+        let queue = KolzoBlockingQueue::new(1);
+        queue.push(1).unwrap();
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(20));
+                assert_eq!(queue.pop(), Some(1));
+            });
+            assert_eq!(queue.push_timeout(2, Duration::from_secs(10)), Ok(()));
+        });
+        assert_eq!(queue.try_pop(), Some(2));
+    }
+
+    #[test]
+    fn test_close_wakes_waiting_consumers() {
+        let queue: KolzoBlockingQueue<i32> = KolzoBlockingQueue::new(1);
+
+        thread::scope(|scope| {
+            let consumers: Vec<_> = (0..3).map(|_| scope.spawn(|| queue.pop())).collect();
+            thread::sleep(Duration::from_millis(20));
+            queue.close();
+
+            for consumer in consumers {
+                assert_eq!(consumer.join().unwrap(), None);
+            }
+        });
+    }
+
+    #[test]
+    fn test_close_wakes_waiting_producers() {
+        let queue = KolzoBlockingQueue::new(1);
+        queue.push(0).unwrap();
+
+        thread::scope(|scope| {
+            let producers: Vec<_> = (1..=3)
+                .map(|value| {
+                    let queue = &queue;
+                    scope.spawn(move || queue.push(value))
+                })
+                .collect();
+            thread::sleep(Duration::from_millis(20));
+            queue.close();
+
+            for (value, producer) in (1..=3).zip(producers) {
+                assert_eq!(producer.join().unwrap(), Err(value));
+            }
+        });
+    }
+
+    #[test]
+    fn test_close_drains_then_returns_none() {
+        let queue = KolzoBlockingQueue::new(4);
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        queue.close();
+        queue.close();
+
+        assert!(queue.is_closed());
+        assert_eq!(queue.try_push(3), Err(PushError::Closed(3)));
+        assert_eq!(
+            queue
+                .push_timeout(3, Duration::ZERO)
+                .unwrap_err()
+                .to_string(),
+            "the queue is closed"
+        );
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop_timeout(Duration::from_secs(10)), Some(2));
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.pop_timeout(Duration::from_secs(10)), None);
+    }
+}
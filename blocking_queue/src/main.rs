@@ -0,0 +1,23 @@
+use blocking_queue::algorithm::KolzoBlockingQueue;
+use std::thread;
+
+fn main() {
+    let queue = KolzoBlockingQueue::new(4);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            for value in 1..=10 {
+                queue.push(value).unwrap();
+            }
+            queue.close();
+        });
+
+        let mut received = Vec::new();
+        while let Some(value) = queue.pop() {
+            received.push(value);
+        }
+        println!("RECEIVED {:?}", received);
+    });
+
+    println!("CLOSED {} LENGTH {}", queue.is_closed(), queue.len());
+}
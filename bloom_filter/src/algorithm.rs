@@ -0,0 +1,396 @@
+use std::collections::hash_map::DefaultHasher;
+use std::f64::consts::LN_2;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// The seeds of the two hashers combined by double hashing.
+const SEEDS: [u64; 2] = [0x9e37_79b9_7f4a_7c15, 0xc2b2_ae3d_27d4_eb4f];
+
+/// The size of the header [`KolzoBloomFilter::to_bytes`] writes before the bits:
+/// the bit count as a `u64` and the hash count as a `u32`, both little-endian.
+const HEADER_LENGTH: usize = 12;
+
+/// The error returned by [`KolzoBloomFilter::from_bytes`] when the bytes do not
+/// describe a filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromBytesError {
+    /// What is wrong with the bytes.
+    reason: &'static str,
+}
+
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bloom filter bytes: {}", self.reason)
+    }
+}
+
+impl std::error::Error for FromBytesError {}
+
+/// A probabilistic set that answers "possibly present" or "definitely absent".
+///
+/// Inserting a value sets `hash_count` bits of a fixed bit array, and a value is
+/// reported present only if all of its bits are set. A value that was inserted is
+/// therefore always reported present, while a value that was not may be reported
+/// present too if other values happened to set all of its bits. The values
+/// themselves are never stored, so they cannot be listed or removed.
+///
+/// The bit positions come from double hashing: two hashers with different seeds
+/// give `h1` and `h2`, and the `i`-th position is `h1 + i * h2` modulo the number
+/// of bits. This behaves like `hash_count` independent hashes for a fraction of
+/// the cost.
+pub struct KolzoBloomFilter<T: Hash + ?Sized> {
+    /// The bit array, eight bits per byte, least significant bit first. Bits past
+    /// `bit_count` in the last byte are always clear.
+    bits: Box<[u8]>,
+    /// The number of bits in the array.
+    bit_count: usize,
+    /// The number of bits set per value.
+    hash_count: u32,
+    /// The filter only hashes values, it never owns them.
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Hash + ?Sized> KolzoBloomFilter<T> {
+    /// Creates a new empty filter sized for `expected_items` values at the given
+    /// false-positive rate.
+    ///
+    /// The number of bits is `m = -n ln(p) / ln(2)^2` and the number of hashes is
+    /// `k = (m / n) ln(2)`, which minimizes the false-positive rate for `n` values
+    /// in `m` bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_items` - The number of values the filter is expected to hold.
+    /// * `false_positive_rate` - The target probability that an absent value is
+    ///   reported present once `expected_items` values have been inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bloom_filter::algorithm::KolzoBloomFilter;
+    ///
+    /// let filter: KolzoBloomFilter<str> = KolzoBloomFilter::with_rate(1_000, 0.01);
+    /// assert_eq!(filter.bit_count(), 9_586);
+    /// assert_eq!(filter.hash_count(), 7);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `expected_items` is zero or `false_positive_rate` is not strictly
+    /// between 0 and 1.
+    pub fn with_rate(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            expected_items > 0,
+            "a bloom filter needs at least 1 expected item"
+        );
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "the false-positive rate must be strictly between 0 and 1"
+        );
+
+        let items = expected_items as f64;
+        let bit_count = (-items * false_positive_rate.ln() / (LN_2 * LN_2)).ceil() as usize;
+        let hash_count = ((bit_count as f64 / items) * LN_2).round().max(1.0) as u32;
+        KolzoBloomFilter {
+            bits: vec![0; bit_count.div_ceil(8)].into_boxed_slice(),
+            bit_count,
+            hash_count,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds a value to the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bloom_filter::algorithm::KolzoBloomFilter;
+    ///
+    /// let mut filter = KolzoBloomFilter::with_rate(100, 0.01);
+    /// filter.insert(&42);
+    /// assert!(filter.contains(&42));
+    /// ```
+    pub fn insert(&mut self, value: &T) {
+        for index in self.bit_indices(value) {
+            self.bits[index / 8] |= 1 << (index % 8);
+        }
+    }
+
+    /// Returns `false` if `value` was never inserted, and `true` if it probably
+    /// was.
+    ///
+    /// There are no false negatives: every inserted value is reported present.
+    pub fn contains(&self, value: &T) -> bool {
+        self.bit_indices(value)
+            .all(|index| self.bits[index / 8] & (1 << (index % 8)) != 0)
+    }
+
+    /// Removes every value, keeping the size of the filter.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Estimates how many distinct values have been inserted from the number of
+    /// set bits, as `-(m / k) ln(1 - X / m)` for `X` set bits.
+    ///
+    /// The estimate is infinite once every bit is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bloom_filter::algorithm::KolzoBloomFilter;
+    ///
+    /// let mut filter = KolzoBloomFilter::with_rate(1_000, 0.01);
+    /// for value in 0..500 {
+    ///     filter.insert(&value);
+    /// }
+    /// assert!((filter.estimated_count() - 500.0).abs() < 25.0);
+    /// ```
+    pub fn estimated_count(&self) -> f64 {
+        let set_bits: u32 = self.bits.iter().map(|byte| byte.count_ones()).sum();
+        let bit_count = self.bit_count as f64;
+        -(bit_count / self.hash_count as f64) * (1.0 - set_bits as f64 / bit_count).ln()
+    }
+
+    /// Returns the number of bits in the filter.
+    pub fn bit_count(&self) -> usize {
+        self.bit_count
+    }
+
+    /// Returns the number of bits set per inserted value.
+    pub fn hash_count(&self) -> u32 {
+        self.hash_count
+    }
+
+    /// Serializes the filter: a 12-byte header with the bit count and hash count,
+    /// then the bit array.
+    ///
+    /// The positions of a value's bits depend on the standard library's default
+    /// hasher, which is not guaranteed to be stable across Rust releases, so the
+    /// bytes should be read back by a build of the same toolchain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bloom_filter::algorithm::KolzoBloomFilter;
+    ///
+    /// let mut filter = KolzoBloomFilter::with_rate(100, 0.01);
+    /// filter.insert("kolzo");
+    ///
+    /// let restored = KolzoBloomFilter::<str>::from_bytes(&filter.to_bytes()).unwrap();
+    /// assert!(restored.contains("kolzo"));
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LENGTH + self.bits.len());
+        bytes.extend_from_slice(&(self.bit_count as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.hash_count.to_le_bytes());
+        bytes.extend_from_slice(&self.bits);
+        bytes
+    }
+
+    /// Reads back a filter written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The serialized filter.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(filter)` - The filter, answering exactly as the one serialized.
+    /// * `Err(FromBytesError)` - If the header is truncated or invalid, or the bit
+    ///   array does not match it.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let invalid = |reason| Err(FromBytesError { reason });
+        if bytes.len() < HEADER_LENGTH {
+            return invalid("the header is truncated");
+        }
+        let (header, bits) = bytes.split_at(HEADER_LENGTH);
+        let bit_count = u64::from_le_bytes(header[..8].try_into().unwrap());
+        let hash_count = u32::from_le_bytes(header[8..].try_into().unwrap());
+
+        let Ok(bit_count) = usize::try_from(bit_count) else {
+            return invalid("the bit count does not fit in memory");
+        };
+        if bit_count == 0 || hash_count == 0 {
+            return invalid("the bit count and hash count must be positive");
+        }
+        if bits.len() != bit_count.div_ceil(8) {
+            return invalid("the bit array length does not match the bit count");
+        }
+        let unused_bits = bits.len() * 8 - bit_count;
+        if unused_bits > 0 && bits[bits.len() - 1] >> (8 - unused_bits) != 0 {
+            return invalid("bits past the bit count are set");
+        }
+
+        Ok(KolzoBloomFilter {
+            bits: bits.into(),
+            bit_count,
+            hash_count,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the indices of the `hash_count` bits for `value`.
+    fn bit_indices(&self, value: &T) -> impl Iterator<Item = usize> {
+        let [first, second] = SEEDS.map(|seed| {
+            let mut hasher = DefaultHasher::new();
+            hasher.write_u64(seed);
+            value.hash(&mut hasher);
+            hasher.finish()
+        });
+        // An odd step never shares a factor of two with the bit count, so the
+        // positions do not collapse onto a few bits.
+        let second = second | 1;
+        let bit_count = self.bit_count as u64;
+        (0..self.hash_count as u64)
+            .map(move |i| (first.wrapping_add(i.wrapping_mul(second)) % bit_count) as usize)
+    }
+}
+
+impl<T: Hash + ?Sized> Clone for KolzoBloomFilter<T> {
+    fn clone(&self) -> Self {
+        KolzoBloomFilter {
+            bits: self.bits.clone(),
+            bit_count: self.bit_count,
+            hash_count: self.hash_count,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Hash + ?Sized> fmt::Debug for KolzoBloomFilter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KolzoBloomFilter")
+            .field("bit_count", &self.bit_count)
+            .field("hash_count", &self.hash_count)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sizes_from_the_formulas() {
+        let filter: KolzoBloomFilter<u32> = KolzoBloomFilter::with_rate(1_000, 0.01);
+        assert_eq!(filter.bit_count(), 9_586);
+        assert_eq!(filter.hash_count(), 7);
+
+        let filter: KolzoBloomFilter<u32> = KolzoBloomFilter::with_rate(10_000, 0.001);
+        assert_eq!(filter.bit_count(), 143_776);
+        assert_eq!(filter.hash_count(), 10);
+
+        // A loose rate still sets at least one bit per value.
+        let filter: KolzoBloomFilter<u32> = KolzoBloomFilter::with_rate(10, 0.9);
+        assert_eq!(filter.hash_count(), 1);
+    }
+
+    #[test]
+    fn test_no_false_negatives() {
+        let mut filter = KolzoBloomFilter::with_rate(5_000, 0.01);
+        for value in 0..5_000u32 {
+            filter.insert(&value);
+        }
+        for value in 0..5_000u32 {
+            assert!(filter.contains(&value), "{} was inserted", value);
+        }
+
+        // Past the expected count, the answers degrade but never to a false negative.
+        for value in 5_000..20_000u32 {
+            filter.insert(&value);
+        }
+        assert!((0..20_000u32).all(|value| filter.contains(&value)));
+    }
+
+    #[test]
+    fn test_false_positive_rate() {
+        for rate in [0.05, 0.01, 0.001] {
+            let mut filter = KolzoBloomFilter::with_rate(10_000, rate);
+            for value in 0..10_000u64 {
+                filter.insert(&value);
+            }
+
+            let probes = 100_000;
+            let false_positives = (10_000..10_000 + probes)
+                .filter(|value| filter.contains(value))
+                .count();
+            let measured = false_positives as f64 / probes as f64;
+            assert!(
+                measured <= 2.0 * rate,
+                "measured {} for a configured rate of {}",
+                measured,
+                rate
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimated_count() {
+        let mut filter = KolzoBloomFilter::with_rate(10_000, 0.01);
+        assert_eq!(filter.estimated_count(), 0.0);
+
+        for value in 0..8_000u32 {
+            filter.insert(&value);
+        }
+        let estimate = filter.estimated_count();
+        assert!((estimate - 8_000.0).abs() < 200.0, "estimated {}", estimate);
+
+        // Repeated values set no new bits.
+        for value in 0..8_000u32 {
+            filter.insert(&value);
+        }
+        assert_eq!(filter.estimated_count(), estimate);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut filter = KolzoBloomFilter::with_rate(100, 0.01);
+        filter.insert("a");
+        filter.clear();
+
+        assert!(!filter.contains("a"));
+        assert_eq!(filter.estimated_count(), 0.0);
+        assert_eq!(filter.bit_count(), 959);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut filter = KolzoBloomFilter::with_rate(1_000, 0.02);
+        for value in (0..2_000u32).step_by(2) {
+            filter.insert(&value);
+        }
+
+        let bytes = filter.to_bytes();
+        assert_eq!(bytes.len(), HEADER_LENGTH + filter.bit_count().div_ceil(8));
+        let restored = KolzoBloomFilter::<u32>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.bit_count(), filter.bit_count());
+        assert_eq!(restored.hash_count(), filter.hash_count());
+        assert_eq!(restored.to_bytes(), bytes);
+        assert!((0..4_000u32).all(|value| restored.contains(&value) == filter.contains(&value)));
+    }
+
+    #[test]
+    fn test_from_invalid_bytes() {
+        let filter: KolzoBloomFilter<u32> = KolzoBloomFilter::with_rate(100, 0.01);
+        let bytes = filter.to_bytes();
+        let read = |bytes: &[u8]| KolzoBloomFilter::<u32>::from_bytes(bytes).map(|_| ());
+
+        assert!(read(&bytes[..HEADER_LENGTH - 1]).is_err());
+        assert!(read(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut zero_hashes = bytes.clone();
+        zero_hashes[8..HEADER_LENGTH].fill(0);
+        assert!(read(&zero_hashes).is_err());
+
+        // 959 bits leave the top bit of the last byte unused.
+        let mut stray_bit = bytes.clone();
+        *stray_bit.last_mut().unwrap() |= 0x80;
+        let error = read(&stray_bit).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid bloom filter bytes: bits past the bit count are set"
+        );
+    }
+}
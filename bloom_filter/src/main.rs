@@ -0,0 +1,22 @@
+use bloom_filter::algorithm::KolzoBloomFilter;
+
+fn main() {
+    let mut filter = KolzoBloomFilter::with_rate(1_000, 0.01);
+    println!("BITS {} HASHES {}", filter.bit_count(), filter.hash_count());
+
+    for word in ["apple", "banana", "cherry"] {
+        filter.insert(word);
+    }
+    for word in ["apple", "cherry", "durian"] {
+        println!("CONTAINS {} {}", word, filter.contains(word));
+    }
+    println!("ESTIMATED COUNT {:.1}", filter.estimated_count());
+
+    let bytes = filter.to_bytes();
+    let restored = KolzoBloomFilter::<str>::from_bytes(&bytes).unwrap();
+    println!(
+        "RESTORED {} BYTES, CONTAINS banana {}",
+        bytes.len(),
+        restored.contains("banana")
+    );
+}
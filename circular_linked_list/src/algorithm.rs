@@ -0,0 +1,514 @@
+use list_common::node::{allocate, free};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// A node in the circular linked list.
+#[derive(Debug)]
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The next node in the circle. The last node links back to the first, so
+    /// this always points at a live node while the node is part of a list.
+    next: NonNull<Node<T>>,
+}
+
+impl<T> Node<T> {
+    /// Returns the node after this one in the circle.
+    fn next_node(&self) -> &Node<T> {
+        // SAFETY: a linked node always points at a live node of the same circle, and
+        // freeing that node takes `&mut` to the list, which this borrow excludes.
+        unsafe { self.next.as_ref() }
+    }
+}
+
+/// A circular singly linked list whose last node links back to the first.
+///
+/// The list keeps a single raw pointer to its tail. The current element is the
+/// node after the tail, so appending behind the current element, advancing it and
+/// removing it are all O(1).
+///
+/// Nodes are owned by the list as a whole rather than by their predecessor, since
+/// a cycle of boxes could never be freed.
+pub struct KolzoCircularList<T> {
+    /// The node just before the current one, or `None` if the list is empty.
+    tail: Option<NonNull<Node<T>>>,
+    /// The number of elements in the circle.
+    length: usize,
+    /// Tells the drop checker that the list owns values of type `T`.
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the circle is only reachable through `tail`, which the list owns, so
+// sending the list sends all of its nodes. `&self` methods only read nodes.
+unsafe impl<T: Send> Send for KolzoCircularList<T> {}
+unsafe impl<T: Sync> Sync for KolzoCircularList<T> {}
+
+impl<T> KolzoCircularList<T> {
+    /// Creates a new empty circular list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circular_linked_list::algorithm::KolzoCircularList;
+    ///
+    /// let list: KolzoCircularList<i32> = KolzoCircularList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoCircularList {
+            tail: None,
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Appends a value to the circle in O(1).
+    ///
+    /// The value is placed just before the current element, so it is the last one
+    /// reached when walking the circle from the current element.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to append.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circular_linked_list::algorithm::KolzoCircularList;
+    ///
+    /// let mut list = KolzoCircularList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// assert_eq!(list.current(), Some(&1));
+    /// assert_eq!(list.iter_n(2).collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    pub fn append(&mut self, value: T) {
+        let mut new_node = allocate(Node {
+            data: value,
+            next: NonNull::dangling(),
+        });
+
+        // SAFETY: `new_node` was just allocated and nothing else points at it yet, and
+        // `tail` is a live node owned by this list.
+        unsafe {
+            match self.tail {
+                Some(mut tail_pointer) => {
+                    new_node.as_mut().next = tail_pointer.as_ref().next;
+                    tail_pointer.as_mut().next = new_node;
+                }
+                // A single node links to itself.
+                None => new_node.as_mut().next = new_node,
+            }
+        }
+
+        self.tail = Some(new_node);
+        self.length += 1;
+    }
+
+    /// Advances the current element by one position in O(1).
+    ///
+    /// Rotating an empty list does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circular_linked_list::algorithm::KolzoCircularList;
+    ///
+    /// let mut list = KolzoCircularList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// list.rotate();
+    /// assert_eq!(list.current(), Some(&2));
+    /// list.rotate();
+    /// assert_eq!(list.current(), Some(&1));
+    /// ```
+    pub fn rotate(&mut self) {
+        if let Some(tail_pointer) = self.tail {
+            // SAFETY: `tail` is a live node owned by this list.
+            self.tail = Some(unsafe { tail_pointer.as_ref() }.next);
+        }
+    }
+
+    /// Returns a reference to the current element.
+    pub fn current(&self) -> Option<&T> {
+        // SAFETY: `tail` is a live node owned by this list, and the returned
+        // reference borrows `self`, which keeps the node after it alive.
+        self.tail
+            .map(|tail_pointer| &unsafe { tail_pointer.as_ref() }.next_node().data)
+    }
+
+    /// Removes and returns the current element in O(1).
+    ///
+    /// The element after it becomes the new current element.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the removed value if the list is not empty.
+    /// * `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circular_linked_list::algorithm::KolzoCircularList;
+    ///
+    /// let mut list = KolzoCircularList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// assert_eq!(list.remove_current(), Some(1));
+    /// assert_eq!(list.current(), Some(&2));
+    /// assert_eq!(list.remove_current(), Some(2));
+    /// assert_eq!(list.remove_current(), None);
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        let mut tail_pointer = self.tail?;
+
+        // SAFETY: `tail` and the node it links to are live nodes owned by this list.
+        // The removed node is unlinked before it is freed, so no pointer to it
+        // remains.
+        let removed = unsafe {
+            let current_pointer = tail_pointer.as_ref().next;
+            if current_pointer == tail_pointer {
+                self.tail = None;
+            } else {
+                tail_pointer.as_mut().next = current_pointer.as_ref().next;
+            }
+            free(current_pointer)
+        };
+
+        self.length -= 1;
+        Some(removed.data)
+    }
+
+    /// Returns an iterator over `count` elements, starting at the current one.
+    ///
+    /// The iterator wraps around the circle as often as needed, and it always
+    /// stops after `count` elements, so it can never loop forever. An empty list
+    /// yields nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of elements to yield.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circular_linked_list::algorithm::KolzoCircularList;
+    ///
+    /// let mut list = KolzoCircularList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// let values: Vec<_> = list.iter_n(5).copied().collect();
+    /// assert_eq!(values, vec![1, 2, 1, 2, 1]);
+    /// ```
+    pub fn iter_n(&self, count: usize) -> IterN<'_, T> {
+        // SAFETY: `tail` is a live node owned by this list, which the iterator
+        // borrows.
+        let next = self
+            .tail
+            .map(|tail_pointer| unsafe { tail_pointer.as_ref() }.next_node());
+        IterN {
+            next,
+            remaining: if next.is_some() { count } else { 0 },
+        }
+    }
+
+    /// Returns the number of elements in the circle.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the circle has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+impl<T> Default for KolzoCircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoCircularList<T> {
+    /// Formats the elements once around the circle, starting at the current one.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_n(self.length)).finish()
+    }
+}
+
+impl<T> Drop for KolzoCircularList<T> {
+    /// Frees the nodes one at a time, starting at the current one. The walk is
+    /// bounded by `length`, so it stops at the tail instead of circling forever.
+    fn drop(&mut self) {
+        let Some(tail_pointer) = self.tail.take() else {
+            return;
+        };
+
+        // SAFETY: `tail` is a live node owned by this list.
+        let mut current = unsafe { tail_pointer.as_ref() }.next;
+        for _ in 0..self.length {
+            // SAFETY: the circle holds exactly `length` nodes, so each one is reached
+            // and freed exactly once, and the link read from the tail after it is
+            // freed is never followed.
+            current = unsafe { free(current) }.next;
+        }
+    }
+}
+
+/// An iterator over a bounded number of elements of a [`KolzoCircularList`].
+pub struct IterN<'a, T> {
+    /// The node whose value is yielded next, or `None` for an empty list.
+    next: Option<&'a Node<T>>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for IterN<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.next?;
+        self.next = Some(node.next_node());
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IterN<'_, T> {}
+
+// SAFETY: the iterator only reads nodes of a shared-borrowed list.
+unsafe impl<T: Sync> Send for IterN<'_, T> {}
+unsafe impl<T: Sync> Sync for IterN<'_, T> {}
+
+/// Solves the Josephus problem by simulating it on a [`KolzoCircularList`].
+///
+/// `n` people, numbered from `0`, stand in a circle. Counting starts at person `0`
+/// and every `k`-th person is eliminated, with counting resuming at the next
+/// person, until one remains.
+///
+/// # Arguments
+///
+/// * `n` - The number of people in the circle.
+/// * `k` - The step size; the `k`-th person counted is eliminated.
+///
+/// # Returns
+///
+/// The 0-indexed original position of the survivor.
+///
+/// # Examples
+///
+/// ```
+/// use circular_linked_list::algorithm::josephus;
+///
+/// assert_eq!(josephus(7, 3), 3);
+/// assert_eq!(josephus(1, 5), 0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` or `k` is `0`.
+pub fn josephus(n: usize, k: usize) -> usize {
+    assert!(n > 0, "the circle must contain at least one person");
+    assert!(k > 0, "the step size must be greater than zero");
+
+    let mut circle = KolzoCircularList::new();
+    for position in 0..n {
+        circle.append(position);
+    }
+
+    while circle.len() > 1 {
+        for _ in 1..k {
+            circle.rotate();
+        }
+        circle.remove_current();
+    }
+
+    circle
+        .remove_current()
+        .expect("the last person in the circle survives")
+}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p circular_linked_list`, with the default Stacked
+// Borrows model and with `MIRIFLAGS=-Zmiri-tree-borrows`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_append_and_rotate() {
+        let mut list = KolzoCircularList::new();
+        list.rotate();
+        assert_eq!(list.current(), None);
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.current(), Some(&1));
+
+        list.rotate();
+        list.rotate();
+        assert_eq!(list.current(), Some(&3));
+
+        list.append(4);
+        assert_eq!(
+            list.iter_n(4).copied().collect::<Vec<_>>(),
+            vec![3, 1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn test_remove_current() {
+        let mut list = KolzoCircularList::new();
+        assert_eq!(list.remove_current(), None);
+
+        for value in 1..=4 {
+            list.append(value);
+        }
+        list.rotate();
+
+        assert_eq!(list.remove_current(), Some(2));
+        assert_eq!(list.current(), Some(&3));
+        assert_eq!(list.iter_n(3).copied().collect::<Vec<_>>(), vec![3, 4, 1]);
+
+        assert_eq!(list.remove_current(), Some(3));
+        assert_eq!(list.remove_current(), Some(4));
+        assert_eq!(list.remove_current(), Some(1));
+        assert_eq!(list.remove_current(), None);
+        assert!(list.is_empty());
+
+        list.append(5);
+        assert_eq!(list.current(), Some(&5));
+    }
+
+    #[test]
+    fn test_iter_n_is_bounded() {
+        let empty: KolzoCircularList<i32> = KolzoCircularList::new();
+        assert_eq!(empty.iter_n(10).next(), None);
+
+        let mut list = KolzoCircularList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let mut iter = list.iter_n(1000);
+        assert_eq!(iter.len(), 1000);
+        assert_eq!(iter.by_ref().count(), 1000);
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.iter_n(0).next(), None);
+        assert_eq!(
+            list.iter_n(7).copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 1, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn test_josephus_known_results() {
+        assert_eq!(josephus(7, 3), 3);
+        assert_eq!(josephus(5, 2), 2);
+        assert_eq!(josephus(41, 3), 30);
+        assert_eq!(josephus(1, 1), 0);
+        assert_eq!(josephus(10, 1), 9);
+    }
+
+    #[test]
+    fn test_josephus_matches_recurrence() {
+        for k in 1..6 {
+            let mut survivor = 0;
+            for n in 1..50 {
+                if n > 1 {
+                    survivor = (survivor + k) % n;
+                }
+                assert_eq!(josephus(n, k), survivor, "n = {n}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_josephus_empty_circle_panics() {
+        josephus(0, 3);
+    }
+
+    #[test]
+    fn test_drop_frees_every_node_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        {
+            let mut list = KolzoCircularList::new();
+            for _ in 0..5 {
+                list.append(DropCounter(Rc::clone(&drops)));
+            }
+            list.rotate();
+            drop(list.remove_current());
+            assert_eq!(drops.get(), 1);
+        }
+
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_debug_lists_one_lap_from_the_current_element() {
+        let mut list = KolzoCircularList::new();
+        assert_eq!(format!("{list:?}"), "[]");
+
+        for value in 1..=3 {
+            list.append(value);
+        }
+        list.rotate();
+        assert_eq!(format!("{list:?}"), "[2, 3, 1]");
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send::<KolzoCircularList<String>>();
+        assert_sync::<KolzoCircularList<String>>();
+        assert_send::<IterN<'static, String>>();
+        assert_sync::<IterN<'static, String>>();
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let mut list = KolzoCircularList::new();
+        for value in 0..size(200_000) {
+            list.append(value);
+        }
+        drop(list);
+    }
+}
@@ -0,0 +1,17 @@
+use circular_linked_list::algorithm::{josephus, KolzoCircularList};
+
+fn main() {
+    let mut list = KolzoCircularList::new();
+    list.append(1);
+    list.append(2);
+    list.append(3);
+    println!("{:?}", list.iter_n(7).collect::<Vec<_>>());
+    list.rotate();
+    println!("CURRENT {:?}", list.current());
+    println!("REMOVE CURRENT {:?}", list.remove_current());
+    println!("{:?}", list.iter_n(list.len()).collect::<Vec<_>>());
+    println!("LENGTH {}", list.len());
+
+    println!("JOSEPHUS (7, 3) SURVIVOR {}", josephus(7, 3));
+    println!("JOSEPHUS (41, 3) SURVIVOR {}", josephus(41, 3));
+}
@@ -0,0 +1,307 @@
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+
+pub use double_linked_list::algorithm::Iter;
+
+/// A double-ended queue built on the doubly linked list.
+///
+/// Every operation at either end is O(1): the front is the head of the list and
+/// the back is reached through its tail pointer. The deque only exposes the
+/// operations that keep those guarantees, and places no bounds on `T`.
+#[derive(Debug)]
+pub struct KolzoDeque<T> {
+    /// The list holding the elements, front first.
+    list: KolzoDoublyLinkedList<T>,
+}
+
+impl<T> KolzoDeque<T> {
+    /// Creates a new empty deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let deque: KolzoDeque<i32> = KolzoDeque::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoDeque {
+            list: KolzoDoublyLinkedList::new(),
+        }
+    }
+
+    /// Adds a value to the front of the deque in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to push.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let mut deque = KolzoDeque::new();
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.list.prepend(value);
+    }
+
+    /// Adds a value to the back of the deque in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to push.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let mut deque = KolzoDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.list.append(value);
+    }
+
+    /// Removes and returns the value at the front of the deque in O(1).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the front value if the deque is not empty.
+    /// * `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let mut deque = KolzoDeque::from(vec![1, 2]);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_first()
+    }
+
+    /// Removes and returns the value at the back of the deque in O(1).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the back value if the deque is not empty.
+    /// * `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let mut deque = KolzoDeque::from(vec![1, 2]);
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop()
+    }
+
+    /// Returns a reference to the value at the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let mut deque = KolzoDeque::new();
+    /// assert_eq!(deque.front(), None);
+    ///
+    /// deque.push_back("first");
+    /// deque.push_back("second");
+    /// assert_eq!(deque.front(), Some(&"first"));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.list.iter().next()
+    }
+
+    /// Returns a reference to the value at the back of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let mut deque = KolzoDeque::new();
+    /// assert_eq!(deque.back(), None);
+    ///
+    /// deque.push_back("first");
+    /// deque.push_back("second");
+    /// assert_eq!(deque.back(), Some(&"second"));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.list.iter().next_back()
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the deque has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns a double-ended iterator over the elements from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use deque::algorithm::KolzoDeque;
+    ///
+    /// let deque = KolzoDeque::from(vec![1, 2, 3]);
+    /// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(deque.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+}
+
+impl<T> Default for KolzoDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for KolzoDeque<T> {
+    /// Pushes every value of the iterator onto the back of the deque.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for KolzoDeque<T> {
+    /// Builds a deque whose front is the first element of the vector.
+    fn from(values: Vec<T>) -> Self {
+        let mut deque = KolzoDeque::new();
+        deque.extend(values);
+        deque
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_push_and_pop_both_ends() {
+        let mut deque = KolzoDeque::new();
+
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.len(), 3);
+
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+        assert!(deque.is_empty());
+
+        deque.push_front(4);
+        assert_eq!(deque.front(), Some(&4));
+        assert_eq!(deque.back(), Some(&4));
+    }
+
+    #[test]
+    fn test_iter() {
+        let deque: KolzoDeque<i32> = KolzoDeque::new();
+        assert_eq!(deque.iter().next(), None);
+
+        let deque = KolzoDeque::from(vec![1, 2, 3, 4]);
+        let mut iter = deque.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+
+        let values: Vec<_> = (&deque).into_iter().rev().copied().collect();
+        assert_eq!(values, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_extend_and_from_vec() {
+        let mut deque = KolzoDeque::from(vec![1, 2]);
+        deque.extend(vec![3, 4]);
+
+        assert_eq!(deque.len(), 4);
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        let empty: KolzoDeque<i32> = KolzoDeque::from(Vec::new());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(215);
+        let mut deque = KolzoDeque::new();
+        let mut model = VecDeque::new();
+
+        for _ in 0..10_000 {
+            match rng.gen_range(0..6) {
+                0 => {
+                    let value: i32 = rng.gen();
+                    deque.push_front(value);
+                    model.push_front(value);
+                }
+                1 => {
+                    let value: i32 = rng.gen();
+                    deque.push_back(value);
+                    model.push_back(value);
+                }
+                2 => assert_eq!(deque.pop_front(), model.pop_front()),
+                3 => assert_eq!(deque.pop_back(), model.pop_back()),
+                4 => assert_eq!(deque.front(), model.front()),
+                _ => assert_eq!(deque.back(), model.back()),
+            }
+
+            assert_eq!(deque.len(), model.len());
+            assert_eq!(deque.is_empty(), model.is_empty());
+        }
+
+        assert!(deque.iter().eq(model.iter()));
+        assert!(deque.iter().rev().eq(model.iter().rev()));
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send::<KolzoDeque<String>>();
+        assert_sync::<KolzoDeque<String>>();
+    }
+}
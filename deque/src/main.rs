@@ -0,0 +1,55 @@
+use deque::algorithm::KolzoDeque;
+
+/// Returns the maximum of every window of `window` consecutive values.
+///
+/// The deque holds indices whose values are in decreasing order, so the front is
+/// always the maximum of the current window. Each index is pushed and popped at
+/// most once, which makes the whole scan O(n).
+fn sliding_window_maximum(values: &[i32], window: usize) -> Vec<i32> {
+    let mut maximums = Vec::new();
+    if window == 0 {
+        return maximums;
+    }
+
+    let mut candidates: KolzoDeque<usize> = KolzoDeque::new();
+    for (index, &value) in values.iter().enumerate() {
+        if candidates
+            .front()
+            .is_some_and(|&front| front + window <= index)
+        {
+            candidates.pop_front();
+        }
+        while candidates.back().is_some_and(|&back| values[back] <= value) {
+            candidates.pop_back();
+        }
+        candidates.push_back(index);
+
+        if index + 1 >= window {
+            if let Some(&front) = candidates.front() {
+                maximums.push(values[front]);
+            }
+        }
+    }
+
+    maximums
+}
+
+fn main() {
+    let mut deque = KolzoDeque::from(vec![2, 3]);
+    deque.push_front(1);
+    deque.push_back(4);
+    println!("{:?}", deque.iter().collect::<Vec<_>>());
+    println!("REVERSED {:?}", deque.iter().rev().collect::<Vec<_>>());
+    println!("FRONT {:?} BACK {:?}", deque.front(), deque.back());
+    println!("POP FRONT {:?}", deque.pop_front());
+    println!("POP BACK {:?}", deque.pop_back());
+    deque.extend([5, 6]);
+    println!("{:?}", deque.iter().collect::<Vec<_>>());
+    println!("LENGTH {}", deque.len());
+
+    let values = [1, 3, -1, -3, 5, 3, 6, 7];
+    println!(
+        "SLIDING WINDOW MAXIMUM {:?}",
+        sliding_window_maximum(&values, 3)
+    );
+}
@@ -1,15 +1,38 @@
+use list_common::arena::SharedArena;
+use list_common::linear::LinearList;
+use list_common::memory::{DeepMeasure, MemoryReport};
+use list_common::node::NodeAllocator;
+use list_common::pretty::{render, RenderOptions};
+#[cfg(feature = "stats")]
+use list_common::stats::ListStats;
+use list_common::stats::StatsCounter;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
 /// A node in the doubly linked list.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Every node is reached from both of its neighbours, and a [`NodeHandle`] points
+/// straight at one, so nodes are made by the list's [`NodeAllocator`], on the heap
+/// or in a [`SharedArena`], and linked by raw pointers in both directions.
+#[derive(Debug)]
 pub struct Node<T> {
     /// The data stored in the node.
     data: T,
     /// The previous node in the doubly linked list.
-    previous: Option<Box<Node<T>>>,
+    previous: Option<NonNull<Node<T>>>,
     /// The next node in the doubly linked list.
-    next: Option<Box<Node<T>>>,
+    next: Option<NonNull<Node<T>>>,
 }
 
 impl<T> Node<T> {
+    /// Creates a new node with the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to store in the node.
     pub fn new(value: T) -> Self {
         Node {
             data: value,
@@ -17,33 +40,99 @@ impl<T> Node<T> {
             next: None,
         }
     }
+
+    /// Returns the node after this one.
+    fn next_node(&self) -> Option<&Node<T>> {
+        // SAFETY: `next` points at a node of this list, and unlinking it takes
+        // `&mut` to the list, which cannot coexist with this borrow.
+        self.next.map(|next| unsafe { &*next.as_ptr() })
+    }
 }
 
 /// A doubly linked list implementation in Rust.
-#[derive(Debug)]
 pub struct KolzoDoublyLinkedList<T> {
     /// The head of the doubly linked list.
-    head: Option<Box<Node<T>>>,
-    /// The tail of the doubly linked list, represented as a raw pointer for efficient appending.
-    tail: Option<*mut Node<T>>,
+    head: Option<NonNull<Node<T>>>,
+    /// The tail of the doubly linked list, kept for efficient appending.
+    tail: Option<NonNull<Node<T>>>,
     /// The length of the doubly linked list.
     length: u64,
+    /// Counters for node visits, allocations and frees, active with the `stats` feature.
+    stats: StatsCounter,
+    /// Makes and frees the nodes, on the heap or in the arena given to
+    /// [`KolzoDoublyLinkedList::new_in`].
+    allocator: NodeAllocator<Node<T>>,
+    /// The list owns boxed nodes holding `T`.
+    marker: PhantomData<Box<Node<T>>>,
 }
 
-impl<T: std::fmt::Debug + Clone> KolzoDoublyLinkedList<T> {
+// SAFETY: `head`, `tail` and the `previous`/`next` links only point at nodes the list
+// owns, and no node is shared with another list. `&self` methods only read nodes,
+// and the stats counters are atomics or zero-sized. An arena is only touched under
+// its lock. A `NodeHandle` is a pointer into the list but is not `Send`, so it
+// cannot follow the list to another thread.
+unsafe impl<T: Send> Send for KolzoDoublyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for KolzoDoublyLinkedList<T> {}
+
+impl<T> KolzoDoublyLinkedList<T> {
     /// Creates a new empty doubly linked list.
     ///
     /// # Examples
     ///
     /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
     /// let list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
-    /// assert_eq!(list.length, 0);
+    /// assert_eq!(list.get(0), None);
     /// ```
     pub fn new() -> Self {
+        Self::with_allocator(NodeAllocator::heap())
+    }
+
+    /// Creates a new empty doubly linked list whose nodes are allocated in `arena`.
+    ///
+    /// The list behaves exactly like one made by [`KolzoDoublyLinkedList::new`], but
+    /// instead of one heap allocation per node it takes slots from the arena's
+    /// chunks, and the slots of removed nodes are reused by later appends. Any
+    /// number of lists, on any threads, can share one arena.
+    ///
+    /// # Arguments
+    ///
+    /// * `arena` - The arena to allocate the nodes in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    /// use list_common::arena::SharedArena;
+    ///
+    /// let arena = SharedArena::with_chunk_size(256);
+    /// let mut list = KolzoDoublyLinkedList::new_in(&arena);
+    /// for value in 0..100 {
+    ///     list.append(value);
+    /// }
+    /// while list.pop().is_some() {}
+    ///
+    /// // The popped nodes give their slots back for the next prepends.
+    /// for value in 0..200 {
+    ///     list.prepend(value);
+    /// }
+    /// assert_eq!(arena.chunk_count(), 1);
+    /// assert_eq!(list.get(0), Some(&199));
+    /// ```
+    pub fn new_in(arena: &SharedArena<Node<T>>) -> Self {
+        Self::with_allocator(NodeAllocator::in_arena(arena))
+    }
+
+    /// Creates an empty list that allocates its nodes with `allocator`.
+    fn with_allocator(allocator: NodeAllocator<Node<T>>) -> Self {
         KolzoDoublyLinkedList {
             head: None,
             tail: None,
             length: 0,
+            stats: StatsCounter::new(),
+            allocator,
+            marker: PhantomData,
         }
     }
 
@@ -52,20 +141,27 @@ impl<T: std::fmt::Debug + Clone> KolzoDoublyLinkedList<T> {
     /// # Examples
     ///
     /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
     /// let mut list = KolzoDoublyLinkedList::new();
     /// list.append(1);
     /// list.append(2);
     /// list.append(3);
     /// list.print(); // Output: 1 -> 2 -> 3 -> None
     /// ```
-    pub fn print(&self) {
-        let mut current = self.head.as_ref();
+    pub fn print(&self)
+    where
+        T: std::fmt::Debug,
+    {
+        let mut current = self.head_node();
         while let Some(node) = current {
+            self.stats.record_visit();
             print!("{:?} -> ", node.data);
-            current = node.next.as_ref();
+            current = node.next_node();
         }
         println!("None");
     }
+
     /// Appends a new node with the given value to the end of the doubly linked list.
     ///
     /// # Arguments
@@ -75,72 +171,1434 @@ impl<T: std::fmt::Debug + Clone> KolzoDoublyLinkedList<T> {
     /// # Examples
     ///
     /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
     /// let mut list = KolzoDoublyLinkedList::new();
     /// list.append(1);
     /// list.append(2);
     /// list.append(3);
-    /// assert_eq!(list.length, 3);
+    /// assert_eq!(list.get(2), Some(&3));
     /// ```
     ///
-    /// # Safety
-    ///
-    /// This method uses raw pointers to modify the internal structure of the doubly linked list.
-    /// It is marked as `unsafe` because dereferencing raw pointers can lead to undefined behavior
-    /// if not done correctly.
-    ///
     /// # Panics
     ///
     /// This method does not panic.
     pub fn append(&mut self, value: T) {
-        let new_node = Box::new(Node::new(value));
-        let new_node_ptr: *mut _ = Box::into_raw(new_node);
+        let mut new_node = self.allocator.allocate(Node::new(value));
+        self.stats.record_allocation();
 
-        unsafe {
-            if self.head.is_none() {
-                self.head = Some(Box::from_raw(new_node_ptr));
-                self.tail = Some(new_node_ptr);
-            } else {
-                if let Some(current) = self.tail {
-                    (*current).next = Some(Box::from_raw(new_node_ptr));
-                    (*new_node_ptr).previous = Some(Box::from_raw(current));
-                    self.tail = Some(new_node_ptr);
-                }
+        match self.tail {
+            // SAFETY: `tail` points at the last node, which the list owns, and the
+            // new node is not linked anywhere yet.
+            Some(mut tail_pointer) => unsafe {
+                new_node.as_mut().previous = Some(tail_pointer);
+                tail_pointer.as_mut().next = Some(new_node);
+            },
+            None => {
+                self.head = Some(new_node);
             }
-
-            self.length += 1;
         }
+
+        self.tail = Some(new_node);
+        self.length += 1;
     }
 
-    pub fn pop(&mut self, value: T) {
-        // Some code
+    /// Removes and returns the last element from the doubly linked list.
+    ///
+    /// Unlike the singly linked list, this does not traverse the list: the new tail
+    /// is reached through the `previous` pointer of the current one.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<T>` - The value of the removed node if the list is not empty, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// assert_eq!(list.pop(), Some(2));
+    /// assert_eq!(list.pop(), Some(1));
+    /// assert_eq!(list.pop(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn pop(&mut self) -> Option<T> {
+        let tail_pointer = self.tail?;
+        // SAFETY: `tail` points at a node the list owns.
+        Some(unsafe { self.unlink(tail_pointer) })
     }
 
+    /// Adds a value to the beginning of the doubly linked list.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to be added to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.prepend(1);
+    /// list.prepend(2);
+    /// list.prepend(3);
+    /// // The list now looks like: 3 -> 2 -> 1 -> None
+    /// assert_eq!(list.get(0), Some(&3));
+    /// ```
     pub fn prepend(&mut self, value: T) {
-        // Some code
+        let mut new_node = self.allocator.allocate(Node::new(value));
+        self.stats.record_allocation();
+
+        match self.head {
+            // SAFETY: `head` points at the first node, which the list owns, and the
+            // new node is not linked anywhere yet.
+            Some(mut old_head) => unsafe {
+                old_head.as_mut().previous = Some(new_node);
+                new_node.as_mut().next = Some(old_head);
+            },
+            None => {
+                self.tail = Some(new_node);
+            }
+        }
+
+        self.head = Some(new_node);
+        self.length += 1;
+    }
+
+    /// Removes the first element from the doubly linked list and returns it, if it exists.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)` containing the value of the first element if the list is not empty.
+    /// - `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// assert_eq!(list.pop_first(), Some(1));
+    /// assert_eq!(list.pop_first(), Some(2));
+    /// assert_eq!(list.pop_first(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn pop_first(&mut self) -> Option<T> {
+        let head_pointer = self.head?;
+        // SAFETY: `head` points at a node the list owns.
+        Some(unsafe { self.unlink(head_pointer) })
+    }
+
+    /// Retrieves a reference to the element at the specified index in the doubly linked list.
+    ///
+    /// The list is walked from whichever end is closer to `index`, so at most
+    /// `length / 2` nodes are visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to retrieve. Must be a non-negative integer.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` containing a reference to the element at the specified index if it exists.
+    /// * `None` if the index is out of bounds or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    /// list.append(30);
+    ///
+    /// assert_eq!(list.get(0), Some(&10));
+    /// assert_eq!(list.get(2), Some(&30));
+    /// assert_eq!(list.get(3), None);
+    /// assert_eq!(list.get(-1), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn get(&self, index: i64) -> Option<&T> {
+        if index.is_negative() {
+            return None;
+        }
+
+        self.node_at(index as u64)
+            // SAFETY: the node is owned by the list, which stays borrowed for as
+            // long as the reference.
+            .map(|node| unsafe { &(*node.as_ptr()).data })
+    }
+
+    /// Updates the value of the element at the specified index in the doubly linked list
+    /// and returns the old value.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to update. Must be a non-negative integer.
+    /// * `value` - The new value to set at the specified index.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the old value of the element at the specified index if it exists.
+    /// * `None` if the index is out of bounds or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    ///
+    /// assert_eq!(list.set(1, 25), Some(20));
+    /// assert_eq!(list.get(1), Some(&25));
+    /// assert_eq!(list.set(2, 40), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn set(&mut self, index: i64, value: T) -> Option<T> {
+        if index.is_negative() {
+            return None;
+        }
+
+        self.node_at(index as u64)
+            // SAFETY: the node is owned by the list, which we borrow mutably.
+            .map(|node| std::mem::replace(unsafe { &mut (*node.as_ptr()).data }, value))
+    }
+
+    /// Inserts a new element with the specified value at the given index in the doubly linked list.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position at which to insert the new element. Must be a non-negative integer.
+    /// * `value` - The value to insert into the doubly linked list.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was inserted.
+    /// * `false` if the index is negative or greater than the length of the list, in
+    ///   which case the value is dropped and the list is unchanged.
+    ///
+    /// # Behavior
+    ///
+    /// * If the index is `0`, the new element is prepended to the list.
+    /// * If the index is equal to the length of the list, the new element is appended to the list.
+    /// * Otherwise, the new element is linked in front of the element currently at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(10);
+    /// list.append(30);
+    ///
+    /// assert!(list.insert(1, 20));
+    /// assert!(list.insert(3, 40));
+    /// assert!(!list.insert(5, 50));
+    /// assert!(!list.insert(-1, 0));
+    ///
+    /// assert_eq!(list.get(1), Some(&20));
+    /// assert_eq!(list.get(3), Some(&40));
+    /// assert_eq!(list.len(), 4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn insert(&mut self, index: i64, value: T) -> bool {
+        if index.is_negative() || index as u64 > self.length {
+            return false;
+        }
+
+        if index == 0 {
+            self.prepend(value);
+            return true;
+        }
+
+        if index as u64 == self.length {
+            self.append(value);
+            return true;
+        }
+
+        let mut node_pointer = self
+            .node_at(index as u64)
+            .expect("an index below the length has a node");
+        // SAFETY: the node is neither the head nor the tail, so it has a
+        // predecessor, and both are owned by the list, which we borrow mutably.
+        // The new node is not linked anywhere yet.
+        unsafe {
+            let mut previous_pointer = node_pointer
+                .as_ref()
+                .previous
+                .expect("a node after the head has a predecessor");
+            let mut new_node = self.allocator.allocate(Node::new(value));
+            self.stats.record_allocation();
+
+            new_node.as_mut().previous = Some(previous_pointer);
+            new_node.as_mut().next = Some(node_pointer);
+            node_pointer.as_mut().previous = Some(new_node);
+            previous_pointer.as_mut().next = Some(new_node);
+        }
+
+        self.length += 1;
+        true
+    }
+
+    /// Removes the element at the specified index from the doubly linked list.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be removed. Must be a non-negative integer and less than the length of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` with the removed value if the index is in bounds.
+    /// * `None` if the index is negative or not less than the length, in which case
+    ///   the list is unchanged.
+    ///
+    /// # Behavior
+    ///
+    /// * If the index is `0`, the head element is removed.
+    /// * If the index is the last element, the tail element is removed.
+    /// * For all other indices, the neighbours of the element are linked to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    /// list.append(30);
+    ///
+    /// assert_eq!(list.remove(1), Some(20));
+    /// assert_eq!(list.remove(2), None);
+    ///
+    /// assert_eq!(list.get(0), Some(&10));
+    /// assert_eq!(list.get(1), Some(&30));
+    /// assert_eq!(list.get(2), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn remove(&mut self, index: i64) -> Option<T> {
+        if index.is_negative() || index as u64 >= self.length {
+            return None;
+        }
+
+        if index == 0 {
+            return self.pop_first();
+        }
+
+        if index as u64 == self.length - 1 {
+            return self.pop();
+        }
+
+        let node_pointer = self.node_at(index as u64)?;
+        // SAFETY: the node is owned by the list.
+        Some(unsafe { self.unlink(node_pointer) })
+    }
+
+    /// Reverses the doubly linked list in place.
+    ///
+    /// # Description
+    /// Every node swaps its `next` and `previous` links. After the operation the
+    /// head of the list is the original tail and the tail is the original head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.append(3);
+    ///
+    /// list.reverse();
+    ///
+    /// assert_eq!(list.get(0), Some(&3));
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(2), Some(&1));
+    /// ```
+    ///
+    /// # Panics
+    /// This function does not panic.
+    pub fn reverse(&mut self) {
+        let mut current_node = self.head;
+        while let Some(mut node_that_is_iterated) = current_node {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes the list owns, and nothing else
+            // borrows the list while we hold `&mut self`.
+            let node = unsafe { node_that_is_iterated.as_mut() };
+            current_node = node.next;
+            std::mem::swap(&mut node.next, &mut node.previous);
+        }
+
+        std::mem::swap(&mut self.head, &mut self.tail);
     }
 
-    pub fn pop_first(&mut self, value: T) {
-        // Some code
+    /// Returns the number of elements in the doubly linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length as usize
     }
 
-    pub fn get(&mut self, value: T) {
-        // Some code
+    /// Returns `true` if the doubly linked list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
     }
 
-    pub fn set(&mut self, value: T) {
-        // Some code
+    /// Returns an iterator over the elements from head to tail.
+    ///
+    /// The iterator is double-ended, so `.rev()` walks from tail to head through
+    /// the `previous` links without collecting the list first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.append(3);
+    ///
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head_node(),
+            // SAFETY: `tail` points at the last node, which the list owns and which
+            // lives as long as the borrow of `self`.
+            back: self
+                .tail
+                .map(|tail_pointer| unsafe { &*tail_pointer.as_ptr() }),
+            remaining: self.length as usize,
+        }
     }
 
-    pub fn insert(&mut self, value: T) {
-        // Some code
+    /// Prints the doubly linked list as boxed nodes joined by arrows, with an index ruler underneath.
+    ///
+    /// See [`list_common::pretty::render`] for the layout and for drawing with
+    /// other [`RenderOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.pretty_print();
+    /// // Output:
+    /// //         +---+     +---+
+    /// // None <- | 1 | <-> | 2 | -> None
+    /// //         +---+     +---+
+    /// //           0         1
+    /// ```
+    pub fn pretty_print(&self)
+    where
+        T: std::fmt::Display,
+    {
+        println!("{}", render(self, RenderOptions::default()));
     }
 
-    pub fn remove(&mut self, value: T) {
-        // Some code
+    /// Reports how much heap memory the nodes of the doubly linked list use.
+    ///
+    /// The figures are computed from `size_of::<Node<T>>()` and the length, so only
+    /// the inline size of each value is counted. Use
+    /// [`KolzoDoublyLinkedList::deep_memory_footprint`] to also count memory the values own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1i32);
+    /// list.append(2);
+    ///
+    /// let report = list.memory_footprint();
+    /// assert_eq!(report.node_count, 2);
+    /// assert_eq!(report.payload_bytes, 8);
+    /// assert!(report.total_bytes > report.payload_bytes);
+    /// ```
+    pub fn memory_footprint(&self) -> MemoryReport {
+        MemoryReport::new(
+            self.length as usize,
+            std::mem::size_of::<Node<T>>(),
+            std::mem::size_of::<T>(),
+            0,
+        )
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Reports how much heap memory the doubly linked list uses, including the heap memory
+    /// owned by its values.
+    ///
+    /// Unlike [`KolzoDoublyLinkedList::memory_footprint`], this walks every node to ask its
+    /// value for the heap bytes it owns, so it runs in O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(String::with_capacity(100));
+    ///
+    /// let shallow = list.memory_footprint();
+    /// let deep = list.deep_memory_footprint();
+    /// assert_eq!(deep.total_bytes, shallow.total_bytes + 100);
+    /// ```
+    pub fn deep_memory_footprint(&self) -> MemoryReport
+    where
+        T: DeepMeasure,
+    {
+        let mut heap_bytes = 0;
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            heap_bytes += node.data.heap_bytes();
+            current = node.next_node();
+        }
+
+        MemoryReport::new(
+            self.length as usize,
+            std::mem::size_of::<Node<T>>(),
+            std::mem::size_of::<T>(),
+            heap_bytes,
+        )
+    }
+
+    /// Returns the node visit, allocation and free counts recorded since the list
+    /// was created or the counters were last reset.
+    ///
+    /// Only available with the `stats` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// list.pop();
+    ///
+    /// let stats = list.stats();
+    /// assert_eq!(stats.allocations, 2);
+    /// assert_eq!(stats.frees, 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> ListStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets the node visit, allocation and free counts back to zero.
+    ///
+    /// Only available with the `stats` feature.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// Returns the first node.
+    fn head_node(&self) -> Option<&Node<T>> {
+        // SAFETY: `head` points at a live node the list owns, and the reference
+        // borrows `self`.
+        self.head.map(|head| unsafe { &*head.as_ptr() })
+    }
+
+    /// Returns a pointer to the node at `index`, walking from the nearer end of the
+    /// list.
+    ///
+    /// Every node the walk lands on is recorded as a visit.
+    fn node_at(&self, index: u64) -> Option<NonNull<Node<T>>> {
+        if index >= self.length {
+            return None;
+        }
+
+        // SAFETY: links point at live nodes the list owns.
+        if index < self.length / 2 {
+            let mut current = self.head?;
+            self.stats.record_visit();
+            for _ in 0..index {
+                current = unsafe { current.as_ref().next? };
+                self.stats.record_visit();
+            }
+            Some(current)
+        } else {
+            let mut current = self.tail?;
+            self.stats.record_visit();
+            for _ in index + 1..self.length {
+                current = unsafe { current.as_ref().previous? };
+                self.stats.record_visit();
+            }
+            Some(current)
+        }
+    }
+
+    /// Unlinks `node` from its neighbours, frees it and returns its value, in O(1).
+    ///
+    /// # Safety
+    ///
+    /// `node` must point at a node that is linked into this list.
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        // SAFETY: guaranteed by the caller; the neighbours are owned by the list too.
+        let removed = unsafe { self.allocator.free(node) };
+        match removed.previous {
+            Some(mut previous_pointer) => unsafe { previous_pointer.as_mut().next = removed.next },
+            None => self.head = removed.next,
+        }
+        match removed.next {
+            Some(mut next_pointer) => unsafe { next_pointer.as_mut().previous = removed.previous },
+            None => self.tail = removed.previous,
+        }
+
+        self.length -= 1;
+        self.stats.record_free();
+        removed.data
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> KolzoDoublyLinkedList<T> {
+    /// Returns a parallel iterator over references to the elements of the doubly linked list.
+    ///
+    /// A linked list cannot be split in half without walking it, so this first
+    /// collects a reference to every element into a `Vec` in one O(n) pass and
+    /// hands that `Vec` to rayon. The setup cost only pays off when the work done
+    /// per element outweighs it.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.append(3);
+    ///
+    /// let sum: i32 = list.par_iter().map(|value| value * 2).sum();
+    /// assert_eq!(sum, 12);
+    /// ```
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T>
+    where
+        T: Sync,
+    {
+        let mut references = Vec::with_capacity(self.length as usize);
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            references.push(&node.data);
+            current = node.next_node();
+        }
+
+        references.into_par_iter()
+    }
+
+    /// Returns a parallel iterator over mutable references to the elements of the doubly linked list.
+    ///
+    /// The references are collected in one O(n) pass, as in [`KolzoDoublyLinkedList::par_iter`].
+    /// The walk follows the `next` links from the head, so each reference borrows
+    /// the `data` field of a different node.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// list.par_iter_mut().for_each(|value| *value += 10);
+    ///
+    /// assert_eq!(list.get(0), Some(&11));
+    /// assert_eq!(list.get(1), Some(&12));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T>
+    where
+        T: Send,
+    {
+        let mut references = Vec::with_capacity(self.length as usize);
+        let mut current = self.head;
+        while let Some(node) = current {
+            self.stats.record_visit();
+            // SAFETY: the walk visits every node once, so each reference borrows the
+            // `data` field of a different node, and all of them borrow `self` mutably.
+            let node = unsafe { &mut *node.as_ptr() };
+            references.push(&mut node.data);
+            current = node.next;
+        }
+
+        references.into_par_iter()
+    }
+}
+
+#[cfg(feature = "handles")]
+impl<T> KolzoDoublyLinkedList<T> {
+    /// Appends a value to the end of the doubly linked list and returns a handle to
+    /// its node, which [`remove_handle`](Self::remove_handle) can later unlink in O(1)
+    /// without searching the list.
+    ///
+    /// Only available with the `handles` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to be stored in the new node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// list.append(1);
+    /// let handle = list.append_handle(2);
+    /// list.append(3);
+    ///
+    /// // SAFETY: the handle comes from `list` and its node is still linked.
+    /// assert_eq!(unsafe { list.remove_handle(handle) }, 2);
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    pub fn append_handle(&mut self, value: T) -> NodeHandle<T> {
+        self.append(value);
+        NodeHandle {
+            node: self
+                .tail
+                .expect("the list cannot be empty right after an append"),
+        }
+    }
+
+    /// Unlinks the node behind `handle` and returns its value, in O(1).
+    ///
+    /// Only available with the `handles` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - A handle returned by [`append_handle`](Self::append_handle).
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been returned by `append_handle` on this same list, and
+    /// its node must still be linked: it must not have been removed through this
+    /// or any other method since. Handles are not tracked, so a stale handle points
+    /// at freed memory.
+    pub unsafe fn remove_handle(&mut self, handle: NodeHandle<T>) -> T {
+        // SAFETY: guaranteed by the caller.
+        unsafe { self.unlink(handle.node) }
+    }
+}
+
+impl<T> Default for KolzoDoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> LinearList<T> for KolzoDoublyLinkedList<T> {
+    fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    fn elements(&self) -> Vec<&T> {
+        let mut elements = Vec::with_capacity(self.length as usize);
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            elements.push(&node.data);
+            current = node.next_node();
+        }
+        elements
+    }
+
+    fn is_doubly_linked(&self) -> bool {
+        true
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoDoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A double-ended iterator over the elements of a [`KolzoDoublyLinkedList`].
+pub struct Iter<'a, T> {
+    /// The node whose value is yielded next from the front.
+    front: Option<&'a Node<T>>,
+    /// The node whose value is yielded next from the back.
+    back: Option<&'a Node<T>>,
+    /// The number of values not yet yielded from either end.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.front.map(|node| {
+            self.front = node.next_node();
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.back.map(|node| {
+            // SAFETY: `previous` points at a node of the list borrowed for `'a`.
+            self.back = node.previous.map(|previous| unsafe { &*previous.as_ptr() });
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// SAFETY: both ends of the iterator only read nodes of a shared-borrowed list.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+/// A handle to a node of a [`KolzoDoublyLinkedList`], returned by
+/// [`KolzoDoublyLinkedList::append_handle`].
+///
+/// A handle is a plain pointer that does not borrow the list, so a caller can keep
+/// one per element in an index and remove elements in O(1). It is only valid while
+/// its node is linked; see [`KolzoDoublyLinkedList::remove_handle`].
+///
+/// Only available with the `handles` feature.
+#[cfg(feature = "handles")]
+#[derive(Debug)]
+pub struct NodeHandle<T> {
+    /// The node the handle refers to.
+    node: NonNull<Node<T>>,
+}
+
+#[cfg(feature = "handles")]
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "handles")]
+impl<T> Copy for NodeHandle<T> {}
+
+#[cfg(feature = "handles")]
+impl<T> PartialEq for NodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+#[cfg(feature = "handles")]
+impl<T> Eq for NodeHandle<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoDoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Drop for KolzoDoublyLinkedList<T> {
+    /// Frees the nodes one at a time, following the links from the head.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            // SAFETY: every node is freed exactly once, and the list is not used
+            // again after `drop`.
+            current = unsafe { self.allocator.free(node) }.next;
+        }
+    }
+}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p double_linked_list`, with the default Stacked
+// Borrows model and with `MIRIFLAGS=-Zmiri-tree-borrows`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    #[test]
+    fn test_append_and_pop() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        assert_eq!(list.length, 3);
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.length, 2);
+
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.length, 1);
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.length, 0);
+
+        assert_eq!(list.pop(), None);
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = KolzoDoublyLinkedList::new();
+
+        list.prepend(1);
+        list.prepend(2);
+        list.prepend(3);
+
+        assert_eq!(list.length, 3);
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+
+        list.append(0);
+        assert_eq!(list.get(3), Some(&0));
+    }
+
+    #[test]
+    fn test_pop_first() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        assert_eq!(list.pop_first(), None);
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        assert_eq!(list.pop_first(), Some(1));
+        assert_eq!(list.pop_first(), Some(2));
+        assert_eq!(list.pop_first(), Some(3));
+        assert_eq!(list.length, 0);
+
+        assert_eq!(list.pop_first(), None);
+
+        list.append(4);
+        assert_eq!(list.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.get(-1), None);
+
+        for value in 0..7 {
+            list.append(value * 10);
+        }
+
+        for index in 0..7 {
+            assert_eq!(list.get(index), Some(&(index as i32 * 10)));
+        }
+
+        assert_eq!(list.get(7), None);
+        assert_eq!(list.get(-1), None);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        assert_eq!(list.set(0, 10), None);
+
+        list.append(10);
+        list.append(20);
+        list.append(30);
+
+        assert_eq!(list.set(0, 15), Some(10));
+        assert_eq!(list.set(2, 35), Some(30));
+
+        assert_eq!(list.get(0), Some(&15));
+        assert_eq!(list.get(1), Some(&20));
+        assert_eq!(list.get(2), Some(&35));
+
+        assert_eq!(list.set(3, 40), None);
+        assert_eq!(list.set(-1, 50), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        list.insert(0, 10);
+        list.insert(1, 30);
+        list.insert(1, 20);
+        list.insert(3, 40);
+
+        assert_eq!(list.length, 4);
+        for index in 0..4 {
+            assert_eq!(list.get(index), Some(&((index as i32 + 1) * 10)));
+        }
+
+        list.insert(10, 50);
+        list.insert(-1, 50);
+        assert_eq!(list.length, 4);
+
+        assert_eq!(list.pop(), Some(40));
+        assert_eq!(list.pop(), Some(30));
+        assert_eq!(list.pop(), Some(20));
+        assert_eq!(list.pop(), Some(10));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        list.remove(0);
+        assert_eq!(list.length, 0);
+
+        list.append(10);
+        list.append(20);
+        list.append(30);
+        list.append(40);
+        list.append(50);
+
+        list.remove(0);
+        assert_eq!(list.get(0), Some(&20));
+        assert_eq!(list.length, 4);
+
+        list.remove(3);
+        assert_eq!(list.get(2), Some(&40));
+        assert_eq!(list.get(3), None);
+        assert_eq!(list.length, 3);
+
+        list.remove(1);
+        assert_eq!(list.get(0), Some(&20));
+        assert_eq!(list.get(1), Some(&40));
+        assert_eq!(list.length, 2);
+
+        list.remove(10);
+        list.remove(-1);
+        assert_eq!(list.length, 2);
+
+        assert_eq!(list.pop(), Some(40));
+        assert_eq!(list.pop(), Some(20));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        list.reverse();
+        assert_eq!(list.get(0), None);
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        list.reverse();
+
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+
+        list.append(4);
+        assert_eq!(list.pop_first(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.iter().next_back(), None);
+        assert!(list.is_empty());
+
+        for value in 1..=5 {
+            list.append(value);
+        }
+        assert_eq!(list.len(), 5);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        list.reverse();
+        let values: Vec<_> = (&list).into_iter().copied().collect();
+        assert_eq!(values, vec![5, 4, 3, 2, 1]);
+        assert!(list.iter().rev().eq([1, 2, 3, 4, 5].iter()));
+    }
+
+    #[cfg(feature = "handles")]
+    #[test]
+    fn test_remove_handle() {
+        let mut list = KolzoDoublyLinkedList::new();
+        let handles: Vec<_> = (0..5).map(|value| list.append_handle(value)).collect();
+
+        unsafe {
+            // A middle node, then the head, then the tail.
+            assert_eq!(list.remove_handle(handles[2]), 2);
+            assert_eq!(list.remove_handle(handles[0]), 0);
+            assert_eq!(list.remove_handle(handles[4]), 4);
+        }
+        assert_eq!(list.len(), 2);
+        assert!(list.iter().eq([1, 3].iter()));
+        assert!(list.iter().rev().eq([3, 1].iter()));
+
+        // The list must stay usable at both ends.
+        let last = list.append_handle(5);
+        list.prepend(0);
+        assert!(list.iter().eq([0, 1, 3, 5].iter()));
+
+        unsafe {
+            assert_eq!(list.remove_handle(last), 5);
+            assert_eq!(list.remove_handle(handles[1]), 1);
+            assert_eq!(list.remove_handle(handles[3]), 3);
+        }
+        assert_eq!(list.pop(), Some(0));
+        assert!(list.is_empty());
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let mut list = KolzoDoublyLinkedList::new();
+        for value in 0..size(200_000) {
+            list.append(value);
+        }
+        drop(list);
+    }
+
+    #[test]
+    fn test_arena_slots_are_recycled() {
+        let counted = Rc::new(());
+        let arena = SharedArena::with_chunk_size(256);
+        let mut list = KolzoDoublyLinkedList::new_in(&arena);
+
+        for round in 0..size(1_000).max(4) {
+            for _ in 0..200 {
+                list.append(Rc::clone(&counted));
+            }
+            list.pop();
+            list.pop_first();
+            list.remove(100);
+            list.insert(50, Rc::clone(&counted));
+            while list.len() > 100 {
+                list.remove(list.len() as i64 / 2);
+            }
+            assert_eq!(Rc::strong_count(&counted), 101, "round {round}");
+            while list.pop().is_some() {}
+        }
+        // Every round reuses the slots freed by the one before, so the arena never
+        // grows past what a single round needs.
+        assert_eq!(arena.chunk_count(), 1);
+
+        list.prepend(Rc::clone(&counted));
+        drop(list);
+        assert_eq!(Rc::strong_count(&counted), 1);
+    }
+
+    #[cfg(feature = "handles")]
+    #[test]
+    fn test_remove_handle_in_an_arena() {
+        let arena = SharedArena::with_chunk_size(4);
+        let mut list = KolzoDoublyLinkedList::new_in(&arena);
+
+        for round in 0..size(1_000).max(10) {
+            let handles: Vec<_> = (0..3).map(|value| list.append_handle(value)).collect();
+            unsafe {
+                assert_eq!(list.remove_handle(handles[1]), 1, "round {round}");
+                assert_eq!(list.remove_handle(handles[0]), 0);
+                assert_eq!(list.remove_handle(handles[2]), 2);
+            }
+            assert!(list.is_empty());
+        }
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_arena_lists_on_several_threads() {
+        let arena = SharedArena::with_chunk_size(128);
+        let workers: Vec<_> = (0..4)
+            .map(|worker| {
+                let arena = arena.clone();
+                std::thread::spawn(move || {
+                    let mut list = KolzoDoublyLinkedList::new_in(&arena);
+                    for value in 0..size(1_000).max(10) {
+                        list.append(worker * 10_000 + value);
+                        if value % 3 == 0 {
+                            list.pop_first();
+                        }
+                    }
+                    list
+                })
+            })
+            .collect();
+
+        for (worker, handle) in workers.into_iter().enumerate() {
+            let list = handle.join().unwrap();
+            assert!(list.iter().all(|&value| value / 10_000 == worker));
+            assert_eq!(list.iter().rev().count(), list.len());
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        assert_eq!(render(&list, RenderOptions::default()), "None");
+
+        list.append(1);
+        list.append(22);
+        list.append(3);
+
+        let expected = [
+            "        +---+     +----+     +---+",
+            "None <- | 1 | <-> | 22 | <-> | 3 | -> None",
+            "        +---+     +----+     +---+",
+            "          0         1          2",
+        ]
+        .join("\n");
+        assert_eq!(render(&list, RenderOptions::default()), expected);
+    }
+
+    #[test]
+    fn test_render_elides_long_list() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        for value in 0..1000 {
+            list.append(value);
+        }
+
+        let options = RenderOptions {
+            max_elements: Some(3),
+            show_indices: false,
+        };
+        let expected = [
+            "        +---+     +---+           +-----+",
+            "None <- | 0 | <-> | 1 | <-> … <-> | 999 | -> None",
+            "        +---+     +---+           +-----+",
+        ]
+        .join("\n");
+        assert_eq!(render(&list, options), expected);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_memory_footprint() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        assert_eq!(list.memory_footprint().total_bytes, 0);
+
+        for value in 0..10 {
+            list.append(value);
+        }
+
+        let report = list.memory_footprint();
+        assert_eq!(report.node_count, 10);
+        // An `i32` and two `Option<NonNull<_>>` links, padded to 8 bytes.
+        assert_eq!(report.bytes_per_node, 24);
+        assert_eq!(report.payload_bytes, 40);
+        assert_eq!(report.total_bytes, 240);
+    }
+
+    #[test]
+    fn test_deep_memory_footprint() {
+        let mut list: KolzoDoublyLinkedList<String> = KolzoDoublyLinkedList::new();
+        list.append(String::with_capacity(10));
+        list.append(String::with_capacity(20));
+
+        let shallow = list.memory_footprint();
+        let deep = list.deep_memory_footprint();
+
+        assert_eq!(deep.node_count, 2);
+        assert_eq!(deep.payload_bytes, shallow.payload_bytes + 30);
+        assert_eq!(deep.total_bytes, shallow.total_bytes + 30);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter_sum_matches_sequential_sum() {
+        let mut list: KolzoDoublyLinkedList<u64> = KolzoDoublyLinkedList::new();
+        for value in 0..10_000 {
+            list.append(value);
+        }
+
+        let mut sequential_sum = 0;
+        let mut current = list.head_node();
+        while let Some(node) = current {
+            sequential_sum += node.data * node.data;
+            current = node.next_node();
+        }
+
+        let parallel_sum: u64 = list.par_iter().map(|value| value * value).sum();
+        assert_eq!(parallel_sum, sequential_sum);
+
+        let empty: KolzoDoublyLinkedList<u64> = KolzoDoublyLinkedList::new();
+        assert_eq!(empty.par_iter().count(), 0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter_mut_updates_every_element_once() {
+        let mut list: KolzoDoublyLinkedList<usize> = KolzoDoublyLinkedList::new();
+        let length = size(1_000_000);
+        for value in 0..length {
+            list.append(value);
+        }
+
+        list.par_iter_mut().for_each(|value| *value += 1);
+
+        assert_eq!(list.par_iter().count(), length);
+        assert!(list
+            .par_iter()
+            .enumerate()
+            .all(|(index, value)| *value == index + 1));
+        assert_eq!(list.pop(), Some(length));
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_append_is_constant_time() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+
+        for value in 0..1000 {
+            list.append(value);
+        }
+
+        let stats = list.stats();
+        assert_eq!(stats.allocations, 1000);
+        assert!(stats.node_visits <= 1000);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_get_walks_from_the_nearer_end() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        for value in 0..1000 {
+            list.append(value);
+        }
+
+        list.reset_stats();
+        assert_eq!(list.get(999), Some(&999));
+        assert!(list.stats().node_visits <= 2);
+
+        list.reset_stats();
+        assert_eq!(list.get(0), Some(&0));
+        assert!(list.stats().node_visits <= 2);
+
+        list.reset_stats();
+        assert_eq!(list.get(500), Some(&500));
+        assert!(list.stats().node_visits <= 501);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_pop_is_constant_time() {
+        let mut list: KolzoDoublyLinkedList<i32> = KolzoDoublyLinkedList::new();
+        for value in 0..1000 {
+            list.append(value);
+        }
+        list.reset_stats();
+
+        assert_eq!(list.pop(), Some(999));
+        assert_eq!(list.pop_first(), Some(0));
+
+        let stats = list.stats();
+        assert_eq!(stats.node_visits, 0);
+        assert_eq!(stats.frees, 2);
+
+        list.reset_stats();
+        assert_eq!(list.stats(), ListStats::default());
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send::<KolzoDoublyLinkedList<String>>();
+        assert_sync::<KolzoDoublyLinkedList<String>>();
+        assert_send::<Iter<'static, String>>();
+        assert_sync::<Iter<'static, String>>();
+        assert_send::<SharedArena<Node<String>>>();
+        assert_sync::<SharedArena<Node<String>>>();
+    }
 }
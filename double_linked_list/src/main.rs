@@ -1,5 +1,4 @@
-mod algorithm;
-use algorithm::KolzoDoublyLinkedList;
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
 
 fn main() {
     let mut ll = KolzoDoublyLinkedList::new();
@@ -7,4 +6,19 @@ fn main() {
     ll.append(2);
     ll.append(3);
     ll.print();
+    ll.pop();
+    ll.print();
+    ll.prepend(1);
+    ll.print();
+    ll.get(1);
+    ll.pop_first();
+    ll.print();
+    ll.set(0, 49);
+    ll.print();
+    ll.insert(1, 100);
+    ll.print();
+    ll.remove(0);
+    ll.print();
+    ll.reverse();
+    ll.print();
 }
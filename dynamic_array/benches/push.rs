@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dynamic_array::algorithm::KolzoVec;
+use linked_list::algorithm::KolzoLinkedList;
+
+/// The number of values pushed per iteration.
+const SIZE: u64 = 100_000;
+
+/// Pushes 100k values onto a growable array and onto a linked list. Both are O(1)
+/// per push, but the array reallocates only O(log n) times and keeps its values
+/// contiguous, while the list allocates a node for every value.
+fn push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_100k");
+
+    group.bench_function("dynamic_array", |b| {
+        b.iter(|| {
+            let mut vec = KolzoVec::new();
+            for value in 0..SIZE {
+                vec.push(black_box(value));
+            }
+            vec
+        })
+    });
+
+    group.bench_function("linked_list", |b| {
+        b.iter(|| {
+            let mut list = KolzoLinkedList::new();
+            for value in 0..SIZE {
+                list.append(black_box(value));
+            }
+            list
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, push);
+criterion_main!(benches);
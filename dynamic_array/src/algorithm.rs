@@ -0,0 +1,736 @@
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+use std::slice;
+
+/// The capacity of the first allocation.
+const MIN_CAPACITY: usize = 4;
+
+/// An allocation with room for `capacity` values of `T`. It owns the memory but not
+/// the values in it, so dropping it frees the memory without dropping any value.
+struct RawBuffer<T> {
+    /// The start of the allocation, dangling while nothing is allocated.
+    ptr: NonNull<T>,
+    /// The number of values the allocation has room for.
+    capacity: usize,
+    /// Tells the drop checker that values of `T` live in the buffer.
+    marker: PhantomData<T>,
+}
+
+// SAFETY: the buffer owns its memory like a `Box<[T]>` would and no other pointer
+// to the allocation exists, so sending it sends the values in it along with it.
+unsafe impl<T: Send> Send for RawBuffer<T> {}
+// SAFETY: through `&RawBuffer<T>` the values can only be read, as through `&[T]`.
+unsafe impl<T: Sync> Sync for RawBuffer<T> {}
+
+impl<T> RawBuffer<T> {
+    /// Creates a buffer without allocating. Zero-sized values need no memory, so
+    /// their buffer has unlimited capacity from the start.
+    fn new() -> Self {
+        let capacity = if mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            0
+        };
+
+        RawBuffer {
+            ptr: NonNull::dangling(),
+            capacity,
+            marker: PhantomData,
+        }
+    }
+
+    /// Doubles the capacity, or makes the first allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    fn grow(&mut self) {
+        // A zero-sized buffer only runs out when `usize::MAX` values are stored.
+        assert!(mem::size_of::<T>() != 0, "capacity overflow");
+
+        let capacity = if self.capacity == 0 {
+            MIN_CAPACITY
+        } else {
+            self.capacity.checked_mul(2).expect("capacity overflow")
+        };
+        self.grow_to(capacity);
+    }
+
+    /// Moves the values to an allocation with room for `capacity` values. It must be
+    /// larger than the current capacity.
+    fn grow_to(&mut self, capacity: usize) {
+        debug_assert!(capacity > self.capacity);
+        // `Layout::array` also rejects sizes above `isize::MAX` bytes.
+        let layout = Layout::array::<T>(capacity).expect("capacity overflow");
+
+        let ptr = if self.capacity == 0 {
+            // SAFETY: `T` is not zero-sized and `capacity` is at least
+            // `MIN_CAPACITY`, so the layout has a non-zero size.
+            unsafe { alloc::alloc(layout) }
+        } else {
+            let old_layout = Layout::array::<T>(self.capacity).unwrap();
+            // SAFETY: `ptr` was allocated with `old_layout`, as the capacity is only
+            // ever set to the capacity of the current allocation. The new size is
+            // non-zero and `Layout::array` checked that it fits in an `isize`.
+            unsafe { alloc::realloc(self.ptr.as_ptr().cast(), old_layout, layout.size()) }
+        };
+
+        self.ptr = match NonNull::new(ptr.cast()) {
+            Some(ptr) => ptr,
+            None => alloc::handle_alloc_error(layout),
+        };
+        self.capacity = capacity;
+    }
+}
+
+impl<T> Drop for RawBuffer<T> {
+    fn drop(&mut self) {
+        if mem::size_of::<T>() != 0 && self.capacity != 0 {
+            let layout = Layout::array::<T>(self.capacity).unwrap();
+            // SAFETY: a non-zero capacity of a non-zero-sized `T` means `ptr` was
+            // allocated with this layout, and the buffer is not used again.
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout) };
+        }
+    }
+}
+
+/// A growable array that manages its own memory.
+///
+/// The values sit contiguously in one allocation whose capacity doubles whenever it
+/// runs out, so `push` is amortized O(1). The array dereferences to a slice, which
+/// provides `get`, indexing, `iter`, sorting and the rest of the slice API.
+pub struct KolzoVec<T> {
+    /// The allocation holding the values.
+    buffer: RawBuffer<T>,
+    /// The number of initialized values at the start of the buffer.
+    length: usize,
+}
+
+impl<T> KolzoVec<T> {
+    /// Creates a new empty array without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynamic_array::algorithm::KolzoVec;
+    ///
+    /// let vec: KolzoVec<i32> = KolzoVec::new();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.capacity(), 0);
+    /// ```
+    pub fn new() -> Self {
+        KolzoVec {
+            buffer: RawBuffer::new(),
+            length: 0,
+        }
+    }
+
+    /// Creates a new empty array with room for at least `capacity` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of values to make room for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynamic_array::algorithm::KolzoVec;
+    ///
+    /// let vec: KolzoVec<i32> = KolzoVec::with_capacity(10);
+    /// assert!(vec.capacity() >= 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity in bytes overflows `isize::MAX`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = KolzoVec::new();
+        if capacity > vec.buffer.capacity {
+            vec.buffer.grow_to(capacity);
+        }
+        vec
+    }
+
+    /// Appends a value to the end of the array, doubling the capacity if it is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to append.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynamic_array::algorithm::KolzoVec;
+    ///
+    /// let mut vec = KolzoVec::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(&vec[..], &[1, 2]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        if self.length == self.buffer.capacity {
+            self.buffer.grow();
+        }
+
+        // SAFETY: `length < capacity` after the growth above, so the slot lies inside
+        // the allocation, and it holds no value that would be overwritten.
+        unsafe { ptr::write(self.buffer.ptr.as_ptr().add(self.length), value) };
+        self.length += 1;
+    }
+
+    /// Removes and returns the last value.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<T>` - The last value, or `None` if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynamic_array::algorithm::KolzoVec;
+    ///
+    /// let mut vec: KolzoVec<i32> = [1, 2].into_iter().collect();
+    /// assert_eq!(vec.pop(), Some(2));
+    /// assert_eq!(vec.pop(), Some(1));
+    /// assert_eq!(vec.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+
+        self.length -= 1;
+        // SAFETY: the slot at the old last index holds an initialized value, and
+        // lowering `length` first means it is no longer counted as one, so the
+        // value is moved out exactly once.
+        unsafe { Some(ptr::read(self.buffer.ptr.as_ptr().add(self.length))) }
+    }
+
+    /// Inserts a value at `index`, shifting every later value one place right.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position the value will have. May equal `len()`.
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynamic_array::algorithm::KolzoVec;
+    ///
+    /// let mut vec: KolzoVec<i32> = [1, 3].into_iter().collect();
+    /// vec.insert(1, 2);
+    /// vec.insert(3, 4);
+    /// assert_eq!(&vec[..], &[1, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than `len()`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(
+            index <= self.length,
+            "insertion index {} is out of bounds for length {}",
+            index,
+            self.length
+        );
+        if self.length == self.buffer.capacity {
+            self.buffer.grow();
+        }
+
+        // SAFETY: `index <= length < capacity`, so `slot` and the `length - index`
+        // values after it, moved one place right, stay inside the allocation.
+        // `ptr::copy` allows the ranges to overlap, and the value written to `slot`
+        // replaces one that was just moved away.
+        unsafe {
+            let slot = self.buffer.ptr.as_ptr().add(index);
+            ptr::copy(slot, slot.add(1), self.length - index);
+            ptr::write(slot, value);
+        }
+        self.length += 1;
+    }
+
+    /// Removes and returns the value at `index`, shifting every later value one
+    /// place left.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the value to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dynamic_array::algorithm::KolzoVec;
+    ///
+    /// let mut vec: KolzoVec<i32> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(vec.remove(0), 1);
+    /// assert_eq!(&vec[..], &[2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `len()`.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.length,
+            "removal index {} is out of bounds for length {}",
+            index,
+            self.length
+        );
+
+        self.length -= 1;
+        // SAFETY: `index` was less than the old length, so `slot` holds an
+        // initialized value, and the `length - index` values after it are moved one
+        // place left over it once it has been read, so none is duplicated.
+        unsafe {
+            let slot = self.buffer.ptr.as_ptr().add(index);
+            let value = ptr::read(slot);
+            ptr::copy(slot.add(1), slot, self.length - index);
+            value
+        }
+    }
+
+    /// Drops every value, keeping the allocation.
+    pub fn clear(&mut self) {
+        let values: *mut [T] = self.as_mut_slice();
+        // Forget the values before dropping them, so a panicking destructor cannot
+        // lead to them being dropped again.
+        self.length = 0;
+        // SAFETY: `values` covers exactly the initialized values, which the array
+        // no longer counts as its own, so each is dropped once.
+        unsafe { ptr::drop_in_place(values) };
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the array holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of values the array can hold before it reallocates.
+    /// Arrays of zero-sized values never allocate and report `usize::MAX`.
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity
+    }
+
+    /// Returns the values as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `length` slots are initialized, `ptr` is non-null and
+        // aligned even while dangling, and the slice borrows the array.
+        unsafe { slice::from_raw_parts(self.buffer.ptr.as_ptr(), self.length) }
+    }
+
+    /// Returns the values as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: as in `as_slice`, and the slice borrows the array mutably, so
+        // nothing else can reach the values.
+        unsafe { slice::from_raw_parts_mut(self.buffer.ptr.as_ptr(), self.length) }
+    }
+}
+
+impl<T> Drop for KolzoVec<T> {
+    fn drop(&mut self) {
+        // The buffer frees the memory when it is dropped right after.
+        self.clear();
+    }
+}
+
+impl<T> Deref for KolzoVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for KolzoVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> Default for KolzoVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for KolzoVec<T> {
+    fn clone(&self) -> Self {
+        let mut vec = KolzoVec::with_capacity(self.length);
+        vec.extend(self.iter().cloned());
+        vec
+    }
+}
+
+impl<T> Extend<T> for KolzoVec<T> {
+    /// Pushes every value of the iterator in order.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for KolzoVec<T> {
+    /// Builds an array holding the values of the iterator in order.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = KolzoVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T> IntoIterator for KolzoVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // The iterator takes over the buffer and the values; the array itself must
+        // not drop them.
+        let vec = ManuallyDrop::new(self);
+        IntoIter {
+            // SAFETY: the array is never dropped, so the buffer is moved out of it
+            // exactly once and the iterator becomes its only owner.
+            buffer: unsafe { ptr::read(&vec.buffer) },
+            start: 0,
+            end: vec.length,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut KolzoVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator that moves the values out of a [`KolzoVec`].
+///
+/// Values that are not consumed are dropped with the iterator.
+pub struct IntoIter<T> {
+    /// The buffer taken over from the array.
+    buffer: RawBuffer<T>,
+    /// The index of the next value from the front.
+    start: usize,
+    /// One past the index of the next value from the back.
+    end: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // SAFETY: the slots in `start..end` hold values not yet moved out, and
+        // raising `start` right after means this one is not read again.
+        let value = unsafe { ptr::read(self.buffer.ptr.as_ptr().add(self.start)) };
+        self.start += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        // SAFETY: the slot at the old `end - 1` holds a value not yet moved out,
+        // and lowering `end` first means it is not read again.
+        unsafe { Some(ptr::read(self.buffer.ptr.as_ptr().add(self.end))) }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for value in self.by_ref() {
+            drop(value);
+        }
+    }
+}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p dynamic_array`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// A payload that counts how many times it has been dropped.
+    #[derive(Debug)]
+    struct DropCounter {
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn counters(count: usize, drops: &Rc<Cell<usize>>) -> KolzoVec<DropCounter> {
+        (0..count)
+            .map(|_| DropCounter {
+                drops: Rc::clone(drops),
+            })
+            .collect()
+    }
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    #[test]
+    fn test_push_pop_get() {
+        let mut vec = KolzoVec::new();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.get(1), Some(&2));
+        assert_eq!(vec.get(3), None);
+        assert_eq!(vec[0], 1);
+
+        vec[0] = 10;
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(&vec[..], &[10, 2]);
+        assert_eq!(vec.pop(), Some(2));
+        assert_eq!(vec.pop(), Some(10));
+        assert_eq!(vec.pop(), None);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_capacity_doubles() {
+        let mut vec = KolzoVec::new();
+        let mut capacities = vec![vec.capacity()];
+        for value in 0..33 {
+            vec.push(value);
+            if capacities.last() != Some(&vec.capacity()) {
+                capacities.push(vec.capacity());
+            }
+        }
+        assert_eq!(capacities, vec![0, 4, 8, 16, 32, 64]);
+
+        let reserved: KolzoVec<u8> = KolzoVec::with_capacity(5);
+        assert_eq!(reserved.capacity(), 5);
+        assert!(reserved.is_empty());
+    }
+
+    #[test]
+    fn test_insert_and_remove_shift() {
+        let mut vec: KolzoVec<i32> = KolzoVec::new();
+        vec.insert(0, 2);
+        vec.insert(0, 0);
+        vec.insert(1, 1);
+        vec.insert(3, 4);
+        vec.insert(3, 3);
+        assert_eq!(&vec[..], &[0, 1, 2, 3, 4]);
+
+        assert_eq!(vec.remove(4), 4);
+        assert_eq!(vec.remove(0), 0);
+        assert_eq!(vec.remove(1), 2);
+        assert_eq!(&vec[..], &[1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index 2 is out of bounds for length 1")]
+    fn test_insert_out_of_bounds_panics() {
+        let mut vec: KolzoVec<i32> = [1].into_iter().collect();
+        vec.insert(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index 1 is out of bounds for length 1")]
+    fn test_remove_out_of_bounds_panics() {
+        let mut vec: KolzoVec<i32> = [1].into_iter().collect();
+        vec.remove(1);
+    }
+
+    #[test]
+    fn test_zero_sized_values() {
+        let mut vec = KolzoVec::new();
+        assert_eq!(vec.capacity(), usize::MAX);
+
+        for _ in 0..1000 {
+            vec.push(());
+        }
+        vec.insert(500, ());
+        assert_eq!(vec.remove(0), ());
+        assert_eq!(vec.len(), 1000);
+        assert_eq!(vec.pop(), Some(()));
+        assert_eq!(vec.iter().count(), 999);
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.len(), 999);
+        assert_eq!(iter.next_back(), Some(()));
+        assert_eq!(iter.count(), 998);
+    }
+
+    #[test]
+    fn test_zero_sized_values_are_dropped() {
+        struct ZeroSized<'a>(&'a Cell<usize>);
+
+        impl Drop for ZeroSized<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut vec = KolzoVec::new();
+        for _ in 0..10 {
+            vec.push(ZeroSized(&drops));
+        }
+        drop(vec.remove(3));
+        assert_eq!(drops.get(), 1);
+        drop(vec);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_every_value_is_dropped_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        let vec = counters(100, &drops);
+        drop(vec);
+        assert_eq!(drops.get(), 100);
+
+        drops.set(0);
+        let mut vec = counters(10, &drops);
+        drop(vec.remove(4));
+        drop(vec.pop());
+        assert_eq!(drops.get(), 2);
+        vec.clear();
+        assert_eq!(drops.get(), 10);
+        assert!(vec.capacity() >= 10);
+        vec.extend(counters(3, &drops));
+        drop(vec);
+        assert_eq!(drops.get(), 13);
+    }
+
+    #[test]
+    fn test_into_iter_drops_the_rest() {
+        let drops = Rc::new(Cell::new(0));
+        let mut iter = counters(10, &drops).into_iter();
+
+        drop(iter.next());
+        drop(iter.next_back());
+        assert_eq!(drops.get(), 2);
+        assert_eq!(iter.len(), 8);
+        drop(iter);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_growth_across_many_reallocations() {
+        let count = size(100_000);
+        let mut vec = KolzoVec::new();
+        for value in 0..count {
+            vec.push(value.to_string());
+        }
+
+        assert_eq!(vec.len(), count);
+        assert!(vec.capacity() >= count);
+        for (index, value) in vec.iter().enumerate() {
+            assert_eq!(*value, index.to_string());
+        }
+
+        let cloned = vec.clone();
+        let collected: Vec<String> = vec.into_iter().rev().collect();
+        assert_eq!(collected.len(), count);
+        assert_eq!(collected[0], (count - 1).to_string());
+        assert_eq!(cloned.len(), count);
+    }
+
+    #[test]
+    fn test_slice_api() {
+        let mut vec: KolzoVec<i32> = [3, 1, 2].into_iter().collect();
+        vec.sort();
+        assert_eq!(&vec[..], &[1, 2, 3]);
+        assert!(vec.contains(&2));
+
+        for value in &mut vec {
+            *value *= 10;
+        }
+        assert_eq!((&vec).into_iter().sum::<i32>(), 60);
+        assert_eq!(format!("{:?}", vec), "[10, 20, 30]");
+    }
+
+    #[test]
+    fn test_matches_vec() {
+        let mut rng = StdRng::seed_from_u64(228);
+        let mut vec = KolzoVec::new();
+        let mut model = Vec::new();
+
+        for _ in 0..size(10_000) {
+            match rng.gen_range(0..4) {
+                0 => {
+                    let value = rng.gen::<u32>().to_string();
+                    vec.push(value.clone());
+                    model.push(value);
+                }
+                1 => assert_eq!(vec.pop(), model.pop()),
+                2 => {
+                    let index = rng.gen_range(0..=model.len());
+                    let value = rng.gen::<u32>().to_string();
+                    vec.insert(index, value.clone());
+                    model.insert(index, value);
+                }
+                _ if !model.is_empty() => {
+                    let index = rng.gen_range(0..model.len());
+                    assert_eq!(vec.remove(index), model.remove(index));
+                }
+                _ => {}
+            }
+            assert_eq!(&vec[..], &model[..]);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use dynamic_array::algorithm::KolzoVec;
+
+fn main() {
+    let mut vec = KolzoVec::new();
+    for value in 1..=5 {
+        vec.push(value);
+        println!(
+            "PUSH {} LENGTH {} CAPACITY {}",
+            value,
+            vec.len(),
+            vec.capacity()
+        );
+    }
+
+    vec.insert(0, 0);
+    println!("AFTER INSERT {:?}", vec);
+    println!("REMOVED {}", vec.remove(2));
+    println!("POPPED {:?}", vec.pop());
+    println!("SUM {}", vec.iter().sum::<i32>());
+
+    vec.sort_by(|a, b| b.cmp(a));
+    println!("SORTED DESCENDING {:?}", vec);
+}
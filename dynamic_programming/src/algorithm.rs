@@ -0,0 +1,351 @@
+use std::fmt;
+
+/// The error returned by [`fibonacci`] when the result does not fit in a `u128`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FibonacciOverflow {
+    /// The index whose Fibonacci number overflowed.
+    n: u32,
+}
+
+impl FibonacciOverflow {
+    /// Returns the index that was asked for.
+    pub fn n(&self) -> u32 {
+        self.n
+    }
+}
+
+impl fmt::Display for FibonacciOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fibonacci number {} does not fit in a u128", self.n)
+    }
+}
+
+impl std::error::Error for FibonacciOverflow {}
+
+/// Computes the `n`-th Fibonacci number, with `fibonacci(0) == 0` and
+/// `fibonacci(1) == 1`.
+///
+/// Only the last two numbers are kept, so this runs in O(n) time and O(1) space.
+/// Every addition is checked, and the largest index that fits is 186.
+///
+/// # Returns
+///
+/// * `Ok(u128)` with the Fibonacci number.
+/// * `Err(FibonacciOverflow)` if it does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use dynamic_programming::algorithm::fibonacci;
+///
+/// assert_eq!(fibonacci(10), Ok(55));
+/// assert!(fibonacci(187).is_err());
+/// ```
+pub fn fibonacci(n: u32) -> Result<u128, FibonacciOverflow> {
+    // `next` is `None` once the following number has overflowed, which is only
+    // an error if the loop goes on to need it.
+    let (mut current, mut next) = (0u128, Some(1u128));
+    for _ in 0..n {
+        let value = next.ok_or(FibonacciOverflow { n })?;
+        next = current.checked_add(value);
+        current = value;
+    }
+    Ok(current)
+}
+
+/// Finds a longest common subsequence of two strings, comparing them by `char`.
+///
+/// A table of `(a.len() + 1) * (b.len() + 1)` entries holds the length of the
+/// longest common subsequence of every pair of prefixes. The table is filled
+/// bottom-up and then walked back from the far corner to rebuild one subsequence,
+/// so nothing recurses. This runs in O(n·m) time and space.
+///
+/// When several subsequences share the longest length, the walk prefers to drop a
+/// character of `a` over one of `b`, so the choice is deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use dynamic_programming::algorithm::longest_common_subsequence;
+///
+/// assert_eq!(longest_common_subsequence("AGGTAB", "GXTXAYB"), "GTAB");
+/// assert_eq!(longest_common_subsequence("abc", "xyz"), "");
+/// ```
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let width = b.len() + 1;
+    // `lengths[i * width + j]` is the answer for `a[..i]` and `b[..j]`.
+    let mut lengths = vec![0usize; (a.len() + 1) * width];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            lengths[i * width + j] = if a[i - 1] == b[j - 1] {
+                lengths[(i - 1) * width + j - 1] + 1
+            } else {
+                lengths[(i - 1) * width + j].max(lengths[i * width + j - 1])
+            };
+        }
+    }
+
+    let mut subsequence = Vec::with_capacity(lengths[lengths.len() - 1]);
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            subsequence.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if lengths[(i - 1) * width + j] >= lengths[i * width + j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    subsequence.iter().rev().collect()
+}
+
+/// Computes the Levenshtein distance between two strings, comparing them by
+/// `char`: the fewest insertions, deletions and substitutions that turn `a` into
+/// `b`.
+///
+/// The table is filled row by row and each row only depends on the previous one,
+/// so two rows are kept. This runs in O(n·m) time and O(m) space.
+///
+/// # Examples
+///
+/// ```
+/// use dynamic_programming::algorithm::edit_distance;
+///
+/// assert_eq!(edit_distance("kitten", "sitting"), 3);
+/// assert_eq!(edit_distance("", "abc"), 3);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // `previous[j]` is the distance from the prefix of `a` so far to `b[..j]`.
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for j in 1..=b.len() {
+            let substitution = previous[j - 1] + usize::from(a_char != b[j - 1]);
+            let deletion = previous[j] + 1;
+            let insertion = current[j - 1] + 1;
+            current[j] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Solves the 0/1 knapsack problem: picks items, each at most once, to maximize
+/// their total value without their total weight exceeding `capacity`.
+///
+/// A table of `(n + 1) * (capacity + 1)` entries holds the best value for every
+/// prefix of the items and every capacity. It is filled bottom-up and walked back
+/// to find which items were taken. This runs in O(n·capacity) time and space, so
+/// it suits capacities that are small enough to index a table.
+///
+/// # Arguments
+///
+/// * `weights` - The weight of each item.
+/// * `values` - The value of each item.
+/// * `capacity` - The largest total weight allowed.
+///
+/// # Returns
+///
+/// The best total value, and the indices of the items that achieve it in
+/// increasing order.
+///
+/// # Panics
+///
+/// Panics if `weights` and `values` have different lengths, or if the table does
+/// not fit in memory.
+///
+/// # Examples
+///
+/// ```
+/// use dynamic_programming::algorithm::knapsack_01;
+///
+/// let (value, chosen) = knapsack_01(&[10, 20, 30], &[60, 100, 120], 50);
+/// assert_eq!(value, 220);
+/// assert_eq!(chosen, vec![1, 2]);
+/// ```
+pub fn knapsack_01(weights: &[u64], values: &[u64], capacity: u64) -> (u64, Vec<usize>) {
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "every item needs both a weight and a value"
+    );
+    let capacity = usize::try_from(capacity).expect("capacity does not fit in memory");
+    let width = capacity + 1;
+
+    // `best[i * width + c]` is the best value using the first `i` items within
+    // capacity `c`.
+    let mut best = vec![0u64; (weights.len() + 1) * width];
+    for (item, (&weight, &value)) in weights.iter().zip(values).enumerate() {
+        let (done, next) = best.split_at_mut((item + 1) * width);
+        let previous = &done[item * width..];
+        for c in 0..width {
+            next[c] = previous[c];
+            if let Ok(weight) = usize::try_from(weight) {
+                if weight <= c {
+                    next[c] = next[c].max(previous[c - weight] + value);
+                }
+            }
+        }
+    }
+
+    let mut chosen = Vec::new();
+    let mut c = capacity;
+    for item in (0..weights.len()).rev() {
+        // An item was taken if including it changed the best value.
+        if best[(item + 1) * width + c] != best[item * width + c] {
+            chosen.push(item);
+            c -= weights[item] as usize;
+        }
+    }
+    chosen.reverse();
+
+    (best[weights.len() * width + capacity], chosen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Returns `true` if `sub` can be formed by deleting characters from `text`.
+    fn is_subsequence(sub: &str, text: &str) -> bool {
+        let mut chars = text.chars();
+        sub.chars().all(|wanted| chars.any(|c| c == wanted))
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        let cases = [
+            (0, 0),
+            (1, 1),
+            (2, 1),
+            (10, 55),
+            (50, 12_586_269_025),
+            (93, 12_200_160_415_121_876_738),
+            (186, 332_825_110_087_067_562_321_196_029_789_634_457_848),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(fibonacci(n), Ok(expected), "fibonacci({n})");
+        }
+    }
+
+    #[test]
+    fn test_fibonacci_overflow() {
+        assert_eq!(fibonacci(187), Err(FibonacciOverflow { n: 187 }));
+        assert_eq!(fibonacci(u32::MAX).unwrap_err().n(), u32::MAX);
+        assert_eq!(
+            fibonacci(200).unwrap_err().to_string(),
+            "fibonacci number 200 does not fit in a u128"
+        );
+    }
+
+    #[test]
+    fn test_longest_common_subsequence() {
+        let cases = [
+            ("AGGTAB", "GXTXAYB", "GTAB"),
+            ("ABCBDAB", "BDCABA", "BCBA"),
+            ("abc", "abc", "abc"),
+            ("abc", "", ""),
+            ("", "", ""),
+            ("abc", "def", ""),
+            ("naïve", "aïe", "aïe"),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(longest_common_subsequence(a, b), expected, "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_longest_common_subsequence_is_symmetric_in_length() {
+        let mut rng = StdRng::seed_from_u64(244);
+        for _ in 0..200 {
+            let a: String = (0..rng.gen_range(0..20))
+                .map(|_| rng.gen_range('a'..='d'))
+                .collect();
+            let b: String = (0..rng.gen_range(0..20))
+                .map(|_| rng.gen_range('a'..='d'))
+                .collect();
+
+            let forward = longest_common_subsequence(&a, &b);
+            let backward = longest_common_subsequence(&b, &a);
+            assert_eq!(forward.chars().count(), backward.chars().count());
+            assert!(is_subsequence(&forward, &a) && is_subsequence(&forward, &b));
+            assert!(is_subsequence(&backward, &a) && is_subsequence(&backward, &b));
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let cases = [
+            ("kitten", "sitting", 3),
+            ("flaw", "lawn", 2),
+            ("intention", "execution", 5),
+            ("", "", 0),
+            ("abc", "", 3),
+            ("abc", "abc", 0),
+            ("café", "cafe", 1),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(edit_distance(a, b), expected, "{a:?} {b:?}");
+            assert_eq!(edit_distance(b, a), expected, "{b:?} {a:?}");
+        }
+    }
+
+    #[test]
+    fn test_knapsack_01() {
+        assert_eq!(
+            knapsack_01(&[10, 20, 30], &[60, 100, 120], 50),
+            (220, vec![1, 2])
+        );
+        assert_eq!(
+            knapsack_01(&[1, 3, 4, 5], &[1, 4, 5, 7], 7),
+            (9, vec![1, 2])
+        );
+        assert_eq!(knapsack_01(&[5], &[10], 4), (0, vec![]));
+        assert_eq!(knapsack_01(&[], &[], 100), (0, vec![]));
+        assert_eq!(knapsack_01(&[0, 2], &[3, 4], 0), (3, vec![0]));
+        assert_eq!(knapsack_01(&[u64::MAX, 1], &[100, 1], 10), (1, vec![1]));
+    }
+
+    #[test]
+    fn test_knapsack_01_choice_is_consistent() {
+        let mut rng = StdRng::seed_from_u64(244);
+        let weights: Vec<u64> = (0..40).map(|_| rng.gen_range(1..30)).collect();
+        let values: Vec<u64> = (0..40).map(|_| rng.gen_range(0..100)).collect();
+        let (value, chosen) = knapsack_01(&weights, &values, 200);
+
+        assert!(chosen.iter().map(|&item| weights[item]).sum::<u64>() <= 200);
+        assert_eq!(chosen.iter().map(|&item| values[item]).sum::<u64>(), value);
+        assert!(chosen.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "weight and a value")]
+    fn test_knapsack_01_mismatched_lengths() {
+        knapsack_01(&[1, 2], &[1], 5);
+    }
+
+    #[test]
+    fn test_large_inputs_do_not_recurse() {
+        // Thousands of table rows would overflow the stack of a recursive solver.
+        let a = "ab".repeat(1_500);
+        let b = "ba".repeat(1_500);
+        assert_eq!(longest_common_subsequence(&a, &b).len(), 2_999);
+        assert_eq!(edit_distance(&a, &b), 2);
+
+        let weights = vec![1; 2_000];
+        let values: Vec<u64> = (0..2_000).collect();
+        let (value, chosen) = knapsack_01(&weights, &values, 1_000);
+        assert_eq!(chosen, (1_000..2_000).collect::<Vec<_>>());
+        assert_eq!(value, (1_000..2_000).sum::<u64>());
+    }
+}
@@ -0,0 +1,17 @@
+use dynamic_programming::algorithm::{
+    edit_distance, fibonacci, knapsack_01, longest_common_subsequence,
+};
+
+fn main() {
+    println!("FIBONACCI 90 {:?}", fibonacci(90));
+    match fibonacci(200) {
+        Ok(value) => println!("FIBONACCI 200 {value}"),
+        Err(error) => println!("FIBONACCI 200 FAILED: {error}"),
+    }
+
+    println!("LCS {:?}", longest_common_subsequence("AGGTAB", "GXTXAYB"));
+    println!("EDIT DISTANCE {}", edit_distance("kitten", "sitting"));
+
+    let (value, chosen) = knapsack_01(&[10, 20, 30], &[60, 100, 120], 50);
+    println!("KNAPSACK VALUE {value} ITEMS {chosen:?}");
+}
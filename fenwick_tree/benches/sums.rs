@@ -0,0 +1,53 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fenwick_tree::algorithm::KolzoFenwick;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The number of interleaved adds and prefix sums per run.
+const OPERATIONS: usize = 1_000;
+
+/// Interleaves random adds with random prefix sums, on a Fenwick tree and on a
+/// plain `Vec` that sums the prefix from scratch for every query. The naive sum
+/// is O(n) per query against O(log n), so the gap grows with the length.
+fn interleaved(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(242);
+    let mut group = c.benchmark_group("add_and_prefix_sum");
+
+    for len in [1_000, 10_000, 100_000] {
+        let values: Vec<i64> = (0..len).map(|_| rng.gen_range(0..1_000)).collect();
+        let operations: Vec<(usize, i64, usize)> = (0..OPERATIONS)
+            .map(|_| {
+                (
+                    rng.gen_range(0..len),
+                    rng.gen_range(-100..100),
+                    rng.gen_range(0..=len),
+                )
+            })
+            .collect();
+
+        group.bench_with_input(BenchmarkId::new("fenwick", len), &values, |b, values| {
+            let mut fenwick = KolzoFenwick::from_slice(values);
+            b.iter(|| {
+                for &(index, delta, count) in &operations {
+                    fenwick.add(index, delta);
+                    black_box(fenwick.prefix_sum(count));
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("naive", len), &values, |b, values| {
+            let mut values = values.clone();
+            b.iter(|| {
+                for &(index, delta, count) in &operations {
+                    values[index] += delta;
+                    black_box(values[..count].iter().sum::<i64>());
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, interleaved);
+criterion_main!(benches);
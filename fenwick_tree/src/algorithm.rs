@@ -0,0 +1,366 @@
+use std::ops::{Bound, RangeBounds};
+
+/// A Fenwick tree, or binary indexed tree, over a sequence of `i64` values.
+///
+/// It supports adding to a value and summing a prefix of the sequence, both in
+/// O(log n), while using no more memory than the values themselves.
+///
+/// # Index conventions
+///
+/// The public API is 0-based like a slice: the values are at indices `0..len()`,
+/// and `prefix_sum(i)` is the sum of the first `i` values, `values[..i]`, so that
+/// `prefix_sum(0)` is 0 and `prefix_sum(len())` is the total.
+///
+/// Internally the tree is 1-based. Slot `j` of `tree`, for `j` in `1..=len()`, holds
+/// the sum of the values at 0-based indices `j - lowbit(j)..j`, where `lowbit(j)`
+/// is the lowest set bit of `j`. Slot 0 is unused. A public index `i` therefore
+/// lives in slot `i + 1`, and `prefix_sum(i)` starts walking from slot `i`.
+#[derive(Debug, Clone)]
+pub struct KolzoFenwick {
+    /// The partial sums described above, with an unused slot 0.
+    tree: Vec<i64>,
+}
+
+/// Returns the lowest set bit of `slot`.
+fn lowbit(slot: usize) -> usize {
+    slot & slot.wrapping_neg()
+}
+
+impl KolzoFenwick {
+    /// Creates a tree of `len` values that are all zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fenwick_tree::algorithm::KolzoFenwick;
+    ///
+    /// let fenwick = KolzoFenwick::with_len(4);
+    /// assert_eq!(fenwick.len(), 4);
+    /// assert_eq!(fenwick.prefix_sum(4), 0);
+    /// ```
+    pub fn with_len(len: usize) -> Self {
+        KolzoFenwick {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    /// Creates a tree holding a copy of `values`, in O(n).
+    ///
+    /// Each slot is finished before its parent, so adding it to the parent once
+    /// builds the tree without the O(n log n) cost of calling `add` per value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fenwick_tree::algorithm::KolzoFenwick;
+    ///
+    /// let fenwick = KolzoFenwick::from_slice(&[1, 2, 3]);
+    /// assert_eq!(fenwick.prefix_sum(2), 3);
+    /// ```
+    pub fn from_slice(values: &[i64]) -> Self {
+        let mut tree = Vec::with_capacity(values.len() + 1);
+        tree.push(0);
+        tree.extend_from_slice(values);
+
+        for slot in 1..tree.len() {
+            let parent = slot + lowbit(slot);
+            if parent < tree.len() {
+                tree[parent] += tree[slot];
+            }
+        }
+
+        KolzoFenwick { tree }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to the value at `index`, in O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The 0-based index of the value.
+    /// * `delta` - The amount to add, which may be negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fenwick_tree::algorithm::KolzoFenwick;
+    ///
+    /// let mut fenwick = KolzoFenwick::with_len(3);
+    /// fenwick.add(1, 5);
+    /// fenwick.add(1, -2);
+    /// assert_eq!(fenwick.range_sum(1..2), 3);
+    /// ```
+    pub fn add(&mut self, index: usize, delta: i64) {
+        assert!(
+            index < self.len(),
+            "index {index} is out of bounds for a Fenwick tree of length {}",
+            self.len()
+        );
+
+        let mut slot = index + 1;
+        while slot < self.tree.len() {
+            self.tree[slot] += delta;
+            slot += lowbit(slot);
+        }
+    }
+
+    /// Returns the sum of the first `count` values, `values[..count]`, in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than `len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fenwick_tree::algorithm::KolzoFenwick;
+    ///
+    /// let fenwick = KolzoFenwick::from_slice(&[4, 5, 6]);
+    /// assert_eq!(fenwick.prefix_sum(0), 0);
+    /// assert_eq!(fenwick.prefix_sum(1), 4);
+    /// assert_eq!(fenwick.prefix_sum(3), 15);
+    /// ```
+    pub fn prefix_sum(&self, count: usize) -> i64 {
+        assert!(
+            count <= self.len(),
+            "prefix of {count} values is out of bounds for a Fenwick tree of length {}",
+            self.len()
+        );
+
+        let mut sum = 0;
+        let mut slot = count;
+        while slot > 0 {
+            sum += self.tree[slot];
+            slot -= lowbit(slot);
+        }
+        sum
+    }
+
+    /// Returns the sum of the values in `range`, in O(log n).
+    ///
+    /// An empty range sums to 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends past `len()`, as slicing
+    /// would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fenwick_tree::algorithm::KolzoFenwick;
+    ///
+    /// let fenwick = KolzoFenwick::from_slice(&[4, 5, 6]);
+    /// assert_eq!(fenwick.range_sum(1..3), 11);
+    /// assert_eq!(fenwick.range_sum(..=1), 9);
+    /// assert_eq!(fenwick.range_sum(2..2), 0);
+    /// ```
+    pub fn range_sum(&self, range: impl RangeBounds<usize>) -> i64 {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(start <= end, "range starts at {start} but ends at {end}");
+
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+
+    /// Finds the smallest index whose inclusive prefix sum, `prefix_sum(index + 1)`,
+    /// reaches `target`, in O(log n).
+    ///
+    /// The search descends the implicit tree in powers of two and relies on the
+    /// prefix sums never decreasing, so every value must be non-negative. Treating
+    /// the values as weights, a uniform `target` in `1..=total` picks each index
+    /// with probability proportional to its weight.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(index)` with the first index where the running sum is at least `target`.
+    /// * `None` if the total of all values is less than `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fenwick_tree::algorithm::KolzoFenwick;
+    ///
+    /// let fenwick = KolzoFenwick::from_slice(&[2, 0, 3]);
+    /// assert_eq!(fenwick.find_prefix(1), Some(0));
+    /// assert_eq!(fenwick.find_prefix(3), Some(2));
+    /// assert_eq!(fenwick.find_prefix(6), None);
+    /// ```
+    pub fn find_prefix(&self, target: i64) -> Option<usize> {
+        let len = self.len();
+        // `slot` only ever covers values whose running sum is still below `target`.
+        let mut slot = 0;
+        let mut remaining = target;
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = slot + step;
+            if next <= len && self.tree[next] < remaining {
+                slot = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+
+        // Slot `slot + 1` is the first one that reaches the target, and holds the
+        // value at 0-based index `slot`.
+        if slot < len {
+            Some(slot)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for KolzoFenwick {
+    /// Creates a tree with no values.
+    fn default() -> Self {
+        Self::with_len(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_slice_matches_adds() {
+        let values = [5, -3, 7, 0, 2, 8, -1, 4, 6, 1, 9];
+        let built = KolzoFenwick::from_slice(&values);
+        let mut added = KolzoFenwick::with_len(values.len());
+        for (index, &value) in values.iter().enumerate() {
+            added.add(index, value);
+        }
+
+        assert_eq!(built.tree, added.tree);
+        for count in 0..=values.len() {
+            assert_eq!(built.prefix_sum(count), values[..count].iter().sum::<i64>());
+        }
+    }
+
+    #[test]
+    fn test_index_conventions() {
+        let fenwick = KolzoFenwick::from_slice(&[10, 20, 30]);
+
+        // `prefix_sum` counts values, while `add` and `find_prefix` take indices.
+        assert_eq!(fenwick.prefix_sum(0), 0);
+        assert_eq!(fenwick.prefix_sum(1), 10);
+        assert_eq!(fenwick.prefix_sum(3), 60);
+        assert_eq!(fenwick.range_sum(0..1), 10);
+        assert_eq!(fenwick.range_sum(2..=2), 30);
+        assert_eq!(fenwick.range_sum(..), 60);
+        assert_eq!(fenwick.find_prefix(10), Some(0));
+        assert_eq!(fenwick.find_prefix(11), Some(1));
+        assert_eq!(fenwick.find_prefix(60), Some(2));
+        assert_eq!(fenwick.find_prefix(61), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let fenwick = KolzoFenwick::with_len(0);
+        assert!(fenwick.is_empty());
+        assert_eq!(fenwick.prefix_sum(0), 0);
+        assert_eq!(fenwick.range_sum(..), 0);
+        assert_eq!(fenwick.find_prefix(0), None);
+        assert_eq!(KolzoFenwick::default().len(), 0);
+    }
+
+    #[test]
+    fn test_find_prefix_skips_zero_weights() {
+        let fenwick = KolzoFenwick::from_slice(&[0, 0, 4, 0, 0, 0, 1, 0]);
+        assert_eq!(fenwick.find_prefix(0), Some(0));
+        assert_eq!(fenwick.find_prefix(1), Some(2));
+        assert_eq!(fenwick.find_prefix(4), Some(2));
+        assert_eq!(fenwick.find_prefix(5), Some(6));
+        assert_eq!(fenwick.find_prefix(6), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_add_out_of_bounds() {
+        KolzoFenwick::with_len(3).add(3, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_prefix_sum_out_of_bounds() {
+        KolzoFenwick::with_len(3).prefix_sum(4);
+    }
+
+    /// An operation applied to both the tree and the oracle.
+    #[derive(Debug, Clone)]
+    enum Operation {
+        Add(usize, i64),
+        PrefixSum(usize),
+        RangeSum(usize, usize),
+        FindPrefix(i64),
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            (any::<usize>(), 0i64..100).prop_map(|(index, delta)| Operation::Add(index, delta)),
+            any::<usize>().prop_map(Operation::PrefixSum),
+            (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Operation::RangeSum(a, b)),
+            (0i64..3_000).prop_map(Operation::FindPrefix),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_prefix_sum_oracle(
+            initial in proptest::collection::vec(0i64..100, 1..64),
+            operations in proptest::collection::vec(operation(), 0..200),
+        ) {
+            let mut fenwick = KolzoFenwick::from_slice(&initial);
+            let mut values = initial;
+            let len = values.len();
+
+            for operation in operations {
+                match operation {
+                    Operation::Add(index, delta) => {
+                        fenwick.add(index % len, delta);
+                        values[index % len] += delta;
+                    }
+                    Operation::PrefixSum(count) => {
+                        let count = count % (len + 1);
+                        prop_assert_eq!(fenwick.prefix_sum(count), values[..count].iter().sum::<i64>());
+                    }
+                    Operation::RangeSum(a, b) => {
+                        let (a, b) = (a % (len + 1), b % (len + 1));
+                        let (start, end) = (a.min(b), a.max(b));
+                        prop_assert_eq!(fenwick.range_sum(start..end), values[start..end].iter().sum::<i64>());
+                    }
+                    Operation::FindPrefix(target) => {
+                        let mut running = 0;
+                        let expected = values.iter().position(|&value| {
+                            running += value;
+                            running >= target
+                        });
+                        prop_assert_eq!(fenwick.find_prefix(target), expected);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use fenwick_tree::algorithm::KolzoFenwick;
+
+fn main() {
+    let mut fenwick = KolzoFenwick::from_slice(&[3, 1, 4, 1, 5, 9, 2, 6]);
+    println!("PREFIX SUM OF 4 {}", fenwick.prefix_sum(4));
+    println!("RANGE SUM 2..6 {}", fenwick.range_sum(2..6));
+
+    fenwick.add(3, 10);
+    println!("RANGE SUM 2..6 AFTER ADD {}", fenwick.range_sum(2..6));
+
+    // With weights as values, a prefix search picks an index in proportion to its weight.
+    println!("FIND PREFIX 8 {:?}", fenwick.find_prefix(8));
+    println!("FIND PREFIX 100 {:?}", fenwick.find_prefix(100));
+}
@@ -0,0 +1,530 @@
+use queue::algorithm::KolzoQueue;
+
+/// The handle of a node in a [`KolzoGraph`], returned by [`KolzoGraph::add_node`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the position of the node in insertion order, starting at 0.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Whether the edges of a [`KolzoGraph`] have a direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// An edge from `a` to `b` can only be followed from `a`.
+    Directed,
+    /// An edge between `a` and `b` can be followed from either end.
+    Undirected,
+}
+
+/// A graph stored as adjacency lists.
+///
+/// Nodes carry data of type `N` and are addressed by the [`NodeId`] handed out when
+/// they are added. Each node keeps the list of nodes its edges lead to, in the order
+/// the edges were added, which fixes the order traversals visit neighbors in.
+///
+/// Every edge carries a weight. Edges added with [`KolzoGraph::add_edge`] weigh 1;
+/// use [`KolzoGraph::add_weighted_edge`] for other weights.
+#[derive(Debug, Clone)]
+pub struct KolzoGraph<N> {
+    /// The data of every node, indexed by `NodeId`.
+    nodes: Vec<N>,
+    /// The outgoing neighbors of every node, indexed by `NodeId`.
+    adjacency: Vec<Vec<NodeId>>,
+    /// The weight of every edge, parallel to `adjacency`.
+    weights: Vec<Vec<u64>>,
+    /// Whether edges are directed.
+    direction: Direction,
+}
+
+impl<N> KolzoGraph<N> {
+    /// Creates a new empty graph whose edges are followed only from their source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::directed();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// graph.add_edge(a, b);
+    ///
+    /// assert!(graph.has_path(a, b));
+    /// assert!(!graph.has_path(b, a));
+    /// ```
+    pub fn directed() -> Self {
+        Self::with_direction(Direction::Directed)
+    }
+
+    /// Creates a new empty graph whose edges can be followed from either end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::undirected();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// graph.add_edge(a, b);
+    ///
+    /// assert!(graph.has_path(b, a));
+    /// ```
+    pub fn undirected() -> Self {
+        Self::with_direction(Direction::Undirected)
+    }
+
+    /// Creates a new empty graph with the given kind of edges.
+    pub fn with_direction(direction: Direction) -> Self {
+        KolzoGraph {
+            nodes: Vec::new(),
+            adjacency: Vec::new(),
+            weights: Vec::new(),
+            direction,
+        }
+    }
+
+    /// Returns whether the edges of the graph are directed.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Adds a node carrying `data` and returns its handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data stored in the node.
+    pub fn add_node(&mut self, data: N) -> NodeId {
+        self.nodes.push(data);
+        self.adjacency.push(Vec::new());
+        self.weights.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    /// Adds an edge of weight 1 from `from` to `to`, and from `to` back to `from`
+    /// in an undirected graph. A self-loop is stored once.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The node the edge starts at.
+    /// * `to` - The node the edge leads to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node does not belong to the graph.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    /// Adds an edge of the given weight from `from` to `to`, and from `to` back to
+    /// `from` in an undirected graph. A self-loop is stored once.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The node the edge starts at.
+    /// * `to` - The node the edge leads to.
+    /// * `weight` - The cost of following the edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::directed();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// graph.add_weighted_edge(a, b, 7);
+    ///
+    /// assert_eq!(graph.edges(a).collect::<Vec<_>>(), vec![(b, 7)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node does not belong to the graph.
+    pub fn add_weighted_edge(&mut self, from: NodeId, to: NodeId, weight: u64) {
+        self.check_node(from);
+        self.check_node(to);
+
+        self.adjacency[from.0].push(to);
+        self.weights[from.0].push(weight);
+        if self.direction == Direction::Undirected && from != to {
+            self.adjacency[to.0].push(from);
+            self.weights[to.0].push(weight);
+        }
+    }
+
+    /// Returns the data of `id`, or `None` if the node does not belong to the graph.
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.nodes.get(id.0)
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns an iterator over the handles of every node, in insertion order.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    /// Returns the nodes the edges of `id` lead to, in the order the edges were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::undirected();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// let c = graph.add_node(());
+    /// graph.add_edge(a, b);
+    /// graph.add_edge(c, a);
+    ///
+    /// assert_eq!(graph.neighbors(a), &[b, c]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` does not belong to the graph.
+    pub fn neighbors(&self, id: NodeId) -> &[NodeId] {
+        self.check_node(id);
+        &self.adjacency[id.0]
+    }
+
+    /// Returns the edges of `id` as pairs of the node they lead to and their weight,
+    /// in the order the edges were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` does not belong to the graph.
+    pub fn edges(&self, id: NodeId) -> impl Iterator<Item = (NodeId, u64)> + '_ {
+        self.check_node(id);
+        self.adjacency[id.0]
+            .iter()
+            .copied()
+            .zip(self.weights[id.0].iter().copied())
+    }
+
+    /// Visits every node reachable from `start` in breadth-first order.
+    ///
+    /// Nodes are marked as visited when they are queued, so cycles and repeated
+    /// edges never queue a node twice.
+    ///
+    /// # Returns
+    ///
+    /// The reachable nodes, closest first, starting with `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::undirected();
+    /// let ids: Vec<_> = (0..4).map(|value| graph.add_node(value)).collect();
+    /// graph.add_edge(ids[0], ids[1]);
+    /// graph.add_edge(ids[1], ids[2]);
+    /// graph.add_edge(ids[0], ids[3]);
+    ///
+    /// assert_eq!(graph.bfs(ids[0]), vec![ids[0], ids[1], ids[3], ids[2]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` does not belong to the graph.
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        self.check_node(start);
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut pending = KolzoQueue::new();
+
+        visited[start.0] = true;
+        pending.enqueue(start);
+        while let Some(current) = pending.dequeue() {
+            order.push(current);
+            for &neighbor in &self.adjacency[current.0] {
+                if !visited[neighbor.0] {
+                    visited[neighbor.0] = true;
+                    pending.enqueue(neighbor);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Visits every node reachable from `start` in depth-first order.
+    ///
+    /// The traversal is iterative. Neighbors are pushed onto the stack in reverse
+    /// so they are explored in the order the edges were added, which gives the same
+    /// order as the recursive algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The reachable nodes in the order they were first visited, starting with `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::undirected();
+    /// let ids: Vec<_> = (0..4).map(|value| graph.add_node(value)).collect();
+    /// graph.add_edge(ids[0], ids[1]);
+    /// graph.add_edge(ids[1], ids[2]);
+    /// graph.add_edge(ids[0], ids[3]);
+    ///
+    /// assert_eq!(graph.dfs(ids[0]), vec![ids[0], ids[1], ids[2], ids[3]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` does not belong to the graph.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        self.check_node(start);
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(current) = stack.pop() {
+            if visited[current.0] {
+                continue;
+            }
+            visited[current.0] = true;
+            order.push(current);
+
+            for &neighbor in self.adjacency[current.0].iter().rev() {
+                if !visited[neighbor.0] {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Returns `true` if `to` can be reached from `from` by following edges.
+    /// Every node can reach itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node does not belong to the graph.
+    pub fn has_path(&self, from: NodeId, to: NodeId) -> bool {
+        self.check_node(to);
+        self.bfs(from).contains(&to)
+    }
+
+    /// Returns `true` if every node can reach every other node.
+    ///
+    /// In a directed graph this is strong connectivity: every node must be reachable
+    /// from the first node both along the edges and against them. The empty graph
+    /// is connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::algorithm::KolzoGraph;
+    ///
+    /// let mut graph = KolzoGraph::directed();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// graph.add_edge(a, b);
+    /// assert!(!graph.is_connected());
+    ///
+    /// graph.add_edge(b, a);
+    /// assert!(graph.is_connected());
+    /// ```
+    pub fn is_connected(&self) -> bool {
+        if self.nodes.is_empty() {
+            return true;
+        }
+
+        let start = NodeId(0);
+        if self.bfs(start).len() != self.nodes.len() {
+            return false;
+        }
+
+        match self.direction {
+            Direction::Undirected => true,
+            Direction::Directed => self.reversed().bfs(start).len() == self.nodes.len(),
+        }
+    }
+
+    /// Returns the shape of the graph with every edge reversed and no node data.
+    fn reversed(&self) -> KolzoGraph<()> {
+        let mut reversed = KolzoGraph {
+            nodes: vec![(); self.nodes.len()],
+            adjacency: vec![Vec::new(); self.nodes.len()],
+            weights: vec![Vec::new(); self.nodes.len()],
+            direction: self.direction,
+        };
+        for from in self.node_ids() {
+            for (to, weight) in self.edges(from) {
+                reversed.adjacency[to.0].push(from);
+                reversed.weights[to.0].push(weight);
+            }
+        }
+        reversed
+    }
+
+    /// Panics with a clear message if `id` does not belong to the graph.
+    fn check_node(&self, id: NodeId) {
+        assert!(
+            id.0 < self.nodes.len(),
+            "node {} does not belong to a graph of {} nodes",
+            id.0,
+            self.nodes.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the graph
+    ///
+    /// ```text
+    /// 0 - 1 - 3 - 5
+    ///  \ /    |
+    ///   2     4      6 - 7
+    /// ```
+    ///
+    /// with the edges added in the order 0-1, 0-2, 1-2, 1-3, 3-4, 3-5, 6-7. In the
+    /// directed version each edge points from the lower to the higher node.
+    fn sample_graph(direction: Direction) -> (KolzoGraph<usize>, Vec<NodeId>) {
+        let mut graph = KolzoGraph::with_direction(direction);
+        let ids: Vec<_> = (0..8).map(|value| graph.add_node(value)).collect();
+        for (from, to) in [(0, 1), (0, 2), (1, 2), (1, 3), (3, 4), (3, 5), (6, 7)] {
+            graph.add_edge(ids[from], ids[to]);
+        }
+        (graph, ids)
+    }
+
+    fn values(graph: &KolzoGraph<usize>, ids: Vec<NodeId>) -> Vec<usize> {
+        ids.into_iter().map(|id| *graph.node(id).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let (graph, ids) = sample_graph(Direction::Undirected);
+        assert_eq!(graph.neighbors(ids[1]), &[ids[0], ids[2], ids[3]]);
+        assert_eq!(graph.neighbors(ids[4]), &[ids[3]]);
+
+        let (graph, ids) = sample_graph(Direction::Directed);
+        assert_eq!(graph.neighbors(ids[1]), &[ids[2], ids[3]]);
+        assert!(graph.neighbors(ids[4]).is_empty());
+    }
+
+    #[test]
+    fn test_weighted_edges() {
+        let mut graph = KolzoGraph::undirected();
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        let c = graph.add_node(2);
+        graph.add_weighted_edge(a, b, 5);
+        graph.add_edge(a, c);
+        graph.add_weighted_edge(b, b, 0);
+
+        assert_eq!(graph.edges(a).collect::<Vec<_>>(), vec![(b, 5), (c, 1)]);
+        assert_eq!(graph.edges(b).collect::<Vec<_>>(), vec![(a, 5), (b, 0)]);
+        assert_eq!(graph.edges(c).collect::<Vec<_>>(), vec![(a, 1)]);
+        assert_eq!(graph.neighbors(b), &[a, b]);
+    }
+
+    #[test]
+    fn test_bfs_order() {
+        let (graph, ids) = sample_graph(Direction::Undirected);
+        assert_eq!(values(&graph, graph.bfs(ids[0])), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(values(&graph, graph.bfs(ids[4])), vec![4, 3, 1, 5, 0, 2]);
+        assert_eq!(values(&graph, graph.bfs(ids[7])), vec![7, 6]);
+
+        let (graph, ids) = sample_graph(Direction::Directed);
+        assert_eq!(values(&graph, graph.bfs(ids[2])), vec![2]);
+    }
+
+    #[test]
+    fn test_dfs_order() {
+        let (graph, ids) = sample_graph(Direction::Undirected);
+        assert_eq!(values(&graph, graph.dfs(ids[0])), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(values(&graph, graph.dfs(ids[5])), vec![5, 3, 1, 0, 2, 4]);
+
+        let (graph, ids) = sample_graph(Direction::Directed);
+        assert_eq!(values(&graph, graph.dfs(ids[1])), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_has_path() {
+        let (graph, ids) = sample_graph(Direction::Undirected);
+        assert!(graph.has_path(ids[0], ids[5]));
+        assert!(graph.has_path(ids[5], ids[0]));
+        assert!(graph.has_path(ids[6], ids[6]));
+        assert!(!graph.has_path(ids[0], ids[7]));
+
+        let (graph, ids) = sample_graph(Direction::Directed);
+        assert!(graph.has_path(ids[0], ids[5]));
+        assert!(!graph.has_path(ids[5], ids[0]));
+    }
+
+    #[test]
+    fn test_is_connected() {
+        let (mut graph, ids) = sample_graph(Direction::Undirected);
+        assert!(!graph.is_connected());
+        graph.add_edge(ids[5], ids[6]);
+        assert!(graph.is_connected());
+
+        let (mut graph, ids) = sample_graph(Direction::Directed);
+        graph.add_edge(ids[5], ids[6]);
+        assert!(!graph.is_connected());
+
+        let empty: KolzoGraph<()> = KolzoGraph::directed();
+        assert!(empty.is_connected());
+
+        let mut cycle = KolzoGraph::directed();
+        let nodes: Vec<_> = (0..4).map(|value| cycle.add_node(value)).collect();
+        for index in 0..4 {
+            cycle.add_edge(nodes[index], nodes[(index + 1) % 4]);
+        }
+        assert!(cycle.is_connected());
+    }
+
+    #[test]
+    fn test_self_loops_and_cycles_terminate() {
+        let mut graph = KolzoGraph::undirected();
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        graph.add_edge(a, a);
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert_eq!(graph.neighbors(a), &[a, b, b]);
+        assert_eq!(graph.bfs(a), vec![a, b]);
+        assert_eq!(graph.dfs(b), vec![b, a]);
+
+        let mut directed = KolzoGraph::directed();
+        let only = directed.add_node(0);
+        directed.add_edge(only, only);
+        assert_eq!(directed.bfs(only), vec![only]);
+        assert_eq!(directed.dfs(only), vec![only]);
+        assert!(directed.is_connected());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_edge_to_unknown_node_panics() {
+        let mut other = KolzoGraph::<()>::directed();
+        other.add_node(());
+        let foreign = other.add_node(());
+
+        let mut graph = KolzoGraph::directed();
+        let a = graph.add_node(());
+        graph.add_edge(a, foreign);
+    }
+}
@@ -0,0 +1,25 @@
+use graph::algorithm::KolzoGraph;
+
+fn main() {
+    let mut graph = KolzoGraph::undirected();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let e = graph.add_node("e");
+    graph.add_edge(a, b);
+    graph.add_edge(a, c);
+    graph.add_edge(b, d);
+    graph.add_edge(c, d);
+
+    let names = |ids: Vec<_>| {
+        ids.into_iter()
+            .map(|id| *graph.node(id).unwrap())
+            .collect::<Vec<_>>()
+    };
+    println!("BFS {:?}", names(graph.bfs(a)));
+    println!("DFS {:?}", names(graph.dfs(a)));
+    println!("HAS PATH a -> d {}", graph.has_path(a, d));
+    println!("HAS PATH a -> e {}", graph.has_path(a, e));
+    println!("CONNECTED {}", graph.is_connected());
+}
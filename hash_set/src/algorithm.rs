@@ -0,0 +1,345 @@
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+
+/// A set of unique values stored by hash.
+///
+/// The set is a map from each value to `()`, so every operation is an expected
+/// O(1) map lookup. Iteration order is unspecified.
+#[derive(Debug, Clone)]
+pub struct KolzoHashSet<T: Eq + Hash> {
+    /// The values of the set, as keys of a map with no payload.
+    map: HashMap<T, ()>,
+}
+
+impl<T: Eq + Hash> KolzoHashSet<T> {
+    /// Creates a new empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let set: KolzoHashSet<i32> = KolzoHashSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoHashSet {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was not in the set yet.
+    /// * `false` if an equal value was already present; the set is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let mut set = KolzoHashSet::new();
+    /// assert!(set.insert(1));
+    /// assert!(!set.insert(1));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        match self.map.entry(value) {
+            hash_map::Entry::Occupied(_) => false,
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(());
+                true
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let set: KolzoHashSet<i32> = [1, 2].into_iter().collect();
+    /// assert!(set.contains(&1));
+    /// assert!(!set.contains(&3));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains_key(value)
+    }
+
+    /// Removes `value` from the set.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was present and has been removed.
+    /// * `false` if the value was not in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let mut set: KolzoHashSet<i32> = [1].into_iter().collect();
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&1));
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the values in unspecified order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            keys: self.map.keys(),
+        }
+    }
+
+    /// Returns an iterator over the values that are in `self`, `other` or both,
+    /// each yielded once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let first: KolzoHashSet<i32> = [1, 2].into_iter().collect();
+    /// let second: KolzoHashSet<i32> = [2, 3].into_iter().collect();
+    ///
+    /// let mut union: Vec<_> = first.union(&second).copied().collect();
+    /// union.sort();
+    /// assert_eq!(union, vec![1, 2, 3]);
+    /// ```
+    pub fn union<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().chain(other.difference(self))
+    }
+
+    /// Returns an iterator over the values that are in both `self` and `other`.
+    ///
+    /// The smaller set is walked and each value is looked up in the larger one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let first: KolzoHashSet<i32> = [1, 2].into_iter().collect();
+    /// let second: KolzoHashSet<i32> = [2, 3].into_iter().collect();
+    ///
+    /// assert_eq!(first.intersection(&second).collect::<Vec<_>>(), vec![&2]);
+    /// ```
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        let (smaller, larger) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        smaller.iter().filter(move |value| larger.contains(value))
+    }
+
+    /// Returns an iterator over the values that are in `self` but not in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let first: KolzoHashSet<i32> = [1, 2].into_iter().collect();
+    /// let second: KolzoHashSet<i32> = [2, 3].into_iter().collect();
+    ///
+    /// assert_eq!(first.difference(&second).collect::<Vec<_>>(), vec![&1]);
+    /// ```
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().filter(move |value| !other.contains(value))
+    }
+
+    /// Returns `true` if every value of `self` is also in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hash_set::algorithm::KolzoHashSet;
+    ///
+    /// let small: KolzoHashSet<i32> = [1].into_iter().collect();
+    /// let large: KolzoHashSet<i32> = [1, 2].into_iter().collect();
+    ///
+    /// assert!(small.is_subset(&large));
+    /// assert!(!large.is_subset(&small));
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|value| other.contains(value))
+    }
+}
+
+impl<T: Eq + Hash> Default for KolzoHashSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for KolzoHashSet<T> {
+    /// Inserts every value of the iterator, skipping values that are already present.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for KolzoHashSet<T> {
+    /// Builds a set holding each distinct value of the iterator once.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = KolzoHashSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, T: Eq + Hash> IntoIterator for &'a KolzoHashSet<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`KolzoHashSet`], in unspecified order.
+pub struct Iter<'a, T> {
+    /// The keys of the underlying map.
+    keys: hash_map::Keys<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
+
+    fn sorted<'a>(values: impl Iterator<Item = &'a i32>) -> Vec<i32> {
+        let mut values: Vec<_> = values.copied().collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let mut set = KolzoHashSet::new();
+
+        assert!(set.insert("a"));
+        assert!(set.insert("b"));
+        assert!(!set.insert("a"));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&"a"));
+
+        assert!(set.remove(&"a"));
+        assert!(!set.remove(&"a"));
+        assert!(!set.contains(&"a"));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![&"b"]);
+    }
+
+    #[test]
+    fn test_duplicates_in_input() {
+        let mut set: KolzoHashSet<i32> = vec![1, 1, 2, 2, 2, 3].into_iter().collect();
+        assert_eq!(set.len(), 3);
+
+        set.extend([3, 4, 4]);
+        assert_eq!(set.len(), 4);
+        assert_eq!(sorted(set.iter()), vec![1, 2, 3, 4]);
+        assert_eq!(set.iter().len(), 4);
+    }
+
+    #[test]
+    fn test_algebra_on_overlapping_sets() {
+        let first: KolzoHashSet<i32> = (0..6).collect();
+        let second: KolzoHashSet<i32> = (3..9).collect();
+
+        assert_eq!(sorted(first.union(&second)), (0..9).collect::<Vec<_>>());
+        assert_eq!(sorted(first.intersection(&second)), vec![3, 4, 5]);
+        assert_eq!(sorted(second.intersection(&first)), vec![3, 4, 5]);
+        assert_eq!(sorted(first.difference(&second)), vec![0, 1, 2]);
+        assert_eq!(sorted(second.difference(&first)), vec![6, 7, 8]);
+
+        assert!(!first.is_subset(&second));
+        let overlap: KolzoHashSet<i32> = first.intersection(&second).copied().collect();
+        assert!(overlap.is_subset(&first));
+        assert!(overlap.is_subset(&second));
+        assert!(overlap.is_subset(&overlap));
+    }
+
+    #[test]
+    fn test_algebra_with_empty_set() {
+        let empty: KolzoHashSet<i32> = KolzoHashSet::new();
+        let set: KolzoHashSet<i32> = (0..3).collect();
+
+        assert_eq!(sorted(set.union(&empty)), vec![0, 1, 2]);
+        assert_eq!(set.intersection(&empty).count(), 0);
+        assert_eq!(sorted(set.difference(&empty)), vec![0, 1, 2]);
+        assert_eq!(empty.difference(&set).count(), 0);
+        assert!(empty.is_subset(&set));
+        assert!(!set.is_subset(&empty));
+    }
+
+    #[test]
+    fn test_matches_hash_set() {
+        let mut rng = StdRng::seed_from_u64(222);
+        let mut set = KolzoHashSet::new();
+        let mut model = HashSet::new();
+
+        for _ in 0..10_000 {
+            let value = rng.gen_range(0..300);
+            match rng.gen_range(0..3) {
+                0 => assert_eq!(set.insert(value), model.insert(value)),
+                1 => assert_eq!(set.remove(&value), model.remove(&value)),
+                _ => assert_eq!(set.contains(&value), model.contains(&value)),
+            }
+            assert_eq!(set.len(), model.len());
+        }
+
+        let other_values: Vec<i32> = (0..150).map(|_| rng.gen_range(0..300)).collect();
+        let other: KolzoHashSet<i32> = other_values.iter().copied().collect();
+        let other_model: HashSet<i32> = other_values.into_iter().collect();
+
+        assert_eq!(sorted(set.union(&other)), sorted(model.union(&other_model)));
+        assert_eq!(
+            sorted(set.intersection(&other)),
+            sorted(model.intersection(&other_model))
+        );
+        assert_eq!(
+            sorted(set.difference(&other)),
+            sorted(model.difference(&other_model))
+        );
+        assert_eq!(set.is_subset(&other), model.is_subset(&other_model));
+    }
+}
@@ -0,0 +1,22 @@
+use hash_set::algorithm::KolzoHashSet;
+
+fn main() {
+    let first: KolzoHashSet<i32> = [1, 2, 3, 4].into_iter().collect();
+    let second: KolzoHashSet<i32> = [3, 4, 5].into_iter().collect();
+
+    let mut union: Vec<_> = first.union(&second).collect();
+    union.sort();
+    println!("UNION {:?}", union);
+
+    let mut intersection: Vec<_> = first.intersection(&second).collect();
+    intersection.sort();
+    println!("INTERSECTION {:?}", intersection);
+
+    let mut difference: Vec<_> = first.difference(&second).collect();
+    difference.sort();
+    println!("DIFFERENCE {:?}", difference);
+
+    let subset: KolzoHashSet<i32> = [3, 4].into_iter().collect();
+    println!("IS SUBSET {}", subset.is_subset(&second));
+    println!("LENGTH {}", first.len());
+}
@@ -0,0 +1,387 @@
+use double_linked_list::algorithm::{KolzoDoublyLinkedList, NodeHandle};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// A cached value with its bookkeeping.
+struct Entry<K, V> {
+    /// The cached value.
+    value: V,
+    /// How many times the entry has been inserted or read.
+    frequency: u64,
+    /// The node holding the key in the bucket for `frequency`.
+    handle: NodeHandle<Rc<K>>,
+}
+
+/// A cache of bounded size that evicts the least frequently used entry.
+///
+/// Every entry counts how many times it was inserted or read. Entries with the
+/// same count share a bucket, a [`KolzoDoublyLinkedList`] of keys ordered from
+/// least to most recently used, and the cache tracks the lowest count in use. Each
+/// entry keeps a handle to its node, so moving it to the next bucket and evicting
+/// the front of the lowest bucket are both O(1). Ties in frequency are broken by
+/// evicting the least recently used entry.
+///
+/// The index and the buckets share each key through an `Rc`, so keys do not need
+/// to be `Clone`.
+pub struct KolzoLfuCache<K: Eq + Hash, V> {
+    /// The maximum number of entries.
+    capacity: usize,
+    /// The entries by key.
+    entries: HashMap<Rc<K>, Entry<K, V>>,
+    /// The keys of the entries with each frequency, least recently used first.
+    buckets: HashMap<u64, KolzoDoublyLinkedList<Rc<K>>>,
+    /// The lowest frequency of any entry. Only meaningful while the cache is not
+    /// empty.
+    min_frequency: u64,
+}
+
+impl<K: Eq + Hash, V> KolzoLfuCache<K, V> {
+    /// Creates a new empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lfu_cache::algorithm::KolzoLfuCache;
+    ///
+    /// let cache: KolzoLfuCache<&str, i32> = KolzoLfuCache::new(2);
+    /// assert_eq!(cache.capacity(), 2);
+    /// assert!(cache.is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "an LFU cache needs a capacity of at least 1");
+        KolzoLfuCache {
+            capacity,
+            entries: HashMap::new(),
+            buckets: HashMap::new(),
+            min_frequency: 0,
+        }
+    }
+
+    /// Returns the value for `key` and counts the access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lfu_cache::algorithm::KolzoLfuCache;
+    ///
+    /// let mut cache = KolzoLfuCache::new(1);
+    /// cache.put("a", 1);
+    /// assert_eq!(cache.get(&"a"), Some(&1));
+    /// assert_eq!(cache.get(&"b"), None);
+    /// ```
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        self.touch(key);
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Returns the value for `key` without counting the access.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Returns how many times `key` has been inserted or read, or `None` if it is
+    /// not cached.
+    pub fn frequency(&self, key: &K) -> Option<u64> {
+        self.entries.get(key).map(|entry| entry.frequency)
+    }
+
+    /// Inserts or updates an entry.
+    ///
+    /// Updating an existing key replaces its value and counts as an access. Adding
+    /// a new key to a full cache first evicts the entry with the lowest frequency,
+    /// choosing the least recently used among equals.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry.
+    /// * `value` - The value to cache.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((key, value))` with the evicted entry, if one had to make room.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lfu_cache::algorithm::KolzoLfuCache;
+    ///
+    /// let mut cache = KolzoLfuCache::new(2);
+    /// cache.put("a", 1);
+    /// cache.put("b", 2);
+    /// cache.get(&"a");
+    /// assert_eq!(cache.put("c", 3), Some(("b", 2)));
+    /// ```
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.value = value;
+            self.touch(&key);
+            return None;
+        }
+
+        let evicted = if self.entries.len() == self.capacity {
+            self.evict()
+        } else {
+            None
+        };
+
+        let key = Rc::new(key);
+        let handle = self
+            .buckets
+            .entry(1)
+            .or_default()
+            .append_handle(Rc::clone(&key));
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                frequency: 1,
+                handle,
+            },
+        );
+        self.min_frequency = 1;
+        evicted
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Moves the entry for `key`, which must be cached, to the next frequency
+    /// bucket as its most recently used key.
+    fn touch(&mut self, key: &K) {
+        let entry = self.entries.get_mut(key).expect("touched keys are cached");
+        let bucket = self
+            .buckets
+            .get_mut(&entry.frequency)
+            .expect("every entry is in the bucket for its frequency");
+
+        // SAFETY: the handle was returned by `append_handle` on this bucket, and a
+        // node leaves its bucket only here or in `evict`, which both forget the
+        // handle right away.
+        let shared_key = unsafe { bucket.remove_handle(entry.handle) };
+        if bucket.is_empty() {
+            self.buckets.remove(&entry.frequency);
+            if self.min_frequency == entry.frequency {
+                self.min_frequency += 1;
+            }
+        }
+
+        entry.frequency += 1;
+        entry.handle = self
+            .buckets
+            .entry(entry.frequency)
+            .or_default()
+            .append_handle(shared_key);
+    }
+
+    /// Removes the least recently used entry of the lowest frequency.
+    fn evict(&mut self) -> Option<(K, V)> {
+        let bucket = self.buckets.get_mut(&self.min_frequency)?;
+        let shared_key = bucket.pop_first()?;
+        if bucket.is_empty() {
+            self.buckets.remove(&self.min_frequency);
+        }
+
+        let (indexed_key, entry) = self
+            .entries
+            .remove_entry(&*shared_key)
+            .expect("every key in a bucket is cached");
+        drop(shared_key);
+        let key = Rc::try_unwrap(indexed_key)
+            .unwrap_or_else(|_| unreachable!("a key is shared only by its entry and its bucket"));
+        Some((key, entry.value))
+    }
+}
+
+// The handles passed to `remove_handle` are checked by running these tests under
+// Miri with `cargo +nightly miri test -p lfu_cache`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// A straightforward LFU cache that scans every entry to find the one to evict.
+    struct NaiveLfu {
+        capacity: usize,
+        /// Each entry as `(key, value, frequency, last used)`.
+        entries: Vec<(u32, u32, u64, u64)>,
+        clock: u64,
+    }
+
+    impl NaiveLfu {
+        fn get(&mut self, key: u32) -> Option<u32> {
+            self.clock += 1;
+            let entry = self.entries.iter_mut().find(|entry| entry.0 == key)?;
+            entry.2 += 1;
+            entry.3 = self.clock;
+            Some(entry.1)
+        }
+
+        fn put(&mut self, key: u32, value: u32) -> Option<(u32, u32)> {
+            self.clock += 1;
+            if let Some(entry) = self.entries.iter_mut().find(|entry| entry.0 == key) {
+                entry.1 = value;
+                entry.2 += 1;
+                entry.3 = self.clock;
+                return None;
+            }
+
+            let mut evicted = None;
+            if self.entries.len() == self.capacity {
+                let (index, _) = self
+                    .entries
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| (entry.2, entry.3))
+                    .unwrap();
+                let entry = self.entries.swap_remove(index);
+                evicted = Some((entry.0, entry.1));
+            }
+            self.entries.push((key, value, 1, self.clock));
+            evicted
+        }
+    }
+
+    #[test]
+    fn test_known_eviction_sequence() {
+        let mut cache = KolzoLfuCache::new(2);
+        assert_eq!(cache.put(1, 1), None);
+        assert_eq!(cache.put(2, 2), None);
+        assert_eq!(cache.get(&1), Some(&1));
+
+        // 2 has been used once and 1 twice.
+        assert_eq!(cache.put(3, 3), Some((2, 2)));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&3), Some(&3));
+
+        // 1 and 3 have both been used twice; 1 less recently.
+        assert_eq!(cache.put(4, 4), Some((1, 1)));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&3), Some(&3));
+        assert_eq!(cache.get(&4), Some(&4));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_ties_evict_the_least_recent() {
+        let mut cache = KolzoLfuCache::new(3);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+
+        // All three have frequency 2 now, touched in the order c, a, b.
+        cache.get(&"c");
+        cache.get(&"a");
+        cache.get(&"b");
+        assert_eq!(cache.put("d", 4), Some(("c", 3)));
+
+        // The new entry has the lowest frequency, so it goes first.
+        assert_eq!(cache.put("e", 5), Some(("d", 4)));
+        assert_eq!(cache.frequency(&"a"), Some(2));
+        assert_eq!(cache.frequency(&"e"), Some(1));
+    }
+
+    #[test]
+    fn test_update_counts_as_an_access() {
+        let mut cache = KolzoLfuCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.put("a", 10), None);
+
+        assert_eq!(cache.frequency(&"a"), Some(2));
+        assert_eq!(cache.put("c", 3), Some(("b", 2)));
+        assert_eq!(cache.peek(&"a"), Some(&10));
+    }
+
+    #[test]
+    fn test_peek_does_not_count() {
+        let mut cache = KolzoLfuCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+
+        assert_eq!(cache.peek(&"a"), Some(&1));
+        assert_eq!(cache.peek(&"a"), Some(&1));
+        assert_eq!(cache.frequency(&"a"), Some(1));
+        assert_eq!(cache.put("c", 3), Some(("a", 1)));
+    }
+
+    #[test]
+    fn test_capacity_one() {
+        let mut cache = KolzoLfuCache::new(1);
+        cache.put(1, "one");
+        cache.get(&1);
+        cache.get(&1);
+
+        // A full cache of one always evicts its only entry, whatever its frequency.
+        assert_eq!(cache.put(2, "two"), Some((1, "one")));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&2), Some(&"two"));
+        assert_eq!(cache.put(2, "deux"), None);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity of at least 1")]
+    fn test_zero_capacity_panics() {
+        KolzoLfuCache::<i32, i32>::new(0);
+    }
+
+    #[test]
+    fn test_keys_need_not_be_clone() {
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        struct Key(u8);
+
+        let mut cache = KolzoLfuCache::new(1);
+        cache.put(Key(1), ());
+        assert_eq!(cache.put(Key(2), ()), Some((Key(1), ())));
+    }
+
+    #[test]
+    fn test_matches_naive_lfu() {
+        let mut rng = StdRng::seed_from_u64(232);
+        for capacity in [1, 2, 5, 20] {
+            let mut cache = KolzoLfuCache::new(capacity);
+            let mut model = NaiveLfu {
+                capacity,
+                entries: Vec::new(),
+                clock: 0,
+            };
+
+            for _ in 0..5_000 {
+                let key = rng.gen_range(0..30);
+                if rng.gen_bool(0.5) {
+                    let value = rng.gen();
+                    assert_eq!(cache.put(key, value), model.put(key, value));
+                } else {
+                    assert_eq!(cache.get(&key).copied(), model.get(key));
+                }
+                assert_eq!(cache.len(), model.entries.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use lfu_cache::algorithm::KolzoLfuCache;
+
+fn main() {
+    let mut cache = KolzoLfuCache::new(2);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    println!("GET a {:?}", cache.get(&"a"));
+    println!("EVICTED {:?}", cache.put("c", 3));
+    println!("GET b {:?}", cache.get(&"b"));
+    println!("GET c {:?}", cache.get(&"c"));
+    println!("EVICTED {:?}", cache.put("d", 4));
+    println!("LENGTH {} CAPACITY {}", cache.len(), cache.capacity());
+}
@@ -0,0 +1,50 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use linked_list::algorithm::KolzoLinkedList;
+use list_common::arena::SharedArena;
+
+const NODES: u64 = 100_000;
+
+/// Appends `NODES` values to an empty list and drops it, with the nodes on the heap
+/// and in an arena. The reused arena already holds the slots freed by the previous
+/// iteration, so it shows the steady state of a list that is filled and emptied
+/// over and over.
+fn bulk_append(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_append");
+
+    group.bench_function("heap", |b| {
+        b.iter(|| {
+            let mut list = KolzoLinkedList::new();
+            for value in 0..NODES {
+                list.append(black_box(value));
+            }
+            black_box(list.last().copied())
+        })
+    });
+
+    group.bench_function("fresh_arena", |b| {
+        b.iter(|| {
+            let arena = SharedArena::new();
+            let mut list = KolzoLinkedList::new_in(&arena);
+            for value in 0..NODES {
+                list.append(black_box(value));
+            }
+            black_box(list.last().copied())
+        })
+    });
+
+    group.bench_function("reused_arena", |b| {
+        let arena = SharedArena::new();
+        b.iter(|| {
+            let mut list = KolzoLinkedList::new_in(&arena);
+            for value in 0..NODES {
+                list.append(black_box(value));
+            }
+            black_box(list.last().copied())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bulk_append);
+criterion_main!(benches);
@@ -1,10 +1,35 @@
+use list_common::arena::SharedArena;
+use list_common::linear::LinearList;
+use list_common::memory::{DeepMeasure, MemoryReport};
+use list_common::node::NodeAllocator;
+use list_common::pretty::{render, RenderOptions};
+#[cfg(feature = "stats")]
+use list_common::stats::ListStats;
+use list_common::stats::StatsCounter;
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Bound, ControlFlow, RangeBounds};
+use std::ptr::NonNull;
+
 /// A node in the linked list.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// The list owns every node. They are made by the list's [`NodeAllocator`], on the
+/// heap or in a [`SharedArena`], and linked by raw pointers, so `tail` and the
+/// cursors can point into the chain.
+#[derive(Debug)]
 pub struct Node<T> {
     /// The data stored in the node.
     data: T,
     /// The next node in the linked list.
-    next: Option<Box<Node<T>>>,
+    next: Option<NonNull<Node<T>>>,
 }
 
 impl<T> Node<T> {
@@ -17,9 +42,10 @@ impl<T> Node<T> {
     /// # Examples
     ///
     /// ```
+    /// use linked_list::algorithm::Node;
+    ///
     /// let node = Node::new(5);
-    /// assert_eq!(node.data, 5);
-    /// assert!(node.next.is_none());
+    /// assert_eq!(format!("{:?}", node), "Node { data: 5, next: None }");
     /// ```
     pub fn new(value: T) -> Self {
         Node {
@@ -27,54 +53,175 @@ impl<T> Node<T> {
             next: None,
         }
     }
+
+    /// Returns the node after this one.
+    fn next_node(&self) -> Option<&Node<T>> {
+        // SAFETY: a link always points at a live node of the same list, which
+        // cannot be freed while this node is borrowed.
+        self.next.map(|next| unsafe { &*next.as_ptr() })
+    }
 }
 
 /// A singly linked list implementation in Rust.
-#[derive(Debug)]
 pub struct KolzoLinkedList<T> {
     /// The head of the linked list.
-    head: Option<Box<Node<T>>>,
+    head: Option<NonNull<Node<T>>>,
     /// The tail of the linked list, represented as a raw pointer for efficient appending.
-    tail: Option<*mut Node<T>>,
+    ///
+    /// Invariant: `None` exactly when `head` is `None`, and otherwise a pointer to the
+    /// last node reachable from `head`. Every method that frees, moves or relinks the
+    /// last node updates it before returning, so it never dangles.
+    tail: Option<NonNull<Node<T>>>,
     /// The length of the linked list.
-    length: u64,
+    length: usize,
+    /// Counters for node visits, allocations and frees, active with the `stats` feature.
+    stats: StatsCounter,
+    /// Makes and frees the nodes, on the heap or in the arena given to
+    /// [`KolzoLinkedList::new_in`].
+    allocator: NodeAllocator<Node<T>>,
+    /// The list owns boxed nodes holding `T`.
+    marker: PhantomData<Box<Node<T>>>,
 }
 
-impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
+// SAFETY: the list owns every node reachable from `head`, the way a `Vec<T>` owns
+// its elements, and `tail` only points into that chain. Sending the list sends the only
+// way to reach its nodes along with it, and through `&self` the nodes are only read,
+// so the list can cross threads under the same conditions as `Vec<T>`. The stats
+// counters are atomics, or zero-sized without the `stats` feature, and an arena is
+// only touched under its lock.
+unsafe impl<T: Send> Send for KolzoLinkedList<T> {}
+unsafe impl<T: Sync> Sync for KolzoLinkedList<T> {}
+
+impl<T> KolzoLinkedList<T> {
     /// Creates a new empty linked list.
     ///
     /// # Examples
     ///
     /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
     /// let list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-    /// assert_eq!(list.length, 0);
+    /// assert_eq!(list.len(), 0);
     /// ```
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new empty linked list whose nodes are allocated in `arena`.
+    ///
+    /// The list behaves exactly like one made by [`KolzoLinkedList::new`], but
+    /// instead of one heap allocation per node it takes slots from the arena's
+    /// chunks, and the slots of removed nodes are reused by later appends. Any
+    /// number of lists, on any threads, can share one arena. Lists split off this
+    /// one, drained from it or cloned from it use the same arena.
+    ///
+    /// # Arguments
+    ///
+    /// * `arena` - The arena to allocate the nodes in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use list_common::arena::SharedArena;
+    ///
+    /// let arena = SharedArena::with_chunk_size(256);
+    /// let mut list = KolzoLinkedList::new_in(&arena);
+    /// list.extend(0..100);
+    /// assert_eq!(arena.chunk_count(), 1);
+    ///
+    /// // Removed nodes give their slots back for the next appends.
+    /// list.truncate(10);
+    /// list.extend(0..200);
+    /// assert_eq!(arena.chunk_count(), 1);
+    /// assert_eq!(list.len(), 210);
+    /// ```
+    pub fn new_in(arena: &SharedArena<Node<T>>) -> Self {
+        Self::with_allocator(NodeAllocator::in_arena(arena))
+    }
+
+    /// Creates an empty list that allocates its nodes in the same place as this
+    /// one, so that nodes can move between the two.
+    fn sibling(&self) -> Self {
+        Self::with_allocator(self.allocator.sibling())
+    }
+
+    /// Creates an empty list that allocates its nodes with `allocator`.
+    fn with_allocator(allocator: NodeAllocator<Node<T>>) -> Self {
         KolzoLinkedList {
             head: None,
             tail: None,
             length: 0,
+            stats: StatsCounter::new(),
+            allocator,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns `other` with nodes this list can take over: `other` itself if the two
+    /// lists allocate their nodes in the same place, and otherwise a list with the
+    /// same values in nodes of this list's allocator.
+    fn adopt(&self, mut other: KolzoLinkedList<T>) -> KolzoLinkedList<T> {
+        if self.allocator.shares_with(&other.allocator) {
+            return other;
         }
+
+        let mut adopted = self.sibling();
+        adopted.extend(other.drain(..));
+        adopted
+    }
+
+    /// Returns the number of elements in the linked list, in O(1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the linked list has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.append(1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
     }
 
-    /// Prints the linked list.
+    /// Prints the linked list to stdout in its [`Display`](fmt::Display) format.
     ///
     /// # Examples
     ///
     /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
     /// let mut list = KolzoLinkedList::new();
     /// list.append(1);
     /// list.append(2);
     /// list.append(3);
     /// list.print(); // Output: 1 -> 2 -> 3 -> None
     /// ```
-    pub fn print(&self) {
-        let mut current = self.head.as_ref();
-        while let Some(node) = current {
-            print!("{:?} -> ", node.data);
-            current = node.next.as_ref();
-        }
-        println!("None");
+    pub fn print(&self)
+    where
+        T: fmt::Display,
+    {
+        println!("{}", self);
     }
 
     /// Appends a value to the end of the linked list.
@@ -86,29 +233,303 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     /// # Examples
     ///
     /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
     /// let mut list = KolzoLinkedList::new();
     /// list.append(1);
     /// list.append(2);
     /// list.append(3);
-    /// assert_eq!(list.length, 3);
+    /// assert_eq!(list.len(), 3);
     /// ```
     pub fn append(&mut self, value: T) {
-        let mut new_node = Box::new(Node::new(value));
-        let new_node_pointer: *mut _ = &mut *new_node;
+        self.stats.record_allocation();
+        let node = self.allocator.allocate(Node::new(value));
+        self.append_node(node);
+    }
+
+    /// Links an unlinked node in after the tail in O(1).
+    fn append_node(&mut self, new_node: NonNull<Node<T>>) {
+        self.link_back(new_node);
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
 
+    /// Links an unlinked node in after the tail without checking the invariants.
+    ///
+    /// Used by the methods that link many nodes in a row, which check the
+    /// invariants once when they are done rather than after every node.
+    fn link_back(&mut self, new_node: NonNull<Node<T>>) {
         match self.tail {
-            Some(tail_pointer) => unsafe {
-                (*tail_pointer).next = Some(new_node);
+            // SAFETY: by the invariant on `tail` it points at the last node, which the
+            // list owns and nothing else borrows while we hold `&mut self`.
+            Some(mut tail_pointer) => unsafe {
+                tail_pointer.as_mut().next = Some(new_node);
             },
             None => {
                 self.head = Some(new_node);
             }
         }
 
-        self.tail = Some(new_node_pointer);
+        self.tail = Some(new_node);
         self.length += 1;
     }
 
+    /// Returns the first node.
+    fn head_node(&self) -> Option<&Node<T>> {
+        // SAFETY: `head` points at a live node the list owns, and the reference
+        // borrows `self`.
+        self.head.map(|head| unsafe { &*head.as_ptr() })
+    }
+
+    /// Returns the first node mutably.
+    fn head_node_mut(&mut self) -> Option<&mut Node<T>> {
+        // SAFETY: as in `head_node`, with the reference mutably borrowing `self`.
+        self.head.map(|head| unsafe { &mut *head.as_ptr() })
+    }
+
+    /// Moves every element of `other` to the end of the linked list in O(1).
+    ///
+    /// The nodes of `other` are linked in as they are, without copying or
+    /// reallocating. Since `other` is taken by value, a list cannot be appended to
+    /// itself:
+    ///
+    /// ```compile_fail,E0505
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+    /// list.append_list(list);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements are moved to the end of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut front: KolzoLinkedList<i32> = (1..=2).collect();
+    /// let back: KolzoLinkedList<i32> = (3..=4).collect();
+    ///
+    /// front.append_list(back);
+    /// assert_eq!(front.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+    /// assert_eq!(front.len(), 4);
+    /// ```
+    pub fn append_list(&mut self, other: KolzoLinkedList<T>) {
+        let mut other = self.adopt(other);
+        if let Some(other_head) = other.head.take() {
+            match self.tail {
+                // SAFETY: by the invariant on `tail` it points at the last node, which
+                // the list owns and nothing else borrows while we hold `&mut self`.
+                Some(mut tail_pointer) => unsafe {
+                    tail_pointer.as_mut().next = Some(other_head);
+                },
+                None => {
+                    self.head = Some(other_head);
+                }
+            }
+
+            // The nodes `other.tail` points into now belong to this list.
+            self.tail = other.tail.take();
+            self.length += std::mem::take(&mut other.length);
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Splits the linked list in two at the given index, keeping the first `at`
+    /// elements and returning a new list holding the rest.
+    ///
+    /// The back nodes are handed over as they are, so this walks `at` nodes but
+    /// copies nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index of the first element to move into the returned list.
+    ///
+    /// # Returns
+    ///
+    /// * `KolzoLinkedList<T>` - The elements from index `at` onwards. It is empty
+    ///   when `at` is the length of the list, and holds every element when `at` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = (1..=5).collect();
+    /// let back = list.split_off(2);
+    ///
+    /// assert_eq!(list.to_string(), "1 -> 2 -> None");
+    /// assert_eq!(back.to_string(), "3 -> 4 -> 5 -> None");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the list, like
+    /// `std::collections::LinkedList::split_off`.
+    pub fn split_off(&mut self, at: usize) -> KolzoLinkedList<T> {
+        assert!(
+            at <= self.len(),
+            "cannot split off at index {} of a list of length {}",
+            at,
+            self.len()
+        );
+
+        let mut back = self.sibling();
+        if at == self.len() {
+            return back;
+        }
+
+        back.tail = self.tail;
+        back.length = self.length - at;
+        self.length = at;
+
+        if at == 0 {
+            back.head = self.head.take();
+            self.tail = None;
+        } else {
+            // Walk to the last node that stays in this list.
+            let mut last_kept = self.head;
+            for _ in 1..at {
+                self.stats.record_visit();
+                // SAFETY: links point at live nodes the list owns.
+                last_kept = last_kept.and_then(|node| unsafe { node.as_ref() }.next);
+            }
+
+            if let Some(mut node) = last_kept {
+                self.stats.record_visit();
+                // SAFETY: as above, and nothing else borrows the list while we hold
+                // `&mut self`.
+                back.head = unsafe { node.as_mut() }.next.take();
+                self.tail = Some(node);
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        {
+            self.assert_invariants();
+            back.assert_invariants();
+        }
+        back
+    }
+
+    /// Keeps the first `new_len` elements and drops the rest.
+    ///
+    /// The dropped nodes are freed one at a time, so truncating a long list does
+    /// not recurse through its `next` links.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_len` - The number of elements to keep. If it is at least the length
+    ///   of the list, nothing happens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = (1..=5).collect();
+    /// list.truncate(2);
+    /// list.append(6);
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 6 -> None");
+    ///
+    /// list.truncate(10);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len() {
+            return;
+        }
+
+        let mut dropped = self.split_off(new_len).head.take();
+        while let Some(node) = dropped {
+            self.stats.record_free();
+            // SAFETY: the chain was cut off this list and nothing else links to it.
+            dropped = unsafe { self.allocator.free(node) }.next;
+        }
+    }
+
+    /// Removes every element, leaving the list empty.
+    ///
+    /// This is `truncate(0)`, so the nodes are freed one at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3]);
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Resizes the list to `new_len` elements, as `Vec::resize` does.
+    ///
+    /// A longer list is truncated, and a shorter one is filled up at the back with
+    /// clones of `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_len` - The length of the list afterwards.
+    /// * `value` - The value appended when the list grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2]);
+    /// list.resize(4, 0);
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 0 -> 0 -> None");
+    ///
+    /// list.resize(1, 0);
+    /// assert_eq!(list.to_string(), "1 -> None");
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let missing = new_len.saturating_sub(self.len());
+        self.truncate(new_len);
+        self.extend(std::iter::repeat_n(value, missing));
+    }
+
+    /// Resizes the list to `new_len` elements, filling it up with values returned
+    /// by `f`, as `Vec::resize_with` does.
+    ///
+    /// A longer list is truncated, and a shorter one is filled up at the back by
+    /// calling `f` once per missing element, so `T` does not need to be `Clone`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_len` - The length of the list afterwards.
+    /// * `f` - Produces the values appended when the list grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut next = 10;
+    /// let mut list = KolzoLinkedList::from([1]);
+    /// list.resize_with(3, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(list.to_string(), "1 -> 11 -> 12 -> None");
+    /// ```
+    pub fn resize_with(&mut self, new_len: usize, f: impl FnMut() -> T) {
+        let missing = new_len.saturating_sub(self.len());
+        self.truncate(new_len);
+        self.extend(std::iter::repeat_with(f).take(missing));
+    }
+
     /// Removes and returns the last element from the linked list.
     ///
     /// # Returns
@@ -118,6 +539,8 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     /// # Examples
     ///
     /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
     /// let mut list = KolzoLinkedList::new();
     /// list.append(1);
     /// list.append(2);
@@ -133,34 +556,42 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     ///
     /// This function does not panic.
     pub fn pop(&mut self) -> Option<T> {
-        if self.head.is_none() {
-            return None;
-        }
+        let head = self.head?;
 
-        if let Some(node) = &self.head {
-            if node.next.is_none() {
-                let head_value = self.head.take().map(|head| {
-                    self.tail = None;
-                    self.length -= 1;
-                    head.data
-                });
-                return head_value;
-            }
+        // SAFETY: `head` points at a live node the list owns.
+        if unsafe { head.as_ref() }.next.is_none() {
+            self.head = None;
+            self.tail = None;
+            self.length -= 1;
+            self.stats.record_free();
+            // SAFETY: the head was the only node and is unlinked now.
+            let head_value = unsafe { self.allocator.free(head) }.data;
+            #[cfg(debug_assertions)]
+            self.assert_invariants();
+            return Some(head_value);
         }
 
-        let mut current = self.head.as_mut().map(|node| &mut **node);
-        while let Some(node) = current {
-            if let Some(existing_node) = &node.next {
+        let mut current = self.head;
+        while let Some(mut node_pointer) = current {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes the list owns, and nothing else
+            // borrows the list while we hold `&mut self`.
+            let node = unsafe { node_pointer.as_mut() };
+            if let Some(existing_node) = node.next_node() {
                 if existing_node.next.is_none() {
                     let tail_value = node.next.take().map(|tail| {
-                        self.tail = Some(node as *mut Node<T>);
+                        self.tail = Some(node_pointer);
                         self.length -= 1;
-                        tail.data
+                        self.stats.record_free();
+                        // SAFETY: the tail was unlinked from the node before it.
+                        unsafe { self.allocator.free(tail) }.data
                     });
+                    #[cfg(debug_assertions)]
+                    self.assert_invariants();
                     return tail_value;
                 }
             }
-            current = node.next.as_mut().map(|node| &mut **node);
+            current = node.next;
         }
 
         None
@@ -175,25 +606,29 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     /// # Examples
     ///
     /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
     /// let mut list = KolzoLinkedList::new();
     /// list.prepend(1);
     /// list.prepend(2);
     /// list.prepend(3);
-    /// assert_eq!(list.length, 3);
+    /// assert_eq!(list.len(), 3);
     /// // The list now looks like: 3 -> 2 -> 1 -> None
     /// ```
     pub fn prepend(&mut self, value: T) {
-        let mut new_node = Box::new(Node::new(value));
-        let new_node_raw_pointer: &mut _ = &mut *new_node;
+        let mut new_node = self.allocator.allocate(Node::new(value));
+        self.stats.record_allocation();
 
-        if self.head.is_none() {
-            self.tail = Some(new_node_raw_pointer);
+        // SAFETY: the node was just allocated and is not linked anywhere yet.
+        unsafe { new_node.as_mut().next = self.head.take() };
+        if self.tail.is_none() {
+            self.tail = Some(new_node);
         }
-
-        new_node.next = self.head.take();
         self.head = Some(new_node);
 
         self.length += 1;
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
     }
 
     /// Removes the first element from the linked list and returns it, if it exists.
@@ -206,7 +641,9 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     /// # Examples
     ///
     /// ```
-    /// let mut list = LinkedList::new();
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
     /// list.append(1);
     /// list.append(2);
     ///
@@ -220,33 +657,118 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     /// This function does not panic.
     pub fn pop_first(&mut self) -> Option<T> {
         match self.head.take() {
-            Some(mut node) => {
+            Some(head) => {
+                self.stats.record_free();
+                // SAFETY: the head was unlinked from the list by `take`.
+                let node = unsafe { self.allocator.free(head) };
                 let data = node.data;
-                self.head = node.next.take();
+                self.head = node.next;
                 if self.head.is_none() {
                     self.tail = None;
                 }
+                self.length -= 1;
+                #[cfg(debug_assertions)]
+                self.assert_invariants();
                 Some(data)
             }
             None => None,
         }
     }
 
+    /// Adds an element to the end of the list in O(1).
+    ///
+    /// This is [`KolzoLinkedList::append`] under the name `VecDeque` and std's
+    /// `LinkedList` use, so code written against them can switch over by changing
+    /// the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.to_string(), "1 -> 2 -> None");
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.append(value);
+    }
+
+    /// Adds an element to the front of the list in O(1).
+    ///
+    /// This is [`KolzoLinkedList::prepend`] under its std name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.push_front(1);
+    /// list.push_front(2);
+    /// assert_eq!(list.to_string(), "2 -> 1 -> None");
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.prepend(value);
+    }
+
+    /// Removes the last element and returns it, or `None` if the list is empty.
+    ///
+    /// This is [`KolzoLinkedList::pop`] under its std name. A singly linked list
+    /// has to walk to the node before the tail, so unlike `VecDeque::pop_back` this
+    /// is O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2]);
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    /// Removes the first element and returns it in O(1), or `None` if the list is
+    /// empty.
+    ///
+    /// This is [`KolzoLinkedList::pop_first`] under its std name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2]);
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_first()
+    }
+
     /// Retrieves a reference to the element at the specified index in the linked list.
     ///
     /// # Arguments
     ///
-    /// * `index` - The index of the element to retrieve. Must be a non-negative integer.
+    /// * `index` - The index of the element to retrieve.
     ///
     /// # Returns
     ///
     /// * `Some(&T)` containing a reference to the element at the specified index if it exists.
-    /// * `None` if the index is out of bounds or negative.
+    /// * `None` if the index is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut list = LinkedList::new();
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
     /// list.append(10);
     /// list.append(20);
     /// list.append(30);
@@ -255,504 +777,7027 @@ impl<T: std::fmt::Debug + Clone> KolzoLinkedList<T> {
     /// assert_eq!(list.get(1), Some(&20));
     /// assert_eq!(list.get(2), Some(&30));
     /// assert_eq!(list.get(3), None);
-    /// assert_eq!(list.get(-1), None);
     /// ```
     ///
     /// # Panics
     ///
     /// This function does not panic.
-    pub fn get(&self, index: i64) -> Option<&T> {
-        if index.is_negative() || index as u64 >= self.length {
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
             return None;
         }
 
-        let mut head_node = &self.head;
+        let mut head_node = self.head_node();
         let mut count = 0;
-        while let Some(ref node) = head_node {
+        while let Some(node) = head_node {
+            self.stats.record_visit();
             if count == index {
                 return Some(&node.data);
             }
-            head_node = &node.next;
+            head_node = node.next_node();
             count += 1;
         }
 
         None
     }
 
-    /// Updates the value of the element at the specified index in the linked list
-    /// and returns the old value.
+    /// Retrieves a mutable reference to the element at the specified index in the
+    /// linked list, so it can be changed in place.
+    ///
+    /// The list stays mutably borrowed for as long as the reference is alive:
+    ///
+    /// ```compile_fail,E0499
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    ///
+    /// let first = list.get_mut(0).unwrap();
+    /// list.append(2);
+    /// *first += 1;
+    /// ```
     ///
     /// # Arguments
     ///
-    /// * `index` - The index of the element to update. Must be a non-negative integer.
-    /// * `value` - The new value to set at the specified index.
+    /// * `index` - The index of the element to retrieve.
     ///
     /// # Returns
     ///
-    /// * `Some(T)` containing the old value of the element at the specified index if it exists.
-    /// * `None` if the index is out of bounds or negative.
+    /// * `Some(&mut T)` containing a mutable reference to the element at the specified index if it exists.
+    /// * `None` if the index is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut list = LinkedList::new();
-    /// list.append(10);
-    /// list.append(20);
-    /// list.append(30);
+    /// use linked_list::algorithm::KolzoLinkedList;
     ///
-    /// assert_eq!(list.set(1, 25), Some(20)); // Replaces the value at index 1
-    /// assert_eq!(list.get(1), Some(&25));    // Verifies the new value at index 1
-    /// assert_eq!(list.set(3, 40), None);     // Index out of bounds
-    /// assert_eq!(list.set(-1, 50), None);    // Negative index
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(vec![1]);
+    /// list.append(vec![2]);
+    ///
+    /// list.get_mut(1).unwrap().push(3);
+    /// assert_eq!(list.get(1), Some(&vec![2, 3]));
+    /// assert_eq!(list.get_mut(2), None);
     /// ```
     ///
     /// # Panics
     ///
     /// This function does not panic.
-    pub fn set(&mut self, index: i64, value: T) -> Option<T> {
-        if index.is_negative() || index as u64 >= self.length {
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
             return None;
         }
 
-        let mut head_node = &mut self.head;
-        let mut count = 0;
-
-        while let Some(ref mut node) = head_node {
-            if count == index {
-                let old_val = std::mem::replace(&mut node.data, value);
-                return Some(old_val);
-            }
-            head_node = &mut node.next;
-            count += 1;
-        }
-        None
+        self.iter_mut().nth(index)
     }
 
-    /// Inserts a new element with the specified value at the given index in the linked list.
+    /// Returns a reference to the first element, or `None` if the list is empty.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `index` - The position at which to insert the new element. Must be a non-negative integer.
-    /// * `value` - The value to insert into the linked list.
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
     ///
-    /// # Behavior
+    /// let mut list = KolzoLinkedList::new();
+    /// assert_eq!(list.first(), None);
     ///
-    /// * If the index is negative or greater than the length of the list, the function returns without inserting.
-    /// * If the index is `0`, the new element is prepended to the list.
-    /// * If the index is equal to the length of the list, the new element is appended to the list.
-    /// * Otherwise, the new element is inserted at the specified position, and subsequent elements are shifted.
+    /// list.append(1);
+    /// list.append(2);
+    /// assert_eq!(list.first(), Some(&1));
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self.head_node().map(|node| &node.data)
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the list is
+    /// empty.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut list = LinkedList::new();
-    /// list.append(10);
-    /// list.append(20);
-    /// list.append(30);
+    /// use linked_list::algorithm::KolzoLinkedList;
     ///
-    /// list.insert(2, 25); // Insert 25 at index 2
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
     ///
-    /// assert_eq!(list.get(0), Some(&10));
-    /// assert_eq!(list.get(1), Some(&20));
-    /// assert_eq!(list.get(2), Some(&25));
-    /// assert_eq!(list.get(3), Some(&30));
+    /// *list.first_mut().unwrap() = 0;
+    /// assert_eq!(list.get(0), Some(&0));
     /// ```
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.head_node_mut().map(|node| &mut node.data)
+    }
+
+    /// Returns a reference to the last element in O(1) by reading through the tail
+    /// pointer, or `None` if the list is empty.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// This function does not panic.
-    pub fn insert(&mut self, index: i64, value: T) {
-        if index.is_negative() || index as u64 >= self.length {
-            return;
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// assert_eq!(list.last(), None);
+    ///
+    /// list.append(1);
+    /// list.append(2);
+    /// assert_eq!(list.last(), Some(&2));
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        // SAFETY: by the invariant on `tail` it points at the last node, which the
+        // list owns. The reference borrows `self`, so the node can be neither freed
+        // nor changed while it is alive.
+        self.tail.map(|tail| unsafe { &tail.as_ref().data })
+    }
+
+    /// Returns a mutable reference to the last element in O(1) by reading through
+    /// the tail pointer, or `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// *list.last_mut().unwrap() += 10;
+    /// assert_eq!(list.get(1), Some(&12));
+    /// ```
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: by the invariant on `tail` it points at the last node, which the
+        // list owns. The reference mutably borrows `self`, so nothing else can reach
+        // the node while it is alive.
+        self.tail.map(|mut tail| unsafe { &mut tail.as_mut().data })
+    }
+
+    /// Returns a reference to the head without removing it, or `None` if the list
+    /// is empty.
+    ///
+    /// This is [`KolzoLinkedList::first`] under the name stacks and queues use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// assert_eq!(list.peek(), None);
+    ///
+    /// list.prepend(1);
+    /// list.prepend(2);
+    /// assert_eq!(list.peek(), Some(&2));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.first()
+    }
+
+    /// Returns a mutable reference to the head without removing it, or `None` if
+    /// the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2]);
+    /// if let Some(head) = list.peek_mut() {
+    ///     *head *= 10;
+    /// }
+    /// assert_eq!(list.pop_first(), Some(10));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.first_mut()
+    }
+
+    /// Returns a reference to the tail without removing it, or `None` if the list
+    /// is empty.
+    ///
+    /// This reads through the tail pointer in O(1), as [`KolzoLinkedList::last`]
+    /// does, so it relies on every method keeping `tail` on the last node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.peek_back(), Some(&3));
+    ///
+    /// list.reverse();
+    /// assert_eq!(list.peek_back(), Some(&1));
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        self.last()
+    }
+
+    /// Returns the middle element, found in a single pass with a slow and a fast
+    /// pointer.
+    ///
+    /// For an even number of elements there are two middles and this returns the
+    /// second of them, so for `1 -> 2 -> 3 -> 4` it is `3`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` containing the element at index `len / 2`.
+    /// * `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// assert_eq!(KolzoLinkedList::from([1, 2, 3]).middle(), Some(&2));
+    /// assert_eq!(KolzoLinkedList::from([1, 2, 3, 4]).middle(), Some(&3));
+    /// ```
+    pub fn middle(&self) -> Option<&T> {
+        self.middle_node().map(|(_, node)| &node.data)
+    }
+
+    /// Returns the index of the middle element, found the same way as
+    /// [`KolzoLinkedList::middle`] and with the same tie-break.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// assert_eq!(KolzoLinkedList::from([1, 2, 3, 4]).middle_index(), Some(2));
+    /// assert_eq!(KolzoLinkedList::<i32>::new().middle_index(), None);
+    /// ```
+    pub fn middle_index(&self) -> Option<usize> {
+        self.middle_node().map(|(index, _)| index)
+    }
+
+    /// Walks a slow pointer one node and a fast pointer two nodes at a time from
+    /// the head, so the slow one is halfway when the fast one runs out, and
+    /// returns the slow node with its index. The length is not consulted.
+    fn middle_node(&self) -> Option<(usize, &Node<T>)> {
+        let mut slow = self.head_node()?;
+        let mut fast = self.head_node();
+        let mut index = 0;
+
+        while let Some(next) = fast.and_then(|node| node.next_node()) {
+            self.stats.record_visit();
+            slow = slow.next_node()?;
+            index += 1;
+            fast = next.next_node();
         }
 
-        if index == 0 {
-            self.prepend(value);
-            return;
+        Some((index, slow))
+    }
+
+    /// Returns the `k`-th element counting back from the tail, where `k = 0` is the
+    /// last element.
+    ///
+    /// This is found in a single pass with two pointers rather than from the
+    /// length: a lead pointer starts `k` nodes ahead of a trailing one and both
+    /// move together until the lead reaches the last node.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - How many elements before the last one to go back.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` containing the element at index `len - 1 - k`.
+    /// * `None` if `k` is not less than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 3, 4]);
+    /// assert_eq!(list.nth_from_end(0), Some(&4));
+    /// assert_eq!(list.nth_from_end(3), Some(&1));
+    /// assert_eq!(list.nth_from_end(4), None);
+    /// ```
+    pub fn nth_from_end(&self, k: usize) -> Option<&T> {
+        self.nth_from_end_node(k).map(|node| &node.data)
+    }
+
+    /// Removes and returns the `k`-th element counting back from the tail, where
+    /// `k = 0` is the last element.
+    ///
+    /// The element is located with the two pointers of
+    /// [`KolzoLinkedList::nth_from_end`] in a single pass. The trailing pointer
+    /// stops on the node before the element, which is then unlinked from it
+    /// directly, moving the tail back if the element was the last one.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - How many elements before the last one to go back.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the removed value.
+    /// * `None` if `k` is not less than the length of the list, in which case the
+    ///   list is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3, 4]);
+    /// assert_eq!(list.remove_nth_from_end(1), Some(3));
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 4 -> None");
+    /// ```
+    pub fn remove_nth_from_end(&mut self, k: usize) -> Option<T> {
+        // The lead starts `k + 1` nodes ahead, so when it runs off the end the
+        // trailing pointer is on the node before the element, or still before the
+        // head if the element is the head.
+        let mut lead = self.head;
+        for _ in 0..=k {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes the list owns.
+            lead = unsafe { lead?.as_ref() }.next;
         }
 
-        if index as u64 == self.length {
-            self.append(value);
-            return;
+        let mut previous: Option<NonNull<Node<T>>> = None;
+        while let Some(node) = lead {
+            self.stats.record_visit();
+            // SAFETY: as above, for both pointers.
+            lead = unsafe { node.as_ref() }.next;
+            previous = match previous {
+                Some(previous) => unsafe { previous.as_ref() }.next,
+                None => self.head,
+            };
         }
 
-        let mut current = &mut self.head;
-        let mut counter = 0;
+        let removed = match previous {
+            None => self.pop_first(),
+            // SAFETY: `previous` is a node of this list.
+            Some(previous) => unsafe { self.unlink_after(previous) },
+        };
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+        removed
+    }
+
+    /// Finds the `k`-th node counting back from the tail without consulting the
+    /// length.
+    fn nth_from_end_node(&self, k: usize) -> Option<&Node<T>> {
+        let mut lead = self.head_node()?;
+        for _ in 0..k {
+            self.stats.record_visit();
+            lead = lead.next_node()?;
+        }
+
+        let mut trail = self.head_node()?;
+        while let Some(next) = lead.next_node() {
+            self.stats.record_visit();
+            lead = next;
+            trail = trail.next_node()?;
+        }
+
+        Some(trail)
+    }
+
+    /// Checks whether the linked list holds an element equal to `value`.
+    ///
+    /// Walks the nodes from the head and stops at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if some element equals `value`.
+    /// * `false` otherwise, including for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append("a");
+    /// list.append("b");
+    ///
+    /// assert!(list.contains(&"b"));
+    /// assert!(!list.contains(&"c"));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|element| element == value)
+    }
+
+    /// Finds the index of the first element equal to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` containing the zero-based index of the first match.
+    /// * `None` if no element equals `value`, including for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    /// list.append(20);
+    ///
+    /// assert_eq!(list.find(&20), Some(1));
+    /// assert_eq!(list.find(&30), None);
+    /// ```
+    pub fn find(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.position(|element| element == value)
+    }
+
+    /// Finds the index of the first element for which `predicate` returns `true`.
+    ///
+    /// Walks the nodes from the head and stops at the first match, so `predicate`
+    /// is not called on the elements after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The test each element is checked against.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` containing the zero-based index of the first match.
+    /// * `None` if no element matches, including for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(4);
+    /// list.append(6);
+    ///
+    /// assert_eq!(list.position(|value| value % 2 == 0), Some(1));
+    /// assert_eq!(list.position(|value| *value > 10), None);
+    /// ```
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Finds the index of the last element for which `predicate` returns `true`.
+    ///
+    /// A singly linked list cannot be walked from the tail, so this makes one pass
+    /// from the head, calls `predicate` on every element in order and remembers the
+    /// latest match.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The test each element is checked against.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` containing the zero-based index of the last match.
+    /// * `None` if no element matches, including for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 4, 6, 7]);
+    /// assert_eq!(list.rposition(|value| value % 2 == 0), Some(2));
+    /// assert_eq!(list.rposition(|value| *value > 10), None);
+    /// ```
+    pub fn rposition(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        let mut last_match = None;
+        for (index, value) in self.iter().enumerate() {
+            if predicate(value) {
+                last_match = Some(index);
+            }
+        }
+        last_match
+    }
+
+    /// Applies `f` to the elements from the head and returns the first result that
+    /// is `Some`.
+    ///
+    /// The walk stops at the first element `f` maps to `Some`, so `f` is not called
+    /// on the elements after it. This searches for structured data without
+    /// collecting the list first.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Maps an element to `Some` result, or to `None` to keep looking.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(U)` containing the first `Some` result of `f`.
+    /// * `None` if `f` returns `None` for every element, including for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from(["one", "2", "3"]);
+    /// assert_eq!(list.find_map(|text| text.parse::<i32>().ok()), Some(2));
+    /// assert_eq!(list.find_map(|text| text.strip_prefix('x')), None);
+    /// ```
+    pub fn find_map<U>(&self, f: impl FnMut(&T) -> Option<U>) -> Option<U> {
+        self.iter().find_map(f)
+    }
+
+    /// Returns `true` if `predicate` returns `true` for at least one element.
+    ///
+    /// The walk stops at the first element that satisfies `predicate`. An empty
+    /// list has no such element, so this returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 4, 7]);
+    /// assert!(list.any(|value| value % 2 == 0));
+    /// assert!(!list.any(|value| *value > 10));
+    /// ```
+    pub fn any(&self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+
+    /// Returns `true` if `predicate` returns `true` for every element.
+    ///
+    /// The walk stops at the first element that fails `predicate`. An empty list
+    /// has no such element, so this returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([2, 4, 7]);
+    /// assert!(list.all(|value| *value > 0));
+    /// assert!(!list.all(|value| value % 2 == 0));
+    /// ```
+    pub fn all(&self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.iter().all(predicate)
+    }
+
+    /// Counts the elements equal to `value` in a single pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 1, 3, 1]);
+    /// assert_eq!(list.count_value(&1), 3);
+    /// assert_eq!(list.count_value(&4), 0);
+    /// ```
+    pub fn count_value(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.count_by(|element| element == value)
+    }
+
+    /// Counts the elements for which `predicate` returns `true` in a single pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The test each element is checked against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list: KolzoLinkedList<i32> = (1..=10).collect();
+    /// assert_eq!(list.count_by(|value| value % 3 == 0), 3);
+    /// ```
+    pub fn count_by(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.iter().filter(|element| predicate(element)).count()
+    }
+
+    /// Returns the smallest element, or `None` if the list is empty.
+    ///
+    /// If several elements are equally small, the first of them is returned.
+    ///
+    /// This is not called `min` to avoid two clashes. A list of `Ord` elements is
+    /// `Ord` itself, so `list.min()` would resolve to `Ord::min` and expect a second
+    /// list. And the `Iterator` names would suggest `Iterator`'s tie-breaking, which
+    /// returns the last of several equal maxima, while both of these return the
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// assert_eq!(KolzoLinkedList::from([3, 1, 2]).min_element(), Some(&1));
+    /// assert_eq!(KolzoLinkedList::<i32>::new().min_element(), None);
+    /// ```
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter()
+            .reduce(|min, element| if element < min { element } else { min })
+    }
+
+    /// Returns the largest element, or `None` if the list is empty.
+    ///
+    /// If several elements are equally large, the first of them is returned, unlike
+    /// `Iterator::max`, which returns the last. See
+    /// [`KolzoLinkedList::min_element`] for why this is not called `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// assert_eq!(KolzoLinkedList::from([3, 1, 2]).max_element(), Some(&3));
+    /// assert_eq!(KolzoLinkedList::<i32>::new().max_element(), None);
+    /// ```
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter()
+            .reduce(|max, element| if element > max { element } else { max })
+    }
+
+    /// Returns the smallest and the largest element in one pass, or `None` if the
+    /// list is empty.
+    ///
+    /// The elements are taken in pairs. The two of a pair are compared with each
+    /// other first, and then only the smaller one is compared with the minimum so
+    /// far and only the larger one with the maximum, which takes about `3n / 2`
+    /// comparisons instead of the `2n` of separate `min_element` and `max_element`
+    /// calls. As with those, the first of several equal extremes is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([4, 9, 1, 7, 1]);
+    /// assert_eq!(list.min_max(), Some((&1, &9)));
+    /// ```
+    pub fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord,
+    {
+        let mut elements = self.iter();
+        let first = elements.next()?;
+        let (mut min, mut max) = (first, first);
+
+        while let Some(earlier) = elements.next() {
+            let (small, large) = match elements.next() {
+                Some(later) => match earlier.cmp(later) {
+                    Ordering::Less => (earlier, later),
+                    Ordering::Greater => (later, earlier),
+                    // Equal elements both count as the earlier one, so the first
+                    // occurrence wins either way.
+                    Ordering::Equal => (earlier, earlier),
+                },
+                None => (earlier, earlier),
+            };
+
+            if small < min {
+                min = small;
+            }
+            if large > max {
+                max = large;
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Updates the value of the element at the specified index in the linked list
+    /// and returns the old value.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to update.
+    /// * `value` - The new value to set at the specified index.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the old value of the element at the specified index if it exists.
+    /// * `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    /// list.append(30);
+    ///
+    /// assert_eq!(list.set(1, 25), Some(20)); // Replaces the value at index 1
+    /// assert_eq!(list.get(1), Some(&25));    // Verifies the new value at index 1
+    /// assert_eq!(list.set(3, 40), None);     // Index out of bounds
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn set(&mut self, index: usize, value: T) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut head_node = self.head;
+        let mut count = 0;
+
+        while let Some(mut node) = head_node {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes the list owns, and nothing else
+            // borrows the list while we hold `&mut self`.
+            let node = unsafe { node.as_mut() };
+            if count == index {
+                let old_val = std::mem::replace(&mut node.data, value);
+                #[cfg(debug_assertions)]
+                self.assert_invariants();
+                return Some(old_val);
+            }
+            head_node = node.next;
+            count += 1;
+        }
+        None
+    }
+
+    /// Inserts a new element with the specified value at the given index in the linked list.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position at which to insert the new element.
+    /// * `value` - The value to insert into the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was inserted.
+    /// * `false` if the index is greater than the length of the list, in which case
+    ///   the value is dropped and the list is unchanged.
+    ///
+    /// # Behavior
+    ///
+    /// * If the index is `0`, the new element is prepended to the list.
+    /// * If the index is equal to the length of the list, the new element is appended to the list.
+    /// * Otherwise, the new element is inserted at the specified position, and subsequent elements are shifted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    /// list.append(30);
+    ///
+    /// assert!(list.insert(2, 25)); // Insert 25 at index 2
+    /// assert!(list.insert(4, 35)); // Index 4 is the length, so 35 is appended
+    /// assert!(!list.insert(6, 45)); // Out of bounds
+    ///
+    /// assert_eq!(list.to_string(), "10 -> 20 -> 25 -> 30 -> 35 -> None");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn insert(&mut self, index: usize, value: T) -> bool {
+        if index > self.len() {
+            return false;
+        }
+
+        if index == 0 {
+            self.prepend(value);
+            return true;
+        }
+
+        if index == self.len() {
+            self.append(value);
+            return true;
+        }
+
+        let mut current = self.head;
+        let mut counter = 0;
+
+        while counter < index - 1 {
+            if let Some(node) = current {
+                self.stats.record_visit();
+                // SAFETY: links point at live nodes the list owns.
+                current = unsafe { node.as_ref() }.next;
+            } else {
+                return false;
+            }
+            counter += 1;
+        }
+
+        if let Some(mut node) = current {
+            self.stats.record_visit();
+            let mut new_node = self.allocator.allocate(Node::new(value));
+            self.stats.record_allocation();
+            // SAFETY: `node` is a live node the list owns and nothing else borrows
+            // the list while we hold `&mut self`. The new node is not linked yet.
+            unsafe {
+                new_node.as_mut().next = node.as_mut().next.take();
+                node.as_mut().next = Some(new_node);
+            }
+            self.length += 1;
+            #[cfg(debug_assertions)]
+            self.assert_invariants();
+            return true;
+        }
+
+        false
+    }
+
+    /// Inserts `value` into a sorted linked list so that it stays sorted.
+    ///
+    /// The new element goes before the first element greater than it, so it lands
+    /// after any elements equal to it and equal elements keep the order they were
+    /// inserted in. A value at least as large as the last element is appended in
+    /// O(1) through the tail pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to insert.
+    ///
+    /// # Behavior
+    ///
+    /// If the list is not sorted, `value` is still inserted and the list stays
+    /// consistent, but where it lands is unspecified: the tail and head are checked
+    /// first, so inserting `3` into `[5, 1]` appends it after the `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// for value in [3, 1, 4, 1, 5] {
+    ///     list.insert_sorted(value);
+    /// }
+    /// assert_eq!(list.to_string(), "1 -> 1 -> 3 -> 4 -> 5 -> None");
+    /// ```
+    pub fn insert_sorted(&mut self, value: T)
+    where
+        T: Ord,
+    {
+        if self.last().is_none_or(|last| *last <= value) {
+            self.append(value);
+            return;
+        }
+
+        if self.first().is_some_and(|first| value < *first) {
+            self.prepend(value);
+            return;
+        }
+
+        // Step past every element not greater than `value`. The last element is
+        // greater, so this stops before the tail and the tail never moves.
+        let Some(mut previous) = self.head else {
+            return;
+        };
+        self.stats.record_visit();
+
+        // SAFETY: links point at live nodes the list owns.
+        while let Some(next) = unsafe { previous.as_ref() }.next {
+            self.stats.record_visit();
+            // SAFETY: as above.
+            if value < unsafe { next.as_ref() }.data {
+                break;
+            }
+            previous = next;
+        }
+
+        let mut new_node = self.allocator.allocate(Node::new(value));
+        self.stats.record_allocation();
+        // SAFETY: `previous` is a live node the list owns and nothing else borrows
+        // the list while we hold `&mut self`. The new node is not linked yet.
+        unsafe {
+            new_node.as_mut().next = previous.as_mut().next.take();
+            previous.as_mut().next = Some(new_node);
+        }
+        self.length += 1;
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Removes the element at the specified index from the linked list and returns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the element to be removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the removed value if the index is in bounds.
+    /// * `None` if the index is out of bounds, in which case the list is unchanged.
+    ///
+    /// # Behavior
+    ///
+    /// * If the index is `0`, the head element is removed.
+    /// * If the index is the last element, the tail pointer is moved to the new last node.
+    /// * For all other indices, the element at the specified index is removed and the list is re-linked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(10);
+    /// list.append(20);
+    /// list.append(30);
+    /// list.append(40);
+    ///
+    /// assert_eq!(list.remove(2), Some(30));
+    /// assert_eq!(list.remove(3), None);
+    ///
+    /// assert_eq!(list.get(0), Some(&10));
+    /// assert_eq!(list.get(1), Some(&20));
+    /// assert_eq!(list.get(2), Some(&40));
+    /// assert_eq!(list.len(), 3);
+    ///
+    /// assert_eq!(list.remove(2), Some(40));
+    /// assert_eq!(list.last(), Some(&20));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function does not panic.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        if index == 0 {
+            return self.pop_first();
+        }
+
+        // Walk to the node just before the one being removed.
+        let mut previous = self.head?;
+        for _ in 1..index {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes the list owns.
+            previous = unsafe { previous.as_ref() }.next?;
+        }
+        self.stats.record_visit();
+
+        // SAFETY: `previous` was reached from `head`, so it is a node of this list.
+        let removed = unsafe { self.unlink_after(previous) };
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+        removed
+    }
+
+    /// Retrieves the element at a signed index, where negative indices count back
+    /// from the end, so `-1` is the last element.
+    ///
+    /// This is the `i64` form [`KolzoLinkedList::get`] took before it switched to
+    /// `usize`, kept for one release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([10, 20, 30]);
+    /// assert_eq!(list.get_signed(-1), Some(&30));
+    /// assert_eq!(list.get_signed(-4), None);
+    /// ```
+    #[deprecated(note = "use `get` with a `usize` index, or `nth_from_end` to count from the end")]
+    pub fn get_signed(&self, index: i64) -> Option<&T> {
+        self.get(self.resolve_signed_index(index)?)
+    }
+
+    /// Replaces the element at a signed index and returns the old value, where
+    /// negative indices count back from the end.
+    ///
+    /// This is the `i64` form [`KolzoLinkedList::set`] took before it switched to
+    /// `usize`, kept for one release.
+    #[deprecated(note = "use `set` with a `usize` index")]
+    pub fn set_signed(&mut self, index: i64, value: T) -> Option<T> {
+        self.set(self.resolve_signed_index(index)?, value)
+    }
+
+    /// Inserts a value at a signed index, where negative indices count back from
+    /// the end, so inserting at `-1` puts the value before the last element, as
+    /// Python's `list.insert` does.
+    ///
+    /// This is the `i64` form [`KolzoLinkedList::insert`] took before it switched
+    /// to `usize`, kept for one release.
+    #[deprecated(note = "use `insert` with a `usize` index")]
+    pub fn insert_signed(&mut self, index: i64, value: T) -> bool {
+        match self.resolve_signed_index(index) {
+            Some(index) => self.insert(index, value),
+            None => false,
+        }
+    }
+
+    /// Removes the element at a signed index and returns it, where negative
+    /// indices count back from the end, so removing `-1` removes the last element.
+    ///
+    /// This is the `i64` form [`KolzoLinkedList::remove`] took before it switched
+    /// to `usize`, kept for one release.
+    #[deprecated(
+        note = "use `remove` with a `usize` index, or `remove_nth_from_end` to count from the end"
+    )]
+    pub fn remove_signed(&mut self, index: i64) -> Option<T> {
+        self.remove(self.resolve_signed_index(index)?)
+    }
+
+    /// Turns an index that counts back from the end when it is negative, as in
+    /// Python, into an index from the head. The result is not checked against the
+    /// length, except that `None` is returned for a negative index reaching back
+    /// past the head.
+    fn resolve_signed_index(&self, index: i64) -> Option<usize> {
+        if index.is_negative() {
+            let from_end = usize::try_from(index.unsigned_abs()).ok()?;
+            self.len().checked_sub(from_end)
+        } else {
+            usize::try_from(index).ok()
+        }
+    }
+
+    /// Unlinks the node after `previous` and returns its value, moving the tail
+    /// back to `previous` if the removed node was the last one.
+    ///
+    /// Returns `None` without changing the list if `previous` is the last node.
+    ///
+    /// # Safety
+    ///
+    /// `previous` must point at a node of this list.
+    unsafe fn unlink_after(&mut self, mut previous: NonNull<Node<T>>) -> Option<T> {
+        // SAFETY: guaranteed by the caller. Nothing else borrows the list while we
+        // hold `&mut self`.
+        let previous_node = unsafe { previous.as_mut() };
+        let removed = previous_node.next?;
+        // SAFETY: only `previous` links to the removed node, and it is relinked
+        // past it right away.
+        let removed = unsafe { self.allocator.free(removed) };
+        previous_node.next = removed.next;
+        if previous_node.next.is_none() {
+            self.tail = Some(previous);
+        }
+
+        self.length -= 1;
+        self.stats.record_free();
+        Some(removed.data)
+    }
+
+    /// Removes the first element equal to `value` in a single pass and returns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the removed element if one was equal to `value`.
+    /// * `None` if no element is equal to `value`, in which case the list is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3, 2]);
+    ///
+    /// assert_eq!(list.remove_value(&2), Some(2));
+    /// assert_eq!(list.remove_value(&5), None);
+    /// assert_eq!(list.to_string(), "1 -> 3 -> 2 -> None");
+    /// ```
+    pub fn remove_value(&mut self, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        if self.head_node()?.data == *value {
+            self.stats.record_visit();
+            return self.pop_first();
+        }
+
+        let mut previous = self.head?;
+        // SAFETY: links point at live nodes the list owns.
+        while let Some(candidate) = unsafe { previous.as_ref() }.next {
+            self.stats.record_visit();
+            // SAFETY: as above.
+            if unsafe { candidate.as_ref() }.data == *value {
+                // SAFETY: `previous` was reached from `head`, so it is a node of
+                // this list.
+                let removed = unsafe { self.unlink_after(previous) };
+                #[cfg(debug_assertions)]
+                self.assert_invariants();
+                return removed;
+            }
+            previous = candidate;
+        }
+
+        None
+    }
+
+    /// Removes every element equal to `value` in a single pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to remove.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([2, 1, 2, 2, 3, 2]);
+    ///
+    /// assert_eq!(list.remove_all(&2), 4);
+    /// assert_eq!(list.to_string(), "1 -> 3 -> None");
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove_all(&mut self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.remove_where(|element| element == value)
+    }
+
+    /// Removes every element for which `predicate` returns `true` in a single pass
+    /// and returns how many were removed.
+    ///
+    /// `predicate` is called exactly once per element, from head to tail, so it may
+    /// keep state about the elements it has seen.
+    fn remove_where(&mut self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        let mut removed = 0;
+        // SAFETY: links point at live nodes the list owns.
+        while let Some(head) = self
+            .head
+            .filter(|head| predicate(&unsafe { head.as_ref() }.data))
+        {
+            self.stats.record_visit();
+            self.stats.record_free();
+            // SAFETY: only `head` links to the first node, and it is moved past it
+            // right away.
+            self.head = unsafe { self.allocator.free(head) }.next;
+            self.length -= 1;
+            removed += 1;
+        }
+
+        let Some(mut kept) = self.head else {
+            self.tail = None;
+            return removed;
+        };
+        self.stats.record_visit();
+
+        // SAFETY: as above.
+        while let Some(candidate) = unsafe { kept.as_ref() }.next {
+            self.stats.record_visit();
+            // SAFETY: as above.
+            if predicate(&unsafe { candidate.as_ref() }.data) {
+                // SAFETY: `kept` was reached from `head`, so it is a node of this
+                // list. Removing the last node moves the tail back to it.
+                unsafe { self.unlink_after(kept) };
+                removed += 1;
+            } else {
+                kept = candidate;
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+        removed
+    }
+
+    /// Reverses the linked list in place.
+    ///
+    /// # Description
+    /// This method reverses the order of the nodes in the linked list. After the
+    /// operation, the head of the list will be the original tail, and the tail
+    /// will be the original head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.append(3);
+    ///
+    /// list.reverse();
+    ///
+    /// assert_eq!(list.get(0), Some(&3));
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(2), Some(&1));
+    ///
+    /// list.append(0);
+    /// assert_eq!(list.get(3), Some(&0));
+    /// ```
+    ///
+    /// # Panics
+    /// This function does not panic.
+    pub fn reverse(&mut self) {
+        // The old head ends up last.
+        self.tail = self.head;
+
+        let mut previous_node = None;
+        let mut current_node = self.head.take();
+
+        while let Some(mut node_that_is_iterated) = current_node {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes the list owns, and nothing else
+            // borrows the list while we hold `&mut self`.
+            let node = unsafe { node_that_is_iterated.as_mut() };
+            let next_node = node.next.take();
+            node.next = previous_node;
+            previous_node = Some(node_that_is_iterated);
+            current_node = next_node;
+        }
+
+        self.head = previous_node;
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Swaps the elements at indices `i` and `j` in a single walk from the head.
+    ///
+    /// The two mutable references come from the same [`KolzoLinkedList::iter_mut`]
+    /// walk, which hands out references to distinct nodes, so `swap` itself needs no
+    /// `unsafe`.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of one element.
+    /// * `j` - The index of the other element.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if both indices are in bounds and the elements were swapped. Swapping
+    ///   an index with itself changes nothing and returns `true`.
+    /// * `false` if either index is out of bounds, in which case the list is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3, 4]);
+    /// assert!(list.swap(0, 3));
+    /// assert!(!list.swap(1, 4));
+    /// assert_eq!(list.to_string(), "4 -> 2 -> 3 -> 1 -> None");
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) -> bool {
+        if i >= self.len() || j >= self.len() {
+            return false;
+        }
+        if i == j {
+            return true;
+        }
+
+        let (low, high) = (i.min(j), i.max(j));
+        let mut values = self.iter_mut().skip(low);
+        if let (Some(first), Some(second)) = (values.next(), values.nth(high - low - 1)) {
+            std::mem::swap(first, second);
+        }
+        true
+    }
+
+    /// Rotates the linked list `n` places to the left, so the element at index `n`
+    /// becomes the head and the first `n` elements move to the back in order.
+    ///
+    /// The nodes are relinked rather than moved: the old tail is linked to the old
+    /// head and the ring is cut again before the new head. `n` wraps around the
+    /// length, and rotating an empty list does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of places to rotate by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3, 4, 5]);
+    /// list.rotate_left(2);
+    /// assert_eq!(list.to_string(), "3 -> 4 -> 5 -> 1 -> 2 -> None");
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.is_empty() || n.is_multiple_of(self.len()) {
+            return;
+        }
+        self.cut_ring_after(n % self.len());
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Rotates the linked list `n` places to the right, so the last `n` elements
+    /// move to the front in order.
+    ///
+    /// Like [`KolzoLinkedList::rotate_left`], this relinks the nodes, wraps `n`
+    /// around the length and does nothing on an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of places to rotate by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3, 4, 5]);
+    /// list.rotate_right(2);
+    /// assert_eq!(list.to_string(), "4 -> 5 -> 1 -> 2 -> 3 -> None");
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.is_empty() {
+            return;
+        }
+        self.rotate_left(self.len() - n % self.len());
+    }
+
+    /// Links the tail to the head, then cuts the ring after the `count`-th node,
+    /// which becomes the new tail. `count` must be between 1 and the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is empty or the chain ends before `count` nodes, which
+    /// would mean the `tail` or `length` invariant is broken.
+    fn cut_ring_after(&mut self, count: usize) {
+        let mut new_tail = self.tail.expect("a non-empty list has a tail");
+        let old_head = self.head.take();
+        // SAFETY: by the invariant on `tail` it points at the last node, which the
+        // list owns. Nothing else borrows the list while we hold `&mut self`.
+        unsafe { new_tail.as_mut().next = old_head };
+
+        for _ in 0..count {
+            self.stats.record_visit();
+            // SAFETY: the nodes form a ring of live nodes the list owns until it is
+            // cut below.
+            new_tail = unsafe { new_tail.as_ref() }
+                .next
+                .expect("every node of the ring links to another");
+        }
+
+        // SAFETY: as above.
+        self.head = unsafe { new_tail.as_mut() }.next.take();
+        self.tail = Some(new_tail);
+    }
+
+    /// Removes consecutive equal elements, keeping the first of each run.
+    ///
+    /// The duplicate nodes are unlinked and dropped in a single pass. Equal
+    /// elements that are not next to each other are kept, so sort the list first
+    /// to remove every duplicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = [1, 1, 2, 2, 2, 3, 1, 1].into_iter().collect();
+    /// list.dedup();
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 1 -> None");
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that `same_bucket` considers equivalent,
+    /// keeping the first of each run.
+    ///
+    /// # Arguments
+    ///
+    /// * `same_bucket` - Called as `same_bucket(a, b)` with an element `a` and the
+    ///   last kept element `b` before it, as `Vec::dedup_by` does. `a` is removed
+    ///   when it returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<&str> = ["a", "A", "b", "B", "a"].into_iter().collect();
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(list.to_string(), "a -> b -> a -> None");
+    /// ```
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&T, &T) -> bool) {
+        let mut kept = match self.head {
+            Some(node) => node,
+            None => return,
+        };
+
+        // SAFETY: links point at live nodes the list owns.
+        while let Some(candidate) = unsafe { kept.as_ref() }.next {
+            self.stats.record_visit();
+            // SAFETY: as above.
+            let (candidate_data, kept_data) =
+                unsafe { (&candidate.as_ref().data, &kept.as_ref().data) };
+            if same_bucket(candidate_data, kept_data) {
+                // SAFETY: `kept` was reached from `head`, so it is a node of this
+                // list. Collapsing a run at the end moves the tail back to it.
+                unsafe { self.unlink_after(kept) };
+            } else {
+                kept = candidate;
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Removes every element equal to one earlier in the list, wherever it is, so
+    /// only the first occurrence of each value is kept, in the original order.
+    ///
+    /// Unlike [`KolzoLinkedList::dedup`], duplicates do not need to be next to each
+    /// other. A first pass tracks the elements seen so far in a `HashSet` of
+    /// references and marks the duplicates, and a second pass unlinks them, so no
+    /// element is cloned or moved. This takes O(n) expected time and O(n) extra
+    /// space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([3, 1, 3, 2, 1, 3]);
+    /// list.dedup_all();
+    /// assert_eq!(list.to_string(), "3 -> 1 -> 2 -> None");
+    /// ```
+    pub fn dedup_all(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+        let duplicates: Vec<bool> = self.iter().map(|element| !seen.insert(element)).collect();
+
+        let mut duplicates = duplicates.into_iter();
+        self.remove_where(|_| duplicates.next().unwrap_or(false));
+    }
+
+    /// Removes every element whose key equals the key of an element earlier in the
+    /// list, keeping the first element with each key, in the original order.
+    ///
+    /// Only the keys are stored in the `HashSet` of keys seen so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Extracts the key the elements are compared by. It is called once
+    ///   per element, from head to tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut words = KolzoLinkedList::from(["apple", "avocado", "banana", "blueberry", "cherry"]);
+    /// words.dedup_all_by_key(|word| word.chars().next());
+    /// assert_eq!(words.to_string(), "apple -> banana -> cherry -> None");
+    /// ```
+    pub fn dedup_all_by_key<K: Eq + Hash>(&mut self, mut key: impl FnMut(&T) -> K) {
+        let mut seen = HashSet::new();
+        self.remove_where(|element| !seen.insert(key(element)));
+    }
+
+    /// Removes the elements in `range` from the linked list and returns them as an
+    /// iterator.
+    ///
+    /// The nodes in the range are unlinked and the list is closed around the gap
+    /// before this returns, so the list is left shorter even if the iterator is
+    /// dropped before it is used up. The values it does not yield are dropped with
+    /// it, as with `Vec::drain`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The indices of the elements to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = (1..=5).collect();
+    /// let drained: Vec<_> = list.drain(1..3).collect();
+    ///
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(list.to_string(), "1 -> 4 -> 5 -> None");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends past the length of the list.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(
+            start <= end,
+            "drain range starts at {} but ends at {}",
+            start,
+            end
+        );
+        assert!(
+            end <= self.len(),
+            "drain range end {} is out of bounds for a list of length {}",
+            end,
+            self.len()
+        );
+
+        let mut drained = self.split_off(start);
+        self.append_list(drained.split_off(end - start));
+
+        Drain {
+            next: drained.head.take(),
+            remaining: std::mem::take(&mut drained.length),
+            allocator: &mut self.allocator,
+        }
+    }
+
+    /// Sorts the linked list in ascending order.
+    ///
+    /// This is a bottom-up merge sort that relinks the existing nodes instead of
+    /// moving their values, so it needs neither `T: Clone` nor any allocation. It
+    /// runs in O(n log n) time with O(1) extra space and is stable: equal elements
+    /// keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = [3, 1, 2].into_iter().collect();
+    /// list.sort();
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.merge_sort(T::cmp);
+    }
+
+    /// Sorts the linked list with a comparator function.
+    ///
+    /// Like [`KolzoLinkedList::sort`], this relinks the nodes in O(n log n) time
+    /// with O(1) extra space and is stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - Returns how the first element is ordered relative to the second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = [3, 1, 2].into_iter().collect();
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(list.to_string(), "3 -> 2 -> 1 -> None");
+    /// ```
+    pub fn sort_by(&mut self, compare: impl FnMut(&T, &T) -> Ordering) {
+        self.merge_sort(compare);
+    }
+
+    /// Sorts the linked list by the key `key` extracts from each element.
+    ///
+    /// Like [`KolzoLinkedList::sort`], this relinks the nodes in O(n log n) time
+    /// with O(1) extra space and is stable. The key is computed again on every
+    /// comparison, so it should be cheap.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Extracts the key to order the elements by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list: KolzoLinkedList<&str> = ["ccc", "a", "bb"].into_iter().collect();
+    /// list.sort_by_key(|word| word.len());
+    /// assert_eq!(list.to_string(), "a -> bb -> ccc -> None");
+    /// ```
+    pub fn sort_by_key<K: Ord>(&mut self, mut key: impl FnMut(&T) -> K) {
+        self.merge_sort(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Merges the sorted list `other` into this sorted list in O(n + m).
+    ///
+    /// The nodes of both lists are relinked with the same merge step the sort uses,
+    /// without cloning or reallocating. The merge is stable: elements of this list
+    /// come before equal elements of `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The sorted list whose elements are merged into this one.
+    ///
+    /// # Behavior
+    ///
+    /// If either list is not sorted, all of the elements are still kept, but the
+    /// result is not sorted either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 4, 6]);
+    /// list.merge_sorted(KolzoLinkedList::from([2, 4, 5, 9]));
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 4 -> 4 -> 5 -> 6 -> 9 -> None");
+    /// ```
+    pub fn merge_sorted(&mut self, other: KolzoLinkedList<T>)
+    where
+        T: Ord,
+    {
+        if self.is_empty() || other.is_empty() {
+            self.append_list(other);
+            return;
+        }
+
+        let mut other = self.adopt(other);
+        // The tail is the last node the merge links, which is only the larger of
+        // the two old tails when both lists are sorted.
+        let left = self.head.take();
+        let right = other.head.take();
+        let (first, last) =
+            merge(left, right, &mut T::cmp, &self.stats).expect("both lists have nodes to merge");
+
+        self.head = Some(first);
+        self.tail = Some(last);
+        self.length += other.length;
+        other.tail = None;
+        other.length = 0;
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Returns `true` if the elements are in non-decreasing order.
+    ///
+    /// Empty and single-element lists are sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// assert!(KolzoLinkedList::from([1, 2, 2, 5]).is_sorted());
+    /// assert!(!KolzoLinkedList::from([1, 3, 2]).is_sorted());
+    /// assert!(KolzoLinkedList::<f64>::new().is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Returns `true` if `compare` holds for every pair of adjacent elements, in
+    /// one pass over the list.
+    ///
+    /// Empty and single-element lists are sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - Called as `compare(a, b)` with each element `a` and the element
+    ///   `b` after it, as `Iterator::is_sorted_by` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([5, 3, 3, 1]);
+    /// assert!(list.is_sorted_by(|a, b| a >= b));
+    /// assert!(!list.is_sorted_by(|a, b| a > b));
+    /// ```
+    pub fn is_sorted_by(&self, mut compare: impl FnMut(&T, &T) -> bool) -> bool {
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            match node.next_node() {
+                Some(next) if !compare(&node.data, &next.data) => return false,
+                next => current = next,
+            }
+        }
+        true
+    }
+
+    /// Sorts the nodes with `compare` by merging sorted runs of 1, 2, 4, ... nodes
+    /// until a single run is left, then points `tail` at the new last node.
+    fn merge_sort(&mut self, mut compare: impl FnMut(&T, &T) -> Ordering) {
+        let mut run_length = 1;
+        while run_length < self.len() {
+            let mut unmerged = self.head.take();
+            // The last node of the runs merged so far.
+            let mut merged_end: Option<NonNull<Node<T>>> = None;
+            while unmerged.is_some() {
+                let mut left = unmerged;
+                let mut right = split_after(&mut left, run_length);
+                unmerged = split_after(&mut right, run_length);
+                if let Some((first, last)) = merge(left, right, &mut compare, &self.stats) {
+                    match merged_end {
+                        // SAFETY: `end` is the last node of the runs merged so far,
+                        // which the list owns.
+                        Some(mut end) => unsafe { end.as_mut().next = Some(first) },
+                        None => self.head = Some(first),
+                    }
+                    merged_end = Some(last);
+                }
+            }
+            self.tail = merged_end;
+            run_length *= 2;
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Prints the linked list as boxed nodes joined by arrows, with an index ruler underneath.
+    ///
+    /// See [`list_common::pretty::render`] for the layout and for drawing with
+    /// other [`RenderOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.pretty_print();
+    /// // Output:
+    /// // +---+    +---+
+    /// // | 1 | -> | 2 | -> None
+    /// // +---+    +---+
+    /// //   0        1
+    /// ```
+    pub fn pretty_print(&self)
+    where
+        T: std::fmt::Display,
+    {
+        println!("{}", render(self, RenderOptions::default()));
+    }
+
+    /// Reports how much heap memory the nodes of the linked list use.
+    ///
+    /// The figures are computed from `size_of::<Node<T>>()` and the length, so only
+    /// the inline size of each value is counted. Use
+    /// [`KolzoLinkedList::deep_memory_footprint`] to also count memory the values own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1i32);
+    /// list.append(2);
+    ///
+    /// let report = list.memory_footprint();
+    /// assert_eq!(report.node_count, 2);
+    /// assert_eq!(report.payload_bytes, 8);
+    /// assert!(report.total_bytes > report.payload_bytes);
+    /// ```
+    pub fn memory_footprint(&self) -> MemoryReport {
+        MemoryReport::new(
+            self.len(),
+            std::mem::size_of::<Node<T>>(),
+            std::mem::size_of::<T>(),
+            0,
+        )
+    }
+
+    /// Reports how much heap memory the linked list uses, including the heap memory
+    /// owned by its values.
+    ///
+    /// Unlike [`KolzoLinkedList::memory_footprint`], this walks every node to ask its
+    /// value for the heap bytes it owns, so it runs in O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(String::with_capacity(100));
+    ///
+    /// let shallow = list.memory_footprint();
+    /// let deep = list.deep_memory_footprint();
+    /// assert_eq!(deep.total_bytes, shallow.total_bytes + 100);
+    /// ```
+    pub fn deep_memory_footprint(&self) -> MemoryReport
+    where
+        T: DeepMeasure,
+    {
+        let mut heap_bytes = 0;
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            heap_bytes += node.data.heap_bytes();
+            current = node.next_node();
+        }
+
+        MemoryReport::new(
+            self.len(),
+            std::mem::size_of::<Node<T>>(),
+            std::mem::size_of::<T>(),
+            heap_bytes,
+        )
+    }
+
+    /// Returns the node visit, allocation and free counts recorded since the list
+    /// was created or the counters were last reset.
+    ///
+    /// Only available with the `stats` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// list.pop();
+    ///
+    /// let stats = list.stats();
+    /// assert_eq!(stats.allocations, 2);
+    /// assert_eq!(stats.frees, 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> ListStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets the node visit, allocation and free counts back to zero.
+    ///
+    /// Only available with the `stats` feature.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// Checks that `length` and `tail` agree with the nodes reachable from `head`.
+    ///
+    /// The walk from `head` counts the nodes and stops after `length + 1` of them,
+    /// so a list whose links accidentally form a cycle is reported instead of
+    /// looping forever. This is O(n) and meant for debugging and tests.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the count equals `length` and the last reachable node is the
+    ///   one `tail` points at, or both are absent.
+    /// * `Err(String)` describing the first invariant found broken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3]);
+    /// list.reverse();
+    /// list.append(4);
+    /// assert_eq!(list.check_invariants(), Ok(()));
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let mut count = 0;
+        let mut last = None;
+        let mut current = self.head;
+        while let Some(node) = current {
+            count += 1;
+            if count > self.length {
+                return Err(format!(
+                    "more than {} nodes are reachable from head, the recorded length",
+                    self.length
+                ));
+            }
+            last = Some(node);
+            // SAFETY: links point at live nodes the list owns, and the walk stops
+            // after `length` nodes in case the chain loops.
+            current = unsafe { node.as_ref() }.next;
+        }
+
+        if count != self.length {
+            return Err(format!(
+                "{} nodes are reachable from head but the recorded length is {}",
+                count, self.length
+            ));
+        }
+        if last != self.tail {
+            return Err(format!(
+                "tail points at {:?} but the last reachable node is {:?}",
+                self.tail, last
+            ));
+        }
+        Ok(())
+    }
+
+    /// Panics if [`KolzoLinkedList::check_invariants`] finds a broken invariant.
+    ///
+    /// Only compiled with debug assertions or for tests. With debug assertions every
+    /// mutating method calls it before returning, so a broken `tail` is caught
+    /// before the next `append` writes through it. This makes `append` and
+    /// `prepend` O(n) in debug builds; the methods that link many nodes at once,
+    /// such as `extend` and `from_fn`, check once at the end instead.
+    #[cfg(any(test, debug_assertions))]
+    fn assert_invariants(&self) {
+        if let Err(message) = self.check_invariants() {
+            panic!("{}", message);
+        }
+    }
+
+    /// Creates a list holding `n` clones of `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from_elem("x", 3);
+    /// assert_eq!(list.to_string(), "x -> x -> x -> None");
+    /// ```
+    pub fn from_elem(value: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_values(std::iter::repeat_n(value, n))
+    }
+
+    /// Creates a list of `n` values, where the value at index `i` is `f(i)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let squares = KolzoLinkedList::from_fn(4, |index| index * index);
+    /// assert_eq!(squares.to_string(), "0 -> 1 -> 4 -> 9 -> None");
+    /// ```
+    pub fn from_fn(n: usize, f: impl FnMut(usize) -> T) -> Self {
+        Self::from_values((0..n).map(f))
+    }
+
+    /// Builds a list holding `values` in order, with `tail` pointing at the last
+    /// node so that later appends stay O(1).
+    fn from_values(values: impl IntoIterator<Item = T>) -> Self {
+        let mut list = KolzoLinkedList::default();
+
+        for value in values {
+            list.stats.record_allocation();
+            let node = list.allocator.allocate(Node::new(value));
+            list.link_back(node);
+        }
+
+        #[cfg(debug_assertions)]
+        list.assert_invariants();
+        list
+    }
+
+    /// Returns an iterator over references to the elements, from head to tail.
+    ///
+    /// The iterator follows the `next` links, so a full scan is O(n) rather than
+    /// the O(n²) of calling [`KolzoLinkedList::get`] for every index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.append(3);
+    ///
+    /// let values: Vec<_> = list.iter().copied().collect();
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head_node(),
+            remaining: self.length,
+            stats: &self.stats,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements, from head to
+    /// tail.
+    ///
+    /// The iterator turns each raw `next` link into a `&mut` to the node behind it,
+    /// which is sound because the list owns every node in the chain, the chain has
+    /// no cycles, and each node is reached exactly once, so no two yielded
+    /// references point at the same `data`. Nothing else can reach the nodes
+    /// meanwhile, because the list stays mutably borrowed for as long as the
+    /// iterator or any reference it yielded is alive:
+    ///
+    /// ```compile_fail,E0499
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    ///
+    /// let mut values = list.iter_mut();
+    /// list.append(2);
+    /// values.next();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// for value in list.iter_mut() {
+    ///     *value += 10;
+    /// }
+    ///
+    /// assert_eq!(list.get(0), Some(&11));
+    /// assert_eq!(list.get(1), Some(&12));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            // SAFETY: `head` points at a live node the list owns, and the iterator
+            // mutably borrows the list.
+            next: self.head.map(|head| unsafe { &mut *head.as_ptr() }),
+            remaining: self.length,
+            stats: &self.stats,
+        }
+    }
+
+    /// Returns a cursor on the first element that can edit the list as it moves.
+    ///
+    /// On an empty list the cursor starts on the ghost position. See [`CursorMut`]
+    /// for how the cursor moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// cursor.insert_before(20);
+    ///
+    /// assert_eq!(list.to_string(), "1 -> 20 -> 3 -> None");
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            previous: None,
+            list: self,
+        }
+    }
+
+    /// Copies the elements into a `Vec`, from head to tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list: KolzoLinkedList<i32> = (1..=3).collect();
+    /// assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Consumes the list and moves its elements into a `Vec`, from head to tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list: KolzoLinkedList<String> = ["a", "b"].into_iter().map(String::from).collect();
+    /// assert_eq!(list.into_vec(), vec!["a", "b"]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.drain(..).collect()
+    }
+
+    /// Combines the elements from head to tail into a single value.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The starting value of the accumulator.
+    /// * `f` - Combines the accumulator with the next element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list: KolzoLinkedList<i32> = (1..=4).collect();
+    /// assert_eq!(list.fold(0, |sum, value| sum + value), 10);
+    /// ```
+    pub fn fold<B>(&self, init: B, f: impl FnMut(B, &T) -> B) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Calls `f` on each element from head to tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list: KolzoLinkedList<&str> = ["a", "b"].into_iter().collect();
+    /// let mut joined = String::new();
+    /// list.for_each(|value| joined.push_str(value));
+    /// assert_eq!(joined, "ab");
+    /// ```
+    pub fn for_each(&self, f: impl FnMut(&T)) {
+        self.iter().for_each(f);
+    }
+
+    /// Calls `f` on each element from head to tail until it returns
+    /// `ControlFlow::Break`.
+    ///
+    /// # Returns
+    ///
+    /// * `ControlFlow::Break(B)` holding the value `f` broke with, in which case the
+    ///   elements after that one were not visited.
+    /// * `ControlFlow::Continue(())` if `f` continued on every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let list: KolzoLinkedList<i32> = [2, 4, 5, 6].into_iter().collect();
+    /// let first_odd = list.try_for_each(|value| {
+    ///     if value % 2 == 1 {
+    ///         ControlFlow::Break(*value)
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// assert_eq!(first_odd, ControlFlow::Break(5));
+    /// ```
+    pub fn try_for_each<B>(&self, f: impl FnMut(&T) -> ControlFlow<B>) -> ControlFlow<B> {
+        self.iter().try_for_each(f)
+    }
+
+    /// Creates a new list holding `f` applied to each element, in the same order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Maps a reference to an element to the value stored in the new list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let numbers: KolzoLinkedList<i32> = (1..=3).collect();
+    /// let labels = numbers.map(|number| format!("#{}", number));
+    /// assert_eq!(labels.to_string(), "#1 -> #2 -> #3 -> None");
+    /// ```
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> KolzoLinkedList<U> {
+        KolzoLinkedList::from_values(self.iter().map(f))
+    }
+
+    /// Consumes the list and creates a new one holding `f` applied to each
+    /// element, in the same order.
+    ///
+    /// Unlike [`KolzoLinkedList::map`], `f` receives each element by value, so it
+    /// can move out of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Maps an element to the value stored in the new list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let words: KolzoLinkedList<String> = ["a", "bc"].into_iter().map(String::from).collect();
+    /// let lengths = words.into_map(|word| word.len());
+    /// assert_eq!(lengths.to_string(), "1 -> 2 -> None");
+    /// ```
+    pub fn into_map<U>(mut self, f: impl FnMut(T) -> U) -> KolzoLinkedList<U> {
+        KolzoLinkedList::from_values(self.drain(..).map(f))
+    }
+
+    /// Creates a new list holding clones of the elements for which `predicate`
+    /// returns `true`, in the same order. The list itself is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Decides whether an element is copied into the new list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let numbers: KolzoLinkedList<i32> = (1..=6).collect();
+    /// let even = numbers.filter(|number| number % 2 == 0);
+    ///
+    /// assert_eq!(even.to_string(), "2 -> 4 -> 6 -> None");
+    /// assert_eq!(numbers.len(), 6);
+    /// ```
+    pub fn filter(&self, mut predicate: impl FnMut(&T) -> bool) -> KolzoLinkedList<T>
+    where
+        T: Clone,
+    {
+        KolzoLinkedList::from_values(self.iter().filter(|value| predicate(value)).cloned())
+    }
+
+    /// Consumes the list and returns it with only the elements for which
+    /// `predicate` returns `true`, in the same order.
+    ///
+    /// The kept nodes are relinked rather than copied, so this needs no
+    /// `T: Clone` and allocates nothing. The other nodes are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Decides whether an element is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let numbers: KolzoLinkedList<i32> = (1..=6).collect();
+    /// let odd = numbers.into_filter(|number| number % 2 == 1);
+    /// assert_eq!(odd.to_string(), "1 -> 3 -> 5 -> None");
+    /// ```
+    pub fn into_filter(mut self, mut predicate: impl FnMut(&T) -> bool) -> KolzoLinkedList<T> {
+        let mut unfiltered = self.head.take();
+        self.tail = None;
+        self.length = 0;
+
+        while let Some(mut node) = unfiltered {
+            self.stats.record_visit();
+            // SAFETY: the unfiltered nodes were taken off the list, so nothing else
+            // borrows them.
+            let node_ref = unsafe { node.as_mut() };
+            unfiltered = node_ref.next.take();
+            if predicate(&node_ref.data) {
+                self.link_back(node);
+            } else {
+                self.stats.record_free();
+                // SAFETY: as above, and the node was just unlinked from the rest.
+                drop(unsafe { self.allocator.free(node) });
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+        self
+    }
+
+    /// Creates a new list holding the values `f` returns as `Some`, in the same
+    /// order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Maps a reference to an element to an optional value for the new list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let words: KolzoLinkedList<&str> = ["1", "two", "3"].into_iter().collect();
+    /// let numbers = words.filter_map(|word| word.parse::<i32>().ok());
+    /// assert_eq!(numbers.to_string(), "1 -> 3 -> None");
+    /// ```
+    pub fn filter_map<U>(&self, f: impl FnMut(&T) -> Option<U>) -> KolzoLinkedList<U> {
+        KolzoLinkedList::from_values(self.iter().filter_map(f))
+    }
+
+    /// Consumes this list and `other` and pairs up their elements by position.
+    ///
+    /// The result is as long as the shorter list. The elements of the longer list
+    /// that have no partner are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements become the second half of each pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// // 342 + 465 with the digits stored least significant first.
+    /// let a = KolzoLinkedList::from([2, 4, 3]);
+    /// let b = KolzoLinkedList::from([5, 6, 4]);
+    ///
+    /// let mut carry = 0;
+    /// let sum = a.zip(b).into_map(|(x, y)| {
+    ///     let digit = x + y + carry;
+    ///     carry = digit / 10;
+    ///     digit % 10
+    /// });
+    /// assert_eq!(sum.to_string(), "7 -> 0 -> 8 -> None");
+    /// ```
+    pub fn zip<U>(mut self, mut other: KolzoLinkedList<U>) -> KolzoLinkedList<(T, U)> {
+        KolzoLinkedList::from_values(self.drain(..).zip(other.drain(..)))
+    }
+
+    /// Consumes this list and `other` and alternates their elements, starting with
+    /// this list: `a1 -> b1 -> a2 -> b2 -> ...`.
+    ///
+    /// Once the shorter list runs out, the rest of the longer one follows as it is.
+    /// The nodes of both lists are relinked without copying or reallocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements go second in each pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let a = KolzoLinkedList::from([1, 3, 5, 6, 7]);
+    /// let b = KolzoLinkedList::from([2, 4]);
+    /// assert_eq!(a.interleave(b).to_string(), "1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> None");
+    /// ```
+    pub fn interleave(mut self, other: KolzoLinkedList<T>) -> KolzoLinkedList<T> {
+        let mut other = self.adopt(other);
+        let mut interleaved = self.sibling();
+        // The longer list provides the last node. With equal lengths it is the last
+        // node of `other`, which always goes second.
+        interleaved.tail = if self.length > other.length {
+            self.tail
+        } else {
+            other.tail.or(self.tail)
+        };
+        interleaved.length = self.length + other.length;
+
+        let mut next = self.head.take();
+        let mut after = other.head.take();
+        self.tail = None;
+        other.tail = None;
+
+        // The empty `next` slot at the end of the nodes linked so far.
+        let mut end = &mut interleaved.head;
+        while let Some(mut node) = next {
+            // SAFETY: the nodes in `next` and `after` were taken off the two lists,
+            // so nothing else borrows them.
+            next = unsafe { node.as_mut() }.next.take();
+            // SAFETY: as above.
+            end = unsafe { &mut (*end.insert(node).as_ptr()).next };
+            // The other list provides the node after this one.
+            std::mem::swap(&mut next, &mut after);
+        }
+        *end = after;
+
+        #[cfg(debug_assertions)]
+        interleaved.assert_invariants();
+        interleaved
+    }
+
+    /// Consumes the linked list and cuts it at every element matching `predicate`,
+    /// returning the pieces in between as lists, in order.
+    ///
+    /// This follows `slice::split`: the matching elements are dropped, and a
+    /// separator at the head or the tail, or two in a row, leaves an empty list in
+    /// the result. The nodes are relinked into the pieces, not cloned, in a single
+    /// O(n) pass that calls `predicate` once per element.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Returns `true` for the elements to split at.
+    ///
+    /// # Returns
+    ///
+    /// * A `Vec` of one more list than there are matching elements. An empty list
+    ///   gives a single empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([10, 40, 33, 20]);
+    /// let pieces = list.split(|value| value % 3 == 0);
+    ///
+    /// assert_eq!(pieces.len(), 2);
+    /// assert_eq!(pieces[0], [10, 40]);
+    /// assert_eq!(pieces[1], [20]);
+    /// ```
+    pub fn split(mut self, mut predicate: impl FnMut(&T) -> bool) -> Vec<KolzoLinkedList<T>> {
+        let mut pieces = Vec::new();
+        let mut piece = self.sibling();
+
+        let mut next = self.head.take();
+        self.tail = None;
+        self.length = 0;
+        while let Some(mut node) = next {
+            self.stats.record_visit();
+            // SAFETY: the nodes in `next` were taken off the list, so nothing else
+            // borrows them.
+            let node_ref = unsafe { node.as_mut() };
+            next = node_ref.next.take();
+            if predicate(&node_ref.data) {
+                self.stats.record_free();
+                // SAFETY: as above, and the node was just unlinked from the rest.
+                drop(unsafe { self.allocator.free(node) });
+                pieces.push(std::mem::replace(&mut piece, self.sibling()));
+            } else {
+                piece.link_back(node);
+            }
+        }
+        pieces.push(piece);
+
+        #[cfg(debug_assertions)]
+        pieces.iter().for_each(KolzoLinkedList::assert_invariants);
+        pieces
+    }
+}
+
+impl<A, B> KolzoLinkedList<(A, B)> {
+    /// Consumes a list of pairs and splits it into a list of the first halves and
+    /// a list of the second halves, both in the original order.
+    ///
+    /// This is the inverse of [`KolzoLinkedList::zip`]. To split the pairs into
+    /// other collections, call `Iterator::unzip` on `list.drain(..)` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let pairs = KolzoLinkedList::from([(1, 'a'), (2, 'b')]);
+    /// let (numbers, letters) = pairs.unzip();
+    ///
+    /// assert_eq!(numbers.to_string(), "1 -> 2 -> None");
+    /// assert_eq!(letters.to_string(), "a -> b -> None");
+    /// ```
+    pub fn unzip(mut self) -> (KolzoLinkedList<A>, KolzoLinkedList<B>) {
+        self.drain(..).unzip()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> KolzoLinkedList<T> {
+    /// Shuffles the linked list in place, so every order of the elements is equally
+    /// likely.
+    ///
+    /// Fisher-Yates needs random access, so the node pointers are unlinked into a
+    /// temporary `Vec`, shuffled there, and relinked in their new order.
+    /// The nodes themselves are not reallocated and no value is moved or cloned.
+    /// It runs in O(n) time with O(n) extra space, and the same RNG state always
+    /// gives the same order.
+    ///
+    /// Only available with the `rand` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw the swaps from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut list: KolzoLinkedList<i32> = (1..=5).collect();
+    /// list.shuffle(&mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(list.len(), 5);
+    /// list.sort();
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> 5 -> None");
+    /// ```
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut nodes = Vec::with_capacity(self.length);
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            self.stats.record_visit();
+            // SAFETY: the nodes in `current` were taken off the list, so nothing else
+            // borrows them.
+            current = unsafe { node.as_mut() }.next.take();
+            nodes.push(node);
+        }
+
+        // Fisher-Yates: fill the positions from the back, each with a node drawn
+        // uniformly from the ones not placed yet.
+        for i in (1..nodes.len()).rev() {
+            nodes.swap(i, rng.gen_range(0..=i));
+        }
+
+        self.tail = nodes.last().copied();
+        for mut node in nodes.into_iter().rev() {
+            // SAFETY: as above.
+            unsafe { node.as_mut().next = self.head.take() };
+            self.head = Some(node);
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+
+    /// Returns a reference to an element chosen uniformly at random, or `None` if
+    /// the list is empty.
+    ///
+    /// This is reservoir sampling with a reservoir of one: the `i`-th element
+    /// (counting from 1) replaces the current choice with probability `1/i`, which
+    /// leaves every element chosen with probability `1/n`. It takes a single pass
+    /// and never reads the length, so the same loop works on a stream of unknown
+    /// length. It draws one random number per element after the first.
+    ///
+    /// Only available with the `rand` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let list = KolzoLinkedList::from([1, 2, 3]);
+    /// let chosen = list.choose(&mut rng).unwrap();
+    /// assert!(list.contains(chosen));
+    ///
+    /// let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+    /// assert_eq!(empty.choose(&mut rng), None);
+    /// ```
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        let mut chosen = None;
+        for (seen, value) in (1..).zip(self.iter()) {
+            if seen == 1 || rng.gen_range(0..seen) == 0 {
+                chosen = Some(value);
+            }
+        }
+        chosen
+    }
+
+    /// Returns references to `k` distinct elements chosen uniformly at random, or
+    /// to every element if the list has fewer than `k`.
+    ///
+    /// This is reservoir sampling (Vitter's Algorithm R): the first `k` elements
+    /// fill the reservoir, and each later element, at index `i`, replaces a random
+    /// slot with probability `k/(i + 1)`. Every subset of size `k` is
+    /// equally likely. Like [`KolzoLinkedList::choose`] it takes a single pass and
+    /// never reads the length, in O(n) time and O(k) extra space.
+    ///
+    /// The name and argument order follow rand's `SliceRandom::choose_multiple`.
+    /// It is not called `sample`, so it does not shadow the `sampling` crate's
+    /// `Sample::sample` on this list.
+    ///
+    /// Only available with the `rand` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw from.
+    /// * `k` - The number of elements to sample.
+    ///
+    /// # Returns
+    ///
+    /// * A `Vec` of `min(k, len)` references. The order of the references is not
+    ///   the order of the list: an element that replaces another takes its slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let list: KolzoLinkedList<i32> = (0..100).collect();
+    /// let sample = list.choose_multiple(&mut rng, 5);
+    /// assert_eq!(sample.len(), 5);
+    ///
+    /// assert_eq!(list.choose_multiple(&mut rng, 1000).len(), 100);
+    /// ```
+    pub fn choose_multiple<R: Rng + ?Sized>(&self, rng: &mut R, k: usize) -> Vec<&T> {
+        let mut values = self.iter();
+        let mut reservoir: Vec<&T> = values.by_ref().take(k).collect();
+        if reservoir.len() < k {
+            return reservoir;
+        }
+
+        for (seen, value) in (k..).zip(values) {
+            let slot = rng.gen_range(0..=seen);
+            if slot < k {
+                reservoir[slot] = value;
+            }
+        }
+        reservoir
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> KolzoLinkedList<T> {
+    /// Returns a parallel iterator over references to the elements of the linked list.
+    ///
+    /// A linked list cannot be split in half without walking it, so this first
+    /// collects a reference to every element into a `Vec` in one O(n) pass and
+    /// hands that `Vec` to rayon. The setup cost only pays off when the work done
+    /// per element outweighs it.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// list.append(3);
+    ///
+    /// let sum: i32 = list.par_iter().map(|value| value * 2).sum();
+    /// assert_eq!(sum, 12);
+    /// ```
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T>
+    where
+        T: Sync,
+    {
+        let mut references = Vec::with_capacity(self.len());
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            references.push(&node.data);
+            current = node.next_node();
+        }
+
+        references.into_par_iter()
+    }
+
+    /// Returns a parallel iterator over mutable references to the elements of the linked list.
+    ///
+    /// The references are collected in one O(n) pass, as in [`KolzoLinkedList::par_iter`].
+    /// Each one comes from turning a raw `next` link into a `&mut` to its node. They
+    /// are disjoint because the chain has no cycles and each node is visited once,
+    /// and nothing else can reach the nodes while `&mut self` is borrowed.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// list.par_iter_mut().for_each(|value| *value += 10);
+    ///
+    /// assert_eq!(list.get(0), Some(&11));
+    /// assert_eq!(list.get(1), Some(&12));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T>
+    where
+        T: Send,
+    {
+        let mut references = Vec::with_capacity(self.len());
+        // SAFETY: links point at live nodes the list owns, nothing else borrows the
+        // list while we hold `&mut self`, and each node is borrowed only once.
+        let mut current = self.head.map(|head| unsafe { &mut *head.as_ptr() });
+        while let Some(node) = current {
+            self.stats.record_visit();
+            // SAFETY: as above.
+            current = node.next.map(|next| unsafe { &mut *next.as_ptr() });
+            references.push(&mut node.data);
+        }
+
+        references.into_par_iter()
+    }
+}
+
+impl<T> LinearList<T> for KolzoLinkedList<T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn elements(&self) -> Vec<&T> {
+        let mut elements = Vec::with_capacity(self.len());
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            self.stats.record_visit();
+            elements.push(&node.data);
+            current = node.next_node();
+        }
+        elements
+    }
+}
+
+impl<T> Default for KolzoLinkedList<T> {
+    /// Creates an empty list.
+    fn default() -> Self {
+        Self::with_allocator(NodeAllocator::heap())
+    }
+}
+
+impl<T: Clone> Clone for KolzoLinkedList<T> {
+    /// Deep-copies every node into a new list whose `tail` points at the copy's
+    /// own last node.
+    ///
+    /// A derived clone would copy the raw `tail` pointer as it is, leaving the
+    /// copy pointing into the original's last node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let original: KolzoLinkedList<i32> = (1..=2).collect();
+    /// let mut copy = original.clone();
+    /// copy.append(3);
+    ///
+    /// assert_eq!(original.to_string(), "1 -> 2 -> None");
+    /// assert_eq!(copy.to_string(), "1 -> 2 -> 3 -> None");
+    /// ```
+    fn clone(&self) -> Self {
+        let mut copy = self.sibling();
+        copy.extend(self.iter().cloned());
+        copy
+    }
+}
+
+impl<T: PartialEq> PartialEq for KolzoLinkedList<T> {
+    /// Two lists are equal if they hold equal values in the same order.
+    ///
+    /// Lists of different lengths are unequal without walking either of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let a: KolzoLinkedList<i32> = (1..=3).collect();
+    /// let b: KolzoLinkedList<i32> = (1..=3).collect();
+    /// let c: KolzoLinkedList<i32> = (1..=2).collect();
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, c);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for KolzoLinkedList<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for KolzoLinkedList<T> {
+    /// A list equals a `Vec` that holds equal values in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 3]);
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// assert_ne!(list, vec![1, 2]);
+    /// ```
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for KolzoLinkedList<T> {
+    /// A list equals a slice that holds equal values in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 3]);
+    /// let values = vec![0, 1, 2, 3];
+    /// assert_eq!(list, &values[1..]);
+    /// assert_ne!(list, &values[..3]);
+    /// ```
+    fn eq(&self, other: &&[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for KolzoLinkedList<T> {
+    /// A list equals an array that holds equal values in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 3]);
+    /// assert_eq!(list, [1, 2, 3]);
+    /// assert_ne!(list, [1, 2, 4]);
+    /// ```
+    fn eq(&self, other: &[U; N]) -> bool {
+        self == &other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<KolzoLinkedList<U>> for Vec<T> {
+    /// The mirror of comparing a list with a `Vec`.
+    fn eq(&self, other: &KolzoLinkedList<U>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<KolzoLinkedList<U>> for &[T] {
+    /// The mirror of comparing a list with a slice.
+    fn eq(&self, other: &KolzoLinkedList<U>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<KolzoLinkedList<U>> for [T; N] {
+    /// The mirror of comparing a list with an array.
+    fn eq(&self, other: &KolzoLinkedList<U>) -> bool {
+        &self.as_slice() == other
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for KolzoLinkedList<T> {
+    /// Compares two lists lexicographically, as `Vec` does.
+    ///
+    /// Elements are compared in order until two differ. If one list is a prefix of
+    /// the other, the shorter one orders first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// assert!(KolzoLinkedList::from([1, 2]) < KolzoLinkedList::from([1, 3]));
+    /// assert!(KolzoLinkedList::from([1, 2]) < KolzoLinkedList::from([1, 2, 0]));
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for KolzoLinkedList<T> {
+    /// Compares two lists lexicographically, as `Vec` does.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoLinkedList<T> {
+    /// Formats the elements from head to tail as a list, like `Vec` and std's
+    /// `LinkedList` do.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for KolzoLinkedList<T> {
+    /// Formats the list as its values joined by arrows and ending in `None`, such
+    /// as `1 -> 2 -> 3 -> None`.
+    ///
+    /// The alternate flag, `{:#}`, puts each value on its own line after its
+    /// index instead, still ending in `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.append(2);
+    ///
+    /// assert_eq!(list.to_string(), "1 -> 2 -> None");
+    /// assert_eq!(format!("{:#}", list), "[0] 1\n[1] 2\nNone");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, value) in self.iter().enumerate() {
+            if f.alternate() {
+                writeln!(f, "[{}] {}", index, value)?;
+            } else {
+                write!(f, "{} -> ", value)?;
+            }
+        }
+        write!(f, "None")
+    }
+}
+
+impl<T> FromIterator<T> for KolzoLinkedList<T> {
+    /// Builds a list holding the values in iteration order.
+    ///
+    /// Each value is appended behind the tail pointer, so collecting n values is
+    /// O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list: KolzoLinkedList<i32> = (1..=3).collect();
+    /// assert_eq!(list.get(0), Some(&1));
+    /// assert_eq!(list.get(2), Some(&3));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = KolzoLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> From<Vec<T>> for KolzoLinkedList<T> {
+    /// Builds a list holding the values of the `Vec` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from(vec![1, 2, 3]);
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    /// ```
+    fn from(values: Vec<T>) -> Self {
+        Self::from_values(values)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for KolzoLinkedList<T> {
+    /// Builds a list holding the values of the array in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 3]);
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    /// ```
+    fn from(values: [T; N]) -> Self {
+        Self::from_values(values)
+    }
+}
+
+impl<T: Clone> From<&[T]> for KolzoLinkedList<T> {
+    /// Builds a list holding clones of the values of the slice in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let values = [1, 2, 3, 4];
+    /// let list = KolzoLinkedList::from(&values[1..]);
+    /// assert_eq!(list.to_string(), "2 -> 3 -> 4 -> None");
+    /// ```
+    fn from(values: &[T]) -> Self {
+        Self::from_values(values.iter().cloned())
+    }
+}
+
+impl<T> Extend<T> for KolzoLinkedList<T> {
+    /// Appends every value of the iterator in order.
+    ///
+    /// Each value is linked behind the tail pointer, so extending by n values is
+    /// O(n) no matter how long the list already is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.append(1);
+    /// list.extend(vec![2, 3]);
+    /// assert_eq!(list.get(2), Some(&3));
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.stats.record_allocation();
+            let node = self.allocator.allocate(Node::new(value));
+            self.link_back(node);
+        }
+
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for KolzoLinkedList<T> {
+    /// Appends a copy of every value of the iterator in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// list.extend(&[1, 2]);
+    /// assert_eq!(list.get(1), Some(&2));
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    /// Iterates over references to the elements, so `for value in &list` works.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let list = KolzoLinkedList::from([1, 2, 3]);
+    /// let mut sum = 0;
+    /// for value in &list {
+    ///     sum += value;
+    /// }
+    /// assert_eq!(sum, 6);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut KolzoLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    /// Iterates over mutable references to the elements, so `for value in &mut list`
+    /// works.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3]);
+    /// for value in &mut list {
+    ///     *value *= 2;
+    /// }
+    /// assert_eq!(list.to_string(), "2 -> 4 -> 6 -> None");
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over references to the elements of a [`KolzoLinkedList`], from
+/// head to tail.
+pub struct Iter<'a, T> {
+    /// The node whose value is yielded next.
+    next: Option<&'a Node<T>>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+    /// The counters of the list, which record every node the iterator visits.
+    stats: &'a StatsCounter,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.stats.record_visit();
+            self.next = node.next_node();
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+// SAFETY: the iterator only reads nodes of a shared-borrowed list, so it behaves
+// like the `&'a [T]` it stands in for.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+/// An iterator over mutable references to the elements of a [`KolzoLinkedList`],
+/// from head to tail.
+pub struct IterMut<'a, T> {
+    /// The node whose value is yielded next.
+    next: Option<&'a mut Node<T>>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+    /// The counters of the list, which record every node the iterator visits.
+    stats: &'a StatsCounter,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.stats.record_visit();
+            // SAFETY: links point at live nodes of the list, which is mutably
+            // borrowed for `'a`, and each node is yielded only once.
+            self.next = node.next.map(|next| unsafe { &mut *next.as_ptr() });
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+// SAFETY: the iterator hands out each element of a mutably borrowed list once, so it
+// behaves like the `&'a mut [T]` it stands in for.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+/// An iterator that removes a range of elements from a [`KolzoLinkedList`] and
+/// yields them by value, created by [`KolzoLinkedList::drain`].
+pub struct Drain<'a, T> {
+    /// The first of the unlinked nodes not yet yielded.
+    next: Option<NonNull<Node<T>>>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+    /// The list's allocator, which frees the drained nodes. Borrowing it keeps the
+    /// list mutably borrowed while the drain is alive, as `Vec::drain` does, even
+    /// though the nodes are already unlinked from it.
+    allocator: &'a mut NodeAllocator<Node<T>>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            // SAFETY: the drained nodes were cut off the list, so the drain owns
+            // them and nothing else links to the first one.
+            let node = unsafe { self.allocator.free(node) };
+            self.next = node.next;
+            self.remaining -= 1;
+            node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    /// Drops the values that were not yielded one node at a time, like the list
+    /// itself does.
+    fn drop(&mut self) {
+        let mut current = self.next.take();
+        while let Some(node) = current {
+            // SAFETY: as in `next`.
+            current = unsafe { self.allocator.free(node) }.next;
+        }
+    }
+}
+
+// SAFETY: the drain owns the nodes it has not yielded, like a `vec::IntoIter<T>`.
+unsafe impl<T: Send> Send for Drain<'_, T> {}
+unsafe impl<T: Sync> Sync for Drain<'_, T> {}
+
+/// A cursor over a [`KolzoLinkedList`] that can edit the list where it stands,
+/// created by [`KolzoLinkedList::cursor_front_mut`].
+///
+/// The cursor is either on an element or on the "ghost" position, which sits after
+/// the tail and before the head, like the ghost of std's unstable `CursorMut`.
+/// Moving past the last element parks the cursor on the ghost, and moving on from
+/// the ghost wraps around to the head.
+///
+/// Every move and edit is O(1), so a pass that edits the list as it scans it is
+/// O(n), where index-based `insert` and `remove` would make it O(n²).
+///
+/// The cursor's pointers are copies of the list's raw links, so no edit made
+/// through the cursor or the list can invalidate the pointers the other holds.
+pub struct CursorMut<'a, T> {
+    /// The node the cursor is on, or `None` on the ghost position.
+    current: Option<NonNull<Node<T>>>,
+    /// The node before `current`: `None` on the head, and the tail on the ghost.
+    previous: Option<NonNull<Node<T>>>,
+    /// The list being edited, borrowed for as long as the cursor lives.
+    list: &'a mut KolzoLinkedList<T>,
+}
+
+// SAFETY: the pointers only lead to nodes of the mutably borrowed list, so the
+// cursor can cross threads under the same conditions as the `&mut` it holds.
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
+impl<T> CursorMut<'_, T> {
+    /// Returns a mutable reference to the element the cursor is on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` if the cursor is on an element.
+    /// * `None` if the cursor is on the ghost position.
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: `current` points at a node the list owns, the cursor borrows the
+        // list mutably, and the returned reference borrows the cursor.
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Returns a mutable reference to the element after the cursor without moving
+    /// it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` if there is a next element. On the ghost position this is
+    ///   the head.
+    /// * `None` if the cursor is on the last element, or on the ghost position of
+    ///   an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.peek_next(), Some(&mut 2));
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_next(), None);
+    /// ```
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            // SAFETY: as in `current`.
+            Some(node) => unsafe { node.as_ref() }.next,
+            None => self.list.head,
+        };
+        // SAFETY: as in `current`.
+        next.map(|node| unsafe { &mut (*node.as_ptr()).data })
+    }
+
+    /// Moves the cursor to the next element.
+    ///
+    /// # Behavior
+    ///
+    /// * On the last element, the cursor moves to the ghost position.
+    /// * On the ghost position, the cursor moves to the head, or stays on the ghost
+    ///   if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2]);
+    /// let mut cursor = list.cursor_front_mut();
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), None);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// ```
+    pub fn move_next(&mut self) {
+        self.list.stats.record_visit();
+        match self.current {
+            Some(node) => {
+                self.previous = Some(node);
+                // SAFETY: as in `current`.
+                self.current = unsafe { node.as_ref() }.next;
+            }
+            None => {
+                self.previous = None;
+                self.current = self.list.head;
+            }
+        }
+    }
+
+    /// Inserts `value` after the element the cursor is on, without moving the
+    /// cursor.
+    ///
+    /// On the ghost position the value becomes the new head.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_after(2);
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    ///
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    /// ```
+    pub fn insert_after(&mut self, value: T) {
+        self.link_after(self.current, value);
+        if self.current.is_none() {
+            // The ghost sits after the tail, which is new if the list was empty.
+            self.previous = self.list.tail;
+        }
+    }
+
+    /// Inserts `value` before the element the cursor is on, without moving the
+    /// cursor.
+    ///
+    /// On the ghost position the value becomes the new tail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([2]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_before(1);
+    /// cursor.move_next();
+    /// cursor.insert_before(3);
+    ///
+    /// assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    /// ```
+    pub fn insert_before(&mut self, value: T) {
+        self.previous = Some(self.link_after(self.previous, value));
+    }
+
+    /// Removes the element the cursor is on and returns it, moving the cursor to
+    /// the element after it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the removed element if the cursor was on one.
+    /// * `None` if the cursor is on the ghost position, in which case the list is
+    ///   unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    ///
+    /// let mut list = KolzoLinkedList::from([1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.remove_current(), Some(1));
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    ///
+    /// assert_eq!(list.to_string(), "2 -> 3 -> None");
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        // SAFETY: as in `current`. Only `previous`, or the head if there is none,
+        // links to the node, and it is relinked past it right away.
+        let removed = unsafe { self.list.allocator.free(current) };
+        match self.previous {
+            // SAFETY: as in `current`.
+            Some(mut node) => unsafe { node.as_mut().next = removed.next },
+            None => self.list.head = removed.next,
+        }
+
+        self.current = removed.next;
+        if self.current.is_none() {
+            // The removed node was the tail, so the one before it ends the list.
+            self.list.tail = self.previous;
+        }
+
+        self.list.length -= 1;
+        self.list.stats.record_free();
+        Some(removed.data)
+    }
+
+    /// Links a new node holding `value` after `previous`, or at the head if it is
+    /// `None`, and returns a pointer to it.
+    fn link_after(&mut self, previous: Option<NonNull<Node<T>>>, value: T) -> NonNull<Node<T>> {
+        let mut new_node = self.list.allocator.allocate(Node::new(value));
+        let slot = match previous {
+            // SAFETY: as in `current`.
+            Some(node) => unsafe { &mut (*node.as_ptr()).next },
+            None => &mut self.list.head,
+        };
+
+        let next = slot.replace(new_node);
+        // SAFETY: the node was just allocated, so nothing else borrows it.
+        unsafe { new_node.as_mut().next = next };
+        if next.is_none() {
+            self.list.tail = Some(new_node);
+        }
+
+        self.list.length += 1;
+        self.list.stats.record_allocation();
+        new_node
+    }
+}
+
+impl<T> Drop for KolzoLinkedList<T> {
+    /// Frees the nodes one at a time, following the links from the head.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            // SAFETY: the list owns its nodes, and the link to each one is followed
+            // only once before it is freed.
+            current = unsafe { self.allocator.free(node) }.next;
+        }
+    }
+}
+
+/// Cuts the chain starting at `nodes` after its first `count` nodes and returns
+/// the rest, or `None` if the chain has at most `count` nodes.
+fn split_after<T>(nodes: &mut Option<NonNull<Node<T>>>, count: usize) -> Option<NonNull<Node<T>>> {
+    let mut current = nodes;
+    for _ in 0..count {
+        match current {
+            // SAFETY: links point at live nodes of the chain, which the caller owns.
+            Some(node) => current = unsafe { &mut (*node.as_ptr()).next },
+            None => return None,
+        }
+    }
+    current.take()
+}
+
+/// Merges the sorted chains `left` and `right`, taking from `left` on ties so the
+/// merge is stable, and returns the first and last nodes of the merged chain, or
+/// `None` if both chains are empty.
+#[allow(clippy::type_complexity)]
+fn merge<T>(
+    mut left: Option<NonNull<Node<T>>>,
+    mut right: Option<NonNull<Node<T>>>,
+    compare: &mut impl FnMut(&T, &T) -> Ordering,
+    stats: &StatsCounter,
+) -> Option<(NonNull<Node<T>>, NonNull<Node<T>>)> {
+    let mut first = None;
+    let mut last: Option<NonNull<Node<T>>> = None;
+
+    // SAFETY, for every dereference below: both chains consist of live nodes that
+    // the caller owns and that nothing else links to.
+    while let (Some(left_node), Some(right_node)) = (left, right) {
+        let (left_data, right_data) =
+            unsafe { (&left_node.as_ref().data, &right_node.as_ref().data) };
+        let source = if compare(left_data, right_data) == Ordering::Greater {
+            &mut right
+        } else {
+            &mut left
+        };
+        if let Some(mut node) = *source {
+            stats.record_visit();
+            *source = unsafe { node.as_mut() }.next.take();
+            match last {
+                Some(mut last_node) => unsafe { last_node.as_mut().next = Some(node) },
+                None => first = Some(node),
+            }
+            last = Some(node);
+        }
+    }
+
+    // At most one side is left and it is already linked in order.
+    if let Some(rest) = left.or(right) {
+        match last {
+            Some(mut last_node) => unsafe { last_node.as_mut().next = Some(rest) },
+            None => first = Some(rest),
+        }
+        stats.record_visit();
+        let mut end = rest;
+        while let Some(next) = unsafe { end.as_ref() }.next {
+            stats.record_visit();
+            end = next;
+        }
+        last = Some(end);
+    }
+    first.zip(last)
+}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p linked_list`, under both Stacked Borrows and Tree
+// Borrows (`MIRIFLAGS=-Zmiri-tree-borrows`). The `rayon` tests also need
+// `-Zmiri-ignore-leaks` for the thread pool, and crossbeam-epoch inside it only
+// passes under Tree Borrows.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cell::Cell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    #[test]
+    fn test_append_and_pop() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.len(), 2);
+
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.len(), 1);
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.len(), 0);
+
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn test_iterators_know_their_length() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4]);
+
+        let mut iter = list.iter();
+        for remaining in (0..4).rev() {
+            assert!(iter.next().is_some());
+            assert_eq!(iter.len(), remaining);
+        }
+        // Fused: an exhausted iterator keeps returning `None`.
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.len(), 4);
+        iter.nth(2);
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        let mut drain = list.drain(1..3);
+        assert_eq!(drain.len(), 2);
+        drain.next();
+        drain.next();
+        assert_eq!(drain.next(), None);
+        assert_eq!(drain.next(), None);
+        drop(drain);
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.iter().len(), 0);
+
+        // `collect` reserves exactly the reported length up front.
+        let large: KolzoLinkedList<u32> = (0..1000).collect();
+        assert_eq!(large.iter().collect::<Vec<_>>().capacity(), 1000);
+    }
+
+    proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore = "proptest reads the working directory, which Miri isolates")]
+        fn test_size_hint_is_exact_at_every_step(
+            values in prop::collection::vec(any::<i32>(), 0..64),
+            range in (0usize..64, 0usize..64),
+        ) {
+            let mut list = KolzoLinkedList::from(values.clone());
+
+            let mut iter = list.iter();
+            for remaining in (0..=values.len()).rev() {
+                prop_assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+                prop_assert_eq!(iter.next().is_some(), remaining > 0);
+            }
+
+            let mut iter = list.iter_mut();
+            for remaining in (0..=values.len()).rev() {
+                prop_assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+                prop_assert_eq!(iter.next().is_some(), remaining > 0);
+            }
+
+            let (start, end) = (range.0.min(range.1), range.0.max(range.1));
+            let (start, end) = (start.min(values.len()), end.min(values.len()));
+            let mut drain = list.drain(start..end);
+            for remaining in (0..=end - start).rev() {
+                prop_assert_eq!(drain.size_hint(), (remaining, Some(remaining)));
+                prop_assert_eq!(drain.next().is_some(), remaining > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_for_loops_by_reference() {
+        fn total<'a>(values: impl IntoIterator<Item = &'a i32>) -> i32 {
+            values.into_iter().sum()
+        }
+
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4]);
+        let mut seen = Vec::new();
+        for value in &list {
+            seen.push(*value);
+        }
+        assert_eq!(seen, vec![1, 2, 3, 4]);
+        assert_eq!(total(&list), 10);
+
+        for value in &mut list {
+            *value *= 10;
+        }
+        assert_eq!(total(&list), 100);
+        assert_eq!(list.to_string(), "10 -> 20 -> 30 -> 40 -> None");
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(total(&empty), 0);
+    }
+
+    #[test]
+    fn test_std_names() {
+        let mut list = KolzoLinkedList::new();
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(list.head.is_none() && list.tail.is_none());
+
+        // The std spellings work on the same list as the original ones.
+        list.push_back(5);
+        list.prepend(4);
+        assert_eq!(list.pop_front(), Some(4));
+        assert_eq!(list.pop(), Some(5));
+    }
+
+    #[test]
+    fn test_append_list() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+        list.append_list((4..=5).collect());
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> 5 -> None");
+        assert_eq!(list.len(), 5);
+
+        // The tail is now the last node of the appended list.
+        list.append(6);
+        assert_eq!(list.last(), Some(&6));
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.pop(), Some(6));
+        assert_eq!(list.pop(), Some(5));
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_append_list_with_empty_lists() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        list.append_list(KolzoLinkedList::new());
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+
+        list.append_list((1..=2).collect());
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+        assert_eq!(list.len(), 2);
+        list.append(3);
+        assert_eq!(list.last(), Some(&3));
+
+        list.append_list(KolzoLinkedList::new());
+        assert_eq!(list.len(), 3);
+        list.append(4);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4, 5]);
+        let mut back = list.split_off(3);
+
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(back.to_string(), "4 -> 5 -> None");
+        assert_eq!((list.len(), back.len()), (3, 2));
+
+        // Both halves have their own tail.
+        list.append(6);
+        back.append(7);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 6 -> None");
+        assert_eq!(back.to_string(), "4 -> 5 -> 7 -> None");
+        list.assert_invariants();
+        back.assert_invariants();
+
+        let tail = back.split_off(2);
+        assert_eq!(tail.to_string(), "7 -> None");
+        assert_eq!(back.last(), Some(&5));
+    }
+
+    #[test]
+    fn test_split_off_at_the_ends() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+
+        let mut empty = list.split_off(3);
+        assert!(empty.is_empty());
+        assert_eq!(list.len(), 3);
+        empty.append(9);
+        assert_eq!(empty.to_string(), "9 -> None");
+
+        let mut everything = list.split_off(0);
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+        assert_eq!(everything.to_string(), "1 -> 2 -> 3 -> None");
+
+        list.append(0);
+        everything.append(4);
+        assert_eq!(list.to_string(), "0 -> None");
+        assert_eq!(everything.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+
+        let mut nothing: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert!(nothing.split_off(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot split off at index 4 of a list of length 3")]
+    fn test_split_off_past_the_end_panics() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = KolzoLinkedList::new();
+
+        list.prepend(1);
+        list.prepend(2);
+        list.prepend(3);
+
+        assert_eq!(list.len(), 3);
+
+        let mut current = list.head_node();
+        assert_eq!(current.map(|node| &node.data), Some(&3));
+        current = current.unwrap().next_node();
+        assert_eq!(current.map(|node| &node.data), Some(&2));
+        current = current.unwrap().next_node();
+        assert_eq!(current.map(|node| &node.data), Some(&1));
+        current = current.unwrap().next_node();
+        assert!(current.is_none());
+    }
+
+    #[test]
+    fn test_pop_first() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        assert_eq!(list.pop_first(), None);
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        assert_eq!(list.pop_first(), Some(1));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop_first(), Some(2));
+        assert_eq!(list.pop_first(), Some(3));
+        assert!(list.is_empty());
+
+        assert_eq!(list.pop_first(), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.get(1), None);
+
+        list.append(10);
+        list.append(20);
+        list.append(30);
+
+        assert_eq!(list.get(0), Some(&10));
+        assert_eq!(list.get(1), Some(&20));
+        assert_eq!(list.get(2), Some(&30));
+
+        assert_eq!(list.get(3), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(list.get_mut(0), None);
+
+        list.append(10);
+        list.append(20);
+        list.append(30);
+
+        *list.get_mut(1).unwrap() += 10;
+        assert_eq!(list.get(1), Some(&30));
+
+        if let Some(last) = list.get_mut(2) {
+            *last = 0;
+        }
+        assert_eq!(list.get(2), Some(&0));
+        assert_eq!(list.get_mut(3), None);
+        assert_eq!(list.to_string(), "10 -> 30 -> 0 -> None");
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+        assert_eq!(list.first_mut(), None);
+        assert_eq!(list.last_mut(), None);
+
+        list.append(1);
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&1));
+
+        list.append(2);
+        list.append(3);
+        *list.first_mut().unwrap() += 10;
+        *list.last_mut().unwrap() += 30;
+        assert_eq!(list.to_string(), "11 -> 2 -> 33 -> None");
+    }
+
+    #[test]
+    fn test_last_follows_the_tail_through_mutations() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4]);
+
+        list.reverse();
+        assert_eq!(list.first(), Some(&4));
+        assert_eq!(list.last(), Some(&1));
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.last(), Some(&2));
+
+        assert_eq!(list.remove(list.len() - 1), Some(2));
+        assert_eq!(list.last(), Some(&3));
+
+        assert_eq!(list.remove(0), Some(4));
+        assert_eq!(list.first(), Some(&3));
+        assert_eq!(list.last(), Some(&3));
+
+        assert_eq!(list.pop_first(), Some(3));
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+
+        list.prepend(5);
+        assert_eq!(list.last(), Some(&5));
+        list.insert(1, 6);
+        assert_eq!(list.last(), Some(&6));
+    }
+
+    #[test]
+    fn test_set() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        assert_eq!(list.set(0, 10), None);
+        assert_eq!(list.set(1, 20), None);
+
+        list.append(10);
+        list.append(20);
+        list.append(30);
+
+        assert_eq!(list.set(0, 15), Some(10));
+        assert_eq!(list.set(1, 25), Some(20));
+        assert_eq!(list.set(2, 35), Some(30));
+
+        assert_eq!(list.get(0), Some(&15));
+        assert_eq!(list.get(1), Some(&25));
+        assert_eq!(list.get(2), Some(&35));
+
+        assert_eq!(list.set(3, 40), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        assert!(list.insert(0, 10));
+        assert_eq!(list.get(0), Some(&10));
+        assert_eq!(list.len(), 1);
+
+        list.append(20);
+        list.append(30);
+
+        assert!(list.insert(0, 5));
+        assert_eq!(list.get(0), Some(&5));
+        assert_eq!(list.get(1), Some(&10));
+        assert_eq!(list.get(2), Some(&20));
+        assert_eq!(list.get(3), Some(&30));
+        assert_eq!(list.len(), 4);
+
+        assert!(list.insert(4, 35));
+        assert_eq!(list.get(4), Some(&35));
+        assert_eq!(list.len(), 5);
+
+        assert!(list.insert(2, 15));
+        assert_eq!(list.get(0), Some(&5));
+        assert_eq!(list.get(1), Some(&10));
+        assert_eq!(list.get(2), Some(&15));
+        assert_eq!(list.get(3), Some(&20));
+        assert_eq!(list.get(4), Some(&30));
+        assert_eq!(list.get(5), Some(&35));
+        assert_eq!(list.len(), 6);
+
+        assert!(!list.insert(10, 40));
+        assert_eq!(list.get(6), None);
+        assert_eq!(list.len(), 6);
+    }
+
+    #[test]
+    fn test_index_bounds() {
+        let fixture = KolzoLinkedList::from([10, 20, 30, 40]);
+        let values = fixture.to_vec();
+        let len = fixture.len();
+
+        // Each index with the element it names and where a value inserted at it
+        // ends up. `len - 1` is the last element and `len` only accepts inserts.
+        let cases: [(usize, Option<i32>, Option<usize>); 7] = [
+            (0, Some(10), Some(0)),
+            (2, Some(30), Some(2)),
+            (len - 1, Some(40), Some(3)),
+            (len, None, Some(4)),
+            (len + 1, None, None),
+            (usize::MAX - 1, None, None),
+            (usize::MAX, None, None),
+        ];
+        for (index, element, inserted_at) in cases {
+            assert_eq!(fixture.get(index), element.as_ref(), "get({index})");
+
+            let mut list = fixture.clone();
+            assert_eq!(list.set(index, 0), element, "set({index})");
+            let replaced: Vec<_> = values
+                .iter()
+                .map(|&value| if Some(value) == element { 0 } else { value })
+                .collect();
+            assert_eq!(list.to_vec(), replaced, "set({index})");
+
+            let mut list = fixture.clone();
+            assert_eq!(list.remove(index), element, "remove({index})");
+            let removed: Vec<_> = values
+                .iter()
+                .copied()
+                .filter(|&value| Some(value) != element)
+                .collect();
+            assert_eq!(list.to_vec(), removed, "remove({index})");
+            list.assert_invariants();
+
+            let mut list = fixture.clone();
+            assert_eq!(
+                list.insert(index, 0),
+                inserted_at.is_some(),
+                "insert({index})"
+            );
+            let mut inserted = values.clone();
+            if let Some(position) = inserted_at {
+                inserted.insert(position, 0);
+            }
+            assert_eq!(list.to_vec(), inserted, "insert({index})");
+            list.assert_invariants();
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_signed_index_shims() {
+        let fixture = KolzoLinkedList::from([10, 20, 30, 40]);
+        let values = fixture.to_vec();
+
+        // Each index with the element it names and where a value inserted at it
+        // ends up.
+        let cases: [(i64, Option<i32>, Option<usize>); 11] = [
+            (0, Some(10), Some(0)),
+            (2, Some(30), Some(2)),
+            (3, Some(40), Some(3)),
+            (4, None, Some(4)),
+            (5, None, None),
+            (-1, Some(40), Some(3)),
+            (-2, Some(30), Some(2)),
+            (-4, Some(10), Some(0)),
+            (-5, None, None),
+            (i64::MAX, None, None),
+            (i64::MIN, None, None),
+        ];
+        for (index, element, inserted_at) in cases {
+            assert_eq!(fixture.get_signed(index), element.as_ref(), "get({index})");
+
+            let mut list = fixture.clone();
+            assert_eq!(list.set_signed(index, 0), element, "set({index})");
+            let replaced: Vec<_> = values
+                .iter()
+                .map(|&value| if Some(value) == element { 0 } else { value })
+                .collect();
+            assert_eq!(list.to_vec(), replaced, "set({index})");
+
+            let mut list = fixture.clone();
+            assert_eq!(list.remove_signed(index), element, "remove({index})");
+            let removed: Vec<_> = values
+                .iter()
+                .copied()
+                .filter(|&value| Some(value) != element)
+                .collect();
+            assert_eq!(list.to_vec(), removed, "remove({index})");
+            list.assert_invariants();
+
+            let mut list = fixture.clone();
+            assert_eq!(
+                list.insert_signed(index, 0),
+                inserted_at.is_some(),
+                "insert({index})"
+            );
+            let mut inserted = values.clone();
+            if let Some(position) = inserted_at {
+                inserted.insert(position, 0);
+            }
+            assert_eq!(list.to_vec(), inserted, "insert({index})");
+            list.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_insert_at_len_keeps_the_tail() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert!(!list.insert(1, 1));
+
+        assert!(list.insert(0, 1));
+        assert!(list.insert(1, 3));
+        assert!(list.insert(1, 2));
+        assert!(list.insert(3, 4));
+
+        // The value inserted at the end is the tail, so appends go after it.
+        list.append(5);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> 5 -> None");
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        assert_eq!(list.remove(0), None);
+        assert_eq!(list.len(), 0);
+
+        list.append(10);
+        list.append(20);
+        list.append(30);
+        list.append(40);
+
+        assert_eq!(list.remove(0), Some(10));
+        assert_eq!(list.get(0), Some(&20));
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.remove(2), Some(40));
+        assert_eq!(list.get(1), Some(&30));
+        assert_eq!(list.get(2), None);
+        assert_eq!(list.len(), 2);
+
+        list.append(50);
+        assert_eq!(list.remove(1), Some(30));
+        assert_eq!(list.get(0), Some(&20));
+        assert_eq!(list.get(1), Some(&50));
+        assert_eq!(list.len(), 2);
+
+        assert_eq!(list.remove(10), None);
+        assert_eq!(list.remove(2), None);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_last_until_empty() {
+        let mut list = KolzoLinkedList::from([0, 1, 2, 3, 4]);
+
+        for expected in (0..5).rev() {
+            assert_eq!(list.remove(list.len() - 1), Some(expected));
+            assert_eq!(list.get(expected), None);
+            assert_eq!(list.len(), reachable_nodes(&list));
+        }
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+        assert_eq!(list.pop(), None);
+
+        list.append(7);
+        list.append(8);
+        assert_eq!(list.remove(1), Some(8));
+        list.append(9);
+        assert_eq!(list.to_string(), "7 -> 9 -> None");
+        assert_eq!(list.pop(), Some(9));
+        assert_eq!(list.pop(), Some(7));
+        assert_eq!(list.pop(), None);
+    }
+
+    /// Counts the nodes reachable from `head`, independently of `length`.
+    fn reachable_nodes<T>(list: &KolzoLinkedList<T>) -> usize {
+        let mut count = 0;
+        let mut current = list.head_node();
+        while let Some(node) = current {
+            count += 1;
+            current = node.next_node();
+        }
+        count
+    }
+
+    #[test]
+    fn test_remove_head_decrements_length_once() {
+        let mut list = KolzoLinkedList::from([0, 1, 2]);
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.len(), 2);
+        assert_eq!(reachable_nodes(&list), 2);
+
+        // Interleave head removals with appends; every index below `len()`
+        // must still be readable and nothing past it.
+        let mut next = 3;
+        for round in 0..20 {
+            list.append(next);
+            next += 1;
+            if round % 3 != 0 {
+                list.append(next);
+                next += 1;
+            }
+            let first = list.get(0).copied();
+            assert_eq!(list.remove(0), first);
+
+            assert_eq!(list.len(), reachable_nodes(&list));
+            assert_eq!(list.get(list.len() - 1), Some(&(next - 1)));
+            assert_eq!(list.get(list.len()), None);
+        }
+
+        while list.remove(0).is_some() {
+            assert_eq!(list.len(), reachable_nodes(&list));
+        }
+        assert!(list.is_empty());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_tail_after_emptying_and_refilling() {
+        let mut list = KolzoLinkedList::from([1, 2]);
+        assert_eq!(list.pop_first(), Some(1));
+        assert_eq!(list.pop_first(), Some(2));
+        assert!(list.tail.is_none());
+        list.append(3);
+        list.append(4);
+        list.assert_invariants();
+        assert_eq!(list.to_string(), "3 -> 4 -> None");
+
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(3));
+        assert!(list.tail.is_none());
+        list.prepend(5);
+        list.append(6);
+        list.assert_invariants();
+        assert_eq!(list.to_string(), "5 -> 6 -> None");
+
+        assert_eq!(list.remove(1), Some(6));
+        assert_eq!(list.remove(0), Some(5));
+        list.prepend(7);
+        list.insert(1, 8);
+        list.reverse();
+        list.append(9);
+        list.assert_invariants();
+        assert_eq!(list.to_string(), "8 -> 7 -> 9 -> None");
+    }
+
+    #[test]
+    fn test_tail_matches_a_model_under_random_operations() {
+        let mut rng = StdRng::seed_from_u64(268);
+        let mut list = KolzoLinkedList::new();
+        let mut model = VecDeque::new();
+
+        for step in 0..2_000 {
+            // Both spellings of the end operations are exercised.
+            let std_name = rng.gen_bool(0.5);
+            match rng.gen_range(0..7) {
+                0 => {
+                    if std_name {
+                        list.push_back(step);
+                    } else {
+                        list.append(step);
+                    }
+                    model.push_back(step);
+                }
+                1 => {
+                    if std_name {
+                        list.push_front(step);
+                    } else {
+                        list.prepend(step);
+                    }
+                    model.push_front(step);
+                }
+                2 if std_name => assert_eq!(list.pop_back(), model.pop_back()),
+                2 => assert_eq!(list.pop(), model.pop_back()),
+                3 if std_name => assert_eq!(list.pop_front(), model.pop_front()),
+                3 => assert_eq!(list.pop_first(), model.pop_front()),
+                4 => {
+                    let index = rng.gen_range(0..=model.len());
+                    assert!(list.insert(index, step));
+                    model.insert(index, step);
+                }
+                5 if !model.is_empty() => {
+                    let index = rng.gen_range(0..model.len());
+                    assert_eq!(list.remove(index), model.remove(index));
+                }
+                _ => {
+                    list.reverse();
+                    model.make_contiguous().reverse();
+                }
+            }
+
+            list.assert_invariants();
+            assert!(list.iter().eq(model.iter()));
+        }
+    }
+
+    #[test]
+    fn test_remove_tail_moves_the_tail_pointer() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+
+        assert_eq!(list.remove(2), Some(3));
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+
+        // Appending links after the new tail rather than the removed node.
+        list.append(4);
+        assert_eq!(list.to_string(), "1 -> 2 -> 4 -> None");
+        assert_eq!(list.pop(), Some(4));
+
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(list.remove(0), Some(1));
+        assert!(list.head.is_none() && list.tail.is_none());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+        assert_eq!(list.peek_back(), None);
+
+        list.append(2);
+        assert_eq!((list.peek(), list.peek_back()), (Some(&2), Some(&2)));
+        list.prepend(1);
+        list.append(3);
+        *list.peek_mut().unwrap() = 0;
+        assert_eq!((list.peek(), list.peek_back()), (Some(&0), Some(&3)));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_peek_after_reverse_and_pop() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+
+        list.reverse();
+        assert_eq!((list.peek(), list.peek_back()), (Some(&3), Some(&1)));
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!((list.peek(), list.peek_back()), (Some(&3), Some(&2)));
+
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!((list.peek(), list.peek_back()), (Some(&3), Some(&3)));
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!((list.peek(), list.peek_back()), (None, None));
+
+        // The emptied list starts over from a fresh tail.
+        list.append(4);
+        list.reverse();
+        list.append(5);
+        assert_eq!((list.peek(), list.peek_back()), (Some(&4), Some(&5)));
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list: KolzoLinkedList<i32> = (0..5).collect();
+
+        // Lengths at or past the end leave the list alone.
+        list.truncate(5);
+        list.truncate(9);
+        assert_eq!(list.to_string(), "0 -> 1 -> 2 -> 3 -> 4 -> None");
+
+        list.truncate(3);
+        assert_eq!(list.len(), 3);
+        list.append(5);
+        assert_eq!(list.to_string(), "0 -> 1 -> 2 -> 5 -> None");
+
+        list.truncate(1);
+        assert_eq!((list.first(), list.last()), (Some(&0), Some(&0)));
+        list.append(6);
+        assert_eq!(list.to_string(), "0 -> 6 -> None");
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+        list.append(7);
+        assert_eq!(list.to_string(), "7 -> None");
+    }
+
+    #[test]
+    fn test_truncate_and_clear_drop_the_removed_values() {
+        let counted = Rc::new(());
+        let mut list: KolzoLinkedList<Rc<()>> = (0..10).map(|_| Rc::clone(&counted)).collect();
+
+        list.truncate(4);
+        assert_eq!(Rc::strong_count(&counted), 5);
+        list.clear();
+        assert_eq!(Rc::strong_count(&counted), 1);
+        assert_eq!(list, KolzoLinkedList::new());
+    }
+
+    #[test]
+    fn test_truncate_long_list() {
+        let mut list: KolzoLinkedList<usize> = (0..size(1_000_000)).collect();
+        list.truncate(1);
+        assert_eq!(list.to_string(), "0 -> None");
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut list = KolzoLinkedList::new();
+        list.resize(3, 'a');
+        assert_eq!(list.to_string(), "a -> a -> a -> None");
+        list.assert_invariants();
+
+        list.resize(0, 'b');
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+
+        // Growing and then shrinking keeps the elements that were already there.
+        let mut list = KolzoLinkedList::from([3, 1, 2]);
+        list.resize(5, 0);
+        assert_eq!(list.to_string(), "3 -> 1 -> 2 -> 0 -> 0 -> None");
+        list.resize(2, 0);
+        assert_eq!(list.to_string(), "3 -> 1 -> None");
+        list.resize(2, 9);
+        list.append(4);
+        assert_eq!(list.to_string(), "3 -> 1 -> 4 -> None");
+        assert_eq!(list.len(), 3);
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_resize_with_without_clone() {
+        #[derive(Debug, PartialEq)]
+        struct NoClone(usize);
+
+        let mut calls = 0;
+        let mut list = KolzoLinkedList::new();
+        list.resize_with(3, || {
+            calls += 1;
+            NoClone(calls)
+        });
+        assert!(list.iter().map(|value| value.0).eq(1..=3));
+
+        list.resize_with(1, || unreachable!("shrinking must not call the filler"));
+        assert_eq!(list.len(), 1);
+        list.resize_with(2, || NoClone(0));
+        assert_eq!(list.last(), Some(&NoClone(0)));
+        assert_eq!(calls, 3);
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_zip() {
+        let zipped = KolzoLinkedList::from([1, 2, 3]).zip(KolzoLinkedList::from(['a', 'b', 'c']));
+        assert_eq!(zipped, [(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(zipped.last(), Some(&(3, 'c')));
+        assert_eq!(zipped.len(), 3);
+
+        // Either side may be the longer one.
+        let zipped = KolzoLinkedList::from([1, 2, 3]).zip(KolzoLinkedList::from(["x"]));
+        assert_eq!(zipped, [(1, "x")]);
+        let mut zipped = KolzoLinkedList::from([1]).zip(KolzoLinkedList::from([4, 5, 6]));
+        zipped.append((7, 8));
+        assert_eq!(zipped, [(1, 4), (7, 8)]);
+        zipped.assert_invariants();
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        let zipped = empty.zip(KolzoLinkedList::from([1, 2]));
+        assert!(zipped.is_empty());
+        assert!(zipped.head.is_none() && zipped.tail.is_none());
+    }
+
+    #[test]
+    fn test_zip_drops_the_leftovers() {
+        let counted = Rc::new(());
+        let longer: KolzoLinkedList<Rc<()>> = (0..5).map(|_| Rc::clone(&counted)).collect();
+
+        let zipped = longer.zip(KolzoLinkedList::from([1, 2]));
+        assert_eq!(Rc::strong_count(&counted), 3);
+        drop(zipped);
+        assert_eq!(Rc::strong_count(&counted), 1);
+    }
+
+    #[test]
+    fn test_interleave() {
+        let interleaved =
+            KolzoLinkedList::from([1, 3, 5]).interleave(KolzoLinkedList::from([2, 4, 6]));
+        assert!(interleaved.iter().copied().eq(1..=6));
+        assert_eq!(interleaved.len(), 6);
+        assert_eq!(interleaved.last(), Some(&6));
+
+        // Either side may have the leftover suffix.
+        let mut interleaved =
+            KolzoLinkedList::from([1, 3, 5, 6]).interleave(KolzoLinkedList::from([2, 4]));
+        interleaved.append(7);
+        assert!(interleaved.iter().copied().eq(1..=7));
+        let mut interleaved =
+            KolzoLinkedList::from([1]).interleave(KolzoLinkedList::from([2, 3, 4]));
+        interleaved.append(5);
+        assert!(interleaved.iter().copied().eq(1..=5));
+        interleaved.assert_invariants();
+
+        let empty = || KolzoLinkedList::<i32>::new();
+        let mut interleaved = empty().interleave(KolzoLinkedList::from([1, 2]));
+        interleaved.append(3);
+        assert_eq!(interleaved.to_string(), "1 -> 2 -> 3 -> None");
+        let mut interleaved = KolzoLinkedList::from([1, 2]).interleave(empty());
+        interleaved.append(3);
+        assert_eq!(interleaved.to_string(), "1 -> 2 -> 3 -> None");
+        let interleaved = empty().interleave(empty());
+        assert!(interleaved.head.is_none() && interleaved.tail.is_none());
+    }
+
+    #[test]
+    fn test_interleave_lengths_add_up() {
+        let mut rng = StdRng::seed_from_u64(309);
+        for _ in 0..100 {
+            let a: Vec<i32> = (0..rng.gen_range(0..20)).map(|_| rng.gen()).collect();
+            let b: Vec<i32> = (0..rng.gen_range(0..20)).map(|_| rng.gen()).collect();
+
+            let interleaved =
+                KolzoLinkedList::from(&a[..]).interleave(KolzoLinkedList::from(&b[..]));
+            let mut expected = Vec::new();
+            for index in 0..a.len().max(b.len()) {
+                expected.extend(a.get(index));
+                expected.extend(b.get(index));
+            }
+            assert!(interleaved.iter().eq(expected.iter()));
+            assert_eq!(interleaved.len(), a.len() + b.len());
+            interleaved.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_unzip() {
+        let (mut firsts, mut seconds) = KolzoLinkedList::from([(1, "one"), (2, "two")]).unzip();
+        firsts.append(3);
+        seconds.append("three");
+        assert_eq!(firsts.to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(seconds.to_string(), "one -> two -> three -> None");
+        firsts.assert_invariants();
+        seconds.assert_invariants();
+
+        let (firsts, seconds) = KolzoLinkedList::<(i32, char)>::new().unzip();
+        assert!(firsts.is_empty() && seconds.is_empty());
+        assert!(firsts.tail.is_none() && seconds.tail.is_none());
+    }
+
+    #[test]
+    fn test_zip_then_unzip_round_trips() {
+        let mut rng = StdRng::seed_from_u64(304);
+        for _ in 0..50 {
+            let len = rng.gen_range(0..40);
+            let left: KolzoLinkedList<u8> = (0..len).map(|_| rng.gen()).collect();
+            let right: KolzoLinkedList<i64> = (0..len).map(|_| rng.gen()).collect();
+
+            let (unzipped_left, unzipped_right) = left.clone().zip(right.clone()).unzip();
+            assert_eq!(unzipped_left, left);
+            assert_eq!(unzipped_right, right);
+            assert_eq!(unzipped_left.last(), left.last());
+            assert_eq!(unzipped_right.last(), right.last());
+            assert_eq!(unzipped_left.len(), len);
+        }
+    }
+
+    #[test]
+    fn test_count_value_and_count_by() {
+        let list = KolzoLinkedList::from([4, 1, 4, 2, 4, 4, 3]);
+        assert_eq!(list.count_value(&4), 4);
+        assert_eq!(list.count_value(&1), 1);
+        assert_eq!(list.count_value(&9), 0);
+        assert_eq!(list.count_by(|value| value % 2 == 0), 5);
+        assert_eq!(list.count_by(|&value| value > 10), 0);
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.count_value(&0), 0);
+        assert_eq!(empty.count_by(|_| true), 0);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(
+            (empty.min_element(), empty.max_element(), empty.min_max()),
+            (None, None, None)
+        );
+
+        let single = KolzoLinkedList::from([5]);
+        assert_eq!(
+            (single.min_element(), single.max_element()),
+            (Some(&5), Some(&5))
+        );
+        assert_eq!(single.min_max(), Some((&5, &5)));
+
+        let descending: KolzoLinkedList<i32> = (1..=9).rev().collect();
+        assert_eq!(
+            (descending.min_element(), descending.max_element()),
+            (Some(&1), Some(&9))
+        );
+        assert_eq!(descending.min_max(), Some((&1, &9)));
+
+        let mut rng = StdRng::seed_from_u64(306);
+        for len in 1..40 {
+            let values: Vec<i32> = (0..len).map(|_| rng.gen_range(-20..20)).collect();
+            let list = KolzoLinkedList::from(&values[..]);
+            let expected = (values.iter().min().unwrap(), values.iter().max().unwrap());
+            assert_eq!(list.min_max(), Some(expected));
+            assert_eq!(
+                (list.min_element(), list.max_element()),
+                (Some(expected.0), Some(expected.1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_max_return_the_first_of_equal_extremes() {
+        let all_equal: KolzoLinkedList<Keyed> = (0..5).map(|id| Keyed { key: 3, id }).collect();
+        assert_eq!(all_equal.min_element().map(|value| value.id), Some(0));
+        assert_eq!(all_equal.max_element().map(|value| value.id), Some(0));
+        let (min, max) = all_equal.min_max().unwrap();
+        assert_eq!((min.id, max.id), (0, 0));
+
+        let keys = [5, 0, 9, 0, 9, 5, 0];
+        for len in 1..=keys.len() {
+            let list: KolzoLinkedList<Keyed> = keys[..len]
+                .iter()
+                .enumerate()
+                .map(|(id, &key)| Keyed { key, id })
+                .collect();
+            let first_of = |key| keys[..len].iter().position(|&other| other == key);
+
+            let (min, max) = list.min_max().unwrap();
+            assert_eq!(Some(min.id), first_of(min.key));
+            assert_eq!(Some(max.id), first_of(max.key));
+            assert_eq!(list.min_element().map(|value| value.id), Some(min.id));
+            assert_eq!(list.max_element().map(|value| value.id), Some(max.id));
+        }
+    }
+
+    #[test]
+    fn test_min_max_uses_three_comparisons_per_pair() {
+        /// Counts every comparison made between two values.
+        struct Counted<'a> {
+            value: i32,
+            comparisons: &'a Cell<usize>,
+        }
+
+        impl PartialEq for Counted<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for Counted<'_> {}
+
+        impl PartialOrd for Counted<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Counted<'_> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.comparisons.set(self.comparisons.get() + 1);
+                self.value.cmp(&other.value)
+            }
+        }
+
+        let comparisons = Cell::new(0);
+        let mut rng = StdRng::seed_from_u64(306);
+        let list: KolzoLinkedList<Counted> = (0..1_001)
+            .map(|_| Counted {
+                value: rng.gen(),
+                comparisons: &comparisons,
+            })
+            .collect();
+
+        list.min_max();
+        // The first element starts both extremes, and each of the 500 pairs after it
+        // costs three comparisons.
+        assert_eq!(comparisons.get(), 1_500);
+    }
+
+    #[test]
+    fn test_remove_value() {
+        let mut list = KolzoLinkedList::from([1, 2, 2, 3, 1]);
+
+        // Only the first occurrence goes, starting with the head.
+        assert_eq!(list.remove_value(&1), Some(1));
+        assert_eq!(list.to_string(), "2 -> 2 -> 3 -> 1 -> None");
+        assert_eq!(list.remove_value(&2), Some(2));
+        assert_eq!(list.to_string(), "2 -> 3 -> 1 -> None");
+        assert_eq!(list.len(), 3);
+
+        // Removing the last element moves the tail.
+        assert_eq!(list.remove_value(&1), Some(1));
+        list.append(4);
+        assert_eq!(list.to_string(), "2 -> 3 -> 4 -> None");
+
+        assert_eq!(list.remove_value(&7), None);
+        assert_eq!(list.len(), 3);
+        list.append(5);
+        assert_eq!(list.last(), Some(&5));
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.remove_value(&1), None);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut list = KolzoLinkedList::from([3, 3, 1, 3, 3, 2, 3]);
+        assert_eq!(list.remove_all(&3), 5);
+        assert_eq!(list.count_value(&3), 0);
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+        assert_eq!(list.len(), 2);
+        list.assert_invariants();
+
+        // The removed tail is replaced by the last kept node.
+        list.append(4);
+        assert_eq!(list.to_string(), "1 -> 2 -> 4 -> None");
+
+        assert_eq!(list.remove_all(&9), 0);
+        assert_eq!(list.len(), 3);
+
+        let mut same = KolzoLinkedList::from([7, 7, 7]);
+        assert_eq!(same.remove_all(&7), 3);
+        assert!(same.is_empty());
+        assert!(same.head.is_none() && same.tail.is_none());
+        same.append(1);
+        assert_eq!(same.to_string(), "1 -> None");
+    }
+
+    #[test]
+    fn test_remove_by_value_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(291);
+        let mut list = KolzoLinkedList::new();
+        let mut model = VecDeque::new();
+
+        for _ in 0..2_000 {
+            let value = rng.gen_range(0..5);
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    list.append(value);
+                    model.push_back(value);
+                }
+                2 => {
+                    let expected = model
+                        .iter()
+                        .position(|&element| element == value)
+                        .and_then(|index| model.remove(index));
+                    assert_eq!(list.remove_value(&value), expected);
+                }
+                _ => {
+                    let before = model.len();
+                    model.retain(|&element| element != value);
+                    assert_eq!(list.remove_all(&value), before - model.len());
+                    assert_eq!(list.count_value(&value), 0);
+                }
+            }
+
+            assert_eq!(list.len(), model.len());
+            list.assert_invariants();
+            assert!(list.iter().eq(model.iter()));
+        }
+    }
+
+    #[test]
+    fn test_len_after_mixed_operations() {
+        let mut list = KolzoLinkedList::new();
+        assert!(list.is_empty());
+
+        list.append(2);
+        assert_eq!(list.len(), 1);
+        list.prepend(0);
+        assert_eq!(list.len(), 2);
+        list.insert(1, 1);
+        assert_eq!(list.len(), 3);
+        list.append(3);
+        assert_eq!(list.len(), 4);
+
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop_first(), Some(1));
+        assert!(list.is_empty());
+
+        // Operations that change nothing leave the length alone.
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.remove(0), None);
+        list.insert(5, 5);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_middle() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.middle(), None);
+        assert_eq!(empty.middle_index(), None);
+
+        let cases: [(&[i32], usize); 5] = [
+            (&[1], 0),
+            (&[1, 2], 1),
+            (&[1, 2, 3, 4, 5], 2),
+            (&[1, 2, 3, 4, 5, 6], 3),
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11], 5),
+        ];
+        for (values, expected) in cases {
+            let list = KolzoLinkedList::from(values);
+            assert_eq!(list.middle_index(), Some(expected));
+            assert_eq!(list.middle(), Some(&values[expected]));
+            assert_eq!(list.middle_index(), Some(list.len() / 2));
+        }
+    }
+
+    #[test]
+    fn test_nth_from_end() {
+        let list = KolzoLinkedList::from([10, 20, 30, 40, 50]);
+        assert_eq!(list.nth_from_end(0), Some(&50));
+        assert_eq!(list.nth_from_end(1), Some(&40));
+        assert_eq!(list.nth_from_end(4), Some(&10));
+        assert_eq!(list.nth_from_end(5), None);
+        assert_eq!(list.nth_from_end(usize::MAX), None);
+
+        for k in 0..list.len() {
+            assert_eq!(list.nth_from_end(k), list.get(list.len() - 1 - k));
+        }
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.nth_from_end(0), None);
+    }
+
+    #[test]
+    fn test_remove_nth_from_end() {
+        let mut list = KolzoLinkedList::from([10, 20, 30, 40, 50]);
+
+        assert_eq!(list.remove_nth_from_end(0), Some(50));
+        assert_eq!(list.last(), Some(&40));
+        assert_eq!(list.remove_nth_from_end(3), Some(10));
+        assert_eq!(list.first(), Some(&20));
+        assert_eq!(list.remove_nth_from_end(1), Some(30));
+        assert_eq!(list.remove_nth_from_end(2), None);
+        assert_eq!(list.to_string(), "20 -> 40 -> None");
+        assert_eq!(list.len(), 2);
+
+        list.append(60);
+        assert_eq!(list.to_string(), "20 -> 40 -> 60 -> None");
+        list.assert_invariants();
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.remove_nth_from_end(0), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert!(!empty.contains(&1));
+
+        let list = KolzoLinkedList::from([3, 1, 4, 1, 5]);
+        assert!(list.contains(&3));
+        assert!(list.contains(&5));
+        assert!(list.contains(&1));
+        assert!(!list.contains(&2));
+    }
+
+    #[test]
+    fn test_contains_without_clone() {
+        #[derive(PartialEq)]
+        struct NoClone(u8);
+
+        let list: KolzoLinkedList<NoClone> = (0..3).map(NoClone).collect();
+        assert!(list.contains(&NoClone(2)));
+        assert!(!list.contains(&NoClone(3)));
+    }
+
+    #[test]
+    fn test_find_and_position() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.find(&1), None);
+        assert_eq!(empty.position(|_| true), None);
+
+        let list = KolzoLinkedList::from([3, 1, 4, 1, 5]);
+        assert_eq!(list.find(&3), Some(0));
+        assert_eq!(list.find(&1), Some(1));
+        assert_eq!(list.find(&5), Some(4));
+        assert_eq!(list.find(&2), None);
+        assert_eq!(list.position(|value| *value > 3), Some(2));
+
+        let mut calls = 0;
+        list.position(|value| {
+            calls += 1;
+            *value == 4
+        });
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_rposition() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.rposition(|_| true), None);
+
+        let list = KolzoLinkedList::from([3, 1, 4, 1, 5]);
+        assert_eq!(list.rposition(|value| *value == 3), Some(0));
+        assert_eq!(list.rposition(|value| *value == 1), Some(3));
+        assert_eq!(list.rposition(|value| *value == 5), Some(4));
+        assert_eq!(list.rposition(|value| *value > 9), None);
+
+        // Every element is checked, in order, to find the last match.
+        let mut seen = Vec::new();
+        list.rposition(|value| {
+            seen.push(*value);
+            *value == 3
+        });
+        assert_eq!(seen, [3, 1, 4, 1, 5]);
+    }
+
+    #[test]
+    fn test_find_map() {
+        let empty: KolzoLinkedList<&str> = KolzoLinkedList::new();
+        assert_eq!(empty.find_map(|text| text.parse::<i32>().ok()), None);
+
+        let list = KolzoLinkedList::from(["7", "x", "8", "y", "9"]);
+        assert_eq!(list.find_map(|text| text.parse::<i32>().ok()), Some(7));
+        assert_eq!(list.find_map(|text| text.strip_prefix('9')), Some(""));
+        assert_eq!(list.find_map(|text| text.strip_prefix('z')), None);
+
+        // Only the prefix up to the first hit is visited.
+        let mut calls = 0;
+        let found = list.find_map(|text| {
+            calls += 1;
+            (*text == "8").then_some(calls)
+        });
+        assert_eq!(found, Some(3));
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        list.find_map(|_| {
+            calls += 1;
+            None::<()>
+        });
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_any_and_all() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert!(!empty.any(|_| true));
+        assert!(empty.all(|_| false));
+
+        let list = KolzoLinkedList::from([2, 4, 5, 6, 8]);
+        assert!(list.any(|value| *value == 8));
+        assert!(!list.any(|value| *value > 8));
+        assert!(list.all(|value| *value > 0));
+        assert!(!list.all(|value| value % 2 == 0));
+
+        // Both stop at the element that decides the answer, the third one here.
+        let mut calls = 0;
+        assert!(list.any(|value| {
+            calls += 1;
+            value % 2 == 1
+        }));
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        assert!(!list.all(|value| {
+            calls += 1;
+            value % 2 == 0
+        }));
+        assert_eq!(calls, 3);
+
+        // Without a deciding element every node is visited.
+        let mut calls = 0;
+        assert!(list.all(|_| {
+            calls += 1;
+            true
+        }));
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_split() {
+        // The examples of `slice::split`.
+        let pieces = KolzoLinkedList::from([10, 40, 33, 20]).split(|value| value % 3 == 0);
+        assert_eq!(pieces, [vec![10, 40], vec![20]]);
+
+        let pieces = KolzoLinkedList::from([10, 40, 33]).split(|value| value % 3 == 0);
+        assert_eq!(pieces, [vec![10, 40], vec![]]);
+
+        let pieces = KolzoLinkedList::from([10, 6, 33, 20]).split(|value| value % 3 == 0);
+        assert_eq!(pieces, [vec![10], vec![], vec![20]]);
+
+        let pieces = KolzoLinkedList::from([3, 1, 3]).split(|value| *value == 3);
+        assert_eq!(pieces, [vec![], vec![1], vec![]]);
+
+        let pieces = KolzoLinkedList::<i32>::new().split(|_| true);
+        assert_eq!(pieces, [Vec::<i32>::new()]);
+
+        let pieces = KolzoLinkedList::from([1, 2]).split(|_| false);
+        assert_eq!(pieces, [vec![1, 2]]);
+
+        // Each piece has its own tail, so appending lands at its end.
+        let mut pieces = KolzoLinkedList::from([1, 2, 0, 3]).split(|value| *value == 0);
+        for piece in &mut pieces {
+            piece.assert_invariants();
+            piece.append(9);
+        }
+        assert_eq!(pieces, [vec![1, 2, 9], vec![3, 9]]);
+    }
+
+    #[test]
+    fn test_split_matches_slice_split() {
+        let mut rng = StdRng::seed_from_u64(323);
+        for len in 0..40 {
+            let values: Vec<u8> = (0..len).map(|_| rng.gen_range(0..4)).collect();
+
+            let mut calls = 0;
+            let pieces = KolzoLinkedList::from(values.clone()).split(|value| {
+                calls += 1;
+                *value == 0
+            });
+            assert_eq!(calls, len);
+
+            let expected: Vec<_> = values.split(|value| *value == 0).collect();
+            assert_eq!(pieces.len(), expected.len());
+            for (piece, slice) in pieces.iter().zip(expected) {
+                assert_eq!(*piece, slice);
+                piece.assert_invariants();
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_moves_the_nodes() {
+        struct NoClone(u8);
+
+        let list: KolzoLinkedList<NoClone> = (0..5).map(NoClone).collect();
+        let addresses: Vec<*const NoClone> = list.iter().map(|value| value as *const _).collect();
+
+        // Without `Clone` the values can only reach the pieces by relinking, and
+        // the addresses show the nodes were not reallocated either.
+        let pieces = list.split(|value| value.0 == 2);
+        let relinked: Vec<*const NoClone> = pieces
+            .iter()
+            .flat_map(|piece| piece.iter().map(|value| value as *const _))
+            .collect();
+        assert_eq!(
+            relinked,
+            [addresses[0], addresses[1], addresses[3], addresses[4]]
+        );
+    }
+
+    #[test]
+    fn test_find_then_remove_first_occurrence() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 2, 1]);
+
+        let index = list.find(&2).unwrap();
+        assert_eq!(list.remove(index), Some(2));
+        assert_eq!(list.to_string(), "1 -> 3 -> 2 -> 1 -> None");
+
+        while let Some(index) = list.find(&1) {
+            list.remove(index);
+        }
+        assert_eq!(list.to_string(), "3 -> 2 -> None");
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4, 5]);
+
+        assert!(list.swap(0, 4));
+        assert_eq!(list.to_string(), "5 -> 2 -> 3 -> 4 -> 1 -> None");
+
+        assert!(list.swap(2, 1));
+        assert_eq!(list.to_string(), "5 -> 3 -> 2 -> 4 -> 1 -> None");
+
+        assert!(list.swap(3, 3));
+        assert_eq!(list.to_string(), "5 -> 3 -> 2 -> 4 -> 1 -> None");
+
+        assert!(!list.swap(0, 5));
+        assert!(!list.swap(7, 1));
+        assert!(!list.swap(5, 5));
+        assert_eq!(list.to_string(), "5 -> 3 -> 2 -> 4 -> 1 -> None");
+
+        // Only values move, so the tail still ends the list.
+        assert_eq!(list.last(), Some(&1));
+        list.append(6);
+        list.assert_invariants();
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert!(!empty.swap(0, 0));
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4, 5]);
+        list.rotate_left(1);
+        assert_eq!(list.to_string(), "2 -> 3 -> 4 -> 5 -> 1 -> None");
+        list.rotate_left(4);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> 5 -> None");
+
+        // Wraps around the length.
+        list.rotate_left(12);
+        assert_eq!(list.to_string(), "3 -> 4 -> 5 -> 1 -> 2 -> None");
+        list.rotate_left(5);
+        assert_eq!(list.to_string(), "3 -> 4 -> 5 -> 1 -> 2 -> None");
+
+        // The new tail is the true end.
+        list.append(6);
+        assert_eq!(list.to_string(), "3 -> 4 -> 5 -> 1 -> 2 -> 6 -> None");
+        assert_eq!(list.len(), 6);
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4, 5]);
+        list.rotate_right(1);
+        assert_eq!(list.to_string(), "5 -> 1 -> 2 -> 3 -> 4 -> None");
+        list.rotate_right(7);
+        assert_eq!(list.to_string(), "3 -> 4 -> 5 -> 1 -> 2 -> None");
+        list.rotate_right(0);
+        assert_eq!(list.to_string(), "3 -> 4 -> 5 -> 1 -> 2 -> None");
+
+        list.append(6);
+        assert_eq!(list.last(), Some(&6));
+        assert_eq!(list.pop(), Some(6));
+        assert_eq!(list.pop(), Some(2));
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_rotate_short_lists() {
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        empty.rotate_left(3);
+        empty.rotate_right(3);
+        assert!(empty.is_empty() && empty.tail.is_none());
+
+        let mut single = KolzoLinkedList::from([1]);
+        single.rotate_left(3);
+        single.rotate_right(2);
+        single.append(2);
+        assert_eq!(single.to_string(), "1 -> 2 -> None");
+        single.rotate_left(1);
+        single.append(3);
+        assert_eq!(single.to_string(), "2 -> 1 -> 3 -> None");
+        single.assert_invariants();
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = KolzoLinkedList::from([1, 1, 2, 2, 2, 3, 1, 1]);
+        list.dedup();
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 1 -> None");
+        assert_eq!(list.len(), 4);
+
+        // The collapsed run at the end leaves the tail on the kept node.
+        list.append(4);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 1 -> 4 -> None");
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_dedup_all_equal_and_empty() {
+        let mut list = KolzoLinkedList::from_elem(7, 5);
+        list.dedup();
+        assert_eq!(list.count_value(&7), 1);
+        assert_eq!(list.to_string(), "7 -> None");
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.last(), Some(&7));
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+        assert!(empty.tail.is_none());
+    }
+
+    #[test]
+    fn test_dedup_all() {
+        let mut list = KolzoLinkedList::from([3, 1, 3, 2, 1, 3]);
+        list.dedup_all();
+        assert_eq!(list.to_string(), "3 -> 1 -> 2 -> None");
+        assert_eq!(list.len(), 3);
+
+        // The old tail was a duplicate, so appending must follow the kept `2`.
+        list.append(4);
+        assert_eq!(list.to_string(), "3 -> 1 -> 2 -> 4 -> None");
+        list.assert_invariants();
+
+        let mut unique = KolzoLinkedList::from([1, 2, 3]);
+        unique.dedup_all();
+        assert_eq!(unique.to_string(), "1 -> 2 -> 3 -> None");
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        empty.dedup_all();
+        assert!(empty.head.is_none() && empty.tail.is_none());
+    }
+
+    #[test]
+    fn test_dedup_all_matches_a_seen_set() {
+        let mut rng = StdRng::seed_from_u64(307);
+        for _ in 0..100 {
+            let values: Vec<u8> = (0..rng.gen_range(0..50))
+                .map(|_| rng.gen_range(0..10))
+                .collect();
+            let mut list = KolzoLinkedList::from(&values[..]);
+            list.dedup_all();
+
+            let mut seen = HashSet::new();
+            let expected: Vec<u8> = values
+                .into_iter()
+                .filter(|&value| seen.insert(value))
+                .collect();
+            assert!(list.iter().eq(expected.iter()));
+            for value in &expected {
+                assert_eq!(list.count_value(value), 1);
+            }
+            list.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_dedup_all_without_clone() {
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        struct NoClone(u8);
+
+        let mut list: KolzoLinkedList<NoClone> = [2, 1, 2, 2, 3, 1].map(NoClone).into();
+        list.dedup_all();
+
+        assert!(list.iter().eq(&[NoClone(2), NoClone(1), NoClone(3)]));
+        assert_eq!(list.last(), Some(&NoClone(3)));
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_dedup_all_by_key_without_clone() {
+        struct Big {
+            id: u32,
+            payload: Vec<u8>,
+        }
+
+        let mut list: KolzoLinkedList<Big> = [1, 2, 1, 1, 3, 2]
+            .into_iter()
+            .enumerate()
+            .map(|(index, id)| Big {
+                id,
+                payload: vec![index as u8; 1024],
+            })
+            .collect();
+        list.dedup_all_by_key(|big| big.id);
+
+        let kept: Vec<_> = list.iter().map(|big| (big.id, big.payload[0])).collect();
+        assert_eq!(kept, vec![(1, 0), (2, 1), (3, 4)]);
+        assert_eq!(list.last().map(|big| big.id), Some(3));
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        // Each element is compared with the last kept one, not its neighbour.
+        let mut list = KolzoLinkedList::from([1, 2, 3, 5, 6, 7, 10]);
+        list.dedup_by(|a, b| a - b <= 2);
+        assert_eq!(list.to_string(), "1 -> 5 -> 10 -> None");
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_drain_middle() {
+        let mut list = KolzoLinkedList::from([0, 1, 2, 3, 4, 5]);
+
+        let drain = list.drain(2..4);
+        assert_eq!(drain.len(), 2);
+        assert_eq!(drain.collect::<Vec<_>>(), vec![2, 3]);
+
+        assert_eq!(list.to_string(), "0 -> 1 -> 4 -> 5 -> None");
+        assert_eq!(list.len(), 4);
+        list.append(6);
+        assert_eq!(list.last(), Some(&6));
+        list.assert_invariants();
+
+        assert_eq!(list.drain(1..=1).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(list.to_string(), "0 -> 4 -> 5 -> 6 -> None");
+    }
+
+    #[test]
+    fn test_drain_prefix_suffix_and_everything() {
+        let mut list = KolzoLinkedList::from([0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(list.drain(..2).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(list.to_string(), "2 -> 3 -> 4 -> 5 -> None");
+
+        assert_eq!(list.drain(3..).collect::<Vec<_>>(), vec![5]);
+        assert_eq!(list.last(), Some(&4));
+
+        assert_eq!(list.drain(1..1).count(), 0);
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.drain(..).collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+        list.append(7);
+        assert_eq!(list.to_string(), "7 -> None");
+    }
+
+    #[test]
+    fn test_drain_dropped_early_still_removes_the_range() {
+        let values: Vec<Rc<i32>> = (0..5).map(Rc::new).collect();
+        let mut list = KolzoLinkedList::from(&values[..]);
+
+        let mut drain = list.drain(1..4);
+        assert_eq!(drain.next().as_deref(), Some(&1));
+        drop(drain);
+
+        assert_eq!(list.len(), 2);
+        assert!(list.iter().map(|value| **value).eq([0, 4]));
+        // The values that were never yielded have been dropped.
+        assert!(values[1..4]
+            .iter()
+            .all(|value| Rc::strong_count(value) == 1));
+        list.assert_invariants();
+    }
+
+    #[test]
+    #[should_panic(expected = "drain range end 4 is out of bounds for a list of length 3")]
+    fn test_drain_past_the_end_panics() {
+        let mut list = KolzoLinkedList::from([0, 1, 2]);
+        list.drain(1..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "drain range starts at 2 but ends at 1")]
+    fn test_drain_reversed_range_panics() {
+        let mut list = KolzoLinkedList::from([0, 1, 2]);
+        let (start, end) = (2, 1);
+        list.drain(start..end);
+    }
+
+    #[test]
+    fn test_cursor_moves_through_the_ghost() {
+        let mut list = KolzoLinkedList::from([1, 2]);
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.current(), Some(&mut 1));
+
+        cursor.move_next();
+        *cursor.current().unwrap() = 20;
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.remove_current(), None);
+
+        // Moving on from the ghost wraps around to the head.
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(list.to_string(), "1 -> 20 -> None");
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), None);
+    }
+
+    #[test]
+    fn test_cursor_inserts_keep_the_tail() {
+        let mut list = KolzoLinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        // On the ghost of an empty list, both inserts start the list.
+        cursor.insert_before(2);
+        cursor.insert_after(0);
+        cursor.insert_before(3);
+        assert_eq!(cursor.current(), None);
+
+        cursor.move_next();
+        cursor.insert_before(-1);
+        cursor.move_next();
+        cursor.insert_before(1);
+        list.assert_invariants();
+        assert_eq!(list.to_string(), "-1 -> 0 -> 1 -> 2 -> 3 -> None");
+        assert_eq!(list.len(), 5);
+
+        // Inserting after the last element moves the tail.
+        let mut cursor = list.cursor_front_mut();
+        for _ in 0..4 {
+            cursor.move_next();
+        }
+        cursor.insert_after(4);
+        list.append(5);
+        assert_eq!(list.to_string(), "-1 -> 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> None");
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_cursor_edits_the_tail_between_list_edits() {
+        let mut list = KolzoLinkedList::new();
+        for round in 0..4 {
+            list.append(round * 10);
+            list.append(round * 10 + 1);
+
+            let mut cursor = list.cursor_front_mut();
+            while cursor.peek_next().is_some() {
+                cursor.move_next();
+            }
+            // Removing the tail parks the cursor on the ghost, where inserting
+            // before it appends.
+            assert_eq!(cursor.remove_current(), Some(round * 10 + 1));
+            assert_eq!(cursor.current(), None);
+            cursor.insert_before(round * 10 + 2);
+            cursor.insert_before(round * 10 + 3);
+
+            list.assert_invariants();
+            assert_eq!(list.pop(), Some(round * 10 + 3));
+            list.assert_invariants();
+        }
+
+        assert_eq!(
+            list.to_string(),
+            "0 -> 2 -> 10 -> 12 -> 20 -> 22 -> 30 -> 32 -> None"
+        );
+    }
+
+    #[test]
+    fn test_cursor_remove_current() {
+        let mut list = KolzoLinkedList::from([1, 2, 3, 4]);
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some(1));
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(3));
+        // Removing the tail parks the cursor on the ghost.
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(5);
+
+        list.assert_invariants();
+        assert_eq!(list.to_string(), "2 -> 5 -> None");
+        list.append(6);
+        assert_eq!(list.len(), 3);
+
+        let mut single = KolzoLinkedList::from([1]);
+        let mut cursor = single.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.remove_current(), None);
+        assert!(single.head.is_none() && single.tail.is_none());
+    }
+
+    /// Removes every value less than the one after it and puts the negation of each
+    /// even value that stays right after it, in one pass of a cursor.
+    fn scan_and_splice(list: &mut KolzoLinkedList<i32>) {
+        let mut cursor = list.cursor_front_mut();
+        while let Some(&mut value) = cursor.current() {
+            if cursor.peek_next().is_some_and(|next| value < *next) {
+                cursor.remove_current();
+                continue;
+            }
+
+            if value % 2 == 0 {
+                cursor.insert_after(-value);
+                cursor.move_next();
+            }
+            cursor.move_next();
+        }
+    }
+
+    #[test]
+    fn test_cursor_scan_and_splice() {
+        let mut rng = StdRng::seed_from_u64(298);
+        for _ in 0..200 {
+            let values: Vec<i32> = (0..rng.gen_range(0..30))
+                .map(|_| rng.gen_range(1..10))
+                .collect();
+            let mut list = KolzoLinkedList::from(&values[..]);
+            scan_and_splice(&mut list);
+
+            let mut expected = Vec::new();
+            for (index, &value) in values.iter().enumerate() {
+                if values.get(index + 1).is_some_and(|&next| value < next) {
+                    continue;
+                }
+                expected.push(value);
+                if value % 2 == 0 {
+                    expected.push(-value);
+                }
+            }
+
+            assert!(list.iter().eq(expected.iter()));
+            assert_eq!(list.len(), expected.len());
+            list.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        assert!(KolzoLinkedList::<i32>::new().is_sorted());
+        assert!(KolzoLinkedList::from([4]).is_sorted());
+        assert!(KolzoLinkedList::from([1, 1, 2, 3, 3]).is_sorted());
+        assert!(!KolzoLinkedList::from([1, 2, 3, 0]).is_sorted());
+        assert!(!KolzoLinkedList::from([2, 1]).is_sorted());
+
+        // Like `Iterator::is_sorted`, an incomparable pair is not sorted.
+        assert!(!KolzoLinkedList::from([1.0, f64::NAN, 2.0]).is_sorted());
+
+        let strictly = |a: &i32, b: &i32| a < b;
+        assert!(KolzoLinkedList::from([1, 2, 3]).is_sorted_by(strictly));
+        assert!(!KolzoLinkedList::from([1, 2, 2]).is_sorted_by(strictly));
+        assert!(KolzoLinkedList::<i32>::new().is_sorted_by(|_, _| false));
+        assert!(KolzoLinkedList::from([7]).is_sorted_by(|_, _| false));
+    }
+
+    #[test]
+    fn test_sort_small_lists() {
+        let cases: [&[i32]; 6] = [
+            &[],
+            &[7],
+            &[1, 2, 3, 4, 5],
+            &[5, 4, 3, 2, 1],
+            &[2, 1, 2, 1, 2, 1, 0],
+            &[3, 3, 3],
+        ];
+        for values in cases {
+            let mut list = KolzoLinkedList::from(values);
+            list.sort();
+            assert!(list.is_sorted());
+
+            let mut expected = values.to_vec();
+            expected.sort();
+            assert!(list.iter().eq(expected.iter()));
+            assert_eq!(list.len(), values.len());
+            list.assert_invariants();
+
+            list.append(100);
+            assert_eq!(list.last(), Some(&100));
+        }
+    }
+
+    #[test]
+    fn test_sort_matches_vec_sort() {
+        let mut rng = StdRng::seed_from_u64(276);
+        let values: Vec<i32> = (0..10_000).map(|_| rng.gen_range(-500..500)).collect();
+
+        let mut list = KolzoLinkedList::from(&values[..]);
+        list.sort();
+        assert!(list.is_sorted());
+
+        let mut expected = values;
+        expected.sort();
+        assert!(list.iter().eq(expected.iter()));
+        assert_eq!(list.len(), 10_000);
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_sort_by_descending() {
+        let mut list = KolzoLinkedList::from([4, 1, 3, 1, 5]);
+        list.sort_by(|a, b| b.cmp(a));
+        assert!(list.is_sorted_by(|a, b| a >= b));
+        assert_eq!(list.to_string(), "5 -> 4 -> 3 -> 1 -> 1 -> None");
+        assert_eq!(list.last(), Some(&1));
+    }
+
+    #[test]
+    fn test_sort_by_key_on_a_field() {
+        struct Person {
+            name: &'static str,
+            age: u32,
+        }
+
+        let mut people: KolzoLinkedList<Person> = [("Ana", 41), ("Bo", 7), ("Cy", 23)]
+            .into_iter()
+            .map(|(name, age)| Person { name, age })
+            .collect();
+        people.sort_by_key(|person| person.age);
+
+        let names: Vec<_> = people.iter().map(|person| person.name).collect();
+        assert_eq!(names, vec!["Bo", "Cy", "Ana"]);
+    }
+
+    #[test]
+    fn test_sort_is_stable() {
+        let mut rng = StdRng::seed_from_u64(277);
+        // Few distinct keys, so every key repeats many times.
+        let pairs: Vec<(u8, usize)> = (0..1_000).map(|id| (rng.gen_range(0..10), id)).collect();
+
+        let mut by_key = KolzoLinkedList::from(&pairs[..]);
+        by_key.sort_by_key(|&(key, _)| key);
+        let mut by_compare = KolzoLinkedList::from(&pairs[..]);
+        by_compare.sort_by(|a, b| a.0.cmp(&b.0));
+        assert!(by_key.is_sorted_by(|a, b| a.0 <= b.0));
+
+        // `Vec::sort_by_key` is stable, so equal keys must stay in insertion order.
+        let mut expected = pairs;
+        expected.sort_by_key(|&(key, _)| key);
+        assert!(by_key.iter().eq(expected.iter()));
+        assert!(by_compare.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn test_sort_without_clone() {
+        #[derive(PartialEq, Eq, PartialOrd, Ord)]
+        struct NoClone(u8);
+
+        let mut list: KolzoLinkedList<NoClone> = [2, 0, 1].into_iter().map(NoClone).collect();
+        list.sort();
+        assert!(list.is_sorted());
+        assert!(list.iter().map(|value| value.0).eq(0..3));
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list = KolzoLinkedList::new();
+        list.insert_sorted(5);
+        assert_eq!(list.to_string(), "5 -> None");
+
+        // Smaller than everything, so it becomes the head.
+        list.insert_sorted(1);
+        list.insert_sorted(9);
+        list.insert_sorted(3);
+        list.insert_sorted(0);
+        assert_eq!(list.to_string(), "0 -> 1 -> 3 -> 5 -> 9 -> None");
+        assert_eq!(list.len(), 5);
+        list.assert_invariants();
+
+        list.append(10);
+        assert_eq!(list.last(), Some(&10));
+    }
+
+    /// Ordered by `key` only, so `id` tells equal values apart.
+    struct Keyed {
+        key: u8,
+        id: usize,
+    }
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn test_insert_sorted_keeps_equal_values_in_insertion_order() {
+        let mut list = KolzoLinkedList::new();
+        for (id, key) in [2, 1, 2, 0, 1, 2, 0].into_iter().enumerate() {
+            list.insert_sorted(Keyed { key, id });
+        }
+
+        let order: Vec<_> = list.iter().map(|value| (value.key, value.id)).collect();
+        assert_eq!(
+            order,
+            vec![(0, 3), (0, 6), (1, 1), (1, 4), (2, 0), (2, 2), (2, 5)]
+        );
+    }
+
+    #[test]
+    fn test_insert_sorted_stays_sorted() {
+        let mut rng = StdRng::seed_from_u64(292);
+        let mut list = KolzoLinkedList::new();
+        let mut model = Vec::new();
+
+        for _ in 0..1_000 {
+            let value = rng.gen_range(-50..50);
+            list.insert_sorted(value);
+            model.push(value);
+
+            assert!(list.is_sorted());
+            assert_eq!(list.len(), model.len());
+        }
+
+        model.sort();
+        assert!(list.iter().eq(model.iter()));
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_merge_sorted_with_empty_lists() {
+        let mut list = KolzoLinkedList::new();
+        list.merge_sorted(KolzoLinkedList::from([1, 2]));
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+
+        list.merge_sorted(KolzoLinkedList::new());
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+        assert_eq!(list.len(), 2);
+
+        list.append(3);
+        assert_eq!(list.last(), Some(&3));
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        empty.merge_sorted(KolzoLinkedList::new());
+        assert!(empty.head.is_none() && empty.tail.is_none());
+    }
+
+    #[test]
+    fn test_merge_sorted_moves_the_tail() {
+        // The last node comes from `other`.
+        let mut list = KolzoLinkedList::from([1, 5]);
+        list.merge_sorted(KolzoLinkedList::from([2, 3, 4, 6, 7, 8, 9]));
+        list.append(10);
+        assert_eq!(list, (1..=10).collect::<Vec<_>>());
+        list.assert_invariants();
+
+        // The last node stays in this list.
+        let mut list = KolzoLinkedList::from([2, 9]);
+        list.merge_sorted(KolzoLinkedList::from([1]));
+        list.append(10);
+        assert_eq!(list.to_string(), "1 -> 2 -> 9 -> 10 -> None");
+    }
+
+    #[test]
+    fn test_merge_sorted_keeps_the_tail_of_unsorted_lists() {
+        // The old tails are 1 and 3, but the merge ends on the 1 after the 5.
+        let mut list = KolzoLinkedList::from([5, 1]);
+        list.merge_sorted(KolzoLinkedList::from([3]));
+        list.assert_invariants();
+        list.append(7);
+        assert_eq!(list.to_string(), "3 -> 5 -> 1 -> 7 -> None");
+
+        let mut list = KolzoLinkedList::from([2, 8]);
+        list.merge_sorted(KolzoLinkedList::from([9, 4, 1]));
+        list.assert_invariants();
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn test_merge_sorted_is_stable() {
+        let mut list: KolzoLinkedList<Keyed> = [(0, 0), (1, 1), (1, 2), (2, 3)]
+            .into_iter()
+            .map(|(key, id)| Keyed { key, id })
+            .collect();
+        let other: KolzoLinkedList<Keyed> = [(1, 4), (2, 5), (2, 6)]
+            .into_iter()
+            .map(|(key, id)| Keyed { key, id })
+            .collect();
+        list.merge_sorted(other);
+
+        let ids: Vec<_> = list.iter().map(|value| value.id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 4, 3, 5, 6]);
+        assert_eq!(list.last().map(|value| value.id), Some(6));
+    }
+
+    #[test]
+    fn test_merge_sorted_matches_vec_sort() {
+        let mut rng = StdRng::seed_from_u64(293);
+        for _ in 0..200 {
+            // Lengths differ wildly so that one side often runs out early.
+            let mut left: Vec<i32> = (0..rng.gen_range(0..100))
+                .map(|_| rng.gen_range(0..50))
+                .collect();
+            let mut right: Vec<i32> = (0..rng.gen_range(0..3))
+                .map(|_| rng.gen_range(0..50))
+                .collect();
+            if rng.gen_bool(0.5) {
+                std::mem::swap(&mut left, &mut right);
+            }
+            left.sort();
+            right.sort();
+
+            let mut list = KolzoLinkedList::from(&left[..]);
+            list.merge_sorted(KolzoLinkedList::from(&right[..]));
+            assert!(list.is_sorted());
+
+            let mut expected = [left, right].concat();
+            expected.sort();
+            assert!(list.iter().eq(expected.iter()));
+            assert_eq!(list.len(), expected.len());
+            list.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_reverse_empty_list() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        list.reverse();
+        assert_eq!(list.get(0), None);
+    }
+
+    #[test]
+    fn test_reverse_single_element_list() {
+        let mut list = KolzoLinkedList::from([1]);
+        list.reverse();
+        assert_eq!(list.get(0), Some(&1));
+    }
+
+    #[test]
+    fn test_reverse_multiple_elements_list() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+
+        list.reverse();
+
+        assert_eq!(list, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_eq_against_vecs_slices_and_arrays() {
+        let list = KolzoLinkedList::from([1, 2, 3]);
+
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], list);
+        assert_eq!(&[1, 2, 3][..], list);
+        assert_eq!([1, 2, 3], list);
+
+        // Different lengths, in both directions.
+        assert_ne!(list, vec![1, 2]);
+        assert_ne!(list, &[1, 2, 3, 4][..]);
+        assert_ne!([1, 2, 3, 3], list);
+
+        // Same length, different contents.
+        assert_ne!(list, vec![1, 2, 4]);
+        assert_ne!(&[0, 2, 3][..], list);
+        assert_ne!(list, [3, 2, 1]);
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty, Vec::<i32>::new());
+        assert_eq!(empty, []);
+        assert_ne!(empty, [0]);
+
+        // The element types only need to compare with each other.
+        let names = KolzoLinkedList::from([String::from("a"), String::from("b")]);
+        assert_eq!(names, ["a", "b"]);
+        assert_eq!(names, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_reverse_twice() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+
+        list.reverse();
+        list.reverse();
+
+        assert_eq!(list, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_reverse_moves_the_tail() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+        list.reverse();
+        list.append(4);
+        assert_eq!(list.to_string(), "3 -> 2 -> 1 -> 4 -> None");
+        assert_eq!(list.len(), 4);
+        assert_eq!(reachable_nodes(&list), 4);
+
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(1));
+        list.append(5);
+        assert_eq!(list.to_string(), "3 -> 2 -> 5 -> None");
+
+        let mut single = KolzoLinkedList::from([1]);
+        single.reverse();
+        single.append(2);
+        assert_eq!(single.to_string(), "1 -> 2 -> None");
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        empty.reverse();
+        assert!(empty.tail.is_none());
+        empty.append(1);
+        assert_eq!(empty.to_string(), "1 -> None");
+    }
+
+    #[test]
+    fn test_check_invariants_reports_corruption() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+        assert_eq!(list.check_invariants(), Ok(()));
+        assert_eq!(KolzoLinkedList::<i32>::new().check_invariants(), Ok(()));
+
+        list.length = 4;
+        let message = list.check_invariants().unwrap_err();
+        assert!(message.contains("recorded length is 4"), "{}", message);
+
+        // A length that is too small stops the walk early, as a cycle would.
+        list.length = 2;
+        let message = list.check_invariants().unwrap_err();
+        assert!(message.contains("more than 2 nodes"), "{}", message);
+
+        list.length = 3;
+        let stale_tail = list.tail;
+        list.tail = list.head;
+        assert!(list.check_invariants().unwrap_err().contains("tail"));
+
+        list.tail = None;
+        assert!(list.check_invariants().is_err());
+        list.tail = stale_tail;
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_invariants_hold_through_previously_buggy_sequences() {
+        // A stale tail after `reverse` made the next `append` write into a node
+        // that was no longer last.
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+        list.reverse();
+        assert_eq!(list.check_invariants(), Ok(()));
+        list.append(4);
+        assert_eq!(list.check_invariants(), Ok(()));
+        list.reverse();
+        list.push_front(5);
+        assert_eq!(list.check_invariants(), Ok(()));
+
+        // Removing the last element, at either end, must move the tail and count
+        // down the length exactly once.
+        let mut rng = StdRng::seed_from_u64(315);
+        let mut list: KolzoLinkedList<i32> = (0..50).collect();
+        while !list.is_empty() {
+            let index = rng.gen_range(0..list.len());
+            list.remove(index);
+            assert_eq!(list.check_invariants(), Ok(()));
+            list.append(-1);
+            assert_eq!(list.check_invariants(), Ok(()));
+            list.pop();
+        }
+        assert_eq!(list.check_invariants(), Ok(()));
+
+        let mut list: KolzoLinkedList<i32> = (0..10).collect();
+        list.rotate_left(3);
+        list.sort();
+        list.truncate(5);
+        list.dedup();
+        list.remove_all(&2);
+        list.split_off(2);
+        assert_eq!(list.check_invariants(), Ok(()));
+        list.append(9);
+        assert_eq!(list.to_string(), "0 -> 1 -> 9 -> None");
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(list.iter().next(), None);
+
+        list.append(1);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1]);
+
+        list.append(2);
+        list.append(3);
+        list.prepend(0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+
+        list.pop();
+        list.pop_first();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+
+        let mut sum = 0;
+        for value in list.iter() {
+            sum += value;
+        }
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn test_iter_without_clone() {
+        // Compiles only because `iter` places no bounds on `T`.
+        fn collect_all<T>(list: &KolzoLinkedList<T>) -> Vec<&T> {
+            list.iter().collect()
+        }
+
+        let mut list: KolzoLinkedList<String> = KolzoLinkedList::new();
+        list.append("a".to_string());
+        list.append("b".to_string());
+        assert_eq!(collect_all(&list), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_methods_without_debug_or_clone() {
+        // Neither `Debug` nor `Clone`, so this compiles only because the list
+        // methods place no bounds on `T`.
+        #[derive(PartialEq)]
+        struct NoClone(u8);
+
+        let mut list = KolzoLinkedList::new();
+        list.append(NoClone(1));
+        list.prepend(NoClone(0));
+        assert!(list.get(1) == Some(&NoClone(1)));
+        assert!(list.set(1, NoClone(2)) == Some(NoClone(1)));
+        assert!(list.pop() == Some(NoClone(2)));
+        assert!(list.pop_first() == Some(NoClone(0)));
+
+        list.append(NoClone(1));
+        list.insert(0, NoClone(0));
+        list.reverse();
+        list.remove(1);
+
+        let list: KolzoLinkedList<NoClone> = (0..3).map(NoClone).collect();
+        assert!(list.iter().map(|value| value.0).eq(0..3));
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert!(list.iter_mut().next().is_none());
+
+        for value in 1..=4 {
+            list.append(value);
+        }
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+
+        assert_eq!(list.get(0), Some(&10));
+        assert_eq!(list.get(3), Some(&40));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![10, 20, 30, 40]
+        );
+
+        // The tail is reached through the chain, so appending after mutating the
+        // last element still links behind it.
+        if let Some(last) = list.iter_mut().last() {
+            *last = 45;
+        }
+        list.append(50);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![10, 20, 30, 45, 50]
+        );
+    }
+
+    #[test]
+    fn test_iter_mut_references_are_disjoint() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        list.append(1);
+        list.append(2);
+
+        let mut values = list.iter_mut();
+        let first = values.next().unwrap();
+        let second = values.next().unwrap();
+        std::mem::swap(first, second);
+
+        assert_eq!(list.get(0), Some(&2));
+        assert_eq!(list.get(1), Some(&1));
+    }
+
+    #[test]
+    fn test_vec_round_trip() {
+        let values = vec![5, 3, 8, 1];
+        let mut list = KolzoLinkedList::from(values.clone());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.to_vec(), values);
+        assert_eq!(list.len(), 4);
+
+        list.append(9);
+        list.assert_invariants();
+        assert_eq!(list.into_vec(), vec![5, 3, 8, 1, 9]);
+
+        let empty = KolzoLinkedList::from(Vec::<i32>::new());
+        assert!(empty.is_empty() && empty.tail.is_none());
+        assert!(empty.to_vec().is_empty());
+        assert!(empty.into_vec().is_empty());
+    }
+
+    #[test]
+    fn test_into_vec_without_clone() {
+        struct NoClone(u8);
+
+        let list = KolzoLinkedList::from((0..4).map(NoClone).collect::<Vec<_>>());
+        let values = list.into_vec();
+        assert_eq!(values.len(), 4);
+        assert!(values.iter().map(|value| value.0).eq(0..4));
+    }
+
+    #[test]
+    fn test_from_array_and_slice() {
+        let mut list = KolzoLinkedList::from([1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        list.append(4);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+        list.assert_invariants();
+
+        let values = vec!["a".to_string(), "b".to_string()];
+        let mut copy = KolzoLinkedList::from(values.as_slice());
+        assert_eq!(copy.len(), 2);
+        copy.append("c".to_string());
+        assert_eq!(copy.to_string(), "a -> b -> c -> None");
+        assert_eq!(values.len(), 2);
+
+        let empty = KolzoLinkedList::<i32>::from([]);
+        assert!(empty.is_empty() && empty.tail.is_none());
+        let empty = KolzoLinkedList::<i32>::from(&[][..]);
+        assert!(empty.is_empty() && empty.tail.is_none());
+    }
+
+    #[test]
+    fn test_fold() {
+        let list: KolzoLinkedList<i32> = (1..=100).collect();
+        assert_eq!(list.fold(0, |sum, value| sum + value), 5050);
+
+        let words = KolzoLinkedList::from(["a", "b", "c"]);
+        assert_eq!(words.fold(String::new(), |text, word| text + word), "abc");
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.fold(7, |sum, value| sum + value), 7);
+    }
+
+    #[test]
+    fn test_for_each() {
+        let list: KolzoLinkedList<i32> = (0..10).collect();
+        let mut count = 0;
+        let mut even = 0;
+        list.for_each(|value| {
+            count += 1;
+            if value % 2 == 0 {
+                even += 1;
+            }
+        });
+        assert_eq!((count, even), (10, 5));
+    }
+
+    #[test]
+    fn test_try_for_each_stops_early() {
+        let list: KolzoLinkedList<i32> = (1..=10).collect();
+        let mut visited = Vec::new();
+        let result = list.try_for_each(|value| {
+            visited.push(*value);
+            if visited.len() == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(result, ControlFlow::Break(()));
+        assert_eq!(visited, vec![1, 2, 3]);
+
+        let result: ControlFlow<()> = list.try_for_each(|_| ControlFlow::Continue(()));
+        assert_eq!(result, ControlFlow::Continue(()));
+    }
+
+    #[test]
+    fn test_map_to_another_type() {
+        let numbers = KolzoLinkedList::from([1, 2, 3]);
+        let mut labels = numbers.map(|number| number.to_string());
+        assert_eq!(labels.to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(labels.len(), 3);
+
+        // The original is untouched and the new tail accepts appends.
+        assert_eq!(numbers.to_string(), "1 -> 2 -> 3 -> None");
+        labels.append("4".to_string());
+        assert_eq!(labels.last().map(String::as_str), Some("4"));
+        labels.assert_invariants();
+    }
+
+    #[test]
+    fn test_into_map_moves_the_elements() {
+        let words: KolzoLinkedList<String> =
+            ["x", "yy", "zzz"].into_iter().map(String::from).collect();
+        let mut pairs = words.into_map(|word| (word.len(), word));
+        pairs.append((0, String::new()));
+        assert_eq!(pairs.len(), 4);
+        assert_eq!(pairs.get(2), Some(&(3, "zzz".to_string())));
+        pairs.assert_invariants();
+    }
+
+    #[test]
+    fn test_map_empty_and_chained() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        let mapped = empty.map(|number| number * 2);
+        assert!(mapped.is_empty() && mapped.tail.is_none());
+        assert!(empty.into_map(|number| number * 2).is_empty());
+
+        let list = KolzoLinkedList::from([1, 2, 3, 4]);
+        let chained = list
+            .map(|number| number * 10)
+            .map(|number| number + 1)
+            .into_map(|number| number.to_string());
+        assert_eq!(chained.to_string(), "11 -> 21 -> 31 -> 41 -> None");
+    }
+
+    #[test]
+    fn test_filter_leaves_the_original_unchanged() {
+        let numbers: KolzoLinkedList<i32> = (1..=10).collect();
+        let mut multiples = numbers.filter(|number| number % 3 == 0);
+
+        assert_eq!(multiples.to_string(), "3 -> 6 -> 9 -> None");
+        assert_eq!(multiples.len(), 3);
+        assert_eq!(numbers.len(), 10);
+        assert!(numbers.iter().copied().eq(1..=10));
+
+        multiples.append(12);
+        assert_eq!(multiples.last(), Some(&12));
+        multiples.assert_invariants();
+
+        assert!(numbers.filter(|_| false).is_empty());
+    }
+
+    #[test]
+    fn test_into_filter_relinks_the_kept_nodes() {
+        #[derive(PartialEq)]
+        struct NoClone(u8);
+
+        let list: KolzoLinkedList<NoClone> = (0..10).map(NoClone).collect();
+        let mut kept = list.into_filter(|value| value.0 % 4 != 3);
+        assert!(kept
+            .iter()
+            .map(|value| value.0)
+            .eq([0, 1, 2, 4, 5, 6, 8, 9]));
+        assert_eq!(kept.len(), 8);
+
+        kept.append(NoClone(10));
+        assert!(kept.last() == Some(&NoClone(10)));
+        kept.assert_invariants();
+
+        // Dropping the last elements moves the tail back.
+        let list = KolzoLinkedList::from([0, 1, 2, 3, 4]);
+        let mut front = list.into_filter(|number| *number < 2);
+        assert_eq!(front.last(), Some(&1));
+        front.append(2);
+        assert_eq!(front.to_string(), "0 -> 1 -> 2 -> None");
+
+        let none = front.into_filter(|_| false);
+        assert!(none.is_empty() && none.tail.is_none());
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let words = KolzoLinkedList::from(["4", "x", "", "15", "-2"]);
+        let mut numbers = words.filter_map(|word| word.parse::<i32>().ok());
+        assert_eq!(numbers.to_string(), "4 -> 15 -> -2 -> None");
+        assert_eq!(numbers.len(), 3);
+        numbers.append(0);
+        numbers.assert_invariants();
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let list: KolzoLinkedList<i32> = (0..10).collect();
+        assert_eq!(list.len(), 10);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+
+        let empty: KolzoLinkedList<i32> = std::iter::empty().collect();
+        assert_eq!(empty.len(), 0);
+        assert!(empty.head.is_none());
+        assert!(empty.tail.is_none());
+    }
+
+    #[test]
+    fn test_from_iterator_keeps_tail() {
+        let mut list: KolzoLinkedList<i32> = (1..=3).collect();
+        list.append(4);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list, [1, 2, 3, 4]);
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(3));
+    }
+
+    #[test]
+    fn test_from_iterator_round_trip() {
+        let original: KolzoLinkedList<String> = ["a", "b", "c"]
+            .iter()
+            .map(|value| value.to_string())
+            .collect();
+        let copy: KolzoLinkedList<String> = original.iter().cloned().collect();
+
+        assert_eq!(copy.len(), original.len());
+        assert!(copy.iter().eq(original.iter()));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        list.extend(Vec::<i32>::new());
+        assert_eq!(list.len(), 0);
+        assert!(list.tail.is_none());
+
+        list.extend(vec![1, 2]);
+        assert_eq!(list.len(), 2);
+
+        list.append(3);
+        list.extend(4..=5);
+        assert_eq!(list.len(), 5);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+
+        // The tail still points at the last node.
+        assert_eq!(list.pop(), Some(5));
+        list.append(6);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 6]
+        );
+    }
+
+    #[test]
+    fn test_extend_by_reference() {
+        let source: KolzoLinkedList<i32> = (1..=3).collect();
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+
+        list.extend(source.iter());
+        list.extend(&[4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(list.to_string(), "None");
+        assert_eq!(format!("{:#}", list), "None");
+
+        list.append(1);
+        assert_eq!(list.to_string(), "1 -> None");
+
+        list.append(2);
+        list.append(3);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(format!("{:#}", list), "[0] 1\n[1] 2\n[2] 3\nNone");
+
+        let words: KolzoLinkedList<String> = ["a b", "c"].iter().map(|w| w.to_string()).collect();
+        assert_eq!(format!("{}", words), "a b -> c -> None");
+    }
+
+    #[test]
+    fn test_clone_is_independent() {
+        let mut original: KolzoLinkedList<String> = ["a", "b", "c"]
+            .iter()
+            .map(|value| value.to_string())
+            .collect();
+        let mut copy = original.clone();
+        assert_eq!(copy.len(), 3);
+        assert!(copy.iter().eq(original.iter()));
+
+        copy.append("d".to_string());
+        copy.set(0, "z".to_string());
+        assert_eq!(original.to_string(), "a -> b -> c -> None");
+        assert_eq!(copy.to_string(), "z -> b -> c -> d -> None");
+
+        original.append("e".to_string());
+        original.pop_first();
+        assert_eq!(original.to_string(), "b -> c -> e -> None");
+        assert_eq!(copy.to_string(), "z -> b -> c -> d -> None");
+    }
+
+    #[test]
+    fn test_clone_tail_points_into_the_copy() {
+        // Small enough to run under Miri, which would flag a tail pointer left
+        // pointing into the original list.
+        let mut original = KolzoLinkedList::from([1, 2, 3]);
+        let mut copy = original.clone();
+
+        original.append(4);
+        copy.append(5);
+        drop(original);
+        copy.append(6);
+        assert_eq!(copy.to_string(), "1 -> 2 -> 3 -> 5 -> 6 -> None");
+        assert_eq!(copy.pop(), Some(6));
+        assert_eq!(copy.pop(), Some(5));
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        let mut empty_copy = empty.clone();
+        assert!(empty_copy.tail.is_none());
+        empty_copy.append(1);
+        assert_eq!(empty_copy.to_string(), "1 -> None");
+    }
+
+    #[test]
+    fn test_equality() {
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty, KolzoLinkedList::new());
+
+        let list = KolzoLinkedList::from([1, 2, 3]);
+        assert_ne!(list, empty);
+        assert_eq!(list, list.clone());
+
+        // A common prefix is not enough.
+        let prefix = KolzoLinkedList::from([1, 2]);
+        assert_ne!(list, prefix);
+        assert_ne!(prefix, list);
+
+        let mut different = list.clone();
+        different.set(2, 4);
+        assert_ne!(list, different);
+    }
+
+    #[test]
+    fn test_equality_ignores_how_the_list_was_built() {
+        let appended: KolzoLinkedList<i32> = (1..=3).collect();
+
+        let mut prepended = KolzoLinkedList::new();
+        prepended.prepend(3);
+        prepended.prepend(2);
+        prepended.prepend(1);
+
+        let mut reversed: KolzoLinkedList<i32> = (1..=3).rev().collect();
+        reversed.reverse();
+
+        assert_eq!(appended, prepended);
+        assert_eq!(appended, reversed);
+    }
+
+    #[test]
+    fn test_lexicographic_order() {
+        let list = |values: &[i32]| KolzoLinkedList::from(values);
+
+        assert!(list(&[1, 2]) < list(&[1, 3]));
+        assert!(list(&[2]) > list(&[1, 9, 9]));
+        // A proper prefix orders first.
+        assert!(list(&[1, 2]) < list(&[1, 2, 0]));
+        assert!(list(&[]) < list(&[0]));
+        assert_eq!(list(&[1, 2]).cmp(&list(&[1, 2])), Ordering::Equal);
+        assert_eq!(list(&[]).cmp(&list(&[])), Ordering::Equal);
+
+        let with_nan = KolzoLinkedList::from([1.0, f64::NAN]);
+        assert_eq!(with_nan.partial_cmp(&with_nan), None);
+        let smaller_head = KolzoLinkedList::from([0.0, f64::NAN]);
+        assert!(smaller_head < with_nan);
+    }
+
+    #[test]
+    fn test_sort_vec_of_lists() {
+        let values: [&[i32]; 6] = [&[1, 3], &[], &[1, 2, 0], &[0, 9], &[1, 2], &[1, 3]];
+        let mut lists: Vec<KolzoLinkedList<i32>> = values
+            .iter()
+            .map(|&values| KolzoLinkedList::from(values))
+            .collect();
+        lists.sort();
+
+        // `Vec` compares the same way, so sorting the vectors gives the same order.
+        let mut expected: Vec<Vec<i32>> = values.iter().map(|values| values.to_vec()).collect();
+        expected.sort();
+        let sorted: Vec<Vec<i32>> = lists.iter().map(KolzoLinkedList::to_vec).collect();
+        assert_eq!(sorted, expected);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send::<KolzoLinkedList<String>>();
+        assert_sync::<KolzoLinkedList<String>>();
+        assert_send::<CursorMut<'_, String>>();
+        assert_sync::<CursorMut<'_, String>>();
+        assert_send::<Iter<'_, String>>();
+        assert_send::<IterMut<'_, String>>();
+        assert_send::<Drain<'_, String>>();
+        assert_send::<SharedArena<Node<String>>>();
+        assert_sync::<SharedArena<Node<String>>>();
+    }
+
+    #[test]
+    fn test_move_list_into_a_thread() {
+        let mut list: KolzoLinkedList<String> = (0..100).map(|value| value.to_string()).collect();
+        list = std::thread::spawn(move || {
+            list.append("100".to_string());
+            list.reverse();
+            list
+        })
+        .join()
+        .unwrap();
 
-        while counter < index - 1 {
-            if let Some(ref mut node) = current {
-                current = &mut node.next;
-            } else {
-                return;
-            }
-            counter += 1;
+        assert_eq!(list.len(), 101);
+        assert_eq!(list.first().map(String::as_str), Some("100"));
+        list.append("last".to_string());
+        assert_eq!(list.last().map(String::as_str), Some("last"));
+        list.assert_invariants();
+    }
+
+    #[test]
+    fn test_share_list_behind_a_mutex() {
+        let shared = std::sync::Arc::new(std::sync::Mutex::new(KolzoLinkedList::new()));
+        let workers: Vec<_> = (0..4)
+            .map(|worker| {
+                let shared = std::sync::Arc::clone(&shared);
+                std::thread::spawn(move || {
+                    for value in 0..250 {
+                        shared.lock().unwrap().append(worker * 1_000 + value);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
         }
 
-        if let Some(ref mut node) = current {
-            let mut new_node = Box::new(Node::new(value));
-            new_node.next = node.next.take();
-            node.next = Some(new_node);
+        let list = shared.lock().unwrap();
+        assert_eq!(list.len(), 1_000);
+        list.assert_invariants();
+        // Each worker's values are still in the order it appended them.
+        for worker in 0..4 {
+            let values: Vec<_> = list
+                .iter()
+                .filter(|&&value| value / 1_000 == worker)
+                .collect();
+            assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
         }
+
+        // A shared reference can be read from several threads at once.
+        std::thread::scope(|scope| {
+            for _ in 0..2 {
+                scope.spawn(|| assert_eq!(list.iter().count(), 1_000));
+            }
+        });
     }
 
-    /// Removes the element at the specified index from the linked list.
-    ///
-    /// # Arguments
-    ///
-    /// * `index` - The index of the element to be removed. Must be a non-negative integer and less than the length of the list.
-    ///
-    /// # Behavior
-    ///
-    /// * If the index is out of bounds (negative or greater than or equal to the length of the list), the function returns without making any changes.
-    /// * If the index is `0`, the head element is removed.
-    /// * If the index is the last element, the tail pointer is updated appropriately.
-    /// * For all other indices, the element at the specified index is removed and the list is re-linked.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// let mut list = LinkedList::new();
-    /// list.append(10);
-    /// list.append(20);
-    /// list.append(30);
-    /// list.append(40);
-    ///
-    /// list.remove(2); // Removes the element at index 2 (value 30)
-    ///
-    /// assert_eq!(list.get(0), Some(&10));
-    /// assert_eq!(list.get(1), Some(&20));
-    /// assert_eq!(list.get(2), Some(&40));
-    /// assert_eq!(list.length, 3);
-    /// ```
-    ///
-    /// # Panics
-    ///
-    /// This function does not panic.
-    pub fn remove(&mut self, index: i64) {
-        if index.is_negative() || index as u64 >= self.length {
-            return;
-        }
+    #[test]
+    fn test_arena_slots_are_recycled() {
+        let counted = Rc::new(());
+        let arena = SharedArena::with_chunk_size(256);
+        let mut list = KolzoLinkedList::new_in(&arena);
 
-        if index == 0 {
-            self.pop_first();
-            self.length -= 1;
-            return;
+        for round in 0..size(1_000).max(4) {
+            list.extend((0..200).map(|_| Rc::clone(&counted)));
+            list.pop();
+            list.pop_first();
+            list.remove(100);
+            list.truncate(150);
+            list.drain(10..60);
+            assert_eq!(list.len(), 100);
+            assert_eq!(Rc::strong_count(&counted), 101, "round {round}");
+            list.clear();
         }
+        // Every round reuses the slots freed by the one before, so the arena never
+        // grows past what a single round needs.
+        assert_eq!(arena.chunk_count(), 1);
 
-        let mut current = &mut self.head;
-        let mut counter = 0;
+        list.extend((0..10).map(|_| Rc::clone(&counted)));
+        list.assert_invariants();
+        drop(list);
+        assert_eq!(Rc::strong_count(&counted), 1);
+    }
 
-        while counter < index - 1 {
-            if let Some(ref mut node) = current {
-                current = &mut node.next;
-            } else {
-                return;
-            }
-            counter += 1;
-        }
+    #[test]
+    fn test_arena_list_matches_a_heap_list() {
+        let arena = SharedArena::with_chunk_size(16);
+        let mut arena_list = KolzoLinkedList::new_in(&arena);
+        let mut heap_list = KolzoLinkedList::new();
+        let mut rng = StdRng::seed_from_u64(207);
 
-        if let Some(ref mut node) = current {
-            if index as u64 == self.length - 1 {
-                if let Some(ref mut last_node) = node.next {
-                    Some(last_node).take();
-                    self.tail = Some(&mut **node);
-                    self.length -= 1;
-                    return;
+        for _ in 0..size(5_000).max(200) {
+            let value = rng.gen_range(0..100);
+            match rng.gen_range(0..5) {
+                0 | 1 => {
+                    arena_list.append(value);
+                    heap_list.append(value);
+                }
+                2 => assert_eq!(arena_list.pop_first(), heap_list.pop_first()),
+                3 => {
+                    let index = rng.gen_range(0..=heap_list.len());
+                    assert_eq!(arena_list.remove(index), heap_list.remove(index));
+                }
+                _ => {
+                    let index = rng.gen_range(0..=heap_list.len());
+                    assert_eq!(
+                        arena_list.insert(index, value),
+                        heap_list.insert(index, value)
+                    );
                 }
-            } else if let Some(ref mut mid_node) = node.next.take() {
-                node.next = mid_node.next.take();
             }
-            self.length -= 1;
         }
+
+        assert_eq!(arena_list, heap_list);
+        arena_list.assert_invariants();
     }
 
-    /// Reverses the linked list in place.
-    ///
-    /// # Description
-    /// This method reverses the order of the nodes in the linked list. After the
-    /// operation, the head of the list will be the original tail, and the tail
-    /// will be the original head.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// let mut list = LinkedList::new();
-    /// list.append(1);
-    /// list.append(2);
-    /// list.append(3);
-    ///
-    /// list.reverse();
-    ///
-    /// assert_eq!(list.get(0), Some(&3));
-    /// assert_eq!(list.get(1), Some(&2));
-    /// assert_eq!(list.get(2), Some(&1));
-    /// ```
-    ///
-    /// # Panics
-    /// This function does not panic.
-    pub fn reverse(&mut self) {
-        let mut previous_node = None;
-        let mut current_node = self.head.take();
+    #[test]
+    fn test_lists_split_from_an_arena_list_share_the_arena() {
+        let arena = SharedArena::with_chunk_size(256);
+        let mut list = KolzoLinkedList::new_in(&arena);
+        list.extend(0..40);
 
-        while let Some(mut node_that_is_iterated) = current_node {
-            let next_node = node_that_is_iterated.next.take();
-            node_that_is_iterated.next = previous_node;
-            previous_node = Some(node_that_is_iterated);
-            current_node = next_node;
-        }
+        let mut back = list.split_off(20);
+        let mut copy = list.clone();
+        back.append(40);
+        copy.append(20);
+        assert!(back.iter().copied().eq(20..=40));
+        assert!(copy.iter().copied().eq(0..=20));
 
-        self.head = previous_node;
+        // Nodes move between lists of the same arena without being copied.
+        list.append_list(back);
+        assert!(list.iter().copied().eq(0..=40));
+        assert_eq!(arena.chunk_count(), 1);
+        list.assert_invariants();
+
+        drop(list);
+        drop(copy);
+        // The freed slots serve a new list before the arena reserves another chunk.
+        let mut reused = KolzoLinkedList::new_in(&arena);
+        reused.extend(0..256);
+        assert_eq!(arena.chunk_count(), 1);
     }
 
-    pub fn playground(&self) {
-        let mut new_ll: KolzoLinkedList<i32> = KolzoLinkedList::new();
+    #[test]
+    fn test_mixing_arena_and_heap_lists() {
+        let arena = SharedArena::with_chunk_size(8);
+        let other_arena = SharedArena::with_chunk_size(8);
+        let in_arena = |arena: &SharedArena<Node<i32>>, values: &[i32]| {
+            let mut list = KolzoLinkedList::new_in(arena);
+            list.extend(values.iter().copied());
+            list
+        };
 
-        new_ll.append(2);
-        new_ll.append(3);
-        new_ll.append(4);
+        let mut list = in_arena(&arena, &[1, 3]);
+        list.append_list(KolzoLinkedList::from([5, 7]));
+        list.append_list(in_arena(&other_arena, &[9]));
+        let mut heap_list = KolzoLinkedList::from([0]);
+        heap_list.append_list(in_arena(&arena, &[2]));
+        assert!(list.iter().copied().eq([1, 3, 5, 7, 9]));
+        assert!(heap_list.iter().copied().eq([0, 2]));
 
-        let test_head = new_ll.head;
-        let test_tail = new_ll.tail;
-        let test_length = new_ll.length;
+        list.merge_sorted(heap_list);
+        list.append(10);
+        assert!(list.iter().copied().eq([0, 1, 2, 3, 5, 7, 9, 10]));
+        list.assert_invariants();
 
-        match test_head {
-            Some(head) => {
-                println!("HEAD DATA {:?}", head.data);
-                println!("HEAD NEXT {:?}", head.next);
-            }
-            None => (),
-        }
+        let mut interleaved =
+            in_arena(&other_arena, &[1, 3]).interleave(KolzoLinkedList::from([2, 4]));
+        interleaved.append(5);
+        assert!(interleaved.iter().copied().eq(1..=5));
+        interleaved.assert_invariants();
+    }
 
-        match test_tail {
-            Some(tail) => unsafe {
-                println!("TAIL DATA {:?}", (*tail).data);
-                println!("TAIL NEXT {:?}", (*tail).next);
-            },
-            None => (),
-        }
+    #[test]
+    fn test_arena_lists_on_several_threads() {
+        let arena = SharedArena::with_chunk_size(128);
+        let workers: Vec<_> = (0..4)
+            .map(|worker| {
+                let arena = arena.clone();
+                std::thread::spawn(move || {
+                    let mut list = KolzoLinkedList::new_in(&arena);
+                    for value in 0..size(1_000).max(10) {
+                        list.append(worker * 10_000 + value);
+                        if value % 3 == 0 {
+                            list.pop_first();
+                        }
+                    }
+                    list
+                })
+            })
+            .collect();
 
-        println!("LENGHT {}", test_length);
+        for (worker, handle) in workers.into_iter().enumerate() {
+            let list = handle.join().unwrap();
+            assert!(list.iter().all(|&value| value / 10_000 == worker));
+            list.assert_invariants();
+        }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
-    fn test_append_and_pop() {
-        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+    fn test_default() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::default();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list, KolzoLinkedList::new());
 
         list.append(1);
-        list.append(2);
-        list.append(3);
-
-        assert_eq!(list.length, 3);
-
-        assert_eq!(list.pop(), Some(3));
-        assert_eq!(list.length, 2);
+        assert_eq!(list.to_string(), "1 -> None");
+    }
 
-        assert_eq!(list.pop(), Some(2));
-        assert_eq!(list.length, 1);
+    #[test]
+    fn test_from_elem() {
+        let mut list = KolzoLinkedList::from_elem(7, 3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.to_string(), "7 -> 7 -> 7 -> None");
 
-        assert_eq!(list.pop(), Some(1));
-        assert_eq!(list.length, 0);
+        list.append(8);
+        assert_eq!(list.to_string(), "7 -> 7 -> 7 -> 8 -> None");
 
-        assert_eq!(list.pop(), None);
+        let empty = KolzoLinkedList::from_elem(7, 0);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.head.is_none() && empty.tail.is_none());
     }
 
     #[test]
-    fn test_prepend() {
-        let mut list = KolzoLinkedList::new();
+    fn test_from_fn() {
+        let mut list = KolzoLinkedList::from_fn(10_000, |index| index as i64 * 2);
+        assert_eq!(list.len(), 10_000);
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(9999), Some(&19_998));
+        assert_eq!(list.get(10_000), None);
 
-        list.prepend(1);
-        list.prepend(2);
-        list.prepend(3);
+        // The tail is the real last node, so appending links after it.
+        list.append(-1);
+        assert_eq!(list.get(10_000), Some(&-1));
+        assert_eq!(list.pop(), Some(-1));
+        assert_eq!(list.pop(), Some(19_998));
 
-        assert_eq!(list.length, 3);
+        let empty = KolzoLinkedList::from_fn(0, |index| index);
+        assert!(empty.tail.is_none());
+    }
 
-        let mut current = list.head.as_ref();
-        assert_eq!(current.map(|node| &node.data), Some(&3));
-        current = current.unwrap().next.as_ref();
-        assert_eq!(current.map(|node| &node.data), Some(&2));
-        current = current.unwrap().next.as_ref();
-        assert_eq!(current.map(|node| &node.data), Some(&1));
-        current = current.unwrap().next.as_ref();
-        assert_eq!(current, None);
+    #[test]
+    fn test_drop_long_list() {
+        let list: KolzoLinkedList<usize> = (0..size(200_000)).collect();
+        drop(list);
     }
 
     #[test]
-    fn test_pop_first() {
+    fn test_render() {
         let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-
-        assert_eq!(list.pop_first(), None);
+        assert_eq!(render(&list, RenderOptions::default()), "None");
 
         list.append(1);
-        list.append(2);
+        list.append(22);
         list.append(3);
 
-        assert_eq!(list.pop_first(), Some(1));
-        assert_eq!(list.pop_first(), Some(2));
-        assert_eq!(list.pop_first(), Some(3));
+        let expected = [
+            "+---+    +----+    +---+",
+            "| 1 | -> | 22 | -> | 3 | -> None",
+            "+---+    +----+    +---+",
+            "  0        1         2",
+        ]
+        .join("\n");
+        assert_eq!(render(&list, RenderOptions::default()), expected);
+    }
 
-        assert_eq!(list.pop_first(), None);
+    #[test]
+    fn test_render_elides_long_list() {
+        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        for value in 0..1000 {
+            list.append(value);
+        }
+
+        let options = RenderOptions {
+            max_elements: Some(3),
+            show_indices: false,
+        };
+        let expected = [
+            "+---+    +---+         +-----+",
+            "| 0 | -> | 1 | -> … -> | 999 | -> None",
+            "+---+    +---+         +-----+",
+        ]
+        .join("\n");
+        assert_eq!(render(&list, options), expected);
     }
 
+    #[cfg(target_pointer_width = "64")]
     #[test]
-    fn test_get() {
+    fn test_memory_footprint() {
         let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(list.memory_footprint().total_bytes, 0);
 
-        assert_eq!(list.get(0), None);
-        assert_eq!(list.get(1), None);
-        assert_eq!(list.get(-1), None);
+        for value in 0..10 {
+            list.append(value);
+        }
 
-        list.append(10);
-        list.append(20);
-        list.append(30);
+        let report = list.memory_footprint();
+        assert_eq!(report.node_count, 10);
+        assert_eq!(report.bytes_per_node, 16);
+        assert_eq!(report.payload_bytes, 40);
+        assert_eq!(report.total_bytes, 160);
+    }
 
-        assert_eq!(list.get(0), Some(&10));
-        assert_eq!(list.get(1), Some(&20));
-        assert_eq!(list.get(2), Some(&30));
+    #[test]
+    fn test_deep_memory_footprint() {
+        let mut list: KolzoLinkedList<String> = KolzoLinkedList::new();
+        list.append(String::with_capacity(10));
+        list.append(String::with_capacity(20));
 
-        assert_eq!(list.get(3), None);
+        let shallow = list.memory_footprint();
+        let deep = list.deep_memory_footprint();
 
-        assert_eq!(list.get(-1), None);
+        assert_eq!(deep.node_count, 2);
+        assert_eq!(deep.payload_bytes, shallow.payload_bytes + 30);
+        assert_eq!(deep.total_bytes, shallow.total_bytes + 30);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn test_set() {
-        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+    fn test_shuffle_is_deterministic_and_keeps_every_element() {
+        let original: KolzoLinkedList<i32> = (0..100).collect();
 
-        assert_eq!(list.set(0, 10), None);
-        assert_eq!(list.set(1, 20), None);
-        assert_eq!(list.set(-1, 30), None);
+        let mut first = original.clone();
+        first.shuffle(&mut StdRng::seed_from_u64(316));
+        let mut second = original.clone();
+        second.shuffle(&mut StdRng::seed_from_u64(316));
+        assert_eq!(first, second);
+        assert_ne!(first, original);
+        first.assert_invariants();
 
-        list.append(10);
-        list.append(20);
-        list.append(30);
+        // The tail follows the new last node.
+        first.append(100);
+        assert_eq!(first.last(), Some(&100));
+        assert_eq!(first.pop(), Some(100));
 
-        assert_eq!(list.set(0, 15), Some(10));
-        assert_eq!(list.set(1, 25), Some(20));
-        assert_eq!(list.set(2, 35), Some(30));
+        assert_eq!(first.len(), original.len());
+        first.sort();
+        assert_eq!(first, original);
 
-        assert_eq!(list.get(0), Some(&15));
-        assert_eq!(list.get(1), Some(&25));
-        assert_eq!(list.get(2), Some(&35));
+        let mut rng = StdRng::seed_from_u64(316);
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        empty.shuffle(&mut rng);
+        assert!(empty.head.is_none() && empty.tail.is_none());
+        let mut single = KolzoLinkedList::from([1]);
+        single.shuffle(&mut rng);
+        single.append(2);
+        assert_eq!(single.to_string(), "1 -> 2 -> None");
+    }
 
-        assert_eq!(list.set(3, 40), None);
+    #[cfg(feature = "rand")]
+    #[test]
+    #[cfg_attr(miri, ignore = "thousands of trials are too slow under Miri")]
+    fn test_shuffle_reaches_every_order() {
+        let mut rng = StdRng::seed_from_u64(316);
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..6_000 {
+            let mut list = KolzoLinkedList::from([1, 2, 3]);
+            list.shuffle(&mut rng);
+            *counts.entry(list.to_string()).or_insert(0) += 1;
+        }
 
-        assert_eq!(list.set(-1, 50), None);
+        // Each of the 3! orders is expected 1000 times.
+        assert_eq!(counts.len(), 6);
+        assert!(counts.values().all(|&count| (850..1150).contains(&count)));
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn test_insert() {
-        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
+    fn test_choose_and_choose_multiple_are_deterministic_and_in_bounds() {
+        let list: KolzoLinkedList<i32> = (0..50).collect();
 
-        list.insert(0, 10);
-        assert_eq!(list.get(0), Some(&10));
+        let choose = |seed| list.choose(&mut StdRng::seed_from_u64(seed)).copied();
+        assert_eq!(choose(317), choose(317));
+        assert!(choose(317).is_some_and(|value| (0..50).contains(&value)));
 
-        list.append(20);
-        list.append(30);
+        let sample = |seed, k| {
+            let sample = list.choose_multiple(&mut StdRng::seed_from_u64(seed), k);
+            sample.into_iter().copied().collect::<Vec<_>>()
+        };
+        assert_eq!(sample(317, 10), sample(317, 10));
+        let mut values = sample(317, 10);
+        assert_eq!(values.len(), 10);
+        assert!(values.iter().all(|value| (0..50).contains(value)));
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len(), 10);
 
-        list.insert(0, 5);
-        assert_eq!(list.get(0), Some(&5));
-        assert_eq!(list.get(1), Some(&10));
-        assert_eq!(list.get(2), Some(&20));
-        assert_eq!(list.get(3), Some(&30));
+        assert!(sample(317, 0).is_empty());
+        assert!(sample(317, 50).into_iter().eq(0..50));
+        assert!(sample(317, 80).into_iter().eq(0..50));
 
-        list.insert(4, 35);
-        assert_eq!(list.get(4), Some(&35));
+        let mut rng = StdRng::seed_from_u64(317);
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(empty.choose(&mut rng), None);
+        assert!(empty.choose_multiple(&mut rng, 3).is_empty());
+        assert_eq!(KolzoLinkedList::from([9]).choose(&mut rng), Some(&9));
+    }
 
-        list.insert(2, 15);
-        assert_eq!(list.get(0), Some(&5));
-        assert_eq!(list.get(1), Some(&10));
-        assert_eq!(list.get(2), Some(&15));
-        assert_eq!(list.get(3), Some(&20));
-        assert_eq!(list.get(4), Some(&30));
-        assert_eq!(list.get(5), Some(&35));
+    #[cfg(feature = "rand")]
+    #[test]
+    #[cfg_attr(miri, ignore = "thousands of trials are too slow under Miri")]
+    fn test_choose_and_choose_multiple_are_roughly_uniform() {
+        let mut rng = StdRng::seed_from_u64(317);
+        let list: KolzoLinkedList<usize> = (0..5).collect();
+        let trials = 10_000;
 
-        list.insert(10, 40);
-        assert_eq!(list.get(6), None);
+        // Each element is expected to be chosen 2000 times.
+        let mut chosen = [0; 5];
+        for _ in 0..trials {
+            chosen[*list.choose(&mut rng).unwrap()] += 1;
+        }
+        assert!(chosen.iter().all(|&count| (1800..2200).contains(&count)));
 
-        list.insert(-1, 50);
-        assert_eq!(list.get(6), None);
+        // With k = 2, each element is expected in 2/5 of the samples.
+        let mut sampled = [0; 5];
+        for _ in 0..trials {
+            for &value in list.choose_multiple(&mut rng, 2) {
+                sampled[value] += 1;
+            }
+        }
+        assert!(sampled.iter().all(|&count| (3700..4300).contains(&count)));
     }
 
+    #[cfg(feature = "rayon")]
     #[test]
-    fn test_remove() {
-        let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-
-        list.remove(0);
-        assert_eq!(list.length, 0);
+    fn test_par_iter_sum_matches_sequential_sum() {
+        let mut list: KolzoLinkedList<u64> = KolzoLinkedList::new();
+        for value in 0..size(10_000) as u64 {
+            list.append(value);
+        }
 
-        list.append(10);
-        list.append(20);
-        list.append(30);
-        list.append(40);
+        let mut sequential_sum = 0;
+        let mut current = list.head_node();
+        while let Some(node) = current {
+            sequential_sum += node.data * node.data;
+            current = node.next_node();
+        }
 
-        list.remove(0);
-        assert_eq!(list.get(0), Some(&20));
-        assert_eq!(list.length, 3);
+        let parallel_sum: u64 = list.par_iter().map(|value| value * value).sum();
+        assert_eq!(parallel_sum, sequential_sum);
 
-        list.remove(2);
-        assert_eq!(list.get(1), Some(&30));
-        assert_eq!(list.get(2), None);
-        assert_eq!(list.length, 2);
+        let empty: KolzoLinkedList<u64> = KolzoLinkedList::new();
+        assert_eq!(empty.par_iter().count(), 0);
+    }
 
-        list.append(50);
-        list.remove(1);
-        assert_eq!(list.get(0), Some(&20));
-        assert_eq!(list.get(1), Some(&50));
-        assert_eq!(list.length, 2);
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter_mut_updates_every_element_once() {
+        let mut list: KolzoLinkedList<usize> = (0..size(1_000_000)).collect();
 
-        list.remove(10);
-        assert_eq!(list.length, 2);
+        list.par_iter_mut().for_each(|value| *value += 1);
 
-        list.remove(-1);
-        assert_eq!(list.length, 2);
+        assert_eq!(list.par_iter().count(), size(1_000_000));
+        assert!(list
+            .par_iter()
+            .enumerate()
+            .all(|(index, value)| *value == index + 1));
     }
 
+    #[cfg(feature = "stats")]
     #[test]
-    fn test_reverse_empty_list() {
+    fn test_stats_append_is_constant_time() {
         let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-        list.reverse();
-        assert_eq!(list.get(0), None);
+
+        for value in 0..1000 {
+            list.append(value);
+        }
+
+        let stats = list.stats();
+        assert_eq!(stats.allocations, 1000);
+        assert!(stats.node_visits <= 1000);
     }
 
+    #[cfg(feature = "stats")]
     #[test]
-    fn test_reverse_single_element_list() {
+    fn test_stats_pop_walks_the_list() {
         let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-        list.append(1);
-        list.reverse();
-        assert_eq!(list.get(0), Some(&1));
+        for value in 0..1000 {
+            list.append(value);
+        }
+        list.reset_stats();
+
+        assert_eq!(list.pop(), Some(999));
+
+        let stats = list.stats();
+        assert!(stats.node_visits >= 999);
+        assert_eq!(stats.frees, 1);
+        assert_eq!(stats.allocations, 0);
     }
 
+    #[cfg(feature = "stats")]
     #[test]
-    fn test_reverse_multiple_elements_list() {
+    fn test_stats_remove_nth_from_end_walks_once() {
+        let mut list: KolzoLinkedList<i32> = (0..100).collect();
+        list.reset_stats();
+
+        assert_eq!(list.remove_nth_from_end(10), Some(89));
+
+        let stats = list.stats();
+        assert_eq!(stats.node_visits, 100);
+        assert_eq!(stats.frees, 1);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_get_visits_up_to_index() {
         let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
+        for value in 0..100 {
+            list.append(value);
+        }
+        list.reset_stats();
 
-        list.reverse();
+        list.get(0);
+        assert_eq!(list.stats().node_visits, 1);
 
-        assert_eq!(list.get(0), Some(&3));
-        assert_eq!(list.get(1), Some(&2));
-        assert_eq!(list.get(2), Some(&1));
+        list.reset_stats();
+        list.get(99);
+        assert_eq!(list.stats().node_visits, 100);
+
+        list.reset_stats();
+        assert_eq!(list.stats(), ListStats::default());
     }
 
+    #[cfg(feature = "stats")]
     #[test]
-    fn test_reverse_twice() {
+    fn test_stats_iter_visits_each_node_once() {
         let mut list: KolzoLinkedList<i32> = KolzoLinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
+        for value in 0..100 {
+            list.append(value);
+        }
+        list.reset_stats();
 
-        list.reverse();
-        list.reverse();
+        assert_eq!(list.iter().count(), 100);
+        assert_eq!(list.stats().node_visits, 100);
+    }
 
-        assert_eq!(list.get(0), Some(&1));
-        assert_eq!(list.get(1), Some(&2));
-        assert_eq!(list.get(2), Some(&3));
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_contains_stops_at_the_first_match() {
+        let list: KolzoLinkedList<i32> = (0..100).collect();
+        list.reset_stats();
+
+        assert!(list.contains(&9));
+        assert_eq!(list.stats().node_visits, 10);
+
+        list.reset_stats();
+        assert!(!list.contains(&100));
+        assert_eq!(list.stats().node_visits, 100);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_get_mut_visits_up_to_index() {
+        let mut list: KolzoLinkedList<i32> = (0..100).collect();
+        list.reset_stats();
+
+        list.get_mut(9);
+        assert_eq!(list.stats().node_visits, 10);
+
+        list.reset_stats();
+        list.get_mut(100);
+        assert_eq!(list.stats().node_visits, 0);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_stats_cursor_pass_is_linear() {
+        let values: Vec<i32> = (0..1_000).map(|value| (value * 7) % 10).collect();
+        let mut list = KolzoLinkedList::from(&values[..]);
+        list.reset_stats();
+
+        scan_and_splice(&mut list);
+        // Each node that ends up in the list, kept or spliced in, is stepped over
+        // once, and removed nodes are not stepped over at all.
+        assert_eq!(list.stats().node_visits, list.len() as u64);
     }
 }
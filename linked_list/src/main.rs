@@ -1,5 +1,4 @@
-mod algorithm;
-use algorithm::KolzoLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
 
 fn main() {
     let mut ll = KolzoLinkedList::new();
@@ -21,6 +20,4 @@ fn main() {
     ll.print();
     ll.reverse();
     ll.print();
-
-    ll.playground();
 }
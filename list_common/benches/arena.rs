@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use list_common::arena::{NodeArena, NodeIndex};
+
+const NODES: usize = 100_000;
+
+struct BoxedNode {
+    data: u64,
+    next: Option<Box<BoxedNode>>,
+}
+
+struct ArenaNode {
+    data: u64,
+    next: Option<NodeIndex>,
+}
+
+fn bulk_append(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_append");
+
+    group.bench_function("boxed", |b| {
+        b.iter(|| {
+            let mut head: Option<Box<BoxedNode>> = None;
+            for value in 0..NODES as u64 {
+                head = Some(Box::new(BoxedNode {
+                    data: value,
+                    next: head,
+                }));
+            }
+
+            black_box(head.as_ref().map(|node| node.data));
+
+            let mut current = head;
+            while let Some(mut node) = current {
+                current = node.next.take();
+            }
+        })
+    });
+
+    group.bench_function("arena", |b| {
+        b.iter(|| {
+            let mut arena = NodeArena::new();
+            let mut head = None;
+            for value in 0..NODES as u64 {
+                head = Some(arena.alloc(ArenaNode {
+                    data: value,
+                    next: head,
+                }));
+            }
+            black_box(arena.get(head.unwrap()).map(|node| (node.data, node.next)));
+        })
+    });
+
+    group.finish();
+}
+
+fn recycle(c: &mut Criterion) {
+    c.bench_function("arena_recycle", |b| {
+        let mut arena = NodeArena::new();
+        let mut live: Vec<_> = (0..1024u64).map(|value| arena.alloc(value)).collect();
+
+        b.iter(|| {
+            for slot in live.iter_mut() {
+                let value = arena.free(*slot).unwrap();
+                *slot = arena.alloc(black_box(value + 1));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bulk_append, recycle);
+criterion_main!(benches);
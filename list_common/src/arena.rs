@@ -0,0 +1,676 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// The default number of slots reserved by each chunk of a [`NodeArena`].
+pub const DEFAULT_CHUNK_SIZE: usize = 1024;
+
+/// A generational handle to a slot handed out by a [`NodeArena`].
+///
+/// The generation is bumped every time a slot is recycled, so a handle that
+/// outlives the value it was created for can never observe the value that
+/// later reuses the same slot. A slot whose generation reaches `u32::MAX` is
+/// retired rather than wrapped around to a generation an old handle may still
+/// carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeIndex {
+    /// The position of the slot across all chunks of the arena.
+    slot: usize,
+    /// The generation of the slot at the time the handle was created.
+    generation: u32,
+}
+
+/// What a slot currently holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SlotState {
+    /// The slot holds a live value, reached through a [`NodeIndex`].
+    Occupied,
+    /// The slot was lent out by [`NodeArena::lend`], and the borrower owns its value
+    /// storage until the slot is given back.
+    Lent,
+    /// The slot is on the free list.
+    Vacant,
+    /// The slot used up its generations and is never handed out again.
+    Retired,
+}
+
+/// A single slot inside an arena chunk.
+///
+/// The value comes first in a `repr(C)` layout, so a pointer to the slot is also a
+/// pointer to its value. That lets [`NodeArena::lend`] hand out a slot as a pointer
+/// to a `T` and [`NodeArena::give_back`] find the slot again from it.
+#[repr(C)]
+struct Slot<T> {
+    /// The value, initialized while the slot is occupied, and managed by the
+    /// borrower while it is lent.
+    value: MaybeUninit<T>,
+    /// The position of the slot across all chunks of the arena.
+    index: usize,
+    /// Bumped every time the slot is recycled.
+    generation: u32,
+    /// What the slot currently holds.
+    state: SlotState,
+    /// The next slot on the free list, while the slot is vacant.
+    ///
+    /// The free list links slots by pointer rather than by position, so lending and
+    /// giving back slots never has to divide a position by the chunk size.
+    next_free: Option<NonNull<Slot<T>>>,
+}
+
+/// A node pool that hands out slots from chunked allocations and recycles freed slots.
+///
+/// Instead of one heap allocation per node, the arena reserves `chunk_size` slots at
+/// a time. Freed slots are threaded onto a free list and handed out again before a
+/// new chunk is reserved, so a workload that removes as often as it inserts settles
+/// into a fixed number of chunks.
+///
+/// Slots are addressed through [`NodeIndex`] handles rather than raw pointers. Every
+/// lookup checks the handle's generation, which turns a use-after-free into a `None`
+/// instead of undefined behavior. The pointer-based lists borrow slots as raw memory
+/// instead, through a [`SharedArena`]; a chunk is never moved or resized, so those
+/// pointers stay valid for as long as the arena lives.
+pub struct NodeArena<T> {
+    /// The chunks of slots, each a raw allocation of `chunk_size` slots.
+    ///
+    /// No reference to a whole chunk is ever created, only to single slots, so the
+    /// pointers lent out into a chunk are never invalidated by the arena's own
+    /// accesses.
+    chunks: Vec<NonNull<Slot<T>>>,
+    /// The number of slots reserved by each chunk.
+    chunk_size: usize,
+    /// The number of slots, counted from the first, that have been handed out at
+    /// least once and so hold an initialized header.
+    initialized: usize,
+    /// The first slot on the free list.
+    free_head: Option<NonNull<Slot<T>>>,
+    /// The number of live values in the arena.
+    length: usize,
+    /// The arena owns the values in its occupied slots.
+    marker: PhantomData<T>,
+}
+
+// SAFETY: the arena owns its chunks and the values in its occupied slots, like a
+// `Vec<T>`. Lent slots belong to their borrowers, which only move them between
+// threads under the same `Send` bound.
+unsafe impl<T: Send> Send for NodeArena<T> {}
+unsafe impl<T: Sync> Sync for NodeArena<T> {}
+
+impl<T> NodeArena<T> {
+    /// Creates a new empty arena with the default chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use list_common::arena::NodeArena;
+    ///
+    /// let arena: NodeArena<i32> = NodeArena::new();
+    /// assert!(arena.is_empty());
+    /// assert_eq!(arena.chunk_count(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Creates a new empty arena whose chunks reserve `chunk_size` slots each.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - The number of slots reserved per chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be greater than zero");
+
+        NodeArena {
+            chunks: Vec::new(),
+            chunk_size,
+            initialized: 0,
+            free_head: None,
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Stores a value in the arena and returns the handle of its slot.
+    ///
+    /// A recycled slot is reused if one is available; otherwise the value goes into
+    /// the last chunk, and a new chunk is reserved only when that one is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use list_common::arena::NodeArena;
+    ///
+    /// let mut arena = NodeArena::new();
+    /// let index = arena.alloc(5);
+    /// assert_eq!(arena.get(index), Some(&5));
+    /// ```
+    pub fn alloc(&mut self, value: T) -> NodeIndex {
+        let slot = self.claim(SlotState::Occupied);
+        // SAFETY: the slot was just claimed, so nothing else refers to its value.
+        let index = unsafe {
+            (*slot).value.write(value);
+            NodeIndex {
+                slot: (*slot).index,
+                generation: (*slot).generation,
+            }
+        };
+        self.length += 1;
+        index
+    }
+
+    /// Removes the value behind `index` and recycles its slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The handle returned by [`NodeArena::alloc`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the value if the handle is still live.
+    /// * `None` if the slot was already freed, even if it has since been reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use list_common::arena::NodeArena;
+    ///
+    /// let mut arena = NodeArena::new();
+    /// let index = arena.alloc("node");
+    /// assert_eq!(arena.free(index), Some("node"));
+    /// assert_eq!(arena.free(index), None);
+    /// ```
+    pub fn free(&mut self, index: NodeIndex) -> Option<T> {
+        let slot = self.occupied(index)?;
+        // SAFETY: the slot is occupied, so its value is initialized and owned by the
+        // arena. Recycling it below marks the value as moved out.
+        let value = unsafe { (*slot).value.assume_init_read() };
+        self.length -= 1;
+        self.recycle(slot);
+        Some(value)
+    }
+
+    /// Returns a reference to the value behind `index`, or `None` if the handle is stale.
+    pub fn get(&self, index: NodeIndex) -> Option<&T> {
+        let slot = self.occupied(index)?;
+        // SAFETY: the value of an occupied slot is initialized and owned by the
+        // arena, which is borrowed for as long as the reference lives.
+        Some(unsafe { (*slot).value.assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the value behind `index`, or `None` if the handle is stale.
+    pub fn get_mut(&mut self, index: NodeIndex) -> Option<&mut T> {
+        let slot = self.occupied(index)?;
+        // SAFETY: as in `get`, and the arena is borrowed mutably.
+        Some(unsafe { (*slot).value.assume_init_mut() })
+    }
+
+    /// Returns `true` if `index` still refers to a live value.
+    pub fn contains(&self, index: NodeIndex) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Returns the number of live values in the arena.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the arena holds no live values.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of slots reserved across all chunks.
+    pub fn capacity(&self) -> usize {
+        self.chunks.len() * self.chunk_size
+    }
+
+    /// Returns the number of chunk allocations made so far.
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Lends a slot out as raw, uninitialized storage for one `T`.
+    ///
+    /// The slot is neither occupied nor free until it is passed to
+    /// [`NodeArena::give_back`]; in between, the borrower owns the storage and any
+    /// value it writes there. Dropping the arena does not drop the values of lent
+    /// slots, so a borrower must give its slots back, and must keep the arena
+    /// alive, which [`SharedArena`] does, for as long as it holds them.
+    pub(crate) fn lend(&mut self) -> NonNull<T> {
+        // SAFETY: a claimed slot lies inside a live chunk, so it is not null. The
+        // value is the first field of the `repr(C)` slot.
+        unsafe { NonNull::new_unchecked(self.claim(SlotState::Lent)) }.cast()
+    }
+
+    /// Takes back a slot lent out by [`NodeArena::lend`] and recycles it.
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must come from `lend` on this arena and must not have been given
+    /// back since, any value written to it must have been moved out or dropped,
+    /// and the borrower must not use it again.
+    pub(crate) unsafe fn give_back(&mut self, pointer: NonNull<T>) {
+        let slot = pointer.cast::<Slot<T>>().as_ptr();
+        // SAFETY: the caller guarantees that `pointer` is a lent slot of this
+        // arena, and the borrower no longer uses it.
+        debug_assert_eq!(unsafe { (*slot).state }, SlotState::Lent);
+        self.recycle(slot);
+    }
+
+    /// Takes a slot off the free list, or the next slot that was never handed out,
+    /// and marks it with `state`.
+    ///
+    /// A new chunk is reserved only when every slot of the existing ones has been
+    /// handed out. Returns a pointer to the slot.
+    fn claim(&mut self, state: SlotState) -> *mut Slot<T> {
+        if let Some(slot) = self.free_head {
+            let slot = slot.as_ptr();
+            // SAFETY: slots on the free list are initialized and vacant, so only the
+            // arena refers to them.
+            unsafe {
+                debug_assert_eq!((*slot).state, SlotState::Vacant);
+                self.free_head = (*slot).next_free.take();
+                (*slot).state = state;
+            }
+            return slot;
+        }
+
+        if self.initialized == self.capacity() {
+            let chunk = Box::<[Slot<T>]>::new_uninit_slice(self.chunk_size);
+            // SAFETY: a boxed slice is never null. The chunk is freed again in `drop`.
+            self.chunks
+                .push(unsafe { NonNull::new_unchecked(Box::into_raw(chunk).cast()) });
+        }
+
+        let index = self.initialized;
+        self.initialized += 1;
+        // Every chunk but the last is full, so the slot lies in the last chunk.
+        let offset = index - (self.chunks.len() - 1) * self.chunk_size;
+        let chunk = *self.chunks.last().expect("a chunk was just reserved");
+        // SAFETY: `offset` is less than `chunk_size`, so the slot lies inside the
+        // chunk. It was never handed out, so nothing refers to it yet.
+        unsafe {
+            let slot = chunk.as_ptr().add(offset);
+            slot.write(Slot {
+                value: MaybeUninit::uninit(),
+                index,
+                generation: 0,
+                state,
+                next_free: None,
+            });
+            slot
+        }
+    }
+
+    /// Bumps the generation of a slot whose value has been moved out and puts it on
+    /// the free list, or retires it if its generation cannot be bumped any more.
+    fn recycle(&mut self, slot: *mut Slot<T>) {
+        // SAFETY: the slot is initialized and its value has been moved out, so only
+        // the arena refers to it.
+        unsafe {
+            if (*slot).generation == u32::MAX {
+                (*slot).state = SlotState::Retired;
+            } else {
+                (*slot).generation += 1;
+                (*slot).state = SlotState::Vacant;
+                (*slot).next_free = self.free_head;
+                self.free_head = NonNull::new(slot);
+            }
+        }
+    }
+
+    /// Returns a pointer to the slot behind `index` if it is occupied by the value
+    /// the handle was created for.
+    fn occupied(&self, index: NodeIndex) -> Option<*mut Slot<T>> {
+        if index.slot >= self.initialized {
+            return None;
+        }
+
+        let slot = self.slot_pointer(index.slot);
+        // SAFETY: the slot is initialized. Only its header is read, which the arena
+        // alone writes, even while the slot is lent.
+        let live = unsafe {
+            (*slot).state == SlotState::Occupied && (*slot).generation == index.generation
+        };
+        live.then_some(slot)
+    }
+
+    /// Returns a pointer to the slot at `index`, which must lie inside a reserved
+    /// chunk.
+    ///
+    /// The pointer is derived from the chunk's own pointer, so writing through it
+    /// never invalidates the pointers lent out into the same chunk.
+    fn slot_pointer(&self, index: usize) -> *mut Slot<T> {
+        let chunk = self.chunks[index / self.chunk_size];
+        // SAFETY: the offset is less than `chunk_size`, so it stays inside the chunk.
+        unsafe { chunk.as_ptr().add(index % self.chunk_size) }
+    }
+}
+
+impl<T> Drop for NodeArena<T> {
+    /// Drops the values of the occupied slots and frees every chunk.
+    fn drop(&mut self) {
+        for index in 0..self.initialized {
+            let slot = self.slot_pointer(index);
+            // SAFETY: the slot is initialized, and the value of an occupied slot is
+            // initialized and owned by the arena.
+            unsafe {
+                if (*slot).state == SlotState::Occupied {
+                    (*slot).value.assume_init_drop();
+                }
+            }
+        }
+
+        for chunk in self.chunks.drain(..) {
+            let slots = std::ptr::slice_from_raw_parts_mut(
+                chunk.as_ptr().cast::<MaybeUninit<Slot<T>>>(),
+                self.chunk_size,
+            );
+            // SAFETY: the chunk was allocated in `claim` as a boxed slice of
+            // `chunk_size` slots, and nothing refers to it any more.
+            drop(unsafe { Box::from_raw(slots) });
+        }
+    }
+}
+
+impl<T> fmt::Debug for NodeArena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeArena")
+            .field("len", &self.length)
+            .field("chunk_size", &self.chunk_size)
+            .field("chunk_count", &self.chunks.len())
+            .finish()
+    }
+}
+
+impl<T> Default for NodeArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`NodeArena`] that several lists can allocate their nodes from.
+///
+/// Cloning a `SharedArena` clones the handle, not the arena: every clone, and every
+/// list built with one, draws from the same chunks, and the arena is freed once the
+/// last of them is dropped. The arena sits behind a mutex, so the lists sharing it
+/// can live on different threads.
+///
+/// # Examples
+///
+/// ```
+/// use list_common::arena::SharedArena;
+///
+/// let arena: SharedArena<u64> = SharedArena::with_chunk_size(64);
+/// assert_eq!(arena.chunk_count(), 0);
+/// assert_eq!(arena.clone().capacity(), 0);
+/// ```
+pub struct SharedArena<T> {
+    /// The arena, shared by every clone of the handle.
+    arena: Arc<Mutex<NodeArena<T>>>,
+}
+
+// SAFETY: a shared arena never holds a value. Nothing can `alloc` through it, so
+// its slots are only lent out as raw storage, and whatever a borrower writes there
+// belongs to the borrower. The arena's own bookkeeping sits behind the mutex.
+unsafe impl<T> Send for SharedArena<T> {}
+unsafe impl<T> Sync for SharedArena<T> {}
+
+impl<T> SharedArena<T> {
+    /// Creates a new empty shared arena with the default chunk size.
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Creates a new empty shared arena whose chunks reserve `chunk_size` slots each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        SharedArena {
+            arena: Arc::new(Mutex::new(NodeArena::with_chunk_size(chunk_size))),
+        }
+    }
+
+    /// Returns the number of slots reserved across all chunks.
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity()
+    }
+
+    /// Returns the number of chunk allocations made so far.
+    pub fn chunk_count(&self) -> usize {
+        self.lock().chunk_count()
+    }
+
+    /// Returns `true` if both handles refer to the same arena.
+    pub fn same_arena(&self, other: &SharedArena<T>) -> bool {
+        Arc::ptr_eq(&self.arena, &other.arena)
+    }
+
+    /// Locks the arena. A panic while the lock was held cannot leave the arena's
+    /// bookkeeping half updated, so a poisoned lock is taken over.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, NodeArena<T>> {
+        self.arena.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Clone for SharedArena<T> {
+    fn clone(&self) -> Self {
+        SharedArena {
+            arena: Arc::clone(&self.arena),
+        }
+    }
+}
+
+impl<T> Default for SharedArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for SharedArena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedArena").field(&*self.lock()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_alloc_and_get() {
+        let mut arena = NodeArena::new();
+
+        let first = arena.alloc(10);
+        let second = arena.alloc(20);
+
+        assert_eq!(arena.get(first), Some(&10));
+        assert_eq!(arena.get(second), Some(&20));
+        assert_eq!(arena.len(), 2);
+
+        *arena.get_mut(first).unwrap() += 5;
+        assert_eq!(arena.get(first), Some(&15));
+    }
+
+    #[test]
+    fn test_chunks_are_reserved_lazily() {
+        let mut arena = NodeArena::with_chunk_size(4);
+        assert_eq!(arena.chunk_count(), 0);
+
+        for value in 0..4 {
+            arena.alloc(value);
+        }
+        assert_eq!(arena.chunk_count(), 1);
+
+        arena.alloc(4);
+        assert_eq!(arena.chunk_count(), 2);
+        assert_eq!(arena.capacity(), 8);
+    }
+
+    #[test]
+    fn test_freed_slots_are_recycled() {
+        let mut arena = NodeArena::with_chunk_size(4);
+        let indices: Vec<_> = (0..4).map(|value| arena.alloc(value)).collect();
+
+        assert_eq!(arena.free(indices[1]), Some(1));
+        assert_eq!(arena.free(indices[2]), Some(2));
+
+        arena.alloc(100);
+        arena.alloc(200);
+
+        assert_eq!(arena.chunk_count(), 1);
+        assert_eq!(arena.len(), 4);
+    }
+
+    #[test]
+    fn test_stale_index_after_recycle() {
+        let mut arena = NodeArena::with_chunk_size(2);
+
+        let stale = arena.alloc(1);
+        assert_eq!(arena.free(stale), Some(1));
+
+        let recycled = arena.alloc(2);
+        assert_ne!(stale, recycled);
+
+        assert_eq!(arena.get(stale), None);
+        assert_eq!(arena.get_mut(stale), None);
+        assert_eq!(arena.free(stale), None);
+        assert_eq!(arena.get(recycled), Some(&2));
+    }
+
+    #[test]
+    fn test_values_are_dropped_exactly_once() {
+        let drops = Rc::new(Cell::new(0));
+
+        {
+            let mut arena = NodeArena::with_chunk_size(3);
+            let indices: Vec<_> = (0..5)
+                .map(|_| arena.alloc(DropCounter(Rc::clone(&drops))))
+                .collect();
+
+            drop(arena.free(indices[0]));
+            drop(arena.free(indices[3]));
+            assert_eq!(drops.get(), 2);
+
+            arena.alloc(DropCounter(Rc::clone(&drops)));
+        }
+
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_churn_keeps_chunk_count_stable() {
+        let mut arena = NodeArena::with_chunk_size(16);
+        let mut live: Vec<_> = (0..16).map(|value| arena.alloc(value)).collect();
+
+        for round in 0..1000 {
+            let index = live.remove(round % live.len());
+            assert!(arena.free(index).is_some());
+            live.push(arena.alloc(round));
+        }
+
+        assert_eq!(arena.chunk_count(), 1);
+        assert_eq!(arena.len(), 16);
+    }
+
+    #[test]
+    fn test_exhausted_generation_retires_the_slot() {
+        let mut arena = NodeArena::with_chunk_size(2);
+        let first = arena.alloc(1);
+
+        // Fast-forward the slot to its last generation rather than recycling it
+        // four billion times.
+        // SAFETY: the slot is initialized and only its header is written.
+        unsafe { (*arena.slot_pointer(first.slot)).generation = u32::MAX };
+        let last = NodeIndex {
+            slot: first.slot,
+            generation: u32::MAX,
+        };
+        assert_eq!(arena.get(first), None);
+        assert_eq!(arena.free(last), Some(1));
+
+        // Wrapping around would make `first` valid again for the next value.
+        let next = arena.alloc(2);
+        assert_ne!(next.slot, last.slot);
+        assert_eq!(arena.get(first), None);
+        assert_eq!(arena.get(last), None);
+        assert_eq!(arena.free(last), None);
+        assert_eq!(arena.get(next), Some(&2));
+        assert_eq!(arena.len(), 1);
+    }
+
+    #[test]
+    fn test_lent_slots_are_recycled() {
+        let drops = Rc::new(Cell::new(0));
+        let mut arena = NodeArena::with_chunk_size(4);
+
+        let stale = arena.alloc(DropCounter(Rc::clone(&drops)));
+        drop(arena.free(stale));
+
+        let lent: Vec<_> = (0..4).map(|_| arena.lend()).collect();
+        assert_eq!(arena.chunk_count(), 1);
+        assert!(!arena.contains(stale));
+
+        for &slot in &lent {
+            // SAFETY: the slot was lent by the arena and holds no value yet.
+            unsafe { slot.as_ptr().write(DropCounter(Rc::clone(&drops))) };
+        }
+        for slot in lent {
+            // SAFETY: the value is read out once and the slot is not used again.
+            drop(unsafe { slot.as_ptr().read() });
+            unsafe { arena.give_back(slot) };
+        }
+        assert_eq!(drops.get(), 5);
+
+        // The given-back slots are reused before a new chunk is reserved, and
+        // dropping the arena drops only the values it holds itself.
+        for _ in 0..4 {
+            arena.alloc(DropCounter(Rc::clone(&drops)));
+        }
+        assert_eq!(arena.chunk_count(), 1);
+        assert!(!arena.contains(stale));
+        drop(arena);
+        assert_eq!(drops.get(), 9);
+    }
+
+    #[test]
+    fn test_shared_arena_handles_share_chunks() {
+        let arena: SharedArena<u64> = SharedArena::with_chunk_size(8);
+        let other = SharedArena::with_chunk_size(8);
+        assert!(arena.same_arena(&arena.clone()));
+        assert!(!arena.same_arena(&other));
+
+        let slot = arena.lock().lend();
+        assert_eq!(arena.clone().chunk_count(), 1);
+        assert_eq!(arena.capacity(), 8);
+        // SAFETY: the slot was lent by this arena and never written.
+        unsafe { arena.lock().give_back(slot) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_chunk_size_panics() {
+        let _arena: NodeArena<i32> = NodeArena::with_chunk_size(0);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod arena;
+pub mod linear;
+pub mod memory;
+pub mod node;
+pub mod pretty;
+pub mod stats;
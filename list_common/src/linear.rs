@@ -0,0 +1,19 @@
+/// The read-only view of a linear list that shared helpers such as
+/// [`crate::pretty::render`] work against.
+pub trait LinearList<T> {
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the list has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns references to the elements in order from head to tail.
+    fn elements(&self) -> Vec<&T>;
+
+    /// Returns `true` if every node also links back to its predecessor.
+    fn is_doubly_linked(&self) -> bool {
+        false
+    }
+}
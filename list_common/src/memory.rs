@@ -0,0 +1,149 @@
+/// A breakdown of the heap memory used by the nodes of a list.
+///
+/// Only node allocations are counted: the list header itself usually lives on the
+/// stack, and allocator bookkeeping is not visible from safe Rust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// The number of nodes in the list.
+    pub node_count: usize,
+    /// The size of a single node, links included.
+    pub bytes_per_node: usize,
+    /// The bytes taken by the stored values: their inline size, plus the heap memory
+    /// they own when the report was built with [`DeepMeasure`].
+    pub payload_bytes: usize,
+    /// The total heap memory: every node, plus the heap memory owned by the values
+    /// when the report was built with [`DeepMeasure`].
+    pub total_bytes: usize,
+}
+
+impl MemoryReport {
+    /// Builds a report for `node_count` nodes of `bytes_per_node` bytes holding
+    /// values of `payload_size` bytes each, plus `heap_bytes` owned by those values.
+    pub fn new(
+        node_count: usize,
+        bytes_per_node: usize,
+        payload_size: usize,
+        heap_bytes: usize,
+    ) -> Self {
+        MemoryReport {
+            node_count,
+            bytes_per_node,
+            payload_bytes: node_count * payload_size + heap_bytes,
+            total_bytes: node_count * bytes_per_node + heap_bytes,
+        }
+    }
+
+    /// Returns the bytes spent on links and padding rather than on values.
+    pub fn overhead_bytes(&self) -> usize {
+        self.total_bytes - self.payload_bytes
+    }
+}
+
+/// Measures the heap memory a value owns beyond its inline size.
+///
+/// # Examples
+///
+/// ```
+/// use list_common::memory::DeepMeasure;
+///
+/// let text = String::with_capacity(32);
+/// assert_eq!(text.heap_bytes(), 32);
+/// assert_eq!(7u64.heap_bytes(), 0);
+/// ```
+pub trait DeepMeasure {
+    /// Returns the number of heap bytes owned by `self`.
+    fn heap_bytes(&self) -> usize;
+}
+
+macro_rules! impl_deep_measure_inline {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl DeepMeasure for $ty {
+                fn heap_bytes(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_deep_measure_inline!(
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    ()
+);
+
+impl DeepMeasure for String {
+    fn heap_bytes(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: DeepMeasure> DeepMeasure for Vec<T> {
+    fn heap_bytes(&self) -> usize {
+        self.capacity() * std::mem::size_of::<T>()
+            + self.iter().map(DeepMeasure::heap_bytes).sum::<usize>()
+    }
+}
+
+impl<T: DeepMeasure> DeepMeasure for Box<T> {
+    fn heap_bytes(&self) -> usize {
+        std::mem::size_of::<T>() + (**self).heap_bytes()
+    }
+}
+
+impl<T: DeepMeasure> DeepMeasure for Option<T> {
+    fn heap_bytes(&self) -> usize {
+        self.as_ref().map_or(0, DeepMeasure::heap_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_totals() {
+        let report = MemoryReport::new(10, 16, 4, 0);
+
+        assert_eq!(report.payload_bytes, 40);
+        assert_eq!(report.total_bytes, 160);
+        assert_eq!(report.overhead_bytes(), 120);
+    }
+
+    #[test]
+    fn test_report_with_heap_bytes() {
+        let report = MemoryReport::new(2, 32, 24, 10);
+
+        assert_eq!(report.payload_bytes, 58);
+        assert_eq!(report.total_bytes, 74);
+    }
+
+    #[test]
+    fn test_deep_measure() {
+        assert_eq!(5i32.heap_bytes(), 0);
+        assert_eq!(String::with_capacity(16).heap_bytes(), 16);
+
+        let mut strings = Vec::with_capacity(4);
+        strings.push(String::with_capacity(8));
+        assert_eq!(strings.heap_bytes(), 4 * std::mem::size_of::<String>() + 8);
+
+        assert_eq!(Box::new(1u64).heap_bytes(), 8);
+        assert_eq!(Some(String::with_capacity(3)).heap_bytes(), 3);
+        assert_eq!(None::<String>.heap_bytes(), 0);
+    }
+}
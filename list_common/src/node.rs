@@ -0,0 +1,179 @@
+use crate::arena::SharedArena;
+use std::fmt;
+use std::ptr::NonNull;
+
+/// Moves `node` onto the heap and returns an owning pointer to it.
+///
+/// The pointer-based lists link their nodes with `NonNull` rather than `Box`, like
+/// std's `LinkedList`. Moving a `Box` asserts unique access to the node it points
+/// at, which would invalidate every other pointer into the chain, such as a list's
+/// `tail`, a back link or a cursor. The node therefore leaves its `Box` here and
+/// only goes back into one in [`free`], when nothing links to it any more.
+///
+/// # Examples
+///
+/// ```
+/// use list_common::node::{allocate, free};
+///
+/// let node = allocate(5);
+/// // SAFETY: `node` came from `allocate` and is not used again.
+/// assert_eq!(unsafe { free(node) }, 5);
+/// ```
+pub fn allocate<N>(node: N) -> NonNull<N> {
+    NonNull::from(Box::leak(Box::new(node)))
+}
+
+/// Frees a node made by [`allocate`] and returns it by value, so the caller can
+/// take its data and links.
+///
+/// # Safety
+///
+/// `node` must come from [`allocate`], nothing may link to it any more, and it must
+/// not be used again.
+pub unsafe fn free<N>(node: NonNull<N>) -> N {
+    // SAFETY: the caller guarantees that `node` is an unaliased pointer made by
+    // `Box::leak` in `allocate`, so ownership can go back to a `Box`.
+    *unsafe { Box::from_raw(node.as_ptr()) }
+}
+
+/// The number of slots a [`NodeAllocator`] takes from or returns to its arena under
+/// one lock.
+const BATCH: usize = 64;
+
+/// Where a list gets its nodes from: the global heap, or a [`SharedArena`].
+///
+/// An arena-backed allocator keeps a few spare slots of its own. It locks the arena
+/// once to take a batch of slots when it runs out, and once to return a batch when
+/// it has too many, so most allocations and frees are a push or pop on a local
+/// `Vec`.
+///
+/// A node must be freed by the allocator that made it or by one that
+/// [`shares_with`](NodeAllocator::shares_with) it.
+pub struct NodeAllocator<N> {
+    /// The arena the slots come from, or `None` to box every node.
+    arena: Option<SharedArena<N>>,
+    /// Slots lent by the arena and not holding a node.
+    spare: Vec<NonNull<N>>,
+}
+
+// SAFETY: the spare slots hold no values and are only reached through `&mut self`,
+// and the arena handle is `Send` and `Sync`. The nodes an allocator makes belong to
+// the list that links them, which carries its own bounds.
+unsafe impl<N> Send for NodeAllocator<N> {}
+unsafe impl<N> Sync for NodeAllocator<N> {}
+
+impl<N> NodeAllocator<N> {
+    /// Returns an allocator that boxes every node with [`allocate`].
+    pub fn heap() -> Self {
+        NodeAllocator {
+            arena: None,
+            spare: Vec::new(),
+        }
+    }
+
+    /// Returns an allocator that takes its nodes from `arena`.
+    pub fn in_arena(arena: &SharedArena<N>) -> Self {
+        NodeAllocator {
+            arena: Some(arena.clone()),
+            spare: Vec::new(),
+        }
+    }
+
+    /// Returns an allocator with no spare slots that gets its nodes from the same
+    /// place as this one, for a list that takes over some of this list's nodes.
+    pub fn sibling(&self) -> Self {
+        NodeAllocator {
+            arena: self.arena.clone(),
+            spare: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the nodes of either allocator can be freed by the other:
+    /// both box their nodes, or both use the same arena.
+    pub fn shares_with(&self, other: &NodeAllocator<N>) -> bool {
+        match (&self.arena, &other.arena) {
+            (None, None) => true,
+            (Some(arena), Some(other)) => arena.same_arena(other),
+            _ => false,
+        }
+    }
+
+    /// Moves `node` into a new slot and returns an owning pointer to it.
+    ///
+    /// Like [`allocate`], the node is linked by raw pointers until it is passed back
+    /// to [`NodeAllocator::free`].
+    pub fn allocate(&mut self, node: N) -> NonNull<N> {
+        let Some(arena) = &self.arena else {
+            return allocate(node);
+        };
+
+        let slot = match self.spare.pop() {
+            Some(slot) => slot,
+            None => {
+                let mut arena = arena.lock();
+                self.spare.extend((0..BATCH).map(|_| arena.lend()));
+                self.spare.pop().expect("a batch has at least one slot")
+            }
+        };
+        // SAFETY: a spare slot is uninitialized storage for one `N` that only this
+        // allocator can reach.
+        unsafe { slot.as_ptr().write(node) };
+        slot
+    }
+
+    /// Frees a node made by [`NodeAllocator::allocate`] and returns it by value.
+    ///
+    /// # Safety
+    ///
+    /// `node` must come from `allocate` on this allocator or on one it
+    /// [`shares_with`](NodeAllocator::shares_with), nothing may link to it any
+    /// more, and it must not be used again.
+    pub unsafe fn free(&mut self, node: NonNull<N>) -> N {
+        let Some(arena) = &self.arena else {
+            // SAFETY: allocators without an arena box their nodes with `allocate`.
+            return unsafe { free(node) };
+        };
+
+        // SAFETY: the caller guarantees that `node` is a live node in a slot of
+        // this arena that nothing else uses. The slot becomes a spare slot, which
+        // is treated as uninitialized from here on.
+        let node_value = unsafe { node.as_ptr().read() };
+        self.spare.push(node);
+        if self.spare.len() >= 2 * BATCH {
+            let mut arena = arena.lock();
+            for slot in self.spare.drain(BATCH..) {
+                // SAFETY: spare slots were lent by this arena and hold no value.
+                unsafe { arena.give_back(slot) };
+            }
+        }
+        node_value
+    }
+}
+
+impl<N> Default for NodeAllocator<N> {
+    fn default() -> Self {
+        Self::heap()
+    }
+}
+
+impl<N> Drop for NodeAllocator<N> {
+    /// Returns the spare slots to the arena.
+    fn drop(&mut self) {
+        if let Some(arena) = &self.arena {
+            let mut arena = arena.lock();
+            for slot in self.spare.drain(..) {
+                // SAFETY: spare slots were lent by this arena and hold no value.
+                unsafe { arena.give_back(slot) };
+            }
+        }
+    }
+}
+
+impl<N> fmt::Debug for NodeAllocator<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeAllocator")
+            .field("arena", &self.arena)
+            .field("spare", &self.spare.len())
+            .finish()
+    }
+}
@@ -0,0 +1,268 @@
+use crate::linear::LinearList;
+use std::fmt::Display;
+
+/// The marker drawn in place of the elements left out by [`RenderOptions::max_elements`].
+const ELISION: &str = "…";
+
+/// Options controlling how [`render`] draws a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// The largest number of elements drawn. Longer lists keep their first and
+    /// last elements and replace the middle with `…`. `None` draws every element.
+    pub max_elements: Option<usize>,
+    /// Whether to draw a row with the index of every element under the nodes.
+    pub show_indices: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            max_elements: None,
+            show_indices: true,
+        }
+    }
+}
+
+/// One column of the drawing: either a boxed node or the elision marker.
+enum Cell {
+    Node { index: usize, label: String },
+    Elision,
+}
+
+impl Cell {
+    /// Returns the width of the column in characters.
+    fn width(&self) -> usize {
+        match self {
+            Cell::Node { label, .. } => label.chars().count() + 4,
+            Cell::Elision => ELISION.chars().count(),
+        }
+    }
+}
+
+/// Draws `list` as a row of boxed nodes joined by arrows.
+///
+/// A singly linked list is drawn with `->` arrows, a doubly linked list with
+/// `<->` arrows and a `None <-` in front of the head. Each line has its trailing
+/// whitespace removed and the lines are joined with `\n`.
+///
+/// # Arguments
+///
+/// * `list` - The list to draw.
+/// * `options` - The elision and index ruler settings.
+///
+/// # Examples
+///
+/// ```
+/// use list_common::linear::LinearList;
+/// use list_common::pretty::{render, RenderOptions};
+///
+/// struct Numbers(Vec<i32>);
+///
+/// impl LinearList<i32> for Numbers {
+///     fn len(&self) -> usize {
+///         self.0.len()
+///     }
+///
+///     fn elements(&self) -> Vec<&i32> {
+///         self.0.iter().collect()
+///     }
+/// }
+///
+/// let drawing = render(&Numbers(vec![1, 2]), RenderOptions::default());
+/// assert_eq!(
+///     drawing,
+///     "+---+    +---+\n\
+///      | 1 | -> | 2 | -> None\n\
+///      +---+    +---+\n  \
+///        0        1"
+/// );
+/// ```
+pub fn render<T: Display>(list: &impl LinearList<T>, options: RenderOptions) -> String {
+    let elements = list.elements();
+    if elements.is_empty() {
+        return String::from("None");
+    }
+
+    let cells = layout(&elements, options.max_elements);
+    let (prefix, arrow) = if list.is_doubly_linked() {
+        ("None <- ", " <-> ")
+    } else {
+        ("", " -> ")
+    };
+    let gap = " ".repeat(arrow.len());
+
+    let mut border = " ".repeat(prefix.len());
+    let mut middle = String::from(prefix);
+    let mut ruler = " ".repeat(prefix.len());
+
+    for (position, cell) in cells.iter().enumerate() {
+        if position > 0 {
+            border.push_str(&gap);
+            middle.push_str(arrow);
+            ruler.push_str(&gap);
+        }
+
+        match cell {
+            Cell::Node { index, label } => {
+                let width = cell.width();
+                border.push('+');
+                border.push_str(&"-".repeat(width - 2));
+                border.push('+');
+                middle.push_str(&format!("| {} |", label));
+
+                let index = index.to_string();
+                let left = width.saturating_sub(index.len()) / 2;
+                let right = width.saturating_sub(index.len() + left);
+                ruler.push_str(&" ".repeat(left));
+                ruler.push_str(&index);
+                ruler.push_str(&" ".repeat(right));
+            }
+            Cell::Elision => {
+                border.push_str(&" ".repeat(cell.width()));
+                middle.push_str(ELISION);
+                ruler.push_str(&" ".repeat(cell.width()));
+            }
+        }
+    }
+    middle.push_str(" -> None");
+
+    let mut lines = vec![border.clone(), middle, border];
+    if options.show_indices {
+        lines.push(ruler);
+    }
+
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Picks the cells to draw, keeping the first and last elements when the list
+/// is longer than `max_elements`.
+fn layout<T: Display>(elements: &[&T], max_elements: Option<usize>) -> Vec<Cell> {
+    let node = |index: usize| Cell::Node {
+        index,
+        label: elements[index].to_string(),
+    };
+
+    match max_elements {
+        Some(max) if elements.len() > max => {
+            let front = max.div_ceil(2);
+            let back = max - front;
+
+            let mut cells: Vec<Cell> = (0..front).map(node).collect();
+            cells.push(Cell::Elision);
+            cells.extend((elements.len() - back..elements.len()).map(node));
+            cells
+        }
+        _ => (0..elements.len()).map(node).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestList {
+        values: Vec<i32>,
+        doubly: bool,
+    }
+
+    impl LinearList<i32> for TestList {
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        fn elements(&self) -> Vec<&i32> {
+            self.values.iter().collect()
+        }
+
+        fn is_doubly_linked(&self) -> bool {
+            self.doubly
+        }
+    }
+
+    fn singly(values: Vec<i32>) -> TestList {
+        TestList {
+            values,
+            doubly: false,
+        }
+    }
+
+    #[test]
+    fn test_render_empty_list() {
+        assert_eq!(render(&singly(vec![]), RenderOptions::default()), "None");
+    }
+
+    #[test]
+    fn test_render_singly_list() {
+        let expected = [
+            "+---+    +----+    +---+",
+            "| 1 | -> | 22 | -> | 3 | -> None",
+            "+---+    +----+    +---+",
+            "  0        1         2",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            render(&singly(vec![1, 22, 3]), RenderOptions::default()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_render_doubly_list() {
+        let list = TestList {
+            values: vec![1, 2],
+            doubly: true,
+        };
+        let expected = [
+            "        +---+     +---+",
+            "None <- | 1 | <-> | 2 | -> None",
+            "        +---+     +---+",
+            "          0         1",
+        ]
+        .join("\n");
+
+        assert_eq!(render(&list, RenderOptions::default()), expected);
+    }
+
+    #[test]
+    fn test_render_without_indices() {
+        let options = RenderOptions {
+            show_indices: false,
+            ..RenderOptions::default()
+        };
+        let expected = ["+---+", "| 7 | -> None", "+---+"].join("\n");
+
+        assert_eq!(render(&singly(vec![7]), options), expected);
+    }
+
+    #[test]
+    fn test_render_elides_the_middle() {
+        let options = RenderOptions {
+            max_elements: Some(3),
+            ..RenderOptions::default()
+        };
+        let expected = [
+            "+---+    +---+         +-----+",
+            "| 0 | -> | 1 | -> … -> | 999 | -> None",
+            "+---+    +---+         +-----+",
+            "  0        1             999",
+        ]
+        .join("\n");
+
+        assert_eq!(render(&singly((0..1000).collect()), options), expected);
+    }
+
+    #[test]
+    fn test_render_short_list_is_not_elided() {
+        let options = RenderOptions {
+            max_elements: Some(3),
+            ..RenderOptions::default()
+        };
+
+        assert!(!render(&singly(vec![1, 2, 3]), options).contains(ELISION));
+    }
+}
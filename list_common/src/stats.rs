@@ -0,0 +1,102 @@
+#[cfg(feature = "stats")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A snapshot of the operation counters kept by a list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListStats {
+    /// The number of nodes visited while walking the list.
+    pub node_visits: u64,
+    /// The number of nodes allocated.
+    pub allocations: u64,
+    /// The number of nodes freed.
+    pub frees: u64,
+}
+
+/// The counters a list updates from its traversal and allocation helpers.
+///
+/// With the `stats` feature enabled the counters are atomics, so they can be bumped
+/// through a shared reference (for example from `get(&self)`). Without the feature
+/// this is a zero-sized type and every method compiles down to nothing, which lets
+/// the lists call it unconditionally.
+#[derive(Debug, Default)]
+pub struct StatsCounter {
+    #[cfg(feature = "stats")]
+    node_visits: AtomicU64,
+    #[cfg(feature = "stats")]
+    allocations: AtomicU64,
+    #[cfg(feature = "stats")]
+    frees: AtomicU64,
+}
+
+impl StatsCounter {
+    /// Creates a new counter with every count at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that one node was visited.
+    #[inline]
+    pub fn record_visit(&self) {
+        #[cfg(feature = "stats")]
+        self.node_visits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records that one node was allocated.
+    #[inline]
+    pub fn record_allocation(&self) {
+        #[cfg(feature = "stats")]
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records that one node was freed.
+    #[inline]
+    pub fn record_free(&self) {
+        #[cfg(feature = "stats")]
+        self.frees.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current counts.
+    #[cfg(feature = "stats")]
+    pub fn snapshot(&self) -> ListStats {
+        ListStats {
+            node_visits: self.node_visits.load(Ordering::Relaxed),
+            allocations: self.allocations.load(Ordering::Relaxed),
+            frees: self.frees.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Sets every count back to zero.
+    #[cfg(feature = "stats")]
+    pub fn reset(&self) {
+        self.node_visits.store(0, Ordering::Relaxed);
+        self.allocations.store(0, Ordering::Relaxed);
+        self.frees.store(0, Ordering::Relaxed);
+    }
+}
+
+#[cfg(all(test, feature = "stats"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_and_reset() {
+        let counter = StatsCounter::new();
+
+        counter.record_visit();
+        counter.record_visit();
+        counter.record_allocation();
+        counter.record_free();
+
+        assert_eq!(
+            counter.snapshot(),
+            ListStats {
+                node_visits: 2,
+                allocations: 1,
+                frees: 1,
+            }
+        );
+
+        counter.reset();
+        assert_eq!(counter.snapshot(), ListStats::default());
+    }
+}
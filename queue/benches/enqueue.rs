@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use queue::algorithm::KolzoQueue;
+
+/// Enqueues and dequeues one element on queues of growing size. If enqueue walked
+/// the list instead of using the tail pointer, the time would grow with the size.
+fn enqueue_at_size(c: &mut Criterion) {
+    let mut group = c.benchmark_group("enqueue_dequeue");
+
+    for size in [1_000, 10_000, 100_000, 1_000_000] {
+        let mut queue: KolzoQueue<u64> = (0..size).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                queue.enqueue(black_box(1));
+                black_box(queue.dequeue());
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, enqueue_at_size);
+criterion_main!(benches);
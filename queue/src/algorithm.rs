@@ -0,0 +1,403 @@
+use list_common::node::{allocate, free};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// A node in the queue.
+///
+/// The queue owns every node, and `tail` points at the last one so that `enqueue`
+/// never walks the chain.
+#[derive(Debug)]
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The node behind this one, towards the back of the queue.
+    next: Option<NonNull<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    /// Returns the node behind this one.
+    fn next_node(&self) -> Option<&Node<T>> {
+        // SAFETY: only `dequeue` frees nodes, and it needs `&mut` to the queue, so
+        // the node behind a borrowed node stays alive for the borrow.
+        self.next.map(|next| unsafe { &*next.as_ptr() })
+    }
+}
+
+/// A first-in, first-out queue built on the singly linked list's head/tail design.
+///
+/// Elements are dequeued from the head and enqueued behind the tail. The tail is
+/// kept as a raw pointer to the last node so that enqueueing never has to walk the
+/// list, which makes both operations O(1).
+pub struct KolzoQueue<T> {
+    /// The front of the queue, where elements are dequeued.
+    head: Option<NonNull<Node<T>>>,
+    /// The back of the queue, kept for efficient enqueueing.
+    tail: Option<NonNull<Node<T>>>,
+    /// The number of elements in the queue.
+    length: usize,
+    /// The queue owns boxed nodes holding `T`.
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the queue owns its whole chain of nodes and `tail` only points into it,
+// so moving the queue to another thread moves every node with it. A shared queue
+// only reads its nodes.
+unsafe impl<T: Send> Send for KolzoQueue<T> {}
+unsafe impl<T: Sync> Sync for KolzoQueue<T> {}
+
+impl<T> KolzoQueue<T> {
+    /// Creates a new empty queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use queue::algorithm::KolzoQueue;
+    ///
+    /// let queue: KolzoQueue<i32> = KolzoQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoQueue {
+            head: None,
+            tail: None,
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds a value to the back of the queue in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to enqueue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use queue::algorithm::KolzoQueue;
+    ///
+    /// let mut queue = KolzoQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// assert_eq!(queue.len(), 2);
+    /// assert_eq!(queue.peek(), Some(&1));
+    /// ```
+    pub fn enqueue(&mut self, value: T) {
+        let new_node = allocate(Node {
+            data: value,
+            next: None,
+        });
+
+        match self.tail {
+            // SAFETY: `tail` always points at the last node, which the queue owns,
+            // and nothing else borrows the queue while we hold `&mut self`.
+            Some(mut tail_pointer) => unsafe {
+                tail_pointer.as_mut().next = Some(new_node);
+            },
+            None => {
+                self.head = Some(new_node);
+            }
+        }
+
+        self.tail = Some(new_node);
+        self.length += 1;
+    }
+
+    /// Removes and returns the value at the front of the queue in O(1).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the oldest value if the queue is not empty.
+    /// * `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use queue::algorithm::KolzoQueue;
+    ///
+    /// let mut queue = KolzoQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    ///
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.head.take().map(|head| {
+            // SAFETY: the head was unlinked from the queue by `take`.
+            let node = unsafe { free(head) };
+            self.head = node.next;
+            if self.head.is_none() {
+                self.tail = None;
+            }
+            self.length -= 1;
+            node.data
+        })
+    }
+
+    /// Returns a reference to the value at the front of the queue without removing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use queue::algorithm::KolzoQueue;
+    ///
+    /// let mut queue = KolzoQueue::new();
+    /// assert_eq!(queue.peek(), None);
+    ///
+    /// queue.enqueue("first");
+    /// queue.enqueue("second");
+    /// assert_eq!(queue.peek(), Some(&"first"));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        // SAFETY: `head` points at a node the queue owns, and the reference
+        // borrows `self`.
+        self.head.map(|head| unsafe { &(*head.as_ptr()).data })
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the queue has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use queue::algorithm::KolzoQueue;
+    ///
+    /// let queue: KolzoQueue<i32> = (1..=3).collect();
+    /// let values: Vec<_> = queue.iter().copied().collect();
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            // SAFETY: `head` points at a node the queue owns, and the iterator
+            // borrows `self`.
+            next: self.head.map(|head| unsafe { &*head.as_ptr() }),
+            remaining: self.length,
+        }
+    }
+}
+
+impl<T> Default for KolzoQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Drop for KolzoQueue<T> {
+    /// Frees the nodes one at a time, following the links from the head.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            // SAFETY: every node is freed exactly once, and the queue is not used
+            // again after `drop`.
+            current = unsafe { free(node) }.next;
+        }
+    }
+}
+
+impl<T> Extend<T> for KolzoQueue<T> {
+    /// Enqueues every value of the iterator in order.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.enqueue(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for KolzoQueue<T> {
+    /// Builds a queue that dequeues the values in iteration order.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = KolzoQueue::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`KolzoQueue`], from front to back.
+pub struct Iter<'a, T> {
+    /// The node whose value is yielded next.
+    next: Option<&'a Node<T>>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next_node();
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// SAFETY: the iterator only reads nodes of a shared-borrowed queue.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p queue`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    #[test]
+    fn test_enqueue_and_dequeue() {
+        let mut queue = KolzoQueue::new();
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.len(), 1);
+
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_enqueue_after_draining() {
+        let mut queue = KolzoQueue::new();
+
+        queue.enqueue(1);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert!(queue.tail.is_none());
+
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.peek(), Some(&2));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut queue = KolzoQueue::new();
+        assert_eq!(queue.peek(), None);
+
+        queue.enqueue(10);
+        queue.enqueue(20);
+        assert_eq!(queue.peek(), Some(&10));
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn test_iter() {
+        let queue: KolzoQueue<i32> = KolzoQueue::new();
+        assert_eq!(queue.iter().next(), None);
+
+        let queue: KolzoQueue<i32> = (1..=5).collect();
+        let mut iter = queue.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.len(), 4);
+
+        let values: Vec<_> = (&queue).into_iter().copied().collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_extend_and_from_iter() {
+        let mut queue: KolzoQueue<i32> = vec![1, 2].into_iter().collect();
+        queue.extend(vec![3, 4]);
+
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        let empty: KolzoQueue<i32> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(214);
+        let mut queue = KolzoQueue::new();
+        let mut model = VecDeque::new();
+
+        for _ in 0..10_000 {
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    let value: i32 = rng.gen();
+                    queue.enqueue(value);
+                    model.push_back(value);
+                }
+                2 => assert_eq!(queue.dequeue(), model.pop_front()),
+                _ => assert_eq!(queue.peek(), model.front()),
+            }
+
+            assert_eq!(queue.len(), model.len());
+            assert_eq!(queue.is_empty(), model.is_empty());
+        }
+
+        assert!(queue.iter().eq(model.iter()));
+    }
+
+    #[test]
+    fn test_drop_long_queue() {
+        let queue: KolzoQueue<usize> = (0..size(200_000)).collect();
+        drop(queue);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send::<KolzoQueue<String>>();
+        assert_sync::<KolzoQueue<String>>();
+        assert_send::<Iter<'static, String>>();
+        assert_sync::<Iter<'static, String>>();
+    }
+}
@@ -0,0 +1,14 @@
+use queue::algorithm::KolzoQueue;
+
+fn main() {
+    let mut queue = KolzoQueue::new();
+    queue.enqueue(1);
+    queue.enqueue(2);
+    queue.enqueue(3);
+    println!("{:?}", queue.iter().collect::<Vec<_>>());
+    println!("PEEK {:?}", queue.peek());
+    println!("DEQUEUE {:?}", queue.dequeue());
+    queue.extend([4, 5]);
+    println!("{:?}", queue.iter().collect::<Vec<_>>());
+    println!("LENGTH {}", queue.len());
+}
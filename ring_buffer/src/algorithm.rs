@@ -0,0 +1,452 @@
+/// A queue with a fixed capacity stored in a circular array.
+///
+/// The values occupy `len` consecutive slots starting at `head`, wrapping around
+/// from the last slot to the first, so both ends can be pushed and popped in O(1)
+/// without ever moving values or reallocating.
+///
+/// When the buffer is full, `push_back` either rejects the new value or, for a
+/// buffer created with [`KolzoRingBuffer::overwriting`], drops the oldest value to
+/// make room.
+#[derive(Debug, Clone)]
+pub struct KolzoRingBuffer<T> {
+    /// The physical storage. Occupied slots hold `Some`.
+    slots: Box<[Option<T>]>,
+    /// The physical index of the front value.
+    head: usize,
+    /// The number of values stored.
+    length: usize,
+    /// Whether pushing onto a full buffer drops the front value instead of failing.
+    overwrite: bool,
+}
+
+impl<T> KolzoRingBuffer<T> {
+    /// Creates a new empty buffer that rejects values once it is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of values the buffer can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_buffer::algorithm::KolzoRingBuffer;
+    ///
+    /// let buffer: KolzoRingBuffer<i32> = KolzoRingBuffer::new(4);
+    /// assert_eq!(buffer.capacity(), 4);
+    /// assert!(buffer.is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a ring buffer needs a capacity of at least 1");
+        KolzoRingBuffer {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            length: 0,
+            overwrite: false,
+        }
+    }
+
+    /// Creates a new empty buffer that drops its oldest value to make room when a
+    /// value is pushed while it is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of values the buffer can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_buffer::algorithm::KolzoRingBuffer;
+    ///
+    /// let mut buffer = KolzoRingBuffer::overwriting(2);
+    /// buffer.extend([1, 2, 3]);
+    /// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn overwriting(capacity: usize) -> Self {
+        KolzoRingBuffer {
+            overwrite: true,
+            ..KolzoRingBuffer::new(capacity)
+        }
+    }
+
+    /// Adds a value at the back of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the value was added. An overwriting buffer always succeeds,
+    ///   dropping its front value if it was full.
+    /// * `Err(value)` if the buffer is full and does not overwrite; the value is
+    ///   handed back and the buffer is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_buffer::algorithm::KolzoRingBuffer;
+    ///
+    /// let mut buffer = KolzoRingBuffer::new(1);
+    /// assert_eq!(buffer.push_back(1), Ok(()));
+    /// assert_eq!(buffer.push_back(2), Err(2));
+    /// ```
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            if !self.overwrite {
+                return Err(value);
+            }
+            self.pop_front();
+        }
+
+        let tail = self.physical(self.length);
+        self.slots[tail] = Some(value);
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the value at the front of the buffer.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<T>` - The oldest value, or `None` if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_buffer::algorithm::KolzoRingBuffer;
+    ///
+    /// let mut buffer = KolzoRingBuffer::new(2);
+    /// buffer.extend([1, 2]);
+    /// assert_eq!(buffer.pop_front(), Some(1));
+    /// assert_eq!(buffer.pop_front(), Some(2));
+    /// assert_eq!(buffer.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+
+        let value = self.slots[self.head].take();
+        self.head = self.physical(1);
+        self.length -= 1;
+        value
+    }
+
+    /// Returns a reference to the value at the front of the buffer, if any.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the value at the back of the buffer, if any.
+    pub fn back(&self) -> Option<&T> {
+        self.length.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns a reference to the value `index` places from the front, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_buffer::algorithm::KolzoRingBuffer;
+    ///
+    /// let mut buffer = KolzoRingBuffer::new(3);
+    /// buffer.extend([1, 2, 3]);
+    /// buffer.pop_front();
+    /// buffer.push_back(4).unwrap();
+    /// assert_eq!(buffer.get(2), Some(&4));
+    /// assert_eq!(buffer.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
+        }
+        self.slots[self.physical(index)].as_ref()
+    }
+
+    /// Returns the number of values stored.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns `true` if the buffer holds `capacity()` values.
+    pub fn is_full(&self) -> bool {
+        self.length == self.slots.len()
+    }
+
+    /// Returns the number of values the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns an iterator over the values from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_buffer::algorithm::KolzoRingBuffer;
+    ///
+    /// let mut buffer = KolzoRingBuffer::new(3);
+    /// buffer.extend([1, 2, 3]);
+    /// buffer.pop_front();
+    /// buffer.push_back(4).unwrap();
+    /// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            buffer: self,
+            front: 0,
+            back: self.length,
+        }
+    }
+
+    /// Converts a logical index, counted from the front, to a physical slot index.
+    fn physical(&self, index: usize) -> usize {
+        // `head` and `index` are both below the capacity, so the sum cannot overflow.
+        (self.head + index) % self.slots.len()
+    }
+}
+
+impl<T> Extend<T> for KolzoRingBuffer<T> {
+    /// Pushes the values of the iterator in order. A buffer that does not overwrite
+    /// stops once it is full and leaves the rest of the iterator unconsumed; an
+    /// overwriting buffer keeps the last `capacity()` values.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if self.push_back(value).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoRingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`KolzoRingBuffer`] from front to back.
+pub struct Iter<'a, T> {
+    /// The buffer being iterated.
+    buffer: &'a KolzoRingBuffer<T>,
+    /// The logical index of the next value from the front.
+    front: usize,
+    /// One past the logical index of the next value from the back.
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let value = self.buffer.get(self.front);
+        self.front += 1;
+        value
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.buffer.get(self.back)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    fn contents(buffer: &KolzoRingBuffer<i32>) -> Vec<i32> {
+        buffer.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_until_full() {
+        let mut buffer = KolzoRingBuffer::new(3);
+        assert_eq!(buffer.front(), None);
+        assert_eq!(buffer.back(), None);
+
+        for value in 1..=3 {
+            assert_eq!(buffer.push_back(value), Ok(()));
+        }
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push_back(4), Err(4));
+        assert_eq!(contents(&buffer), vec![1, 2, 3]);
+        assert_eq!(buffer.front(), Some(&1));
+        assert_eq!(buffer.back(), Some(&3));
+    }
+
+    #[test]
+    fn test_multiple_wraps() {
+        let mut buffer = KolzoRingBuffer::new(4);
+        buffer.extend([0, 1, 2]);
+
+        // Each round moves the head forward by one; 25 rounds wrap it six times.
+        for next in 3..28 {
+            buffer.push_back(next).unwrap();
+            assert_eq!(buffer.pop_front(), Some(next - 3));
+            assert_eq!(contents(&buffer), vec![next - 2, next - 1, next]);
+            assert_eq!(buffer.front(), Some(&(next - 2)));
+            assert_eq!(buffer.back(), Some(&next));
+        }
+
+        buffer.push_back(28).unwrap();
+        assert!(buffer.is_full());
+        assert_eq!(contents(&buffer), vec![25, 26, 27, 28]);
+        assert_eq!(
+            buffer.iter().rev().copied().collect::<Vec<_>>(),
+            vec![28, 27, 26, 25]
+        );
+    }
+
+    #[test]
+    fn test_iter_from_both_ends_across_the_wrap() {
+        let mut buffer = KolzoRingBuffer::new(5);
+        buffer.extend([0, 0, 0, 1, 2]);
+        for _ in 0..3 {
+            buffer.pop_front();
+        }
+        buffer.extend([3, 4, 5]);
+
+        let mut iter = buffer.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_extend_stops_when_full() {
+        let mut buffer = KolzoRingBuffer::new(3);
+        let mut values = 1..=10;
+        buffer.extend(&mut values);
+
+        assert_eq!(contents(&buffer), vec![1, 2, 3]);
+        // The value that did not fit was consumed; the rest were left alone.
+        assert_eq!(values.next(), Some(5));
+    }
+
+    #[test]
+    fn test_overwriting_keeps_the_latest_values() {
+        let mut buffer = KolzoRingBuffer::overwriting(3);
+        for value in 0..10 {
+            assert_eq!(buffer.push_back(value), Ok(()));
+            assert!(buffer.len() <= 3);
+        }
+        assert_eq!(contents(&buffer), vec![7, 8, 9]);
+
+        buffer.extend(10..12);
+        assert_eq!(contents(&buffer), vec![9, 10, 11]);
+        assert_eq!(buffer.pop_front(), Some(9));
+        assert_eq!(contents(&buffer), vec![10, 11]);
+    }
+
+    #[test]
+    fn test_capacity_one() {
+        let mut buffer = KolzoRingBuffer::new(1);
+        for value in 0..5 {
+            buffer.push_back(value).unwrap();
+            assert_eq!(buffer.push_back(-1), Err(-1));
+            assert_eq!(buffer.front(), buffer.back());
+            assert_eq!(buffer.pop_front(), Some(value));
+        }
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity of at least 1")]
+    fn test_zero_capacity_panics() {
+        KolzoRingBuffer::<i32>::new(0);
+    }
+
+    #[test]
+    fn test_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(229);
+        for capacity in [1, 2, 3, 7, 16] {
+            let mut buffer = KolzoRingBuffer::new(capacity);
+            let mut model = VecDeque::with_capacity(capacity);
+
+            for _ in 0..2_000 {
+                if rng.gen_bool(0.55) {
+                    let value = rng.gen_range(0..1000);
+                    let result = buffer.push_back(value);
+                    if model.len() < capacity {
+                        model.push_back(value);
+                        assert_eq!(result, Ok(()));
+                    } else {
+                        assert_eq!(result, Err(value));
+                    }
+                } else {
+                    assert_eq!(buffer.pop_front(), model.pop_front());
+                }
+
+                assert_eq!(buffer.len(), model.len());
+                assert_eq!(buffer.is_full(), model.len() == capacity);
+                assert_eq!(buffer.front(), model.front());
+                assert_eq!(buffer.back(), model.back());
+                assert!(buffer.iter().eq(model.iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_overwriting_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(229);
+        let capacity = 5;
+        let mut buffer = KolzoRingBuffer::overwriting(capacity);
+        let mut model = VecDeque::new();
+
+        for _ in 0..10_000 {
+            if rng.gen_bool(0.6) {
+                let value = rng.gen_range(0..1000);
+                buffer.push_back(value).unwrap();
+                model.push_back(value);
+                if model.len() > capacity {
+                    model.pop_front();
+                }
+            } else {
+                assert_eq!(buffer.pop_front(), model.pop_front());
+            }
+            assert!(buffer.iter().eq(model.iter()));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use ring_buffer::algorithm::KolzoRingBuffer;
+
+fn main() {
+    let mut buffer = KolzoRingBuffer::new(3);
+    for value in 1..=4 {
+        match buffer.push_back(value) {
+            Ok(()) => println!("PUSHED {}", value),
+            Err(value) => println!("FULL, REJECTED {}", value),
+        }
+    }
+    println!("POP {:?}", buffer.pop_front());
+    buffer.push_back(5).unwrap();
+    println!("CONTENTS {:?}", buffer.iter().collect::<Vec<_>>());
+
+    let mut recent = KolzoRingBuffer::overwriting(3);
+    recent.extend(1..=10);
+    println!("LAST THREE {:?}", recent.iter().collect::<Vec<_>>());
+}
@@ -0,0 +1,239 @@
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
+use rand::distributions::Open01;
+use rand::Rng;
+
+/// Takes a uniform random sample of `k` values from a stream in one pass, without
+/// knowing the length of the stream up front.
+///
+/// This is Vitter's Algorithm L. The first `k` values fill the reservoir. After
+/// that, instead of drawing a random number for every value as Algorithm R does,
+/// it draws how many values to skip before the next one that replaces a random
+/// slot. The skips grow as the stream goes on, so a stream of `n` values needs
+/// only about `k * (1 + ln(n / k))` random draws, and the skipped values are
+/// passed over with [`Iterator::nth`].
+///
+/// Every `k`-subset of the stream is equally likely to be returned. The order of
+/// the values within the sample is not random.
+///
+/// # Arguments
+///
+/// * `iter` - The stream to sample from.
+/// * `k` - The number of values to sample.
+/// * `rng` - The source of randomness. A seeded RNG gives a repeatable sample.
+///
+/// # Returns
+///
+/// A `Vec` of `k` values from the stream, or every value of the stream, in order,
+/// if it holds fewer than `k`.
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use sampling::algorithm::reservoir_sample;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let sample = reservoir_sample(0..1_000, 10, &mut rng);
+/// assert_eq!(sample.len(), 10);
+/// assert!(sample.iter().all(|value| (0..1_000).contains(value)));
+///
+/// assert_eq!(reservoir_sample(0..3, 10, &mut rng), vec![0, 1, 2]);
+/// ```
+pub fn reservoir_sample<T, I, R>(iter: I, k: usize, rng: &mut R) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    R: Rng + ?Sized,
+{
+    let mut iter = iter.into_iter();
+    let mut reservoir: Vec<T> = iter.by_ref().take(k).collect();
+    if k == 0 || reservoir.len() < k {
+        return reservoir;
+    }
+
+    // `w` is the largest of `k` uniform keys; each value after the reservoir is
+    // kept with the chance that its key would beat it. Open01 excludes 0, whose
+    // logarithm is infinite.
+    let mut w = (rng.sample::<f64, _>(Open01).ln() / k as f64).exp();
+    loop {
+        // The number of values to pass over follows a geometric distribution. A
+        // skip too large for `usize` saturates and simply ends the stream.
+        let skip = (rng.sample::<f64, _>(Open01).ln() / (1.0 - w).ln()).floor() as usize;
+        match iter.nth(skip) {
+            Some(value) => {
+                reservoir[rng.gen_range(0..k)] = value;
+                w *= (rng.sample::<f64, _>(Open01).ln() / k as f64).exp();
+            }
+            None => break,
+        }
+    }
+
+    reservoir
+}
+
+/// Uniform random sampling of the elements of a list, built on [`reservoir_sample`].
+pub trait Sample<T> {
+    /// Takes a uniform random sample of `k` references to the elements of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of elements to sample.
+    /// * `rng` - The source of randomness.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `k` references, or of every element in list order if the list
+    /// holds fewer than `k`.
+    fn sample<R: Rng + ?Sized>(&self, k: usize, rng: &mut R) -> Vec<&T>;
+}
+
+impl<T> Sample<T> for KolzoLinkedList<T> {
+    /// Samples the singly linked list in one pass over [`KolzoLinkedList::iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::algorithm::KolzoLinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use sampling::algorithm::Sample;
+    ///
+    /// let mut list = KolzoLinkedList::new();
+    /// for value in 0..100 {
+    ///     list.append(value);
+    /// }
+    ///
+    /// let sample = list.sample(5, &mut StdRng::seed_from_u64(1));
+    /// assert_eq!(sample.len(), 5);
+    /// ```
+    fn sample<R: Rng + ?Sized>(&self, k: usize, rng: &mut R) -> Vec<&T> {
+        reservoir_sample(self.iter(), k, rng)
+    }
+}
+
+impl<T> Sample<T> for KolzoDoublyLinkedList<T> {
+    /// Samples the doubly linked list in one pass over [`KolzoDoublyLinkedList::iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_linked_list::algorithm::KolzoDoublyLinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use sampling::algorithm::Sample;
+    ///
+    /// let mut list = KolzoDoublyLinkedList::new();
+    /// for value in 0..100 {
+    ///     list.append(value);
+    /// }
+    ///
+    /// let sample = list.sample(5, &mut StdRng::seed_from_u64(1));
+    /// assert_eq!(sample.len(), 5);
+    /// ```
+    fn sample<R: Rng + ?Sized>(&self, k: usize, rng: &mut R) -> Vec<&T> {
+        reservoir_sample(self.iter(), k, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_seeded_sample_is_repeatable() {
+        let first = reservoir_sample(0..10_000, 20, &mut StdRng::seed_from_u64(240));
+        let second = reservoir_sample(0..10_000, 20, &mut StdRng::seed_from_u64(240));
+        let other = reservoir_sample(0..10_000, 20, &mut StdRng::seed_from_u64(241));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_short_stream_is_returned_whole() {
+        let mut rng = StdRng::seed_from_u64(240);
+
+        assert_eq!(reservoir_sample(0..5, 5, &mut rng), vec![0, 1, 2, 3, 4]);
+        assert_eq!(reservoir_sample(0..5, 9, &mut rng), vec![0, 1, 2, 3, 4]);
+        assert!(reservoir_sample(0..5, 0, &mut rng).is_empty());
+        assert!(reservoir_sample(0..0, 3, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_list_samples() {
+        let mut list = KolzoLinkedList::new();
+        let mut doubly = KolzoDoublyLinkedList::new();
+        for value in 0..50 {
+            list.append(value);
+            doubly.append(value);
+        }
+
+        // Both lists feed the same stream, so the same seed picks the same sample.
+        let from_list = list.sample(10, &mut StdRng::seed_from_u64(240));
+        let from_doubly = doubly.sample(10, &mut StdRng::seed_from_u64(240));
+        assert_eq!(from_list, from_doubly);
+        assert_eq!(from_list.len(), 10);
+
+        let mut short = KolzoDoublyLinkedList::new();
+        short.append('a');
+        short.append('b');
+        assert_eq!(
+            short.sample(3, &mut StdRng::seed_from_u64(240)),
+            vec![&'a', &'b']
+        );
+    }
+
+    /// Checks that each of `n` values lands in a sample of `k` with frequency close
+    /// to `k / n` over many seeded runs.
+    fn assert_uniform_inclusion(n: usize, k: usize, runs: usize, tolerance: f64) {
+        let mut rng = StdRng::seed_from_u64(240);
+        let mut counts = vec![0usize; n];
+        for _ in 0..runs {
+            for value in reservoir_sample(0..n, k, &mut rng) {
+                counts[value] += 1;
+            }
+        }
+
+        let expected = k as f64 / n as f64;
+        for (value, &count) in counts.iter().enumerate() {
+            let frequency = count as f64 / runs as f64;
+            assert!(
+                (frequency - expected).abs() < tolerance,
+                "value {value} was sampled with frequency {frequency}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_inclusion_frequency() {
+        assert_uniform_inclusion(20, 5, 20_000, 0.02);
+    }
+
+    #[test]
+    fn test_inclusion_frequency_with_long_skips() {
+        // A long stream and a small reservoir exercise the skip distribution.
+        assert_uniform_inclusion(500, 5, 20_000, 0.004);
+    }
+
+    proptest! {
+        #[test]
+        fn test_sample_is_drawn_from_the_stream(
+            n in 0usize..300,
+            k in 0usize..40,
+            seed in any::<u64>(),
+        ) {
+            let mut sample = reservoir_sample(0..n, k, &mut StdRng::seed_from_u64(seed));
+            prop_assert_eq!(sample.len(), k.min(n));
+
+            // Values are distinct and in range, so no stream value was taken twice.
+            sample.sort_unstable();
+            sample.dedup();
+            prop_assert_eq!(sample.len(), k.min(n));
+            prop_assert!(sample.iter().all(|&value| value < n));
+        }
+    }
+}
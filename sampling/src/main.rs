@@ -0,0 +1,20 @@
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use sampling::algorithm::{reservoir_sample, Sample};
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(240);
+    println!("STREAM {:?}", reservoir_sample(1..=1_000_000, 5, &mut rng));
+    println!("SHORT STREAM {:?}", reservoir_sample(1..=3, 5, &mut rng));
+
+    let mut list = KolzoLinkedList::new();
+    let mut doubly = KolzoDoublyLinkedList::new();
+    for value in 0..100 {
+        list.append(value);
+        doubly.append(value);
+    }
+    println!("LIST {:?}", list.sample(3, &mut rng));
+    println!("DOUBLY {:?}", doubly.sample(3, &mut rng));
+}
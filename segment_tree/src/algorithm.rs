@@ -0,0 +1,395 @@
+use std::ops::{Bound, Range, RangeBounds};
+
+/// The combiner type of the ready-made `i64` trees.
+pub type I64Combiner = fn(&i64, &i64) -> i64;
+
+/// A segment tree answering range queries over a sequence in O(log n).
+///
+/// The tree is stored flat in a `Vec` of `2 * len` slots: the values sit in the
+/// leaves at `len..2 * len`, and the slot `i` below `len` holds the combination of
+/// its children at `2 * i` and `2 * i + 1`. Slot 0 is never read. This layout works
+/// for any length, not just powers of two.
+///
+/// `combine` must be associative, so that `combine(combine(a, b), c)` equals
+/// `combine(a, combine(b, c))`. It does not need to be commutative: queries always
+/// combine values in sequence order.
+#[derive(Clone)]
+pub struct KolzoSegmentTree<T, F>
+where
+    F: Fn(&T, &T) -> T,
+{
+    /// The internal nodes followed by the leaves, as described above.
+    tree: Vec<T>,
+    /// The number of values.
+    len: usize,
+    /// The associative operation folded over each queried range.
+    combine: F,
+}
+
+impl<T: Clone, F> KolzoSegmentTree<T, F>
+where
+    F: Fn(&T, &T) -> T,
+{
+    /// Builds a tree over a copy of `values` in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The sequence to query.
+    /// * `combine` - The associative operation to fold over ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::new(&[3, 1, 4], |a: &i32, b: &i32| a * b);
+    /// assert_eq!(tree.query(..), 12);
+    /// ```
+    pub fn new(values: &[T], combine: F) -> Self {
+        Self::from_vec(values.to_vec(), combine)
+    }
+
+    /// Builds a tree that takes ownership of `values`, in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The sequence to query.
+    /// * `combine` - The associative operation to fold over ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let words = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    /// let tree = KolzoSegmentTree::from_vec(words, |a: &String, b: &String| a.clone() + b);
+    /// assert_eq!(tree.query(1..), "bc");
+    /// ```
+    pub fn from_vec(values: Vec<T>, combine: F) -> Self {
+        let len = values.len();
+        let mut tree = Vec::with_capacity(2 * len);
+        // The internal slots are placeholders until they are computed below.
+        if let Some(first) = values.first() {
+            tree.resize(len, first.clone());
+        }
+        tree.extend(values);
+
+        for node in (1..len).rev() {
+            tree[node] = combine(&tree[2 * node], &tree[2 * node + 1]);
+        }
+
+        KolzoSegmentTree { tree, len, combine }
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::sum_tree(&[4, 5]);
+    /// assert_eq!(tree.get(1), Some(&5));
+    /// assert_eq!(tree.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            Some(&self.tree[self.len + index])
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the value at `index` and recomputes its ancestors, in O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position to update.
+    /// * `value` - The new value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let mut tree = KolzoSegmentTree::sum_tree(&[1, 2, 3]);
+    /// tree.update(1, 10);
+    /// assert_eq!(tree.query(..), 14);
+    /// ```
+    pub fn update(&mut self, index: usize, value: T) {
+        assert!(
+            index < self.len,
+            "index {index} is out of bounds for a segment tree of length {}",
+            self.len
+        );
+
+        let mut node = self.len + index;
+        self.tree[node] = value;
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = (self.combine)(&self.tree[2 * node], &self.tree[2 * node + 1]);
+        }
+    }
+
+    /// Folds `combine` over the values in `range`, in O(log n).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` with the combination of the values in `range`, in order.
+    /// * `None` if `range` is empty or reaches past the end. An empty range has no
+    ///   value because `combine` is not required to have an identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::min_tree(&[5, 3, 8]);
+    /// assert_eq!(tree.try_query(1..=2), Some(3));
+    /// assert_eq!(tree.try_query(2..2), None);
+    /// assert_eq!(tree.try_query(2..4), None);
+    /// ```
+    pub fn try_query(&self, range: impl RangeBounds<usize>) -> Option<T> {
+        let Range { start, end } = self.resolve(range)?;
+
+        // Walk up from both ends at once, folding the left edge into `left` and the
+        // right edge into `right` so the values are combined in sequence order.
+        let (mut low, mut high) = (start + self.len, end + self.len);
+        let mut left: Option<T> = None;
+        let mut right: Option<T> = None;
+        while low < high {
+            if low % 2 == 1 {
+                left = Some(match left {
+                    Some(acc) => (self.combine)(&acc, &self.tree[low]),
+                    None => self.tree[low].clone(),
+                });
+                low += 1;
+            }
+            if high % 2 == 1 {
+                high -= 1;
+                right = Some(match right {
+                    Some(acc) => (self.combine)(&self.tree[high], &acc),
+                    None => self.tree[high].clone(),
+                });
+            }
+            low /= 2;
+            high /= 2;
+        }
+
+        match (left, right) {
+            (Some(left), Some(right)) => Some((self.combine)(&left, &right)),
+            (left, right) => left.or(right),
+        }
+    }
+
+    /// Folds `combine` over the values in `range`, in O(log n).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or reaches past the end, in the same cases where
+    /// [`KolzoSegmentTree::try_query`] returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::sum_tree(&[1, 2, 3, 4]);
+    /// assert_eq!(tree.query(1..3), 5);
+    /// assert_eq!(tree.query(..), 10);
+    /// ```
+    pub fn query(&self, range: impl RangeBounds<usize>) -> T {
+        let (start, end) = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.try_query((start, end)).unwrap_or_else(|| {
+            panic!(
+                "range ({start:?}, {end:?}) is empty or out of bounds for a segment tree of length {}",
+                self.len
+            )
+        })
+    }
+
+    /// Turns `range` into a half-open `start..end` with `start < end <= len`.
+    fn resolve(&self, range: impl RangeBounds<usize>) -> Option<Range<usize>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        if start < end && end <= self.len {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+}
+
+impl KolzoSegmentTree<i64, I64Combiner> {
+    /// Builds a tree answering range sums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::sum_tree(&[2, -1, 7]);
+    /// assert_eq!(tree.query(0..2), 1);
+    /// ```
+    pub fn sum_tree(values: &[i64]) -> Self {
+        Self::new(values, |a, b| a + b)
+    }
+
+    /// Builds a tree answering range minimums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::min_tree(&[2, -1, 7]);
+    /// assert_eq!(tree.query(..), -1);
+    /// ```
+    pub fn min_tree(values: &[i64]) -> Self {
+        Self::new(values, |a, b| *a.min(b))
+    }
+
+    /// Builds a tree answering range maximums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use segment_tree::algorithm::KolzoSegmentTree;
+    ///
+    /// let tree = KolzoSegmentTree::max_tree(&[2, -1, 7]);
+    /// assert_eq!(tree.query(..2), 2);
+    /// ```
+    pub fn max_tree(values: &[i64]) -> Self {
+        Self::new(values, |a, b| *a.max(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Checks every non-empty `l..r` query of `tree` against a fold over `values`.
+    fn assert_all_ranges<F>(tree: &KolzoSegmentTree<i64, F>, values: &[i64], combine: F)
+    where
+        F: Fn(&i64, &i64) -> i64,
+    {
+        for l in 0..values.len() {
+            for r in l + 1..=values.len() {
+                let expected = values[l + 1..r]
+                    .iter()
+                    .fold(values[l], |acc, value| combine(&acc, value));
+                assert_eq!(tree.query(l..r), expected, "query {l}..{r}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_range_before_and_after_updates() {
+        let mut rng = StdRng::seed_from_u64(241);
+        let mut values: Vec<i64> = (0..100).map(|_| rng.gen_range(-1_000..1_000)).collect();
+        let combiners: [I64Combiner; 3] = [|a, b| a + b, |a, b| *a.min(b), |a, b| *a.max(b)];
+        let mut trees = [
+            KolzoSegmentTree::sum_tree(&values),
+            KolzoSegmentTree::min_tree(&values),
+            KolzoSegmentTree::max_tree(&values),
+        ];
+
+        for (tree, combine) in trees.iter().zip(combiners) {
+            assert_all_ranges(tree, &values, combine);
+        }
+
+        for _ in 0..30 {
+            let index = rng.gen_range(0..values.len());
+            let value = rng.gen_range(-1_000..1_000);
+            values[index] = value;
+            for tree in &mut trees {
+                tree.update(index, value);
+            }
+        }
+
+        for (tree, combine) in trees.iter().zip(combiners) {
+            assert_all_ranges(tree, &values, combine);
+        }
+    }
+
+    #[test]
+    fn test_combines_in_sequence_order() {
+        let letters: Vec<String> = "segmenttree".chars().map(String::from).collect();
+        let mut tree = KolzoSegmentTree::new(&letters, |a: &String, b: &String| a.clone() + b);
+
+        for l in 0..letters.len() {
+            for r in l + 1..=letters.len() {
+                assert_eq!(tree.query(l..r), letters[l..r].concat());
+            }
+        }
+
+        tree.update(0, "S".to_string());
+        assert_eq!(tree.query(..), "Segmenttree");
+        assert_eq!(tree.query(..=2), "Seg");
+    }
+
+    #[test]
+    fn test_empty_ranges_are_rejected() {
+        let tree = KolzoSegmentTree::sum_tree(&[1, 2, 3]);
+        assert_eq!(tree.try_query(0..0), None);
+        assert_eq!(tree.try_query(3..3), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..1;
+        assert_eq!(tree.try_query(reversed), None);
+        assert_eq!(tree.try_query(2..4), None);
+        assert_eq!(tree.try_query(0..=3), None);
+        assert_eq!(tree.try_query(2..=2), Some(3));
+
+        let empty = KolzoSegmentTree::sum_tree(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.try_query(..), None);
+        assert_eq!(empty.try_query(0..0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty or out of bounds")]
+    fn test_query_panics_on_empty_range() {
+        KolzoSegmentTree::sum_tree(&[1, 2, 3]).query(1..1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_update_panics_out_of_bounds() {
+        KolzoSegmentTree::sum_tree(&[1, 2, 3]).update(3, 0);
+    }
+
+    #[test]
+    fn test_single_value() {
+        let mut tree = KolzoSegmentTree::max_tree(&[42]);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree.query(..), 42);
+        tree.update(0, -7);
+        assert_eq!(tree.query(0..1), -7);
+        assert_eq!(tree.get(0), Some(&-7));
+    }
+}
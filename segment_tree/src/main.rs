@@ -0,0 +1,20 @@
+use segment_tree::algorithm::KolzoSegmentTree;
+
+fn main() {
+    let values = [5, 3, 8, 1, 9, 2];
+    let mut sums = KolzoSegmentTree::sum_tree(&values);
+    let mins = KolzoSegmentTree::min_tree(&values);
+    let maxes = KolzoSegmentTree::max_tree(&values);
+
+    println!("SUM 1..4 {}", sums.query(1..4));
+    println!("MIN 2..=5 {}", mins.query(2..=5));
+    println!("MAX ALL {}", maxes.query(..));
+
+    sums.update(2, 0);
+    println!("SUM 1..4 AFTER UPDATE {}", sums.query(1..4));
+    println!("EMPTY RANGE {:?}", sums.try_query(3..3));
+
+    let words = vec!["seg".to_string(), "ment".to_string(), "tree".to_string()];
+    let concat = KolzoSegmentTree::from_vec(words, |a: &String, b: &String| format!("{a}{b}"));
+    println!("CONCAT {}", concat.query(..));
+}
@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use selection::algorithm::median;
+
+/// Finds the median of random input once, with quickselect and by sorting the
+/// whole slice first. Quickselect only follows one side of each partition, so its
+/// lead grows with the input.
+fn single_median(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(239);
+    let mut group = c.benchmark_group("median_random");
+
+    for size in [10_000, 100_000, 1_000_000] {
+        let input: Vec<u32> = (0..size).map(|_| rng.gen()).collect();
+
+        group.bench_with_input(BenchmarkId::new("quickselect", size), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |values| black_box(*median(values).unwrap()),
+                BatchSize::LargeInput,
+            )
+        });
+
+        group.bench_with_input(BenchmarkId::new("sort", size), &input, |b, input| {
+            b.iter_batched_ref(
+                || input.clone(),
+                |values| {
+                    values.sort_unstable();
+                    black_box(values[(values.len() - 1) / 2])
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, single_median);
+criterion_main!(benches);
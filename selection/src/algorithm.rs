@@ -0,0 +1,262 @@
+use linked_list::algorithm::KolzoLinkedList;
+use list_common::linear::LinearList;
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// Finds the `k`-th smallest value of a slice, counting from 0, in average O(n).
+///
+/// Each round picks a random pivot, partitions the candidate range around it and
+/// keeps only the side that holds position `k`. Unlike a sort, only one side is
+/// ever followed. The random pivot makes the quadratic worst case vanishingly
+/// unlikely for any input, and the partition splits runs of equal values evenly
+/// between the sides so they do not slow it down either.
+///
+/// The slice is reordered: afterwards every value before position `k` is less than
+/// or equal to the returned one, and every value after it is greater than or equal.
+///
+/// # Arguments
+///
+/// * `data` - The values to select from.
+/// * `k` - The rank of the value to find, 0 for the smallest.
+///
+/// # Returns
+///
+/// * `Some(&T)` with the value that would be at index `k` if `data` were sorted.
+/// * `None` if `k` is not less than `data.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use selection::algorithm::quickselect;
+///
+/// let mut values = [5, 1, 4, 2, 3];
+/// assert_eq!(quickselect(&mut values, 0), Some(&1));
+/// assert_eq!(quickselect(&mut values, 3), Some(&4));
+/// assert_eq!(quickselect(&mut values, 5), None);
+/// ```
+pub fn quickselect<T: Ord>(data: &mut [T], k: usize) -> Option<&T> {
+    if k >= data.len() {
+        return None;
+    }
+
+    let mut rng = rand::thread_rng();
+    let (mut low, mut high) = (0, data.len());
+    while high - low > 1 {
+        data.swap(low, rng.gen_range(low..high));
+        let pivot = low + partition(&mut data[low..high]);
+
+        match k.cmp(&pivot) {
+            Ordering::Less => high = pivot,
+            Ordering::Greater => low = pivot + 1,
+            Ordering::Equal => break,
+        }
+    }
+
+    Some(&data[k])
+}
+
+/// Finds the `k`-th largest value of a slice, counting from 0, in average O(n).
+///
+/// This is [`quickselect`] for rank `data.len() - 1 - k`, and reorders the slice
+/// the same way.
+///
+/// # Examples
+///
+/// ```
+/// use selection::algorithm::kth_largest;
+///
+/// let mut values = [5, 1, 4, 2, 3];
+/// assert_eq!(kth_largest(&mut values, 0), Some(&5));
+/// assert_eq!(kth_largest(&mut values, 1), Some(&4));
+/// assert_eq!(kth_largest(&mut values, 5), None);
+/// ```
+pub fn kth_largest<T: Ord>(data: &mut [T], k: usize) -> Option<&T> {
+    if k >= data.len() {
+        return None;
+    }
+    let rank = data.len() - 1 - k;
+    quickselect(data, rank)
+}
+
+/// Finds the median of a slice in average O(n), reordering it as [`quickselect`]
+/// does.
+///
+/// For an even number of values this is the lower of the two middle values, so
+/// the result is always one of the values rather than an average of two.
+///
+/// # Returns
+///
+/// * `Some(&T)` with the median.
+/// * `None` if `data` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use selection::algorithm::median;
+///
+/// assert_eq!(median(&mut [3, 1, 2]), Some(&2));
+/// assert_eq!(median(&mut [4, 1, 3, 2]), Some(&2));
+/// assert_eq!(median::<i32>(&mut []), None);
+/// ```
+pub fn median<T: Ord>(data: &mut [T]) -> Option<&T> {
+    let rank = data.len().checked_sub(1)? / 2;
+    quickselect(data, rank)
+}
+
+/// Finds the `k`-th smallest value of a [`KolzoLinkedList`], counting from 0.
+///
+/// A linked list cannot be partitioned in place, so references to its values are
+/// copied into a scratch `Vec` in one O(n) pass and selected there with
+/// [`quickselect`]. The list itself is left untouched.
+///
+/// # Returns
+///
+/// * `Some(&T)` with the value that would be at index `k` if the list were sorted.
+/// * `None` if `k` is not less than the length of the list.
+///
+/// # Examples
+///
+/// ```
+/// use linked_list::algorithm::KolzoLinkedList;
+/// use selection::algorithm::kth_smallest_in_list;
+///
+/// let mut list = KolzoLinkedList::new();
+/// list.append(30);
+/// list.append(10);
+/// list.append(20);
+///
+/// assert_eq!(kth_smallest_in_list(&list, 0), Some(&10));
+/// assert_eq!(kth_smallest_in_list(&list, 2), Some(&30));
+/// assert_eq!(kth_smallest_in_list(&list, 3), None);
+/// assert_eq!(list.get(0), Some(&30));
+/// ```
+pub fn kth_smallest_in_list<T: Ord>(list: &KolzoLinkedList<T>, k: usize) -> Option<&T> {
+    let mut scratch = list.elements();
+    quickselect(&mut scratch, k).copied()
+}
+
+/// Partitions `values` around the pivot at index 0 and returns the pivot's final
+/// index. Values before it are less than or equal to the pivot, and values after
+/// it are greater than or equal.
+///
+/// This is Hoare's partition: two indices move towards each other from the ends
+/// and swap each pair of values on the wrong sides. Both stop at values equal to
+/// the pivot, so equal values end up spread over both sides.
+fn partition<T: Ord>(values: &mut [T]) -> usize {
+    let (pivot, rest) = values
+        .split_first_mut()
+        .expect("a partition needs at least the pivot");
+    let (mut left, mut right) = (0, rest.len());
+    loop {
+        while left < right && rest[left] < *pivot {
+            left += 1;
+        }
+        while left < right && rest[right - 1] > *pivot {
+            right -= 1;
+        }
+        if left >= right {
+            break;
+        }
+        right -= 1;
+        rest.swap(left, right);
+        left += 1;
+    }
+
+    // `rest[..left]` is at most the pivot, so the pivot belongs right after it.
+    values.swap(0, left);
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_every_rank() {
+        let sorted: Vec<i32> = (0..50).collect();
+        let shuffled: Vec<i32> = (0..50).map(|value| (value * 17) % 50).collect();
+
+        for (k, expected) in sorted.iter().enumerate() {
+            let mut values = shuffled.clone();
+            assert_eq!(quickselect(&mut values, k), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(quickselect::<i32>(&mut [], 0), None);
+        assert_eq!(quickselect(&mut [1, 2, 3], 3), None);
+        assert_eq!(kth_largest(&mut [1, 2, 3], 3), None);
+        assert_eq!(quickselect(&mut [7], 0), Some(&7));
+    }
+
+    #[test]
+    fn test_all_equal_values() {
+        let mut values = vec![4; 10_000];
+        assert_eq!(quickselect(&mut values, 5_000), Some(&4));
+        assert_eq!(median(&mut values), Some(&4));
+    }
+
+    #[test]
+    fn test_partition() {
+        let mut values = [3, 5, 1, 3, 4, 3, 2];
+        let pivot = partition(&mut values);
+
+        assert_eq!(values[pivot], 3);
+        assert!(values[..pivot].iter().all(|&value| value <= 3));
+        assert!(values[pivot + 1..].iter().all(|&value| value >= 3));
+
+        // Equal values split evenly instead of all landing on one side.
+        let mut equal = [7; 101];
+        assert_eq!(partition(&mut equal), 50);
+        assert_eq!(partition(&mut [1]), 0);
+    }
+
+    #[test]
+    fn test_kth_smallest_in_list() {
+        let mut list = KolzoLinkedList::new();
+        for value in [5, 3, 9, 3, 1] {
+            list.append(value);
+        }
+
+        let ranked: Vec<_> = (0..5)
+            .map(|k| *kth_smallest_in_list(&list, k).unwrap())
+            .collect();
+        assert_eq!(ranked, vec![1, 3, 3, 5, 9]);
+        assert_eq!(kth_smallest_in_list(&list, 5), None);
+        assert_eq!(list.elements(), vec![&5, &3, &9, &3, &1]);
+
+        let empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        assert_eq!(kth_smallest_in_list(&empty, 0), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_sorting(
+            values in proptest::collection::vec(-20i32..20, 0..200),
+            k in 0usize..220,
+        ) {
+            let mut sorted = values.clone();
+            sorted.sort();
+
+            let mut selected = values.clone();
+            let found = quickselect(&mut selected, k).copied();
+            prop_assert_eq!(found, sorted.get(k).copied());
+
+            // The selected value splits the reordered slice.
+            if let Some(found) = found {
+                prop_assert!(selected[..k].iter().all(|&value| value <= found));
+                prop_assert!(selected[k + 1..].iter().all(|&value| value >= found));
+            }
+
+            let mut selected = values.clone();
+            let largest = kth_largest(&mut selected, k).copied();
+            prop_assert_eq!(largest, sorted.iter().rev().nth(k).copied());
+
+            let mut selected = values;
+            let middle = sorted.len().checked_sub(1).map(|last| sorted[last / 2]);
+            prop_assert_eq!(median(&mut selected).copied(), middle);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use linked_list::algorithm::KolzoLinkedList;
+use selection::algorithm::{kth_largest, kth_smallest_in_list, median, quickselect};
+
+fn main() {
+    let mut values = vec![9, 1, 8, 2, 7, 3, 6, 4, 5];
+    println!("3RD SMALLEST {:?}", quickselect(&mut values, 2));
+    println!("LARGEST {:?}", kth_largest(&mut values, 0));
+    println!("MEDIAN {:?}", median(&mut values));
+    println!("10TH SMALLEST {:?}", quickselect(&mut values, 9));
+
+    let mut list = KolzoLinkedList::new();
+    for value in [30, 10, 20] {
+        list.append(value);
+    }
+    println!("LIST 2ND SMALLEST {:?}", kth_smallest_in_list(&list, 1));
+}
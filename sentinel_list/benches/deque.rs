@@ -0,0 +1,77 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use sentinel_list::algorithm::KolzoSentinelList;
+
+/// The number of elements pushed and popped per iteration.
+const SIZE: i64 = 10_000;
+
+/// Pushes onto both ends and pops everything back off, the deque workload where
+/// the doubly linked list has to check for an empty list on every call.
+fn push_pop_both_ends(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop_both_ends_10k");
+
+    group.bench_function("sentinel_list", |b| {
+        b.iter(|| {
+            let mut list = KolzoSentinelList::new();
+            for value in 0..SIZE / 2 {
+                list.append(black_box(value));
+                list.prepend(black_box(value));
+            }
+            while let Some(value) = list.pop_first() {
+                black_box(value);
+                black_box(list.pop());
+            }
+        })
+    });
+
+    group.bench_function("double_linked_list", |b| {
+        b.iter(|| {
+            let mut list = KolzoDoublyLinkedList::new();
+            for value in 0..SIZE / 2 {
+                list.append(black_box(value));
+                list.prepend(black_box(value));
+            }
+            while let Some(value) = list.pop_first() {
+                black_box(value);
+                black_box(list.pop());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// Inserts and removes at positions spread across a list of 1,000 elements, which
+/// is dominated by the walk to the position from the nearer end.
+fn insert_remove_middle(c: &mut Criterion) {
+    let positions = [1, 250, 500, 750, 999];
+    let mut group = c.benchmark_group("insert_remove_1k");
+
+    let mut sentinel: KolzoSentinelList<i64> = (0..1_000).collect();
+    group.bench_function("sentinel_list", |b| {
+        b.iter(|| {
+            for &index in &positions {
+                sentinel.insert(black_box(index), index);
+                black_box(sentinel.remove(black_box(index)));
+            }
+        })
+    });
+
+    let mut doubly = KolzoDoublyLinkedList::new();
+    for value in 0..1_000 {
+        doubly.append(value);
+    }
+    group.bench_function("double_linked_list", |b| {
+        b.iter(|| {
+            for &index in &positions {
+                doubly.insert(black_box(index), index);
+                doubly.remove(black_box(index));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, push_pop_both_ends, insert_remove_middle);
+criterion_main!(benches);
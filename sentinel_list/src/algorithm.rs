@@ -0,0 +1,962 @@
+use list_common::linear::LinearList;
+use list_common::pretty::{render, RenderOptions};
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+/// A node in the sentinel list.
+///
+/// Every node, the sentinel included, is linked in both directions by raw
+/// pointers, and the list owns all of them.
+struct Node<T> {
+    /// The data stored in the node. Initialized in every node except the sentinel.
+    data: MaybeUninit<T>,
+    /// The previous node, or the sentinel for the first node.
+    previous: *mut Node<T>,
+    /// The next node, or the sentinel for the last node.
+    next: *mut Node<T>,
+}
+
+/// A doubly linked list arranged in a ring around one sentinel node.
+///
+/// The sentinel holds no value and sits between the last node and the first one:
+/// its `next` is the head and its `previous` is the tail, and in an empty list both
+/// point back at the sentinel itself. Because every node always has a real
+/// neighbor on both sides, linking and unlinking are the same four pointer writes
+/// wherever they happen. None of the empty-list, single-element or head/tail
+/// special cases of `double_linked_list`'s `KolzoDoublyLinkedList` are needed.
+///
+/// The API mirrors the doubly linked list's, so the two can be compared directly.
+pub struct KolzoSentinelList<T> {
+    /// The sentinel node, allocated once and freed when the list is dropped.
+    sentinel: *mut Node<T>,
+    /// The number of elements in the list, not counting the sentinel.
+    length: usize,
+    /// The list owns boxed nodes holding `T`.
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> KolzoSentinelList<T> {
+    /// Creates a new empty list, allocating its sentinel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let list: KolzoSentinelList<i32> = KolzoSentinelList::new();
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.validate(), Ok(()));
+    /// ```
+    pub fn new() -> Self {
+        let sentinel = Box::into_raw(Box::new(Node {
+            data: MaybeUninit::uninit(),
+            previous: ptr::null_mut(),
+            next: ptr::null_mut(),
+        }));
+        // SAFETY: `sentinel` was just allocated. An empty ring is the sentinel
+        // linked to itself.
+        unsafe {
+            (*sentinel).previous = sentinel;
+            (*sentinel).next = sentinel;
+        }
+
+        KolzoSentinelList {
+            sentinel,
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Appends a value to the end of the list in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to append.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list = KolzoSentinelList::new();
+    /// list.append(1);
+    /// list.append(2);
+    /// assert_eq!(list.get(1), Some(&2));
+    /// ```
+    pub fn append(&mut self, value: T) {
+        // SAFETY: the sentinel is always a live node of the ring.
+        unsafe { self.link_before(self.sentinel, value) };
+    }
+
+    /// Prepends a value to the start of the list in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to prepend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list = KolzoSentinelList::new();
+    /// list.prepend(2);
+    /// list.prepend(1);
+    /// assert_eq!(list.get(0), Some(&1));
+    /// ```
+    pub fn prepend(&mut self, value: T) {
+        // SAFETY: the node after the sentinel is the head, or the sentinel itself.
+        unsafe { self.link_before((*self.sentinel).next, value) };
+    }
+
+    /// Removes the last element of the list in O(1) and returns its value, or
+    /// `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = (1..=2).collect();
+    /// assert_eq!(list.pop(), Some(2));
+    /// assert_eq!(list.pop(), Some(1));
+    /// assert_eq!(list.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: the list is not empty, so the tail is a node other than the
+        // sentinel.
+        Some(unsafe { self.unlink((*self.sentinel).previous) })
+    }
+
+    /// Removes the first element of the list in O(1) and returns its value, or
+    /// `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = (1..=2).collect();
+    /// assert_eq!(list.pop_first(), Some(1));
+    /// assert_eq!(list.pop_first(), Some(2));
+    /// assert_eq!(list.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: the list is not empty, so the head is a node other than the
+        // sentinel.
+        Some(unsafe { self.unlink((*self.sentinel).next) })
+    }
+
+    /// Returns a reference to the value at `index`, walking from the nearer end.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` if the index is in bounds.
+    /// * `None` if the index is negative or not less than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let list: KolzoSentinelList<i32> = [10, 20, 30].into_iter().collect();
+    /// assert_eq!(list.get(2), Some(&30));
+    /// assert_eq!(list.get(3), None);
+    /// assert_eq!(list.get(-1), None);
+    /// ```
+    pub fn get(&self, index: i64) -> Option<&T> {
+        if index.is_negative() || index as u64 >= self.length as u64 {
+            return None;
+        }
+        // SAFETY: an in-bounds position is a node other than the sentinel, kept
+        // alive by the borrow of the list.
+        Some(unsafe { (*self.node_at(index as usize)).data.assume_init_ref() })
+    }
+
+    /// Replaces the value at `index` and returns the old value, or returns `None`
+    /// if the index is negative or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = [10, 20].into_iter().collect();
+    /// assert_eq!(list.set(1, 25), Some(20));
+    /// assert_eq!(list.get(1), Some(&25));
+    /// assert_eq!(list.set(2, 40), None);
+    /// ```
+    pub fn set(&mut self, index: i64, value: T) -> Option<T> {
+        if index.is_negative() || index as u64 >= self.length as u64 {
+            return None;
+        }
+        // SAFETY: as in `get`, and the list is borrowed mutably.
+        let data = unsafe { (*self.node_at(index as usize)).data.assume_init_mut() };
+        Some(std::mem::replace(data, value))
+    }
+
+    /// Inserts a value so that it ends up at `index`.
+    ///
+    /// The new node is linked in front of the node at `index`, which is the
+    /// sentinel when `index` equals the length, so inserting at either end needs
+    /// no special case.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was inserted.
+    /// * `false` if the index is negative or greater than the length of the list, in
+    ///   which case the value is dropped and the list is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = [10, 30].into_iter().collect();
+    /// assert!(list.insert(1, 20));
+    /// assert!(list.insert(3, 40));
+    /// assert!(!list.insert(5, 50));
+    /// assert!(list.iter().eq([10, 20, 30, 40].iter()));
+    /// ```
+    pub fn insert(&mut self, index: i64, value: T) -> bool {
+        if index.is_negative() || index as u64 > self.length as u64 {
+            return false;
+        }
+        // SAFETY: positions up to the length are live nodes of the ring.
+        unsafe { self.link_before(self.node_at(index as usize), value) };
+        true
+    }
+
+    /// Removes the element at `index` and returns its value.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` with the removed value if the index is in bounds.
+    /// * `None` if the index is negative or not less than the length, in which case
+    ///   the list is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = [10, 20, 30].into_iter().collect();
+    /// assert_eq!(list.remove(1), Some(20));
+    /// assert_eq!(list.remove(2), None);
+    /// assert!(list.iter().eq([10, 30].iter()));
+    /// ```
+    pub fn remove(&mut self, index: i64) -> Option<T> {
+        if index.is_negative() || index as u64 >= self.length as u64 {
+            return None;
+        }
+        // SAFETY: an in-bounds position is a node other than the sentinel.
+        Some(unsafe { self.unlink(self.node_at(index as usize)) })
+    }
+
+    /// Reverses the list in place.
+    ///
+    /// Every node of the ring, the sentinel included, swaps its `next` and
+    /// `previous` links, which turns the ring around without touching the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = (1..=3).collect();
+    /// list.reverse();
+    /// assert!(list.iter().eq([3, 2, 1].iter()));
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut current = self.sentinel;
+        // SAFETY: the walk follows `next` links around the ring, which visits each
+        // live node exactly once before returning to the sentinel.
+        unsafe {
+            loop {
+                let node = &mut *current;
+                std::mem::swap(&mut node.previous, &mut node.next);
+                // The old `next` is now in `previous`.
+                current = node.previous;
+                if current == self.sentinel {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an iterator over the elements from head to tail.
+    ///
+    /// The iterator is double-ended, so `iter().rev()` walks from tail to head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let list: KolzoSentinelList<i32> = (1..=3).collect();
+    /// assert!(list.iter().rev().eq([3, 2, 1].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        // SAFETY: the sentinel is live for as long as the list.
+        let (front, back) = unsafe { ((*self.sentinel).next, (*self.sentinel).previous) };
+        Iter {
+            front,
+            back,
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a cursor positioned on the first element, or on the sentinel if the
+    /// list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = (1..=4).collect();
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// cursor.insert_before(20);
+    /// assert!(list.iter().eq([1, 20, 3, 4].iter()));
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        // SAFETY: the sentinel is live for as long as the list.
+        let current = unsafe { (*self.sentinel).next };
+        CursorMut {
+            current,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor positioned on the last element, or on the sentinel if the
+    /// list is empty.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        // SAFETY: the sentinel is live for as long as the list.
+        let current = unsafe { (*self.sentinel).previous };
+        CursorMut {
+            current,
+            list: self,
+        }
+    }
+
+    /// Prints the list as boxed nodes joined by arrows, with an index ruler
+    /// underneath.
+    ///
+    /// See [`list_common::pretty::render`] for the layout.
+    pub fn pretty_print(&self)
+    where
+        T: std::fmt::Display,
+    {
+        println!("{}", render(self, RenderOptions::default()));
+    }
+
+    /// Walks the ring and checks that the links agree.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if following `next` from the sentinel passes exactly `len()`
+    ///   nodes before coming back, and every node's `next` links back to it
+    ///   through `previous`.
+    /// * `Err(String)` describing the first violation found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sentinel_list::algorithm::KolzoSentinelList;
+    ///
+    /// let mut list: KolzoSentinelList<i32> = (0..10).collect();
+    /// list.remove(4);
+    /// list.reverse();
+    /// assert_eq!(list.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let mut current = self.sentinel;
+        let mut count = 0;
+        // SAFETY: the walk stops at the first broken back link, and it never takes
+        // more than `len() + 1` steps, so it only follows links that have been
+        // consistent so far.
+        unsafe {
+            loop {
+                let next = (*current).next;
+                if (*next).previous != current {
+                    return Err(format!(
+                        "the node after position {count} does not link back to it"
+                    ));
+                }
+                if next == self.sentinel {
+                    break;
+                }
+                count += 1;
+                if count > self.length {
+                    return Err(format!(
+                        "the ring holds more than {} nodes besides the sentinel",
+                        self.length
+                    ));
+                }
+                current = next;
+            }
+        }
+
+        if count != self.length {
+            return Err(format!(
+                "the ring holds {count} nodes besides the sentinel but its length is {}",
+                self.length
+            ));
+        }
+        Ok(())
+    }
+
+    /// Links a new node holding `value` in front of `successor` and returns it.
+    ///
+    /// # Safety
+    ///
+    /// `successor` must be a live node of this list's ring, possibly the sentinel.
+    unsafe fn link_before(&mut self, successor: *mut Node<T>, value: T) -> *mut Node<T> {
+        let predecessor = (*successor).previous;
+        let node = Box::into_raw(Box::new(Node {
+            data: MaybeUninit::new(value),
+            previous: predecessor,
+            next: successor,
+        }));
+        (*predecessor).next = node;
+        (*successor).previous = node;
+        self.length += 1;
+        node
+    }
+
+    /// Unlinks `node`, frees it and returns its value.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a live node of this list's ring other than the sentinel.
+    unsafe fn unlink(&mut self, node: *mut Node<T>) -> T {
+        (*(*node).previous).next = (*node).next;
+        (*(*node).next).previous = (*node).previous;
+        self.length -= 1;
+        Box::from_raw(node).data.assume_init()
+    }
+
+    /// Returns the node at position `index`, walking from the nearer end. Position
+    /// `len()` is the sentinel.
+    ///
+    /// `index` must not be greater than `len()`.
+    fn node_at(&self, index: usize) -> *mut Node<T> {
+        debug_assert!(index <= self.length);
+        let mut current = self.sentinel;
+        // SAFETY: at most `len()` steps in either direction stay on live nodes.
+        unsafe {
+            if index < self.length / 2 {
+                for _ in 0..=index {
+                    current = (*current).next;
+                }
+            } else {
+                for _ in index..self.length {
+                    current = (*current).previous;
+                }
+            }
+        }
+        current
+    }
+}
+
+impl<T> Default for KolzoSentinelList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for KolzoSentinelList<T> {
+    /// Frees every node from the head, then the sentinel, whose value was never
+    /// initialized and must not be dropped.
+    fn drop(&mut self) {
+        while self.pop_first().is_some() {}
+        // SAFETY: the sentinel came from `Box::into_raw` in `new` and is freed only
+        // here. `MaybeUninit` does not drop its contents.
+        drop(unsafe { Box::from_raw(self.sentinel) });
+    }
+}
+
+impl<T> Extend<T> for KolzoSentinelList<T> {
+    /// Appends every value of the iterator to the list.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for KolzoSentinelList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = KolzoSentinelList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoSentinelList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for KolzoSentinelList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> LinearList<T> for KolzoSentinelList<T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn is_doubly_linked(&self) -> bool {
+        true
+    }
+}
+
+/// A double-ended iterator over the elements of a [`KolzoSentinelList`].
+pub struct Iter<'a, T> {
+    /// The node whose value is yielded next from the front.
+    front: *mut Node<T>,
+    /// The node whose value is yielded next from the back.
+    back: *mut Node<T>,
+    /// The number of values not yet yielded from either end.
+    remaining: usize,
+    /// The iterator borrows the list.
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: values remain, so `front` is a node other than the sentinel, kept
+        // alive by the list borrowed for `'a`.
+        unsafe {
+            let node = &*self.front;
+            self.front = node.next;
+            Some(node.data.assume_init_ref())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: as in `next`, for `back`.
+        unsafe {
+            let node = &*self.back;
+            self.back = node.previous;
+            Some(node.data.assume_init_ref())
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// A cursor over a [`KolzoSentinelList`] that can edit the list where it stands.
+///
+/// The cursor rests either on an element or on the sentinel, which sits between
+/// the last element and the first. Moving past either end lands on the sentinel,
+/// and moving again wraps around to the other end.
+///
+/// Created by [`KolzoSentinelList::cursor_front_mut`] and
+/// [`KolzoSentinelList::cursor_back_mut`].
+pub struct CursorMut<'a, T> {
+    /// The node the cursor rests on, possibly the sentinel.
+    current: *mut Node<T>,
+    /// The list being edited.
+    list: &'a mut KolzoSentinelList<T>,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Returns the element under the cursor, or `None` on the sentinel.
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.current == self.list.sentinel {
+            return None;
+        }
+        // SAFETY: `current` is a live node other than the sentinel, and the cursor
+        // borrows the list mutably.
+        Some(unsafe { (*self.current).data.assume_init_mut() })
+    }
+
+    /// Moves the cursor to the next element, or from the last element onto the
+    /// sentinel, or from the sentinel onto the first element.
+    pub fn move_next(&mut self) {
+        // SAFETY: `current` is a live node of the ring.
+        self.current = unsafe { (*self.current).next };
+    }
+
+    /// Moves the cursor to the previous element, or from the first element onto
+    /// the sentinel, or from the sentinel onto the last element.
+    pub fn move_prev(&mut self) {
+        // SAFETY: `current` is a live node of the ring.
+        self.current = unsafe { (*self.current).previous };
+    }
+
+    /// Inserts a value in front of the cursor, which stays where it is. On the
+    /// sentinel, this appends the value to the list.
+    pub fn insert_before(&mut self, value: T) {
+        // SAFETY: `current` is a live node of the ring.
+        unsafe { self.list.link_before(self.current, value) };
+    }
+
+    /// Inserts a value behind the cursor, which stays where it is. On the
+    /// sentinel, this prepends the value to the list.
+    pub fn insert_after(&mut self, value: T) {
+        // SAFETY: `current` is a live node of the ring, and so is its successor.
+        unsafe { self.list.link_before((*self.current).next, value) };
+    }
+
+    /// Removes the element under the cursor and returns it, moving the cursor to
+    /// the next element. Returns `None` and does nothing on the sentinel.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current == self.list.sentinel {
+            return None;
+        }
+        // SAFETY: `current` is a live node other than the sentinel.
+        unsafe {
+            let node = self.current;
+            self.current = (*node).next;
+            Some(self.list.unlink(node))
+        }
+    }
+}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p sentinel_list`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cell::Cell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    /// A payload that counts how many times it has been dropped.
+    struct DropCounter {
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    #[test]
+    fn test_append_and_pop() {
+        let mut list: KolzoSentinelList<i32> = KolzoSentinelList::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        assert_eq!(list.length, 3);
+        assert_eq!(list.validate(), Ok(()));
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+
+        // The empty ring is the sentinel linked to itself again.
+        unsafe {
+            assert_eq!((*list.sentinel).next, list.sentinel);
+            assert_eq!((*list.sentinel).previous, list.sentinel);
+        }
+    }
+
+    #[test]
+    fn test_prepend_and_pop_first() {
+        let mut list = KolzoSentinelList::new();
+        assert_eq!(list.pop_first(), None);
+
+        list.prepend(1);
+        list.prepend(2);
+        list.append(0);
+        assert!(list.iter().eq([2, 1, 0].iter()));
+
+        assert_eq!(list.pop_first(), Some(2));
+        assert_eq!(list.pop_first(), Some(1));
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop_first(), None);
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut list: KolzoSentinelList<i32> = (0..7).collect();
+
+        assert!((0..7).all(|index| list.get(index) == Some(&(index as i32))));
+        assert_eq!(list.get(7), None);
+        assert_eq!(list.get(-1), None);
+
+        assert_eq!(list.set(5, 50), Some(5));
+        assert_eq!(list.set(-1, 0), None);
+        assert_eq!(list.get(5), Some(&50));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list = KolzoSentinelList::new();
+        list.insert(0, 2);
+        list.insert(0, 0);
+        list.insert(1, 1);
+        list.insert(3, 3);
+
+        // Out-of-range indices are ignored, as in the doubly linked list.
+        list.insert(5, 5);
+        list.insert(-1, -1);
+
+        assert!(list.iter().eq([0, 1, 2, 3].iter()));
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list: KolzoSentinelList<i32> = (0..5).collect();
+
+        assert_eq!(list.remove(2), Some(2));
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.remove(2), Some(4));
+        assert_eq!(list.remove(2), None);
+        assert_eq!(list.remove(-1), None);
+        assert!(list.iter().eq([1, 3].iter()));
+
+        assert_eq!(list.remove(0), Some(1));
+        assert_eq!(list.remove(0), Some(3));
+        assert!(list.is_empty());
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list: KolzoSentinelList<i32> = KolzoSentinelList::new();
+        list.reverse();
+        assert_eq!(list.validate(), Ok(()));
+
+        list.extend(1..=5);
+        list.reverse();
+        assert!(list.iter().eq([5, 4, 3, 2, 1].iter()));
+        assert_eq!(list.validate(), Ok(()));
+
+        list.append(0);
+        list.prepend(6);
+        assert!(list.iter().rev().eq([0, 1, 2, 3, 4, 5, 6].iter()));
+    }
+
+    #[test]
+    fn test_iter() {
+        let list: KolzoSentinelList<i32> = KolzoSentinelList::new();
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.iter().next_back(), None);
+
+        let list: KolzoSentinelList<i32> = (1..=5).collect();
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert!((&list).into_iter().eq([1, 2, 3, 4, 5].iter()));
+        assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+    }
+
+    #[test]
+    fn test_cursor_walks_around_the_sentinel() {
+        let mut list: KolzoSentinelList<i32> = (1..=3).collect();
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        *cursor.current().unwrap() = 30;
+        assert!(list.iter().eq([1, 2, 30].iter()));
+    }
+
+    #[test]
+    fn test_cursor_edits() {
+        let mut list: KolzoSentinelList<i32> = [1, 5, 2, 8, 3].into_iter().collect();
+
+        // Removes every value that is smaller than the one before it.
+        let mut cursor = list.cursor_front_mut();
+        let mut previous = None;
+        while let Some(&mut value) = cursor.current() {
+            if previous.is_some_and(|previous| value < previous) {
+                cursor.remove_current();
+            } else {
+                previous = Some(value);
+                cursor.move_next();
+            }
+        }
+        assert!(list.iter().eq([1, 5, 8].iter()));
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_before(0);
+        cursor.insert_after(2);
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        // On the sentinel, inserting before appends and inserting after prepends.
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_before(9);
+        cursor.insert_after(-1);
+        assert!(list.iter().eq([-1, 0, 2, 5, 8, 9].iter()));
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_cursor_on_empty_list() {
+        let mut list = KolzoSentinelList::new();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+
+        cursor.insert_after(1);
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_detects_a_wrong_length() {
+        let mut list: KolzoSentinelList<i32> = (0..3).collect();
+
+        list.length = 2;
+        assert_eq!(
+            list.validate(),
+            Err("the ring holds more than 2 nodes besides the sentinel".to_string())
+        );
+
+        list.length = 4;
+        assert_eq!(
+            list.validate(),
+            Err("the ring holds 3 nodes besides the sentinel but its length is 4".to_string())
+        );
+        list.length = 3;
+    }
+
+    #[test]
+    fn test_drop_frees_every_value_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut list: KolzoSentinelList<DropCounter> = (0..10)
+            .map(|_| DropCounter {
+                drops: Rc::clone(&drops),
+            })
+            .collect();
+
+        drop(list.remove(4));
+        assert_eq!(drops.get(), 1);
+
+        // The sentinel's value is never initialized, so it must not count.
+        drop(list);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(236);
+        let mut list = KolzoSentinelList::new();
+        let mut model = VecDeque::new();
+
+        for _ in 0..size(10_000) {
+            let index = rng.gen_range(0..=model.len() as i64 + 1);
+            match rng.gen_range(0..7) {
+                0 => {
+                    let value: i32 = rng.gen();
+                    list.append(value);
+                    model.push_back(value);
+                }
+                1 => {
+                    let value: i32 = rng.gen();
+                    list.prepend(value);
+                    model.push_front(value);
+                }
+                2 => assert_eq!(list.pop(), model.pop_back()),
+                3 => assert_eq!(list.pop_first(), model.pop_front()),
+                4 => {
+                    let value: i32 = rng.gen();
+                    list.insert(index, value);
+                    if index as usize <= model.len() {
+                        model.insert(index as usize, value);
+                    }
+                }
+                5 => {
+                    let expected = model.remove(index as usize);
+                    assert_eq!(list.remove(index), expected);
+                }
+                _ => assert_eq!(list.get(index), model.get(index as usize)),
+            }
+            assert_eq!(list.len(), model.len());
+        }
+
+        assert_eq!(list.validate(), Ok(()));
+        assert!(list.iter().eq(model.iter()));
+        assert!(list.iter().rev().eq(model.iter().rev()));
+    }
+}
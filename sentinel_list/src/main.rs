@@ -0,0 +1,27 @@
+use sentinel_list::algorithm::KolzoSentinelList;
+
+fn main() {
+    let mut list: KolzoSentinelList<i32> = (1..=5).collect();
+    list.prepend(0);
+    list.insert(3, 100);
+    println!("CONTENTS {:?}", list.iter().collect::<Vec<_>>());
+
+    // Drop every even value in one pass.
+    let mut cursor = list.cursor_front_mut();
+    while let Some(value) = cursor.current() {
+        if *value % 2 == 0 {
+            cursor.remove_current();
+        } else {
+            cursor.move_next();
+        }
+    }
+    println!("ODD VALUES {:?}", list.iter().collect::<Vec<_>>());
+
+    list.reverse();
+    println!(
+        "REVERSED {:?} VALID {:?}",
+        list.iter().collect::<Vec<_>>(),
+        list.validate()
+    );
+    list.pretty_print();
+}
@@ -0,0 +1,283 @@
+use binary_heap::algorithm::KolzoHeap;
+use graph::algorithm::{KolzoGraph, NodeId};
+use std::collections::HashMap;
+
+/// Computes the shortest distance from `source` to every reachable node with
+/// Dijkstra's algorithm.
+///
+/// Nodes are settled in order of distance using a [`KolzoHeap`] min-heap. A node
+/// can sit in the heap several times with different distances; stale entries are
+/// skipped when popped instead of being updated in place. This runs in
+/// O((V + E) log E). Edge weights are unsigned, so the greedy order is always
+/// correct; zero-weight edges are allowed.
+///
+/// When several paths share the shortest distance, the predecessor is the one
+/// through which the distance was found first and is not replaced by later ties.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted graph to search.
+/// * `source` - The node distances are measured from.
+///
+/// # Returns
+///
+/// A map from every node reachable from `source` to its distance and its
+/// predecessor on a shortest path. The source maps to `(0, None)`; unreachable
+/// nodes are absent.
+///
+/// # Examples
+///
+/// ```
+/// use graph::algorithm::KolzoGraph;
+/// use shortest_path::algorithm::dijkstra;
+///
+/// let mut graph = KolzoGraph::directed();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_weighted_edge(a, b, 5);
+/// graph.add_weighted_edge(a, c, 1);
+/// graph.add_weighted_edge(c, b, 2);
+///
+/// let paths = dijkstra(&graph, a);
+/// assert_eq!(paths[&a], (0, None));
+/// assert_eq!(paths[&b], (3, Some(c)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `source` does not belong to the graph.
+pub fn dijkstra<N>(
+    graph: &KolzoGraph<N>,
+    source: NodeId,
+) -> HashMap<NodeId, (u64, Option<NodeId>)> {
+    let mut paths = HashMap::new();
+    let mut settled = vec![false; graph.node_count()];
+    let mut pending = KolzoHeap::new();
+
+    paths.insert(source, (0, None));
+    pending.push((0u64, source));
+
+    while let Some((distance, current)) = pending.pop() {
+        if settled[current.index()] {
+            continue;
+        }
+        settled[current.index()] = true;
+
+        for (neighbor, weight) in graph.edges(current) {
+            let candidate = distance.saturating_add(weight);
+            let improves = match paths.get(&neighbor) {
+                Some(&(known, _)) => candidate < known,
+                None => true,
+            };
+
+            if improves {
+                paths.insert(neighbor, (candidate, Some(current)));
+                pending.push((candidate, neighbor));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Follows the predecessors reported by [`dijkstra`] back from `target` to `source`.
+///
+/// # Arguments
+///
+/// * `paths` - The result of running [`dijkstra`] from `source`.
+/// * `source` - The node the search started from.
+/// * `target` - The node to find the path to.
+///
+/// # Returns
+///
+/// * `Some(Vec<NodeId>)` with the nodes of a shortest path, from `source` to
+///   `target` inclusive.
+/// * `None` if `target` is unreachable, or if `paths` does not lead back to `source`.
+///
+/// # Examples
+///
+/// ```
+/// use graph::algorithm::KolzoGraph;
+/// use shortest_path::algorithm::{dijkstra, reconstruct_path};
+///
+/// let mut graph = KolzoGraph::undirected();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let island = graph.add_node("island");
+/// graph.add_edge(a, b);
+/// graph.add_edge(b, c);
+///
+/// let paths = dijkstra(&graph, a);
+/// assert_eq!(reconstruct_path(&paths, a, c), Some(vec![a, b, c]));
+/// assert_eq!(reconstruct_path(&paths, a, island), None);
+/// ```
+pub fn reconstruct_path(
+    paths: &HashMap<NodeId, (u64, Option<NodeId>)>,
+    source: NodeId,
+    target: NodeId,
+) -> Option<Vec<NodeId>> {
+    let mut path = vec![target];
+    let mut current = target;
+
+    // A shortest path visits every node at most once, which bounds the walk even
+    // if `paths` was not produced by `dijkstra`.
+    while current != source {
+        if path.len() > paths.len() {
+            return None;
+        }
+        current = paths.get(&current)?.1?;
+        path.push(current);
+    }
+
+    path.reverse();
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_known_distances() {
+        // The classic example graph, with 0 as the source.
+        let mut graph = KolzoGraph::undirected();
+        let ids: Vec<_> = (0..6).map(|value| graph.add_node(value)).collect();
+        for (from, to, weight) in [
+            (0, 1, 7),
+            (0, 2, 9),
+            (0, 5, 14),
+            (1, 2, 10),
+            (1, 3, 15),
+            (2, 3, 11),
+            (2, 5, 2),
+            (3, 4, 6),
+            (4, 5, 9),
+        ] {
+            graph.add_weighted_edge(ids[from], ids[to], weight);
+        }
+
+        let paths = dijkstra(&graph, ids[0]);
+        let distances: Vec<_> = ids.iter().map(|id| paths[id].0).collect();
+        assert_eq!(distances, vec![0, 7, 9, 20, 20, 11]);
+
+        assert_eq!(
+            reconstruct_path(&paths, ids[0], ids[4]),
+            Some(vec![ids[0], ids[2], ids[5], ids[4]])
+        );
+        assert_eq!(reconstruct_path(&paths, ids[0], ids[0]), Some(vec![ids[0]]));
+    }
+
+    #[test]
+    fn test_unreachable_nodes_are_absent() {
+        let mut graph = KolzoGraph::directed();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_weighted_edge(b, a, 1);
+        graph.add_weighted_edge(a, c, 3);
+
+        let paths = dijkstra(&graph, a);
+        assert_eq!(paths.len(), 2);
+        assert!(!paths.contains_key(&b));
+        assert_eq!(reconstruct_path(&paths, a, b), None);
+    }
+
+    #[test]
+    fn test_zero_weight_edges() {
+        let mut graph = KolzoGraph::directed();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        graph.add_weighted_edge(a, c, 1);
+        graph.add_weighted_edge(a, b, 0);
+        graph.add_weighted_edge(b, c, 0);
+        graph.add_weighted_edge(c, c, 0);
+
+        let paths = dijkstra(&graph, a);
+        assert_eq!(paths[&b], (0, Some(a)));
+        assert_eq!(paths[&c], (0, Some(b)));
+        assert_eq!(reconstruct_path(&paths, a, c), Some(vec![a, b, c]));
+    }
+
+    #[test]
+    fn test_equal_cost_paths_keep_the_first_predecessor() {
+        // a -> b -> d and a -> c -> d both cost 2.
+        let mut graph = KolzoGraph::directed();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        let d = graph.add_node(());
+        graph.add_weighted_edge(a, b, 1);
+        graph.add_weighted_edge(a, c, 1);
+        graph.add_weighted_edge(b, d, 1);
+        graph.add_weighted_edge(c, d, 1);
+
+        let paths = dijkstra(&graph, a);
+        assert_eq!(paths[&d], (2, Some(b)));
+    }
+
+    #[test]
+    fn test_reconstruct_path_rejects_broken_chains() {
+        let mut graph = KolzoGraph::<()>::directed();
+        let source = graph.add_node(());
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+
+        // Predecessors that loop between `a` and `b` and never reach `source`.
+        let mut cyclic = HashMap::new();
+        cyclic.insert(a, (1, Some(b)));
+        cyclic.insert(b, (1, Some(a)));
+        assert_eq!(reconstruct_path(&cyclic, source, a), None);
+    }
+
+    fn graph_strategy() -> impl Strategy<Value = (usize, Vec<(usize, usize, u64)>)> {
+        (1usize..12).prop_flat_map(|nodes| {
+            let edge = (0..nodes, 0..nodes, 0u64..20);
+            (Just(nodes), proptest::collection::vec(edge, 0..40))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_distances_satisfy_triangle_inequality((nodes, edges) in graph_strategy()) {
+            let mut graph = KolzoGraph::directed();
+            let ids: Vec<_> = (0..nodes).map(|value| graph.add_node(value)).collect();
+            for &(from, to, weight) in &edges {
+                graph.add_weighted_edge(ids[from], ids[to], weight);
+            }
+
+            let paths = dijkstra(&graph, ids[0]);
+
+            // No edge out of a reachable node offers a shorter way to its target.
+            for (&node, &(distance, _)) in &paths {
+                for (neighbor, weight) in graph.edges(node) {
+                    let reported = paths.get(&neighbor).map(|&(known, _)| known);
+                    prop_assert!(reported.is_some_and(|known| known <= distance + weight));
+                }
+            }
+
+            // Every reported distance is realised by the reported predecessor chain.
+            for (&node, &(distance, _)) in &paths {
+                let path = reconstruct_path(&paths, ids[0], node);
+                prop_assert!(path.is_some());
+                let path = path.unwrap_or_default();
+
+                let mut total = 0;
+                for step in path.windows(2) {
+                    let cheapest = graph
+                        .edges(step[0])
+                        .filter(|&(to, _)| to == step[1])
+                        .map(|(_, weight)| weight)
+                        .min();
+                    prop_assert!(cheapest.is_some());
+                    total += cheapest.unwrap_or_default();
+                    prop_assert!(paths[&step[0]].0 <= paths[&step[1]].0);
+                }
+                prop_assert_eq!(total, distance);
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use graph::algorithm::KolzoGraph;
+use shortest_path::algorithm::{dijkstra, reconstruct_path};
+
+fn main() {
+    let mut graph = KolzoGraph::directed();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    graph.add_weighted_edge(a, b, 4);
+    graph.add_weighted_edge(a, c, 1);
+    graph.add_weighted_edge(c, b, 2);
+    graph.add_weighted_edge(b, d, 5);
+
+    let paths = dijkstra(&graph, a);
+    for id in graph.node_ids() {
+        println!("{} {:?}", graph.node(id).unwrap(), paths.get(&id));
+    }
+
+    let path = reconstruct_path(&paths, a, d).unwrap_or_default();
+    let names: Vec<_> = path.iter().map(|id| *graph.node(*id).unwrap()).collect();
+    println!("PATH a -> d {:?}", names);
+}
@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use linked_list::algorithm::KolzoLinkedList;
+use skip_list::algorithm::KolzoSkipList;
+
+/// The number of elements in both lists.
+const SIZE: u64 = 100_000;
+
+/// Looks up values spread across 100k elements. The skip list should answer in
+/// expected O(log n), while the linked list has to scan linearly.
+fn contains(c: &mut Criterion) {
+    let mut skip_list = KolzoSkipList::with_seed(217);
+    let mut linked_list = KolzoLinkedList::new();
+    for value in 0..SIZE {
+        skip_list.insert(value);
+        linked_list.append(value);
+    }
+
+    let targets = [0, SIZE / 4, SIZE / 2, SIZE - 1, SIZE];
+    let mut group = c.benchmark_group("contains_100k");
+
+    group.bench_function("skip_list", |b| {
+        b.iter(|| {
+            for target in &targets {
+                black_box(skip_list.contains(black_box(target)));
+            }
+        })
+    });
+
+    group.bench_function("linked_list", |b| {
+        b.iter(|| {
+            for target in &targets {
+                black_box(linked_list.contains(black_box(target)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, contains);
+criterion_main!(benches);
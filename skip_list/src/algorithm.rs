@@ -0,0 +1,532 @@
+use list_common::arena::{NodeArena, NodeIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::ops::RangeBounds;
+
+/// The highest level a node can be promoted to. With a promotion probability of
+/// one half this keeps searches logarithmic for up to about 2^32 elements.
+pub const MAX_LEVEL: usize = 32;
+
+/// A node in the skip list.
+#[derive(Debug)]
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The next node on each level the node takes part in, lowest level first.
+    forward: Vec<Option<NodeIndex>>,
+}
+
+/// A sorted set built as a skip list with probabilistic balancing.
+///
+/// Every element is on the bottom level, and each node is promoted to the next
+/// level with probability one half. Searches start on the highest level and drop
+/// down whenever the next node would overshoot, which gives expected O(log n)
+/// `insert`, `contains` and `remove`.
+///
+/// Nodes live in a [`NodeArena`] and link to each other through its handles, so
+/// the list needs no raw pointers.
+///
+/// The list holds each value at most once: inserting a value that is already
+/// present leaves the list unchanged and returns `false`, like
+/// [`std::collections::BTreeSet::insert`].
+#[derive(Debug)]
+pub struct KolzoSkipList<T: Ord> {
+    /// The nodes of the list.
+    nodes: NodeArena<Node<T>>,
+    /// The first node on each level, lowest level first.
+    head: Vec<Option<NodeIndex>>,
+    /// The number of levels currently in use.
+    level: usize,
+    /// The number of elements in the list.
+    length: usize,
+    /// The random number generator used to pick the level of new nodes.
+    rng: StdRng,
+}
+
+impl<T: Ord> KolzoSkipList<T> {
+    /// Creates a new empty skip list whose levels are drawn from a randomly seeded generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let list: KolzoSkipList<i32> = KolzoSkipList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::from_rng(StdRng::from_entropy())
+    }
+
+    /// Creates a new empty skip list whose levels are drawn from a generator seeded
+    /// with `seed`, so the same operations always build the same structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for level assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let mut list = KolzoSkipList::with_seed(42);
+    /// list.insert(1);
+    /// assert!(list.contains(&1));
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: StdRng) -> Self {
+        KolzoSkipList {
+            nodes: NodeArena::new(),
+            head: vec![None; MAX_LEVEL],
+            level: 0,
+            length: 0,
+            rng,
+        }
+    }
+
+    /// Inserts a value into the list in expected O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to insert.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was inserted.
+    /// * `false` if an equal value was already present; the list is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let mut list = KolzoSkipList::with_seed(1);
+    /// assert!(list.insert(3));
+    /// assert!(list.insert(1));
+    /// assert!(!list.insert(3));
+    ///
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3]);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        let update = self.predecessors(&value);
+
+        if let Some(next) = self.forward(update[0], 0) {
+            if self.node(next).data == value {
+                return false;
+            }
+        }
+
+        let node_level = self.random_level();
+        if node_level > self.level {
+            self.level = node_level;
+        }
+
+        let forward = (0..node_level)
+            .map(|level| self.forward(update[level], level))
+            .collect();
+        let index = self.nodes.alloc(Node {
+            data: value,
+            forward,
+        });
+        for (level, predecessor) in update.into_iter().enumerate().take(node_level) {
+            self.set_forward(predecessor, level, Some(index));
+        }
+
+        self.length += 1;
+        true
+    }
+
+    /// Returns `true` if the list contains `value`, in expected O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let mut list = KolzoSkipList::with_seed(1);
+    /// list.insert(2);
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&3));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            current = self.advance(current, level, value);
+        }
+
+        self.forward(current, 0)
+            .is_some_and(|next| self.node(next).data == *value)
+    }
+
+    /// Removes `value` from the list in expected O(log n).
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the value was present and has been removed.
+    /// * `false` if the value was not in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let mut list = KolzoSkipList::with_seed(1);
+    /// list.insert(1);
+    /// list.insert(2);
+    ///
+    /// assert!(list.remove(&1));
+    /// assert!(!list.remove(&1));
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&2]);
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        let update = self.predecessors(value);
+
+        let target = match self.forward(update[0], 0) {
+            Some(next) if self.node(next).data == *value => next,
+            _ => return false,
+        };
+
+        let node = self
+            .nodes
+            .free(target)
+            .expect("a node reachable from the list is live");
+        for (level, next) in node.forward.into_iter().enumerate() {
+            self.set_forward(update[level], level, next);
+        }
+
+        while self.level > 0 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+
+        self.length -= 1;
+        true
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an iterator over the elements in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let mut list = KolzoSkipList::with_seed(1);
+    /// for value in [3, 1, 2] {
+    ///     list.insert(value);
+    /// }
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: &self.nodes,
+            next: self.head[0],
+            remaining: self.length,
+        }
+    }
+
+    /// Returns an iterator over the elements that fall inside `range`, in ascending order.
+    ///
+    /// The first element is found in expected O(log n), after which the iterator
+    /// walks the bottom level until it leaves the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The bounds of the values to yield, such as `a..b` or `a..=b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use skip_list::algorithm::KolzoSkipList;
+    ///
+    /// let mut list = KolzoSkipList::with_seed(1);
+    /// for value in 0..10 {
+    ///     list.insert(value);
+    /// }
+    ///
+    /// assert_eq!(list.range(3..6).copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    /// assert_eq!(list.range(8..).copied().collect::<Vec<_>>(), vec![8, 9]);
+    /// ```
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T, R> {
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.forward(current, level) {
+                let before_start = match range.start_bound() {
+                    std::ops::Bound::Included(start) => self.node(next).data < *start,
+                    std::ops::Bound::Excluded(start) => self.node(next).data <= *start,
+                    std::ops::Bound::Unbounded => false,
+                };
+                if !before_start {
+                    break;
+                }
+                current = Some(next);
+            }
+        }
+
+        Range {
+            nodes: &self.nodes,
+            next: self.forward(current, 0),
+            range,
+        }
+    }
+
+    /// Returns, for every level, the last node whose value is less than `value`,
+    /// where `None` stands for the head.
+    fn predecessors(&self, value: &T) -> [Option<NodeIndex>; MAX_LEVEL] {
+        let mut update = [None; MAX_LEVEL];
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            current = self.advance(current, level, value);
+            update[level] = current;
+        }
+        update
+    }
+
+    /// Moves right along `level` from `current` while the next value is less than `value`.
+    fn advance(
+        &self,
+        mut current: Option<NodeIndex>,
+        level: usize,
+        value: &T,
+    ) -> Option<NodeIndex> {
+        while let Some(next) = self.forward(current, level) {
+            if self.node(next).data >= *value {
+                break;
+            }
+            current = Some(next);
+        }
+        current
+    }
+
+    /// Returns the node after `current` on `level`, where `None` stands for the head.
+    fn forward(&self, current: Option<NodeIndex>, level: usize) -> Option<NodeIndex> {
+        match current {
+            Some(index) => self.node(index).forward[level],
+            None => self.head[level],
+        }
+    }
+
+    /// Links `current` to `next` on `level`, where `None` stands for the head.
+    fn set_forward(&mut self, current: Option<NodeIndex>, level: usize, next: Option<NodeIndex>) {
+        match current {
+            Some(index) => {
+                self.nodes
+                    .get_mut(index)
+                    .expect("a node reachable from the list is live")
+                    .forward[level] = next
+            }
+            None => self.head[level] = next,
+        }
+    }
+
+    fn node(&self, index: NodeIndex) -> &Node<T> {
+        self.nodes
+            .get(index)
+            .expect("a node reachable from the list is live")
+    }
+
+    /// Draws the level of a new node: one, plus one more for every coin flip in a row that comes up heads.
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.gen_bool(0.5) {
+            level += 1;
+        }
+        level
+    }
+}
+
+impl<T: Ord> Default for KolzoSkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for KolzoSkipList<T> {
+    /// Inserts every value of the iterator, skipping values that are already present.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a KolzoSkipList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`KolzoSkipList`] in ascending order.
+pub struct Iter<'a, T> {
+    /// The nodes of the list being iterated.
+    nodes: &'a NodeArena<Node<T>>,
+    /// The node whose value is yielded next.
+    next: Option<NodeIndex>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.nodes.get(self.next?)?;
+        self.next = node.forward[0];
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator over the elements of a [`KolzoSkipList`] inside a range, in ascending order.
+pub struct Range<'a, T, R> {
+    /// The nodes of the list being iterated.
+    nodes: &'a NodeArena<Node<T>>,
+    /// The node whose value is yielded next, if it is still inside the range.
+    next: Option<NodeIndex>,
+    /// The bounds of the values to yield.
+    range: R,
+}
+
+impl<'a, T: Ord, R: RangeBounds<T>> Iterator for Range<'a, T, R> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.nodes.get(self.next?)?;
+        if !self.range.contains(&node.data) {
+            self.next = None;
+            return None;
+        }
+
+        self.next = node.forward[0];
+        Some(&node.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::SliceRandom;
+
+    fn shuffled_list(size: i32, seed: u64) -> KolzoSkipList<i32> {
+        let mut values: Vec<i32> = (0..size).collect();
+        values.shuffle(&mut StdRng::seed_from_u64(seed));
+
+        let mut list = KolzoSkipList::with_seed(seed);
+        for value in values {
+            assert!(list.insert(value));
+        }
+        list
+    }
+
+    #[test]
+    fn test_insert_shuffled_iterates_sorted() {
+        let list = shuffled_list(1000, 217);
+
+        assert_eq!(list.len(), 1000);
+        assert!(list.iter().copied().eq(0..1000));
+        assert_eq!(list.iter().len(), 1000);
+    }
+
+    #[test]
+    fn test_contains() {
+        let list = shuffled_list(200, 1);
+
+        assert!(list.contains(&0));
+        assert!(list.contains(&199));
+        assert!(!list.contains(&-1));
+        assert!(!list.contains(&200));
+
+        let empty: KolzoSkipList<i32> = KolzoSkipList::with_seed(1);
+        assert!(!empty.contains(&0));
+    }
+
+    #[test]
+    fn test_remove_half() {
+        let mut list = shuffled_list(1000, 2);
+
+        for value in (0..1000).step_by(2) {
+            assert!(list.remove(&value));
+        }
+        assert!(!list.remove(&0));
+        assert!(!list.remove(&1000));
+
+        assert_eq!(list.len(), 500);
+        assert!(list.iter().copied().eq((1..1000).step_by(2)));
+        assert!(!list.contains(&500));
+        assert!(list.contains(&501));
+
+        for value in (1..1000).step_by(2) {
+            assert!(list.remove(&value));
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.level, 0);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_duplicates_are_rejected() {
+        let mut list = KolzoSkipList::with_seed(3);
+
+        assert!(list.insert(5));
+        assert!(!list.insert(5));
+        list.extend([5, 4, 5, 4]);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![4, 5]);
+
+        assert!(list.remove(&5));
+        assert!(!list.contains(&5));
+    }
+
+    #[test]
+    fn test_range() {
+        let list = shuffled_list(20, 4);
+
+        assert_eq!(
+            list.range(5..9).copied().collect::<Vec<_>>(),
+            vec![5, 6, 7, 8]
+        );
+        assert_eq!(list.range(17..=19).count(), 3);
+        assert_eq!(list.range(..3).copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.range(..).count(), 20);
+        assert_eq!(list.range(7..7).next(), None);
+        assert_eq!(list.range(50..60).next(), None);
+        assert_eq!(
+            list.range((std::ops::Bound::Excluded(3), std::ops::Bound::Included(5)))
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![4, 5]
+        );
+    }
+
+    #[test]
+    fn test_same_seed_builds_same_levels() {
+        let first = shuffled_list(100, 5);
+        let second = shuffled_list(100, 5);
+
+        assert_eq!(first.level, second.level);
+        assert_eq!(first.head, second.head);
+    }
+}
@@ -0,0 +1,15 @@
+use skip_list::algorithm::KolzoSkipList;
+
+fn main() {
+    let mut list = KolzoSkipList::with_seed(7);
+    for value in [5, 1, 4, 2, 3] {
+        list.insert(value);
+    }
+    println!("{:?}", list.iter().collect::<Vec<_>>());
+    println!("INSERT DUPLICATE 3 {}", list.insert(3));
+    println!("CONTAINS 4 {}", list.contains(&4));
+    println!("RANGE 2..4 {:?}", list.range(2..4).collect::<Vec<_>>());
+    println!("REMOVE 4 {}", list.remove(&4));
+    println!("{:?}", list.iter().collect::<Vec<_>>());
+    println!("LENGTH {}", list.len());
+}
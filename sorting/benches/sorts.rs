@@ -0,0 +1,50 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sorting::algorithm::{
+    bubble_sort, heap_sort, insertion_sort, merge_sort, quick_sort, selection_sort,
+};
+
+/// The O(n²) algorithms are only measured up to this size.
+const QUADRATIC_LIMIT: usize = 1_000;
+
+/// A sorting algorithm over a slice of `u32`.
+type Sort = fn(&mut [u32]);
+
+/// Sorts random input of a few sizes with every algorithm, alongside `slice::sort`
+/// as a baseline.
+fn sorts(c: &mut Criterion) {
+    let algorithms: [(&str, Sort, bool); 7] = [
+        ("bubble_sort", bubble_sort, true),
+        ("insertion_sort", insertion_sort, true),
+        ("selection_sort", selection_sort, true),
+        ("merge_sort", merge_sort, false),
+        ("quick_sort", quick_sort, false),
+        ("heap_sort", heap_sort, false),
+        ("slice_sort", <[u32]>::sort, false),
+    ];
+
+    let mut rng = StdRng::seed_from_u64(230);
+    let mut group = c.benchmark_group("sort_random");
+
+    for size in [100, 1_000, 10_000] {
+        let input: Vec<u32> = (0..size).map(|_| rng.gen()).collect();
+        for (name, sort, quadratic) in algorithms {
+            if quadratic && size > QUADRATIC_LIMIT {
+                continue;
+            }
+            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
+                b.iter_batched_ref(
+                    || input.clone(),
+                    |values| sort(values),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, sorts);
+criterion_main!(benches);
@@ -0,0 +1,477 @@
+use linked_list::algorithm::KolzoLinkedList;
+use std::cmp::Ordering;
+
+/// Sorts a slice in ascending order with bubble sort, in O(n²).
+///
+/// Each pass swaps adjacent values that are out of order, which carries the largest
+/// remaining value to the end. A pass without swaps ends the sort early, so sorted
+/// input takes O(n). Equal values are never swapped, so the sort is stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use sorting::algorithm::bubble_sort;
+///
+/// let mut values = [3, 1, 2];
+/// bubble_sort(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn bubble_sort<T: Ord>(values: &mut [T]) {
+    let mut end = values.len();
+    while end > 1 {
+        let mut last_swap = 0;
+        for index in 1..end {
+            if values[index - 1] > values[index] {
+                values.swap(index - 1, index);
+                last_swap = index;
+            }
+        }
+        // Everything from the last swap onwards is already in its final place.
+        end = last_swap;
+    }
+}
+
+/// Sorts a slice in ascending order with insertion sort, in O(n²).
+///
+/// Each value is moved left past the larger values before it, growing a sorted
+/// prefix. Nearly sorted input takes close to O(n). A value never moves past an
+/// equal one, so the sort is stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use sorting::algorithm::insertion_sort;
+///
+/// let mut values = [3, 1, 2];
+/// insertion_sort(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn insertion_sort<T: Ord>(values: &mut [T]) {
+    for sorted in 1..values.len() {
+        let mut index = sorted;
+        while index > 0 && values[index - 1] > values[index] {
+            values.swap(index - 1, index);
+            index -= 1;
+        }
+    }
+}
+
+/// Sorts a slice in ascending order with selection sort, in O(n²).
+///
+/// Each pass finds the smallest remaining value and swaps it to the front of the
+/// unsorted part, so at most n - 1 swaps are made. The swap can carry a value past
+/// an equal one, so the sort is not stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use sorting::algorithm::selection_sort;
+///
+/// let mut values = [3, 1, 2];
+/// selection_sort(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn selection_sort<T: Ord>(values: &mut [T]) {
+    for start in 0..values.len() {
+        let mut smallest = start;
+        for index in start + 1..values.len() {
+            if values[index] < values[smallest] {
+                smallest = index;
+            }
+        }
+        values.swap(start, smallest);
+    }
+}
+
+/// Sorts a slice in ascending order with merge sort, in O(n log n).
+///
+/// The sort works on a list of positions rather than on the values, so it needs
+/// neither `Clone` nor unsafe code: the positions are merge sorted top-down with a
+/// scratch buffer of O(n) `usize`s, then the values are moved into place by
+/// following the cycles of the resulting permutation. Ties are taken from the left
+/// half first, so the sort is stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use sorting::algorithm::merge_sort;
+///
+/// let mut values = [3, 1, 2];
+/// merge_sort(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn merge_sort<T: Ord>(values: &mut [T]) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    let mut scratch = order.clone();
+    merge_sort_positions(values, &mut order, &mut scratch);
+    apply_permutation(values, order);
+}
+
+/// Sorts `order`, a list of positions in `values`, by the values they point at.
+/// `scratch` must be as long as `order`.
+fn merge_sort_positions<T: Ord>(values: &[T], order: &mut [usize], scratch: &mut [usize]) {
+    if order.len() < 2 {
+        return;
+    }
+
+    let middle = order.len() / 2;
+    merge_sort_positions(values, &mut order[..middle], &mut scratch[..middle]);
+    merge_sort_positions(values, &mut order[middle..], &mut scratch[middle..]);
+
+    let (mut left, mut right) = (0, middle);
+    for slot in scratch.iter_mut() {
+        let take_left =
+            right == order.len() || (left < middle && values[order[left]] <= values[order[right]]);
+        if take_left {
+            *slot = order[left];
+            left += 1;
+        } else {
+            *slot = order[right];
+            right += 1;
+        }
+    }
+    order.copy_from_slice(scratch);
+}
+
+/// Rearranges `values` so that position `i` holds the value that was at `order[i]`.
+fn apply_permutation<T>(values: &mut [T], mut order: Vec<usize>) {
+    for start in 0..order.len() {
+        // Walk the cycle through `start`, pulling each value into place and marking
+        // the positions done by pointing them at themselves.
+        let mut current = start;
+        while order[current] != start {
+            let source = order[current];
+            values.swap(current, source);
+            order[current] = current;
+            current = source;
+        }
+        order[current] = current;
+    }
+}
+
+/// Sorts a slice in ascending order with quicksort, in O(n log n) on average.
+///
+/// The pivot is the median of the first, middle and last values, which keeps
+/// sorted and reverse-sorted input at O(n log n). Each partition splits the slice
+/// three ways, into values less than, equal to and greater than the pivot, so runs
+/// of duplicates are finished in one pass instead of degrading to O(n²). The
+/// smaller side is sorted recursively and the larger one in a loop, bounding the
+/// stack depth at O(log n). The worst case is still O(n²). Partitioning swaps
+/// values over long distances, so the sort is not stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use sorting::algorithm::quick_sort;
+///
+/// let mut values = [3, 1, 2];
+/// quick_sort(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn quick_sort<T: Ord>(mut values: &mut [T]) {
+    while values.len() > 1 {
+        let pivot = median_of_three(values);
+        values.swap(0, pivot);
+        let (less, greater) = partition(values);
+
+        let (smaller, larger) = {
+            let (rest, above) = values.split_at_mut(greater);
+            let below = &mut rest[..less];
+            if below.len() < above.len() {
+                (below, above)
+            } else {
+                (above, below)
+            }
+        };
+        quick_sort(smaller);
+        values = larger;
+    }
+}
+
+/// Returns the index of the median of the first, middle and last values.
+fn median_of_three<T: Ord>(values: &[T]) -> usize {
+    let (first, middle, last) = (0, values.len() / 2, values.len() - 1);
+    let (low, high) = match values[first].cmp(&values[last]) {
+        Ordering::Greater => (last, first),
+        _ => (first, last),
+    };
+
+    if values[middle] < values[low] {
+        low
+    } else if values[middle] > values[high] {
+        high
+    } else {
+        middle
+    }
+}
+
+/// Partitions `values` around the pivot at index 0 into values less than, equal to
+/// and greater than it.
+///
+/// # Returns
+///
+/// `(less, greater)` such that `values[..less]` is less than the pivot,
+/// `values[less..greater]` equals it and `values[greater..]` is greater.
+fn partition<T: Ord>(values: &mut [T]) -> (usize, usize) {
+    // `values[less]` is always a copy of the pivot, since `less..index` holds only
+    // values equal to it.
+    let (mut less, mut index, mut greater) = (0, 1, values.len());
+    while index < greater {
+        match values[index].cmp(&values[less]) {
+            Ordering::Less => {
+                values.swap(less, index);
+                less += 1;
+                index += 1;
+            }
+            Ordering::Greater => {
+                greater -= 1;
+                values.swap(index, greater);
+            }
+            Ordering::Equal => index += 1,
+        }
+    }
+    (less, greater)
+}
+
+/// Sorts a slice in ascending order with heapsort, in O(n log n) and O(1) extra
+/// space.
+///
+/// This delegates to [`binary_heap::algorithm::heapsort`]: the slice is arranged
+/// into a max-heap, then the top is repeatedly swapped behind the shrinking heap.
+/// The sort is not stable.
+///
+/// # Arguments
+///
+/// * `values` - The slice to sort.
+///
+/// # Examples
+///
+/// ```
+/// use sorting::algorithm::heap_sort;
+///
+/// let mut values = [3, 1, 2];
+/// heap_sort(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn heap_sort<T: Ord>(values: &mut [T]) {
+    binary_heap::algorithm::heapsort(values);
+}
+
+/// Sorts a [`KolzoLinkedList`] with one of the slice sorts.
+///
+/// The values are moved into a `Vec` with [`KolzoLinkedList::into_vec`], sorted
+/// there and moved back into a list that replaces the original, so the sort keeps
+/// the stability of `sort`.
+///
+/// # Arguments
+///
+/// * `list` - The list to sort.
+/// * `sort` - The algorithm to sort with, such as [`merge_sort`].
+///
+/// # Examples
+///
+/// ```
+/// use linked_list::algorithm::KolzoLinkedList;
+/// use sorting::algorithm::{merge_sort, sort_linked_list};
+///
+/// let mut list = KolzoLinkedList::new();
+/// list.append(3);
+/// list.append(1);
+/// list.append(2);
+///
+/// sort_linked_list(&mut list, merge_sort);
+/// assert_eq!(list.pop_first(), Some(1));
+/// assert_eq!(list.pop_first(), Some(2));
+/// assert_eq!(list.pop_first(), Some(3));
+/// ```
+pub fn sort_linked_list<T: Ord>(list: &mut KolzoLinkedList<T>, sort: fn(&mut [T])) {
+    let mut values = std::mem::take(list).into_vec();
+    sort(&mut values);
+    *list = KolzoLinkedList::from(values);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use list_common::linear::LinearList;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// A value ordered by `key` only, carrying its original position so stability
+    /// can be checked.
+    #[derive(Debug, Clone, Copy)]
+    struct Tagged {
+        key: i32,
+        position: usize,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    /// The name of an algorithm, the algorithm, and whether it is stable.
+    type Algorithm<T> = (&'static str, fn(&mut [T]), bool);
+
+    /// Every algorithm under test.
+    fn algorithms<T: Ord>() -> Vec<Algorithm<T>> {
+        vec![
+            ("bubble_sort", bubble_sort, true),
+            ("insertion_sort", insertion_sort, true),
+            ("selection_sort", selection_sort, false),
+            ("merge_sort", merge_sort, true),
+            ("quick_sort", quick_sort, false),
+            ("heap_sort", heap_sort, false),
+        ]
+    }
+
+    /// The input shapes every algorithm is checked against.
+    fn inputs(rng: &mut StdRng) -> Vec<(&'static str, Vec<i32>)> {
+        let random: Vec<i32> = (0..500).map(|_| rng.gen_range(-1000..1000)).collect();
+        let mut sorted = random.clone();
+        sorted.sort();
+        let reversed: Vec<i32> = sorted.iter().rev().copied().collect();
+        let duplicates: Vec<i32> = (0..500).map(|_| rng.gen_range(0..4)).collect();
+
+        vec![
+            ("empty", Vec::new()),
+            ("single", vec![7]),
+            ("random", random),
+            ("sorted", sorted),
+            ("reversed", reversed),
+            ("duplicates", duplicates),
+            ("all equal", vec![5; 200]),
+        ]
+    }
+
+    fn tag(keys: &[i32]) -> Vec<Tagged> {
+        keys.iter()
+            .enumerate()
+            .map(|(position, &key)| Tagged { key, position })
+            .collect()
+    }
+
+    fn is_stable(values: &[Tagged]) -> bool {
+        values
+            .windows(2)
+            .all(|pair| pair[0].key != pair[1].key || pair[0].position < pair[1].position)
+    }
+
+    #[test]
+    fn test_matches_slice_sort() {
+        let mut rng = StdRng::seed_from_u64(230);
+        for (input_name, input) in inputs(&mut rng) {
+            let mut expected = input.clone();
+            expected.sort();
+
+            for (name, sort, _) in algorithms::<i32>() {
+                let mut values = input.clone();
+                sort(&mut values);
+                assert_eq!(values, expected, "{} on {} input", name, input_name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stable_algorithms_keep_equal_values_in_order() {
+        let mut rng = StdRng::seed_from_u64(230);
+        for (input_name, input) in inputs(&mut rng) {
+            let mut expected = tag(&input);
+            expected.sort();
+
+            for (name, sort, stable) in algorithms::<Tagged>() {
+                let mut values = tag(&input);
+                sort(&mut values);
+                assert_eq!(values, expected, "{} on {} input", name, input_name);
+                if stable {
+                    assert!(is_stable(&values), "{} on {} input", name, input_name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unstable_algorithms_are_unstable() {
+        let mut rng = StdRng::seed_from_u64(230);
+        let duplicates: Vec<i32> = (0..100).map(|_| rng.gen_range(0..4)).collect();
+
+        for (name, sort, stable) in algorithms::<Tagged>() {
+            let mut values = tag(&duplicates);
+            sort(&mut values);
+            assert_eq!(is_stable(&values), stable, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut values = ['a', 'b', 'c', 'd', 'e'];
+        apply_permutation(&mut values, vec![2, 0, 1, 4, 3]);
+        assert_eq!(values, ['c', 'a', 'b', 'e', 'd']);
+    }
+
+    #[test]
+    fn test_sort_linked_list() {
+        for (name, sort, _) in algorithms::<i32>() {
+            let mut list = KolzoLinkedList::new();
+            for value in [5, 3, 9, 1, 3] {
+                list.append(value);
+            }
+
+            sort_linked_list(&mut list, sort);
+            assert_eq!(list.elements(), vec![&1, &3, &3, &5, &9], "{}", name);
+
+            // The rebuilt list must still accept appends at its tail.
+            list.append(10);
+            assert_eq!(list.elements().last(), Some(&&10), "{}", name);
+        }
+
+        let mut empty: KolzoLinkedList<i32> = KolzoLinkedList::new();
+        sort_linked_list(&mut empty, quick_sort);
+        assert!(empty.elements().is_empty());
+    }
+
+    #[test]
+    fn test_sort_linked_list_without_clone_or_debug() {
+        #[derive(PartialEq, Eq, PartialOrd, Ord)]
+        struct Plain(u8);
+
+        let mut list: KolzoLinkedList<Plain> = [3, 1, 2].map(Plain).into();
+        sort_linked_list(&mut list, heap_sort);
+        assert!(list.iter().map(|plain| plain.0).eq([1, 2, 3]));
+    }
+}
@@ -0,0 +1,34 @@
+use linked_list::algorithm::KolzoLinkedList;
+use sorting::algorithm::{
+    bubble_sort, heap_sort, insertion_sort, merge_sort, quick_sort, selection_sort,
+    sort_linked_list,
+};
+
+/// A sorting algorithm over a slice of `i32`.
+type Sort = fn(&mut [i32]);
+
+fn main() {
+    let input = [38, 27, 43, 3, 9, 82, 10, 3];
+    let algorithms: [(&str, Sort); 6] = [
+        ("BUBBLE", bubble_sort),
+        ("INSERTION", insertion_sort),
+        ("SELECTION", selection_sort),
+        ("MERGE", merge_sort),
+        ("QUICK", quick_sort),
+        ("HEAP", heap_sort),
+    ];
+
+    for (name, sort) in algorithms {
+        let mut values = input;
+        sort(&mut values);
+        println!("{} {:?}", name, values);
+    }
+
+    let mut list = KolzoLinkedList::new();
+    for value in input {
+        list.append(value);
+    }
+    sort_linked_list(&mut list, merge_sort);
+    print!("LIST ");
+    list.print();
+}
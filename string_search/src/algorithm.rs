@@ -0,0 +1,255 @@
+//! Substring search returning every match, including overlapping ones.
+//!
+//! # Offsets
+//!
+//! Both searches work on the UTF-8 bytes of the strings and report byte offsets
+//! into the haystack, the same offsets `str::find` and `str::match_indices` use,
+//! so `&haystack[offset..]` always starts with the needle. Because UTF-8 is
+//! self-synchronizing, a valid needle can only match a valid haystack at a char
+//! boundary, so every offset is a valid place to slice.
+//!
+//! Matches may overlap: searching for `"aaa"` in `"aaaaa"` finds `[0, 1, 2]`,
+//! where `match_indices` would stop at `[0]`. An empty needle matches at every
+//! char boundary of the haystack, including its end, as it does for
+//! `match_indices`. A needle longer than the haystack never matches.
+
+/// Computes the prefix function of the bytes of `pattern`.
+///
+/// Entry `i` is the length of the longest proper prefix of `pattern[..=i]` that is
+/// also a suffix of it. This is the table Knuth-Morris-Pratt uses to know how far
+/// it can fall back after a mismatch without rereading the haystack.
+///
+/// # Examples
+///
+/// ```
+/// use string_search::algorithm::prefix_function;
+///
+/// assert_eq!(prefix_function("abacaba"), vec![0, 0, 1, 0, 1, 2, 3]);
+/// assert_eq!(prefix_function("aaaa"), vec![0, 1, 2, 3]);
+/// assert!(prefix_function("").is_empty());
+/// ```
+pub fn prefix_function(pattern: &str) -> Vec<usize> {
+    let bytes = pattern.as_bytes();
+    let mut table = vec![0; bytes.len()];
+    for i in 1..bytes.len() {
+        // Try successively shorter borders of `bytes[..i]` until one extends.
+        let mut border = table[i - 1];
+        while border > 0 && bytes[i] != bytes[border] {
+            border = table[border - 1];
+        }
+        if bytes[i] == bytes[border] {
+            border += 1;
+        }
+        table[i] = border;
+    }
+    table
+}
+
+/// Finds every byte offset of `needle` in `haystack` with Knuth-Morris-Pratt, in
+/// O(n + m).
+///
+/// See the module documentation for how offsets, overlapping matches and empty
+/// needles are handled.
+///
+/// # Examples
+///
+/// ```
+/// use string_search::algorithm::kmp_find_all;
+///
+/// assert_eq!(kmp_find_all("aaaaa", "aaa"), vec![0, 1, 2]);
+/// assert_eq!(kmp_find_all("añoaño", "ño"), vec![1, 5]);
+/// assert_eq!(kmp_find_all("ab", "abc"), Vec::<usize>::new());
+/// ```
+pub fn kmp_find_all(haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return char_boundaries(haystack);
+    }
+
+    let table = prefix_function(needle);
+    let needle = needle.as_bytes();
+    let mut matches = Vec::new();
+    let mut matched = 0;
+    for (i, &byte) in haystack.as_bytes().iter().enumerate() {
+        while matched > 0 && byte != needle[matched] {
+            matched = table[matched - 1];
+        }
+        if byte == needle[matched] {
+            matched += 1;
+        }
+        if matched == needle.len() {
+            matches.push(i + 1 - needle.len());
+            // Fall back to the longest border so overlapping matches are found.
+            matched = table[matched - 1];
+        }
+    }
+    matches
+}
+
+/// The base of the rolling hash, one more than the largest byte.
+const BASE: u64 = 256;
+/// The prime modulus of the rolling hash. It is small enough that
+/// `(MODULUS - 1) * BASE + 255` fits in a `u64`.
+const MODULUS: u64 = 1_000_000_007;
+
+/// Finds every byte offset of `needle` in `haystack` with Rabin-Karp, in O(n + m)
+/// on average.
+///
+/// A polynomial hash of each window of the haystack is rolled forward one byte at
+/// a time and compared with the hash of the needle. Equal hashes are only a hint,
+/// so every candidate is verified byte by byte before it is reported, and a hash
+/// collision can cost time but never a wrong answer.
+///
+/// See the module documentation for how offsets, overlapping matches and empty
+/// needles are handled.
+///
+/// # Examples
+///
+/// ```
+/// use string_search::algorithm::rabin_karp_find_all;
+///
+/// assert_eq!(rabin_karp_find_all("abracadabra", "abra"), vec![0, 7]);
+/// assert_eq!(rabin_karp_find_all("aaaaa", "aaa"), vec![0, 1, 2]);
+/// ```
+pub fn rabin_karp_find_all(haystack: &str, needle: &str) -> Vec<usize> {
+    rabin_karp_with_modulus(haystack, needle, MODULUS)
+}
+
+/// Rabin-Karp with a given modulus, so tests can force hash collisions.
+fn rabin_karp_with_modulus(haystack: &str, needle: &str, modulus: u64) -> Vec<usize> {
+    if needle.is_empty() {
+        return char_boundaries(haystack);
+    }
+    let (haystack, needle) = (haystack.as_bytes(), needle.as_bytes());
+    if needle.len() > haystack.len() {
+        return Vec::new();
+    }
+
+    let hash = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .fold(0, |hash, &byte| (hash * BASE + u64::from(byte)) % modulus)
+    };
+    // The weight of the byte leaving the window, BASE^(m - 1).
+    let leading_weight = (1..needle.len()).fold(1, |weight, _| weight * BASE % modulus);
+
+    let target = hash(needle);
+    let mut window = hash(&haystack[..needle.len()]);
+    let mut matches = Vec::new();
+    for start in 0..=haystack.len() - needle.len() {
+        if window == target && &haystack[start..start + needle.len()] == needle {
+            matches.push(start);
+        }
+
+        if let Some(&incoming) = haystack.get(start + needle.len()) {
+            let outgoing = u64::from(haystack[start]) * leading_weight % modulus;
+            window = ((window + modulus - outgoing) * BASE + u64::from(incoming)) % modulus;
+        }
+    }
+    matches
+}
+
+/// Returns every char boundary of `text`, where an empty needle matches.
+fn char_boundaries(text: &str) -> Vec<usize> {
+    text.char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(text.len()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Tries the needle at every char boundary, allowing overlaps.
+    fn naive_find_all(haystack: &str, needle: &str) -> Vec<usize> {
+        (0..=haystack.len())
+            .filter(|&offset| {
+                haystack.is_char_boundary(offset) && haystack[offset..].starts_with(needle)
+            })
+            .collect()
+    }
+
+    /// Runs both algorithms and checks that they agree before returning the result.
+    fn find_all(haystack: &str, needle: &str) -> Vec<usize> {
+        let kmp = kmp_find_all(haystack, needle);
+        assert_eq!(kmp, rabin_karp_find_all(haystack, needle));
+        kmp
+    }
+
+    #[test]
+    fn test_overlapping_matches() {
+        assert_eq!(find_all("aaaaa", "aaa"), vec![0, 1, 2]);
+        assert_eq!(find_all("abababa", "aba"), vec![0, 2, 4]);
+        assert_eq!(find_all("aaaaa", "a"), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert!(find_all("abc", "abcd").is_empty());
+        assert!(find_all("", "a").is_empty());
+        assert!(find_all("abcabc", "cb").is_empty());
+    }
+
+    #[test]
+    fn test_empty_needle_matches_every_char_boundary() {
+        assert_eq!(find_all("", ""), vec![0]);
+        assert_eq!(find_all("abc", ""), vec![0, 1, 2, 3]);
+        assert_eq!(find_all("añb", ""), vec![0, 1, 3, 4]);
+        assert_eq!(
+            find_all("añb", ""),
+            "añb"
+                .match_indices("")
+                .map(|(offset, _)| offset)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_multi_byte_offsets() {
+        let haystack = "€a€ab€";
+        let matches = find_all(haystack, "€a");
+        assert_eq!(matches, vec![0, 4]);
+        for offset in matches {
+            assert!(haystack[offset..].starts_with("€a"));
+        }
+
+        // `é` and `è` share their first byte, which must not produce a match.
+        assert!(find_all("è", "é").is_empty());
+        assert_eq!(find_all("éèé", "é"), vec![0, 4]);
+    }
+
+    #[test]
+    fn test_prefix_function() {
+        assert_eq!(prefix_function("a"), vec![0]);
+        assert_eq!(prefix_function("abcab"), vec![0, 0, 0, 1, 2]);
+        assert_eq!(prefix_function("aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_rabin_karp_verifies_collisions() {
+        // With a modulus of 1 every window collides, so only verification keeps
+        // the answers right.
+        for (haystack, needle) in [("abcabcab", "cab"), ("aaaaa", "aa"), ("xyz", "q")] {
+            assert_eq!(
+                rabin_karp_with_modulus(haystack, needle, 1),
+                naive_find_all(haystack, needle)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive_search(haystack in "[ab€é]{0,40}", needle in "[ab€é]{0,4}") {
+            let expected = naive_find_all(&haystack, &needle);
+            prop_assert_eq!(kmp_find_all(&haystack, &needle), expected.clone());
+            prop_assert_eq!(rabin_karp_find_all(&haystack, &needle), expected.clone());
+            prop_assert_eq!(rabin_karp_with_modulus(&haystack, &needle, 7), expected);
+        }
+
+        #[test]
+        fn test_first_match_agrees_with_find(haystack in "[ab]{0,30}", needle in "[ab]{1,3}") {
+            prop_assert_eq!(kmp_find_all(&haystack, &needle).first().copied(), haystack.find(&needle));
+        }
+    }
+}
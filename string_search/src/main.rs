@@ -0,0 +1,12 @@
+use string_search::algorithm::{kmp_find_all, prefix_function, rabin_karp_find_all};
+
+fn main() {
+    println!("PREFIX FUNCTION {:?}", prefix_function("abacaba"));
+    println!("KMP {:?}", kmp_find_all("aaaaa", "aaa"));
+    println!(
+        "RABIN-KARP {:?}",
+        rabin_karp_find_all("abracadabra", "abra")
+    );
+    println!("MULTI-BYTE {:?}", kmp_find_all("naïve naïve", "ïv"));
+    println!("EMPTY NEEDLE {:?}", rabin_karp_find_all("añb", ""));
+}
@@ -0,0 +1,333 @@
+//! The operation model shared by the differential and conformance tests.
+//!
+//! Every list type is driven through [`TestList::apply`], which maps an
+//! [`Operation`] onto that list's own API and reports what it produced as an
+//! [`Outcome`]. Two lists, or a list and the `VecDeque` model, agree on a stream of
+//! operations when every outcome, every length and the final contents match.
+
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, RngAlgorithm, TestError, TestRng, TestRunner};
+use sentinel_list::algorithm::KolzoSentinelList;
+use std::collections::VecDeque;
+
+/// The number of operation streams generated per run.
+const CASES: u32 = 512;
+/// The largest number of operations in a single stream.
+const MAX_OPERATIONS: usize = 64;
+/// Indices are drawn from this range so that out-of-bounds and negative indices show up regularly.
+const INDEX_RANGE: std::ops::Range<i64> = -2..12;
+
+/// An operation from the API shared by the list types.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Append(i32),
+    Prepend(i32),
+    PopBack,
+    PopFront,
+    Insert(i64, i32),
+    Remove(i64),
+    Get(i64),
+    Reverse,
+}
+
+fn operation_strategy() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        any::<i32>().prop_map(Operation::Append),
+        any::<i32>().prop_map(Operation::Prepend),
+        Just(Operation::PopBack),
+        Just(Operation::PopFront),
+        (INDEX_RANGE, any::<i32>()).prop_map(|(index, value)| Operation::Insert(index, value)),
+        INDEX_RANGE.prop_map(Operation::Remove),
+        INDEX_RANGE.prop_map(Operation::Get),
+        Just(Operation::Reverse),
+    ]
+}
+
+/// What an operation produced, so that every list's answer can be compared.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    /// The operation returns nothing.
+    Done,
+    /// The value returned by a pop, `remove` or `get`.
+    Value(Option<i32>),
+    /// Whether `insert` accepted the index.
+    Inserted(bool),
+}
+
+/// A list that can be driven by generated [`Operation`]s.
+pub trait TestList: Default {
+    /// Applies `operation` and returns what it produced, or `None` if the list has
+    /// no such operation, in which case the list must be left unchanged.
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome>;
+
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize;
+
+    /// Collects the elements of the list from front to back.
+    fn contents(&self) -> Vec<i32>;
+
+    /// Checks the list's internal links, for lists that can validate them.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// The reference model: `VecDeque` with the lists' rules for out-of-bounds and
+/// negative indices, which are rejected without changing the list.
+impl TestList for VecDeque<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        let index = |index: i64| usize::try_from(index).ok();
+        Some(match *operation {
+            Operation::Append(value) => {
+                self.push_back(value);
+                Outcome::Done
+            }
+            Operation::Prepend(value) => {
+                self.push_front(value);
+                Outcome::Done
+            }
+            Operation::PopBack => Outcome::Value(self.pop_back()),
+            Operation::PopFront => Outcome::Value(self.pop_front()),
+            Operation::Insert(position, value) => Outcome::Inserted(
+                match index(position).filter(|&position| position <= self.len()) {
+                    Some(position) => {
+                        self.insert(position, value);
+                        true
+                    }
+                    None => false,
+                },
+            ),
+            Operation::Remove(position) => {
+                Outcome::Value(index(position).and_then(|position| self.remove(position)))
+            }
+            Operation::Get(position) => {
+                Outcome::Value(index(position).and_then(|position| self.get(position).copied()))
+            }
+            Operation::Reverse => {
+                self.make_contiguous().reverse();
+                Outcome::Done
+            }
+        })
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+}
+
+/// `KolzoLinkedList` takes `usize` indices, so a negative index cannot be passed
+/// and is answered with the rejection the other lists give it.
+impl TestList for KolzoLinkedList<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        Some(match *operation {
+            Operation::Append(value) => {
+                self.append(value);
+                Outcome::Done
+            }
+            Operation::Prepend(value) => {
+                self.prepend(value);
+                Outcome::Done
+            }
+            Operation::PopBack => Outcome::Value(self.pop()),
+            Operation::PopFront => Outcome::Value(self.pop_first()),
+            Operation::Insert(index, value) => Outcome::Inserted(
+                usize::try_from(index).is_ok_and(|index| self.insert(index, value)),
+            ),
+            Operation::Remove(index) => Outcome::Value(
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.remove(index)),
+            ),
+            Operation::Get(index) => Outcome::Value(
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.get(index).copied()),
+            ),
+            Operation::Reverse => {
+                self.reverse();
+                Outcome::Done
+            }
+        })
+    }
+
+    fn len(&self) -> usize {
+        KolzoLinkedList::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+}
+
+impl TestList for KolzoDoublyLinkedList<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        Some(match *operation {
+            Operation::Append(value) => {
+                self.append(value);
+                Outcome::Done
+            }
+            Operation::Prepend(value) => {
+                self.prepend(value);
+                Outcome::Done
+            }
+            Operation::PopBack => Outcome::Value(self.pop()),
+            Operation::PopFront => Outcome::Value(self.pop_first()),
+            Operation::Insert(index, value) => Outcome::Inserted(self.insert(index, value)),
+            Operation::Remove(index) => Outcome::Value(self.remove(index)),
+            Operation::Get(index) => Outcome::Value(self.get(index).copied()),
+            Operation::Reverse => {
+                self.reverse();
+                Outcome::Done
+            }
+        })
+    }
+
+    fn len(&self) -> usize {
+        KolzoDoublyLinkedList::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+}
+
+impl TestList for KolzoSentinelList<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        Some(match *operation {
+            Operation::Append(value) => {
+                self.append(value);
+                Outcome::Done
+            }
+            Operation::Prepend(value) => {
+                self.prepend(value);
+                Outcome::Done
+            }
+            Operation::PopBack => Outcome::Value(self.pop()),
+            Operation::PopFront => Outcome::Value(self.pop_first()),
+            Operation::Insert(index, value) => Outcome::Inserted(self.insert(index, value)),
+            Operation::Remove(index) => Outcome::Value(self.remove(index)),
+            Operation::Get(index) => Outcome::Value(self.get(index).copied()),
+            Operation::Reverse => {
+                self.reverse();
+                Outcome::Done
+            }
+        })
+    }
+
+    fn len(&self) -> usize {
+        KolzoSentinelList::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        KolzoSentinelList::validate(self)
+    }
+}
+
+/// Replays `operations` against `L` and the reference list `R` and fails at the
+/// first step where they disagree or where `L`'s links stop validating.
+///
+/// Operations that `L` does not support are skipped for both lists.
+pub fn check_agreement<L: TestList, R: TestList>(
+    operations: &[Operation],
+) -> Result<(), TestCaseError> {
+    let mut list = L::default();
+    let mut reference = R::default();
+
+    for (step, operation) in operations.iter().enumerate() {
+        let Some(result) = list.apply(operation) else {
+            continue;
+        };
+        let reference_result = reference
+            .apply(operation)
+            .expect("the reference list supports every operation");
+
+        prop_assert_eq!(
+            result,
+            reference_result,
+            "step {} ({:?}) returned different values",
+            step,
+            operation
+        );
+        prop_assert_eq!(
+            list.len(),
+            reference.len(),
+            "step {} ({:?}) left the lists with different lengths",
+            step,
+            operation
+        );
+        prop_assert_eq!(
+            list.contents(),
+            reference.contents(),
+            "step {} ({:?}) left the lists with different contents",
+            step,
+            operation
+        );
+        prop_assert_eq!(
+            list.validate(),
+            Ok(()),
+            "step {} ({:?}) broke the list's links",
+            step,
+            operation
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads the seed from `DIFFERENTIAL_SEED`, falling back to the current time.
+fn seed() -> u64 {
+    match std::env::var("DIFFERENTIAL_SEED") {
+        Ok(seed) => seed
+            .parse()
+            .expect("DIFFERENTIAL_SEED must be an unsigned integer"),
+        Err(_) => std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or_default(),
+    }
+}
+
+/// Runs `check` on generated operation streams and panics with the seed and the
+/// minimal failing sequence if any stream fails.
+pub fn run_differential(check: fn(&[Operation]) -> Result<(), TestCaseError>) {
+    let seed = seed();
+    let mut seed_bytes = [0u8; 32];
+    seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
+
+    let config = Config {
+        cases: CASES,
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let mut runner = TestRunner::new_with_rng(
+        config,
+        TestRng::from_seed(RngAlgorithm::ChaCha, &seed_bytes),
+    );
+
+    let result = runner.run(
+        &vec(operation_strategy(), 0..MAX_OPERATIONS),
+        |operations| check(&operations),
+    );
+
+    match result {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, operations)) => panic!(
+            "lists diverged with seed {seed} (rerun with DIFFERENTIAL_SEED={seed}): {reason}\n\
+             minimal operation sequence: {operations:?}"
+        ),
+        Err(TestError::Abort(reason)) => {
+            panic!("differential run with seed {seed} aborted: {reason}")
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Conformance testing of every list type against a `VecDeque` model.
+//!
+//! [`conformance_tests!`] generates the same tests for each list: a set of fixed
+//! edge cases around empty lists, single elements and both ends, and a run of
+//! generated operation streams. Every step compares the list's returned values,
+//! length and contents with the model, so a list that rejects an index the others
+//! accept, or loses an element, fails at the step where it happened. Each list
+//! only runs the operations its API offers.
+//!
+//! The generated runs honour `DIFFERENTIAL_SEED` like the differential tests.
+
+mod common;
+
+use common::{check_agreement, run_differential, Operation, Outcome, TestList};
+use deque::algorithm::KolzoDeque;
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
+use sentinel_list::algorithm::KolzoSentinelList;
+use std::collections::VecDeque;
+use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+use xor_linked_list::algorithm::KolzoXorLinkedList;
+
+/// `KolzoUnrolledLinkedList` has no pops or reversal, and its `insert` panics on
+/// an out-of-bounds index, so the bounds check is done here and reported as the
+/// rejection the other lists give.
+impl TestList for KolzoUnrolledLinkedList<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        match *operation {
+            Operation::Append(value) => {
+                self.push_back(value);
+                Some(Outcome::Done)
+            }
+            Operation::Prepend(value) => {
+                self.push_front(value);
+                Some(Outcome::Done)
+            }
+            Operation::Insert(index, value) => {
+                let index = usize::try_from(index)
+                    .ok()
+                    .filter(|&index| index <= self.len());
+                if let Some(index) = index {
+                    self.insert(index, value);
+                }
+                Some(Outcome::Inserted(index.is_some()))
+            }
+            Operation::Remove(index) => Some(Outcome::Value(
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.remove(index)),
+            )),
+            Operation::Get(index) => Some(Outcome::Value(
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.get(index).copied()),
+            )),
+            Operation::PopBack | Operation::PopFront | Operation::Reverse => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        KolzoUnrolledLinkedList::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+}
+
+/// `KolzoXorLinkedList` only offers the operations at its two ends.
+impl TestList for KolzoXorLinkedList<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        match *operation {
+            Operation::Append(value) => {
+                self.push_back(value);
+                Some(Outcome::Done)
+            }
+            Operation::Prepend(value) => {
+                self.push_front(value);
+                Some(Outcome::Done)
+            }
+            Operation::PopBack => Some(Outcome::Value(self.pop_back())),
+            Operation::PopFront => Some(Outcome::Value(self.pop_front())),
+            _ => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        KolzoXorLinkedList::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        KolzoXorLinkedList::validate(self)
+    }
+}
+
+/// `KolzoDeque` only offers the operations at its two ends.
+impl TestList for KolzoDeque<i32> {
+    fn apply(&mut self, operation: &Operation) -> Option<Outcome> {
+        match *operation {
+            Operation::Append(value) => {
+                self.push_back(value);
+                Some(Outcome::Done)
+            }
+            Operation::Prepend(value) => {
+                self.push_front(value);
+                Some(Outcome::Done)
+            }
+            Operation::PopBack => Some(Outcome::Value(self.pop_back())),
+            Operation::PopFront => Some(Outcome::Value(self.pop_front())),
+            _ => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        KolzoDeque::len(self)
+    }
+
+    fn contents(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+}
+
+/// Returns operation sequences that walk the edge cases every list must handle
+/// the same way.
+fn edge_cases() -> Vec<Vec<Operation>> {
+    use Operation::*;
+
+    let mut long_run: Vec<Operation> = (0..40).map(Append).collect();
+    long_run.extend([
+        Insert(17, -1),
+        Insert(40, -2),
+        Remove(16),
+        Remove(39),
+        Get(20),
+    ]);
+    long_run.extend((0..45).map(|step| if step % 2 == 0 { PopFront } else { PopBack }));
+    long_run.extend((0..40).map(|index| Remove(39 - index)));
+
+    vec![
+        vec![
+            PopBack,
+            PopFront,
+            Get(0),
+            Remove(0),
+            Remove(-1),
+            Insert(1, 1),
+            Insert(-1, 1),
+            Reverse,
+        ],
+        vec![
+            Append(1),
+            Get(0),
+            Get(1),
+            Reverse,
+            PopBack,
+            PopFront,
+            Prepend(2),
+            PopFront,
+            PopBack,
+        ],
+        vec![
+            Insert(0, 2),
+            Insert(1, 4),
+            Insert(0, 1),
+            Insert(2, 3),
+            Insert(5, 9),
+            Insert(4, 5),
+            Reverse,
+            Remove(4),
+            Remove(0),
+            Remove(1),
+            Remove(3),
+            Get(-1),
+        ],
+        long_run,
+    ]
+}
+
+/// Generates a module of conformance tests for each `module: ListType` pair.
+macro_rules! conformance_tests {
+    ($($module:ident: $list:ty),* $(,)?) => {
+        $(
+            mod $module {
+                use super::*;
+
+                #[test]
+                fn test_edge_cases_match_the_model() {
+                    for operations in edge_cases() {
+                        if let Err(error) =
+                            check_agreement::<$list, VecDeque<i32>>(&operations)
+                        {
+                            panic!("{error}\noperation sequence: {operations:?}");
+                        }
+                    }
+                }
+
+                #[test]
+                fn test_generated_operations_match_the_model() {
+                    run_differential(check_agreement::<$list, VecDeque<i32>>);
+                }
+            }
+        )*
+    };
+}
+
+conformance_tests! {
+    singly: KolzoLinkedList<i32>,
+    doubly: KolzoDoublyLinkedList<i32>,
+    sentinel: KolzoSentinelList<i32>,
+    unrolled: KolzoUnrolledLinkedList<i32>,
+    xor: KolzoXorLinkedList<i32>,
+    deque_list: KolzoDeque<i32>,
+}
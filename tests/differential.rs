@@ -0,0 +1,27 @@
+//! Differential testing of the linked lists.
+//!
+//! One randomly generated stream of operations from the API the lists share is
+//! replayed against `KolzoDoublyLinkedList` and each of the other lists,
+//! `KolzoLinkedList` and `KolzoSentinelList`. After every operation the returned
+//! values, the lengths and the full contents of both lists must agree, so a
+//! divergence points straight at the step where one implementation went wrong.
+//!
+//! Failing streams are shrunk to a minimal sequence. The failure message reports
+//! the seed of the run; set `DIFFERENTIAL_SEED` to that value to replay it.
+
+mod common;
+
+use common::{check_agreement, run_differential};
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
+use sentinel_list::algorithm::KolzoSentinelList;
+
+#[test]
+fn test_singly_and_doubly_lists_agree() {
+    run_differential(check_agreement::<KolzoLinkedList<i32>, KolzoDoublyLinkedList<i32>>);
+}
+
+#[test]
+fn test_doubly_and_sentinel_lists_agree() {
+    run_differential(check_agreement::<KolzoSentinelList<i32>, KolzoDoublyLinkedList<i32>>);
+}
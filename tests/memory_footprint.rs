@@ -0,0 +1,20 @@
+use double_linked_list::algorithm::KolzoDoublyLinkedList;
+use linked_list::algorithm::KolzoLinkedList;
+
+#[test]
+fn test_doubly_list_nodes_are_larger_than_singly_list_nodes() {
+    let mut singly = KolzoLinkedList::new();
+    let mut doubly = KolzoDoublyLinkedList::new();
+    for value in 0..100i32 {
+        singly.append(value);
+        doubly.append(value);
+    }
+
+    let singly_report = singly.memory_footprint();
+    let doubly_report = doubly.memory_footprint();
+
+    assert_eq!(singly_report.payload_bytes, doubly_report.payload_bytes);
+    assert!(doubly_report.bytes_per_node > singly_report.bytes_per_node);
+    assert!(doubly_report.total_bytes > singly_report.total_bytes);
+    assert!(doubly_report.overhead_bytes() > singly_report.overhead_bytes());
+}
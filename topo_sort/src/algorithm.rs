@@ -0,0 +1,296 @@
+use graph::algorithm::{KolzoGraph, NodeId};
+use queue::algorithm::KolzoQueue;
+use std::fmt;
+
+/// The error returned by [`topological_sort`] when the graph has a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// The nodes of one cycle, each with an edge to the next and the last with an
+    /// edge back to the first.
+    cycle: Vec<NodeId>,
+}
+
+impl CycleError {
+    /// Returns the nodes of one cycle in the graph, in edge order. A self-loop is a
+    /// cycle of one node.
+    pub fn cycle(&self) -> &[NodeId] {
+        &self.cycle
+    }
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle through nodes")?;
+        for node in self.cycle.iter().chain(self.cycle.first()) {
+            write!(f, " {}", node.index())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// Orders the nodes of a directed graph so that every edge points forward, using
+/// Kahn's algorithm.
+///
+/// Nodes without incoming edges are queued on a [`KolzoQueue`]. Each dequeued node
+/// is appended to the ordering and its edges are removed, which queues every
+/// neighbor whose last incoming edge that was. Ties are broken by insertion order.
+/// This runs in O(V + E).
+///
+/// In an undirected graph every edge can be followed both ways, so any edge forms
+/// a cycle.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sort.
+///
+/// # Returns
+///
+/// * `Ok(Vec<NodeId>)` with every node, ordered so that each edge goes from an
+///   earlier node to a later one.
+/// * `Err(CycleError)` naming the nodes of one cycle if no such order exists.
+///
+/// # Examples
+///
+/// ```
+/// use graph::algorithm::KolzoGraph;
+/// use topo_sort::algorithm::topological_sort;
+///
+/// let mut graph = KolzoGraph::directed();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge(b, a);
+/// graph.add_edge(a, c);
+///
+/// assert_eq!(topological_sort(&graph), Ok(vec![b, a, c]));
+///
+/// graph.add_edge(c, b);
+/// let error = topological_sort(&graph).unwrap_err();
+/// assert_eq!(error.cycle().len(), 3);
+/// ```
+pub fn topological_sort<N>(graph: &KolzoGraph<N>) -> Result<Vec<NodeId>, CycleError> {
+    let mut in_degree = vec![0usize; graph.node_count()];
+    for node in graph.node_ids() {
+        for &neighbor in graph.neighbors(node) {
+            in_degree[neighbor.index()] += 1;
+        }
+    }
+
+    let mut frontier = KolzoQueue::new();
+    for node in graph.node_ids() {
+        if in_degree[node.index()] == 0 {
+            frontier.enqueue(node);
+        }
+    }
+
+    let mut order = Vec::with_capacity(graph.node_count());
+    while let Some(node) = frontier.dequeue() {
+        order.push(node);
+        for &neighbor in graph.neighbors(node) {
+            in_degree[neighbor.index()] -= 1;
+            if in_degree[neighbor.index()] == 0 {
+                frontier.enqueue(neighbor);
+            }
+        }
+    }
+
+    if order.len() == graph.node_count() {
+        Ok(order)
+    } else {
+        Err(CycleError {
+            cycle: find_cycle(graph, &in_degree),
+        })
+    }
+}
+
+/// Finds a cycle among the nodes Kahn's algorithm could not order, which are the
+/// nodes left with a non-zero in-degree.
+///
+/// Every such node has an incoming edge from another such node, so walking those
+/// edges backwards never gets stuck. After as many steps as there are nodes the
+/// walk must have entered a cycle; walking on until a node repeats traces it.
+fn find_cycle<N>(graph: &KolzoGraph<N>, in_degree: &[usize]) -> Vec<NodeId> {
+    let blocked = |node: NodeId| in_degree[node.index()] > 0;
+
+    let mut predecessor = vec![None; graph.node_count()];
+    for node in graph.node_ids().filter(|&node| blocked(node)) {
+        for &neighbor in graph.neighbors(node) {
+            if blocked(neighbor) {
+                predecessor[neighbor.index()].get_or_insert(node);
+            }
+        }
+    }
+
+    let back = |node: NodeId| -> NodeId {
+        predecessor[node.index()].expect("a blocked node has a blocked predecessor")
+    };
+
+    let mut current = graph
+        .node_ids()
+        .find(|&node| blocked(node))
+        .expect("an incomplete ordering leaves a blocked node");
+    for _ in 0..graph.node_count() {
+        current = back(current);
+    }
+
+    let mut cycle = vec![current];
+    let mut node = back(current);
+    while node != current {
+        cycle.push(node);
+        node = back(node);
+    }
+
+    // The walk followed edges backwards.
+    cycle.reverse();
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Asserts that `order` holds every node once and that every edge points forward.
+    fn assert_valid_order<N>(graph: &KolzoGraph<N>, order: &[NodeId]) {
+        let mut position = vec![None; graph.node_count()];
+        for (index, node) in order.iter().enumerate() {
+            assert!(position[node.index()].is_none(), "node listed twice");
+            position[node.index()] = Some(index);
+        }
+        assert_eq!(order.len(), graph.node_count());
+
+        for node in graph.node_ids() {
+            for &neighbor in graph.neighbors(node) {
+                assert!(position[node.index()] < position[neighbor.index()]);
+            }
+        }
+    }
+
+    /// Asserts that consecutive nodes of `cycle`, wrapping around, are joined by edges.
+    fn assert_is_cycle<N>(graph: &KolzoGraph<N>, cycle: &[NodeId]) {
+        assert!(!cycle.is_empty());
+        for (index, &node) in cycle.iter().enumerate() {
+            let next = cycle[(index + 1) % cycle.len()];
+            assert!(graph.neighbors(node).contains(&next));
+        }
+    }
+
+    #[test]
+    fn test_known_dag() {
+        let mut graph = KolzoGraph::directed();
+        let ids: Vec<_> = (0..6).map(|value| graph.add_node(value)).collect();
+        for (from, to) in [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)] {
+            graph.add_edge(ids[from], ids[to]);
+        }
+
+        let order = topological_sort(&graph).unwrap();
+        assert_valid_order(&graph, &order);
+        assert_eq!(order, vec![ids[4], ids[5], ids[2], ids[0], ids[3], ids[1]]);
+    }
+
+    #[test]
+    fn test_cycle_is_reported() {
+        // 0 -> 1 -> 2 -> 3 -> 1, with 4 hanging off the cycle.
+        let mut graph = KolzoGraph::directed();
+        let ids: Vec<_> = (0..5).map(|value| graph.add_node(value)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)] {
+            graph.add_edge(ids[from], ids[to]);
+        }
+
+        let error = topological_sort(&graph).unwrap_err();
+        assert_is_cycle(&graph, error.cycle());
+        assert_eq!(error.cycle().len(), 3);
+        assert!(!error.cycle().contains(&ids[0]));
+        assert!(!error.cycle().contains(&ids[4]));
+        assert!(error.to_string().starts_with("graph contains a cycle"));
+    }
+
+    #[test]
+    fn test_self_loop_is_a_cycle() {
+        let mut graph = KolzoGraph::directed();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        graph.add_edge(a, b);
+        graph.add_edge(b, b);
+
+        let error = topological_sort(&graph).unwrap_err();
+        assert_eq!(error.cycle(), &[b]);
+        assert_eq!(
+            error.to_string(),
+            "graph contains a cycle through nodes 1 1"
+        );
+    }
+
+    #[test]
+    fn test_empty_and_disconnected_graphs() {
+        let empty: KolzoGraph<()> = KolzoGraph::directed();
+        assert_eq!(topological_sort(&empty), Ok(Vec::new()));
+
+        let mut graph = KolzoGraph::directed();
+        let ids: Vec<_> = (0..5).map(|value| graph.add_node(value)).collect();
+        graph.add_edge(ids[3], ids[1]);
+
+        let order = topological_sort(&graph).unwrap();
+        assert_valid_order(&graph, &order);
+        assert_eq!(order, vec![ids[0], ids[2], ids[3], ids[4], ids[1]]);
+    }
+
+    #[test]
+    fn test_undirected_edge_is_a_cycle() {
+        let mut graph = KolzoGraph::undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        graph.add_edge(a, b);
+
+        let error = topological_sort(&graph).unwrap_err();
+        assert_is_cycle(&graph, error.cycle());
+    }
+
+    /// Generates a DAG: a node count, a relabelling of the nodes, and edges that go
+    /// from a lower to a higher position in that relabelling.
+    fn dag_strategy() -> impl Strategy<Value = (Vec<usize>, Vec<(usize, usize)>)> {
+        (1usize..20).prop_flat_map(|nodes| {
+            let labels = Just((0..nodes).collect::<Vec<_>>()).prop_shuffle();
+            let edges = proptest::collection::vec((0..nodes, 0..nodes), 0..60);
+            (labels, edges)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_every_edge_goes_forward((labels, edges) in dag_strategy()) {
+            let mut graph = KolzoGraph::directed();
+            let ids: Vec<_> = (0..labels.len()).map(|value| graph.add_node(value)).collect();
+            for (first, second) in edges {
+                if first != second {
+                    let (low, high) = (first.min(second), first.max(second));
+                    graph.add_edge(ids[labels[low]], ids[labels[high]]);
+                }
+            }
+
+            let order = topological_sort(&graph);
+            prop_assert!(order.is_ok());
+            assert_valid_order(&graph, &order.unwrap_or_default());
+        }
+
+        #[test]
+        fn test_cycles_are_found((labels, edges) in dag_strategy(), extra in any::<prop::sample::Index>()) {
+            let mut graph = KolzoGraph::directed();
+            let ids: Vec<_> = (0..labels.len()).map(|value| graph.add_node(value)).collect();
+            for &(first, second) in &edges {
+                graph.add_edge(ids[labels[first]], ids[labels[second]]);
+            }
+            // Close a cycle for certain: a self-loop on one node.
+            let looped = ids[extra.index(ids.len())];
+            graph.add_edge(looped, looped);
+
+            let error = topological_sort(&graph);
+            prop_assert!(error.is_err());
+            if let Err(error) = error {
+                assert_is_cycle(&graph, error.cycle());
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use graph::algorithm::KolzoGraph;
+use topo_sort::algorithm::topological_sort;
+
+fn main() {
+    let mut graph = KolzoGraph::directed();
+    let shirt = graph.add_node("shirt");
+    let tie = graph.add_node("tie");
+    let jacket = graph.add_node("jacket");
+    let trousers = graph.add_node("trousers");
+    let belt = graph.add_node("belt");
+    graph.add_edge(shirt, tie);
+    graph.add_edge(tie, jacket);
+    graph.add_edge(trousers, belt);
+    graph.add_edge(belt, jacket);
+    graph.add_edge(shirt, belt);
+
+    match topological_sort(&graph) {
+        Ok(order) => {
+            let names: Vec<_> = order.iter().map(|id| *graph.node(*id).unwrap()).collect();
+            println!("ORDER {:?}", names);
+        }
+        Err(error) => println!("{error}"),
+    }
+
+    graph.add_edge(jacket, shirt);
+    match topological_sort(&graph) {
+        Ok(order) => println!("ORDER {:?}", order),
+        Err(error) => println!("{error}"),
+    }
+}
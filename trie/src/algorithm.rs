@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+
+/// A node of the trie. The path from the root spells a prefix.
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// The children of the node, one per next character.
+    children: HashMap<char, Node>,
+    /// Whether the prefix spelled by this node is a stored key.
+    terminal: bool,
+}
+
+/// A set of strings stored as a prefix tree.
+///
+/// Each edge is labelled with one `char`, so any Unicode text can be stored and
+/// lookups take O(k) for a key of k characters regardless of how many keys are
+/// stored.
+///
+/// The empty string is an ordinary key: it is stored on the root and can be
+/// inserted, looked up and removed like any other. Every key starts with the empty
+/// prefix, so `starts_with("")` is `true` exactly when the trie is not empty.
+#[derive(Debug, Clone)]
+pub struct KolzoTrie {
+    /// The node for the empty prefix.
+    root: Node,
+    /// The number of keys stored.
+    length: usize,
+}
+
+impl KolzoTrie {
+    /// Creates a new empty trie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trie::algorithm::KolzoTrie;
+    ///
+    /// let trie = KolzoTrie::new();
+    /// assert!(trie.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoTrie {
+            root: Node::default(),
+            length: 0,
+        }
+    }
+
+    /// Adds a key to the trie.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to add.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the key was not stored yet.
+    /// * `false` if the key was already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trie::algorithm::KolzoTrie;
+    ///
+    /// let mut trie = KolzoTrie::new();
+    /// assert!(trie.insert("car"));
+    /// assert!(!trie.insert("car"));
+    /// assert_eq!(trie.len(), 1);
+    /// ```
+    pub fn insert(&mut self, key: &str) -> bool {
+        let mut node = &mut self.root;
+        for character in key.chars() {
+            node = node.children.entry(character).or_default();
+        }
+
+        let added = !node.terminal;
+        node.terminal = true;
+        if added {
+            self.length += 1;
+        }
+        added
+    }
+
+    /// Returns `true` if `key` is stored in the trie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trie::algorithm::KolzoTrie;
+    ///
+    /// let trie: KolzoTrie = ["cart"].into_iter().collect();
+    /// assert!(trie.contains("cart"));
+    /// assert!(!trie.contains("car"));
+    /// ```
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some_and(|node| node.terminal)
+    }
+
+    /// Removes `key` from the trie.
+    ///
+    /// Nodes that no longer lead to any key are pruned, while nodes shared with
+    /// other keys are kept.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the key was present and has been removed.
+    /// * `false` if the key was not in the trie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trie::algorithm::KolzoTrie;
+    ///
+    /// let mut trie: KolzoTrie = ["car", "cart"].into_iter().collect();
+    /// assert!(trie.remove("car"));
+    /// assert!(!trie.remove("car"));
+    /// assert!(trie.contains("cart"));
+    /// ```
+    pub fn remove(&mut self, key: &str) -> bool {
+        let characters: Vec<char> = key.chars().collect();
+        let removed = remove_from(&mut self.root, &characters);
+        if removed {
+            self.length -= 1;
+        }
+        removed
+    }
+
+    /// Returns `true` if some stored key starts with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trie::algorithm::KolzoTrie;
+    ///
+    /// let trie: KolzoTrie = ["cart"].into_iter().collect();
+    /// assert!(trie.starts_with("ca"));
+    /// assert!(trie.starts_with("cart"));
+    /// assert!(!trie.starts_with("carts"));
+    /// ```
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        // Pruning on removal guarantees every node leads to at least one key, except
+        // for the root of an empty trie.
+        !self.is_empty() && self.find(prefix).is_some()
+    }
+
+    /// Returns every stored key that starts with `prefix`, in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trie::algorithm::KolzoTrie;
+    ///
+    /// let trie: KolzoTrie = ["car", "cart", "care", "dog"].into_iter().collect();
+    /// assert_eq!(trie.keys_with_prefix("car"), vec!["car", "care", "cart"]);
+    /// assert!(trie.keys_with_prefix("x").is_empty());
+    /// ```
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut keys = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            collect_keys(node, &mut prefix.to_string(), &mut keys);
+        }
+        keys.sort();
+        keys
+    }
+
+    /// Returns the number of keys stored.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the trie holds no keys.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the node spelling `prefix`, if there is one.
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for character in prefix.chars() {
+            node = node.children.get(&character)?;
+        }
+        Some(node)
+    }
+}
+
+impl Default for KolzoTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Extend<&'a str> for KolzoTrie {
+    /// Inserts every key of the iterator, skipping keys that are already present.
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for KolzoTrie {
+    /// Builds a trie holding each distinct key of the iterator once.
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = KolzoTrie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+/// Unmarks the key spelled by `rest` below `node` and prunes the children that no
+/// longer lead to any key.
+///
+/// # Returns
+///
+/// `true` if the key was present.
+fn remove_from(node: &mut Node, rest: &[char]) -> bool {
+    let Some((first, rest)) = rest.split_first() else {
+        let removed = node.terminal;
+        node.terminal = false;
+        return removed;
+    };
+
+    let Some(child) = node.children.get_mut(first) else {
+        return false;
+    };
+    let removed = remove_from(child, rest);
+    if removed && !child.terminal && child.children.is_empty() {
+        node.children.remove(first);
+    }
+    removed
+}
+
+/// Appends every key at or below `node` to `keys`. `prefix` spells `node` and is
+/// restored before returning.
+fn collect_keys(node: &Node, prefix: &mut String, keys: &mut Vec<String>) {
+    if node.terminal {
+        keys.push(prefix.clone());
+    }
+    for (&character, child) in &node.children {
+        prefix.push(character);
+        collect_keys(child, prefix, keys);
+        prefix.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    /// Counts the nodes below and including `node`.
+    fn count_nodes(node: &Node) -> usize {
+        1 + node.children.values().map(count_nodes).sum::<usize>()
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let mut trie: KolzoTrie = ["car", "cart", "care"].into_iter().collect();
+        assert_eq!(trie.len(), 3);
+        // The root, "c", "a", "r", then "t" and "e".
+        assert_eq!(count_nodes(&trie.root), 6);
+
+        assert!(trie.remove("car"));
+        assert_eq!(trie.len(), 2);
+        assert!(!trie.contains("car"));
+        assert!(trie.contains("cart"));
+        assert!(trie.contains("care"));
+        assert_eq!(count_nodes(&trie.root), 6);
+
+        assert!(trie.starts_with("car"));
+        assert!(trie.starts_with("ca"));
+        assert!(!trie.starts_with("cars"));
+        assert_eq!(trie.keys_with_prefix("car"), vec!["care", "cart"]);
+        assert_eq!(trie.keys_with_prefix("c"), vec!["care", "cart"]);
+        assert!(trie.keys_with_prefix("cat").is_empty());
+    }
+
+    #[test]
+    fn test_remove_prunes_branches() {
+        let mut trie: KolzoTrie = ["car", "cart"].into_iter().collect();
+
+        assert!(trie.remove("cart"));
+        assert_eq!(count_nodes(&trie.root), 4);
+        assert!(trie.contains("car"));
+        assert!(!trie.starts_with("cart"));
+
+        assert!(!trie.remove("ca"));
+        assert!(!trie.remove("cars"));
+        assert!(trie.remove("car"));
+        assert_eq!(count_nodes(&trie.root), 1);
+        assert!(trie.is_empty());
+    }
+
+    #[test]
+    fn test_empty_string_is_a_key() {
+        let mut trie = KolzoTrie::new();
+        assert!(!trie.contains(""));
+        assert!(!trie.starts_with(""));
+        assert!(trie.keys_with_prefix("").is_empty());
+
+        assert!(trie.insert(""));
+        assert!(!trie.insert(""));
+        assert!(trie.contains(""));
+        assert!(trie.starts_with(""));
+        assert_eq!(trie.keys_with_prefix(""), vec![""]);
+
+        trie.insert("a");
+        assert_eq!(trie.keys_with_prefix(""), vec!["", "a"]);
+        assert!(trie.remove(""));
+        assert!(!trie.contains(""));
+        assert!(trie.contains("a"));
+        assert_eq!(trie.len(), 1);
+    }
+
+    #[test]
+    fn test_unicode_keys() {
+        let trie: KolzoTrie = ["für", "fürst", "日本", "日本語"].into_iter().collect();
+
+        assert!(trie.contains("日本"));
+        assert!(!trie.contains("日"));
+        assert!(trie.starts_with("日"));
+        assert_eq!(trie.keys_with_prefix("fü"), vec!["für", "fürst"]);
+        assert_eq!(trie.keys_with_prefix("日本"), vec!["日本", "日本語"]);
+    }
+
+    #[test]
+    fn test_matches_btree_set() {
+        let mut rng = StdRng::seed_from_u64(227);
+        let mut trie = KolzoTrie::new();
+        let mut model = BTreeSet::new();
+
+        for _ in 0..10_000 {
+            let length = rng.gen_range(0..5);
+            let key: String = (0..length).map(|_| rng.gen_range('a'..='c')).collect();
+            match rng.gen_range(0..4) {
+                0 => assert_eq!(trie.insert(&key), model.insert(key.clone())),
+                1 => assert_eq!(trie.remove(&key), model.remove(&key)),
+                2 => assert_eq!(trie.contains(&key), model.contains(&key)),
+                _ => {
+                    let expected: Vec<_> = model
+                        .iter()
+                        .filter(|stored| stored.starts_with(&key))
+                        .cloned()
+                        .collect();
+                    assert_eq!(trie.starts_with(&key), !expected.is_empty());
+                    assert_eq!(trie.keys_with_prefix(&key), expected);
+                }
+            }
+            assert_eq!(trie.len(), model.len());
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use trie::algorithm::KolzoTrie;
+
+fn main() {
+    let mut trie: KolzoTrie = ["car", "cart", "care", "cat", "dog"].into_iter().collect();
+    println!("LENGTH {}", trie.len());
+    println!("CONTAINS car {}", trie.contains("car"));
+    println!("PREFIX car {:?}", trie.keys_with_prefix("car"));
+
+    trie.remove("car");
+    println!("CONTAINS car {}", trie.contains("car"));
+    println!("STARTS WITH car {}", trie.starts_with("car"));
+    println!("PREFIX ca {:?}", trie.keys_with_prefix("ca"));
+}
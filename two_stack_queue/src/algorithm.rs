@@ -0,0 +1,342 @@
+use dynamic_array::algorithm::KolzoVec;
+use std::iter::{Chain, Rev};
+use std::slice;
+
+/// A first-in, first-out queue built from two stacks.
+///
+/// New values are pushed onto the in-stack. Values leave from the top of the
+/// out-stack, and only when the out-stack is empty is the whole in-stack popped
+/// onto it, which reverses it into dequeue order. Each value is therefore moved
+/// at most once, so `enqueue`, `dequeue` and `peek` are amortized O(1) without the
+/// raw tail pointer that `KolzoQueue` in the queue crate needs.
+#[derive(Debug, Clone, Default)]
+pub struct KolzoTwoStackQueue<T> {
+    /// The newest values, with the newest on top.
+    inbox: KolzoVec<T>,
+    /// The oldest values, with the oldest on top.
+    outbox: KolzoVec<T>,
+    /// The number of values moved from `inbox` to `outbox` so far.
+    transfers: usize,
+}
+
+impl<T> KolzoTwoStackQueue<T> {
+    /// Creates a new empty queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_stack_queue::algorithm::KolzoTwoStackQueue;
+    ///
+    /// let queue: KolzoTwoStackQueue<i32> = KolzoTwoStackQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoTwoStackQueue {
+            inbox: KolzoVec::new(),
+            outbox: KolzoVec::new(),
+            transfers: 0,
+        }
+    }
+
+    /// Adds a value to the back of the queue in amortized O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to enqueue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_stack_queue::algorithm::KolzoTwoStackQueue;
+    ///
+    /// let mut queue = KolzoTwoStackQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    pub fn enqueue(&mut self, value: T) {
+        self.inbox.push(value);
+    }
+
+    /// Removes and returns the value at the front of the queue in amortized O(1).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the oldest value if the queue is not empty.
+    /// * `None` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_stack_queue::algorithm::KolzoTwoStackQueue;
+    ///
+    /// let mut queue = KolzoTwoStackQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    ///
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), Some(2));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.refill_outbox();
+        self.outbox.pop()
+    }
+
+    /// Returns a reference to the value at the front of the queue without removing
+    /// it, in amortized O(1).
+    ///
+    /// Like `dequeue`, this moves the in-stack onto the out-stack when the out-stack
+    /// is empty, which is why it needs `&mut self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_stack_queue::algorithm::KolzoTwoStackQueue;
+    ///
+    /// let mut queue = KolzoTwoStackQueue::new();
+    /// assert_eq!(queue.peek(), None);
+    ///
+    /// queue.enqueue("first");
+    /// queue.enqueue("second");
+    /// assert_eq!(queue.peek(), Some(&"first"));
+    /// ```
+    pub fn peek(&mut self) -> Option<&T> {
+        self.refill_outbox();
+        self.outbox.last()
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.inbox.len() + self.outbox.len()
+    }
+
+    /// Returns `true` if the queue has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of values moved from the in-stack to the out-stack since
+    /// the queue was created.
+    ///
+    /// A value is moved at most once, so this never exceeds the number of values
+    /// ever enqueued. It is kept to make the amortized cost observable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_stack_queue::algorithm::KolzoTwoStackQueue;
+    ///
+    /// let mut queue = KolzoTwoStackQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    /// assert_eq!(queue.transfers(), 0);
+    ///
+    /// queue.dequeue();
+    /// queue.dequeue();
+    /// assert_eq!(queue.transfers(), 2);
+    /// ```
+    pub fn transfers(&self) -> usize {
+        self.transfers
+    }
+
+    /// Returns an iterator over the values from front to back.
+    ///
+    /// The out-stack is read from its top and the in-stack from its bottom, so
+    /// neither stack is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_stack_queue::algorithm::KolzoTwoStackQueue;
+    ///
+    /// let mut queue: KolzoTwoStackQueue<i32> = (1..=3).collect();
+    /// queue.dequeue();
+    /// queue.enqueue(4);
+    ///
+    /// let values: Vec<_> = queue.iter().copied().collect();
+    /// assert_eq!(values, vec![2, 3, 4]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.outbox.iter().rev().chain(self.inbox.iter()),
+            remaining: self.len(),
+        }
+    }
+
+    /// Moves every value of the in-stack onto the out-stack if the out-stack is
+    /// empty, reversing them into dequeue order.
+    fn refill_outbox(&mut self) {
+        if self.outbox.is_empty() {
+            while let Some(value) = self.inbox.pop() {
+                self.outbox.push(value);
+                self.transfers += 1;
+            }
+        }
+    }
+}
+
+impl<T> Extend<T> for KolzoTwoStackQueue<T> {
+    /// Enqueues every value of the iterator in order.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.enqueue(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for KolzoTwoStackQueue<T> {
+    /// Builds a queue that dequeues the values in iteration order.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = KolzoTwoStackQueue::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoTwoStackQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`KolzoTwoStackQueue`], from front to back.
+pub struct Iter<'a, T> {
+    /// The out-stack from the top, followed by the in-stack from the bottom.
+    inner: Chain<Rev<slice::Iter<'a, T>>, slice::Iter<'a, T>>,
+    /// The number of values not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.inner.next()?;
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_fifo_order() {
+        let mut queue = KolzoTwoStackQueue::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        assert_eq!(queue.dequeue(), Some(1));
+
+        queue.enqueue(3);
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.peek(), Some(&3));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_empty_queue() {
+        let mut queue: KolzoTwoStackQueue<i32> = KolzoTwoStackQueue::new();
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.iter().next(), None);
+        assert_eq!(queue.len(), 0);
+
+        queue.enqueue(1);
+        queue.dequeue();
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn test_each_value_is_transferred_at_most_once() {
+        let mut queue = KolzoTwoStackQueue::new();
+        let mut enqueued = 0;
+        let mut rng = StdRng::seed_from_u64(245);
+
+        for _ in 0..10_000 {
+            if rng.gen_bool(0.6) {
+                queue.enqueue(enqueued);
+                enqueued += 1;
+            } else {
+                queue.dequeue();
+            }
+            assert!(queue.transfers() <= enqueued);
+        }
+
+        // Draining moves whatever is still in the in-stack exactly once more.
+        while queue.dequeue().is_some() {}
+        assert_eq!(queue.transfers(), enqueued);
+    }
+
+    #[test]
+    fn test_transfer_only_when_outbox_is_empty() {
+        let mut queue: KolzoTwoStackQueue<i32> = (0..5).collect();
+        assert_eq!(queue.peek(), Some(&0));
+        assert_eq!(queue.transfers(), 5);
+
+        // The out-stack is not empty, so these stay in the in-stack.
+        queue.enqueue(5);
+        queue.enqueue(6);
+        for expected in 0..5 {
+            assert_eq!(queue.dequeue(), Some(expected));
+        }
+        assert_eq!(queue.transfers(), 5);
+
+        assert_eq!(queue.dequeue(), Some(5));
+        assert_eq!(queue.transfers(), 7);
+    }
+
+    #[test]
+    fn test_iter_does_not_disturb_the_stacks() {
+        let mut queue: KolzoTwoStackQueue<i32> = (1..=3).collect();
+        queue.dequeue();
+        queue.enqueue(4);
+        queue.enqueue(5);
+
+        let iter = queue.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.copied().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+        assert_eq!((&queue).into_iter().count(), 4);
+        assert_eq!(queue.transfers(), 3);
+        assert_eq!(queue.dequeue(), Some(2));
+    }
+
+    #[test]
+    fn test_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(245);
+        let mut queue = KolzoTwoStackQueue::new();
+        let mut model = VecDeque::new();
+
+        for step in 0..5_000 {
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    queue.enqueue(step);
+                    model.push_back(step);
+                }
+                2 => assert_eq!(queue.dequeue(), model.pop_front()),
+                _ => assert_eq!(queue.peek(), model.front()),
+            }
+
+            assert_eq!(queue.len(), model.len());
+            if step % 100 == 0 {
+                assert!(queue.iter().eq(model.iter()));
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use two_stack_queue::algorithm::KolzoTwoStackQueue;
+
+fn main() {
+    let mut queue = KolzoTwoStackQueue::new();
+    queue.enqueue(1);
+    queue.enqueue(2);
+    queue.enqueue(3);
+    println!("PEEK {:?}", queue.peek());
+    println!("DEQUEUE {:?}", queue.dequeue());
+
+    queue.enqueue(4);
+    println!("QUEUE {:?}", queue.iter().collect::<Vec<_>>());
+    println!("LENGTH {}", queue.len());
+    println!("TRANSFERS {}", queue.transfers());
+
+    while let Some(value) = queue.dequeue() {
+        println!("DEQUEUE {value}");
+    }
+    println!("EMPTY DEQUEUE {:?}", queue.dequeue());
+}
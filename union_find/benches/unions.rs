@@ -0,0 +1,54 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use union_find::algorithm::KolzoUnionFind;
+
+/// The number of ids and of unions performed on them.
+const SIZE: usize = 1_000_000;
+
+/// Performs a million random unions followed by a million `connected` queries.
+/// With path compression and union by size each operation is amortized nearly
+/// O(1), so the total should grow linearly with `SIZE`.
+fn unions(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(226);
+    let pairs: Vec<(usize, usize)> = (0..SIZE)
+        .map(|_| (rng.gen_range(0..SIZE), rng.gen_range(0..SIZE)))
+        .collect();
+
+    let mut group = c.benchmark_group("union_find_1m");
+    group.sample_size(10);
+
+    group.bench_function("union", |b| {
+        b.iter_batched(
+            || KolzoUnionFind::with_len(SIZE),
+            |mut sets| {
+                for &(a, b) in &pairs {
+                    black_box(sets.union(a, b));
+                }
+                sets
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("union_then_connected", |b| {
+        b.iter_batched(
+            || KolzoUnionFind::with_len(SIZE),
+            |mut sets| {
+                for &(a, b) in &pairs {
+                    sets.union(a, b);
+                }
+                for &(a, b) in pairs.iter().rev() {
+                    black_box(sets.connected(b, a));
+                }
+                sets
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, unions);
+criterion_main!(benches);
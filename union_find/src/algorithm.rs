@@ -0,0 +1,499 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A disjoint-set forest over the ids `0..len()`.
+///
+/// Each set is a tree whose root is its representative. `find` compresses the
+/// path it walks so later lookups are shorter, and `union` hangs the smaller tree
+/// under the larger one. Together they make every operation amortized O(α(n)),
+/// which is effectively constant.
+#[derive(Debug, Clone)]
+pub struct KolzoUnionFind {
+    /// The parent of every id; a root is its own parent.
+    parent: Vec<usize>,
+    /// The number of ids in the tree below each root. Only meaningful for roots.
+    size: Vec<usize>,
+    /// The number of disjoint sets.
+    sets: usize,
+}
+
+impl KolzoUnionFind {
+    /// Creates a new structure with no ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoUnionFind;
+    ///
+    /// let sets = KolzoUnionFind::new();
+    /// assert!(sets.is_empty());
+    /// assert_eq!(sets.count_sets(), 0);
+    /// ```
+    pub fn new() -> Self {
+        KolzoUnionFind {
+            parent: Vec::new(),
+            size: Vec::new(),
+            sets: 0,
+        }
+    }
+
+    /// Creates a structure holding the ids `0..len`, each in a set of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoUnionFind;
+    ///
+    /// let sets = KolzoUnionFind::with_len(3);
+    /// assert_eq!(sets.len(), 3);
+    /// assert_eq!(sets.count_sets(), 3);
+    /// ```
+    pub fn with_len(len: usize) -> Self {
+        KolzoUnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// Adds a new id in a set of its own.
+    ///
+    /// # Returns
+    ///
+    /// The new id, which is the previous value of `len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoUnionFind;
+    ///
+    /// let mut sets = KolzoUnionFind::new();
+    /// assert_eq!(sets.make_set(), 0);
+    /// assert_eq!(sets.make_set(), 1);
+    /// assert_eq!(sets.count_sets(), 2);
+    /// ```
+    pub fn make_set(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.size.push(1);
+        self.sets += 1;
+        id
+    }
+
+    /// Returns the representative of the set containing `id`.
+    ///
+    /// Every id on the way to the root is re-pointed directly at the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoUnionFind;
+    ///
+    /// let mut sets = KolzoUnionFind::with_len(3);
+    /// sets.union(0, 1);
+    /// assert_eq!(sets.find(0), sets.find(1));
+    /// assert_ne!(sets.find(0), sets.find(2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not less than `len()`.
+    pub fn find(&mut self, id: usize) -> usize {
+        let mut root = id;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = id;
+        while current != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - An id in the first set.
+    /// * `b` - An id in the second set.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the two sets were distinct and have been merged.
+    /// * `false` if `a` and `b` were already in the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoUnionFind;
+    ///
+    /// let mut sets = KolzoUnionFind::with_len(2);
+    /// assert!(sets.union(0, 1));
+    /// assert!(!sets.union(1, 0));
+    /// assert_eq!(sets.count_sets(), 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not less than `len()`.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut root_a, mut root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        if self.size[root_a] < self.size[root_b] {
+            std::mem::swap(&mut root_a, &mut root_b);
+        }
+        self.parent[root_b] = root_a;
+        self.size[root_a] += self.size[root_b];
+        self.sets -= 1;
+        true
+    }
+
+    /// Returns `true` if `a` and `b` are in the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not less than `len()`.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of ids in the set containing `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not less than `len()`.
+    pub fn set_size(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn count_sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns the number of ids.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if there are no ids.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+}
+
+impl Default for KolzoUnionFind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A disjoint-set structure over arbitrary keys.
+///
+/// Each key is given a [`KolzoUnionFind`] id the first time it is seen, and a
+/// HashMap translates keys to ids.
+#[derive(Debug, Clone)]
+pub struct KolzoKeyedUnionFind<K: Eq + Hash + Clone> {
+    /// The id of every key.
+    ids: HashMap<K, usize>,
+    /// The key of every id, so representatives can be reported as keys.
+    keys: Vec<K>,
+    /// The sets of ids.
+    sets: KolzoUnionFind,
+}
+
+impl<K: Eq + Hash + Clone> KolzoKeyedUnionFind<K> {
+    /// Creates a new structure with no keys.
+    pub fn new() -> Self {
+        KolzoKeyedUnionFind {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+            sets: KolzoUnionFind::new(),
+        }
+    }
+
+    /// Adds `key` in a set of its own.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the key is new.
+    /// * `false` if the key was already present; its set is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoKeyedUnionFind;
+    ///
+    /// let mut sets = KolzoKeyedUnionFind::new();
+    /// assert!(sets.make_set("a"));
+    /// assert!(!sets.make_set("a"));
+    /// assert_eq!(sets.len(), 1);
+    /// ```
+    pub fn make_set(&mut self, key: K) -> bool {
+        if self.ids.contains_key(&key) {
+            return false;
+        }
+        self.insert(key);
+        true
+    }
+
+    /// Returns the representative key of the set containing `key`, or `None` if the
+    /// key was never added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoKeyedUnionFind;
+    ///
+    /// let mut sets = KolzoKeyedUnionFind::new();
+    /// sets.union("a", "b");
+    /// let root = sets.find(&"a").copied();
+    /// assert_eq!(sets.find(&"b").copied(), root);
+    /// assert_eq!(sets.find(&"c"), None);
+    /// ```
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let id = *self.ids.get(key)?;
+        let root = self.sets.find(id);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets containing `a` and `b`, adding either key first if it is new.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the two sets were distinct and have been merged.
+    /// * `false` if `a` and `b` were already in the same set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use union_find::algorithm::KolzoKeyedUnionFind;
+    ///
+    /// let mut sets = KolzoKeyedUnionFind::new();
+    /// assert!(sets.union("a", "b"));
+    /// assert!(!sets.union("b", "a"));
+    /// assert_eq!(sets.count_sets(), 1);
+    /// ```
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let a = self.id_or_insert(a);
+        let b = self.id_or_insert(b);
+        self.sets.union(a, b)
+    }
+
+    /// Returns `true` if `a` and `b` have both been added and are in the same set.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.ids.get(a), self.ids.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn count_sets(&self) -> usize {
+        self.sets.count_sets()
+    }
+
+    /// Returns the number of keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if there are no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the id of `key`, adding it first if it is new.
+    fn id_or_insert(&mut self, key: K) -> usize {
+        match self.ids.get(&key) {
+            Some(&id) => id,
+            None => self.insert(key),
+        }
+    }
+
+    /// Adds a key that is known to be new and returns its id.
+    fn insert(&mut self, key: K) -> usize {
+        let id = self.sets.make_set();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+}
+
+impl<K: Eq + Hash + Clone> Default for KolzoKeyedUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph::algorithm::KolzoGraph;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_chains_of_unions() {
+        let mut sets = KolzoUnionFind::with_len(10);
+
+        // Two chains: 0-1-2-3-4 and 5-6-7, leaving 8 and 9 alone.
+        for id in 0..4 {
+            assert!(sets.union(id, id + 1));
+        }
+        for id in 5..7 {
+            assert!(sets.union(id, id + 1));
+        }
+
+        assert_eq!(sets.count_sets(), 4);
+        assert!(sets.connected(0, 4));
+        assert!(sets.connected(7, 5));
+        assert!(!sets.connected(4, 5));
+        assert!(!sets.connected(8, 9));
+        assert_eq!(sets.set_size(2), 5);
+        assert_eq!(sets.set_size(6), 3);
+        assert_eq!(sets.set_size(9), 1);
+
+        assert!(sets.union(4, 5));
+        assert!(sets.connected(0, 7));
+        assert_eq!(sets.set_size(0), 8);
+        assert_eq!(sets.count_sets(), 3);
+    }
+
+    #[test]
+    fn test_unions_are_idempotent() {
+        let mut sets = KolzoUnionFind::with_len(3);
+
+        assert!(sets.union(0, 1));
+        assert!(!sets.union(0, 1));
+        assert!(!sets.union(1, 0));
+        assert!(!sets.union(2, 2));
+        assert_eq!(sets.count_sets(), 2);
+        assert_eq!(sets.set_size(1), 2);
+    }
+
+    #[test]
+    fn test_make_set_grows() {
+        let mut sets = KolzoUnionFind::default();
+        assert!(sets.is_empty());
+
+        let a = sets.make_set();
+        let b = sets.make_set();
+        sets.union(a, b);
+        let c = sets.make_set();
+
+        assert_eq!(sets.len(), 3);
+        assert_eq!(sets.count_sets(), 2);
+        assert_eq!(sets.find(c), c);
+    }
+
+    #[test]
+    fn test_find_compresses_paths() {
+        let mut sets = KolzoUnionFind::with_len(4);
+
+        // Build the tree by hand so it is a chain 3 -> 2 -> 1 -> 0.
+        sets.parent = vec![0, 0, 1, 2];
+        sets.size = vec![4, 1, 1, 1];
+        sets.sets = 1;
+
+        assert_eq!(sets.find(3), 0);
+        assert_eq!(sets.parent, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_find_unknown_id_panics() {
+        KolzoUnionFind::with_len(2).find(2);
+    }
+
+    #[test]
+    fn test_matches_naive_labels() {
+        let mut rng = StdRng::seed_from_u64(226);
+        let mut sets = KolzoUnionFind::with_len(200);
+        // The naive model gives every id a label and relabels on union.
+        let mut labels: Vec<usize> = (0..200).collect();
+
+        for _ in 0..10_000 {
+            let a = rng.gen_range(0..200);
+            let b = rng.gen_range(0..200);
+            if rng.gen_bool(0.3) {
+                let (from, to) = (labels[b], labels[a]);
+                let merged = from != to;
+                for label in labels.iter_mut().filter(|label| **label == from) {
+                    *label = to;
+                }
+                assert_eq!(sets.union(a, b), merged);
+            } else {
+                assert_eq!(sets.connected(a, b), labels[a] == labels[b]);
+            }
+
+            let mut distinct = labels.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(sets.count_sets(), distinct.len());
+        }
+    }
+
+    #[test]
+    fn test_keyed_union_find() {
+        let mut sets = KolzoKeyedUnionFind::new();
+        assert!(sets.make_set("x"));
+
+        assert!(sets.union("a", "b"));
+        assert!(sets.union("c", "b"));
+        assert!(!sets.union("a", "c"));
+
+        assert_eq!(sets.len(), 4);
+        assert_eq!(sets.count_sets(), 2);
+        assert!(sets.connected(&"a", &"c"));
+        assert!(!sets.connected(&"a", &"x"));
+        assert!(!sets.connected(&"a", &"missing"));
+        assert_eq!(sets.find(&"x"), Some(&"x"));
+
+        let root = *sets.find(&"a").unwrap();
+        assert!(["a", "b", "c"].contains(&root));
+        assert_eq!(sets.find(&"c"), Some(&root));
+    }
+
+    #[test]
+    fn test_counts_graph_components() {
+        // The components are {0, 1, 2, 3}, {4, 5}, {6} and {7, 8, 9}.
+        let mut graph = KolzoGraph::undirected();
+        let ids: Vec<_> = (0..10).map(|value| graph.add_node(value)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 2), (4, 5), (7, 8), (9, 8)] {
+            graph.add_edge(ids[from], ids[to]);
+        }
+
+        let mut sets = KolzoUnionFind::with_len(graph.node_count());
+        for node in graph.node_ids() {
+            for &neighbor in graph.neighbors(node) {
+                sets.union(node.index(), neighbor.index());
+            }
+        }
+
+        assert_eq!(sets.count_sets(), 4);
+        for node in graph.node_ids() {
+            let reachable = graph.bfs(node);
+            assert_eq!(sets.set_size(node.index()), reachable.len());
+            for other in reachable {
+                assert!(sets.connected(node.index(), other.index()));
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use union_find::algorithm::{KolzoKeyedUnionFind, KolzoUnionFind};
+
+fn main() {
+    let mut sets = KolzoUnionFind::with_len(6);
+    sets.union(0, 1);
+    sets.union(1, 2);
+    sets.union(4, 5);
+    println!("SETS {}", sets.count_sets());
+    println!("CONNECTED 0 2 {}", sets.connected(0, 2));
+    println!("CONNECTED 2 4 {}", sets.connected(2, 4));
+
+    let mut friends = KolzoKeyedUnionFind::new();
+    friends.union("ana", "bo");
+    friends.union("cy", "dee");
+    friends.union("bo", "dee");
+    friends.make_set("eve");
+    println!("GROUPS {}", friends.count_sets());
+    println!("ANA AND CY {}", friends.connected(&"ana", &"cy"));
+}
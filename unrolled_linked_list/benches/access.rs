@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use linked_list::algorithm::KolzoLinkedList;
+use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+
+/// The number of elements in both lists.
+const SIZE: u64 = 100_000;
+
+/// Builds both lists with the values `0..SIZE`.
+fn lists() -> (KolzoUnrolledLinkedList<u64>, KolzoLinkedList<u64>) {
+    let unrolled: KolzoUnrolledLinkedList<u64> = (0..SIZE).collect();
+    let mut linked = KolzoLinkedList::new();
+    for value in 0..SIZE {
+        linked.append(value);
+    }
+    (unrolled, linked)
+}
+
+/// Sums every element. The unrolled list follows one pointer per 16 elements and
+/// reads the rest from contiguous memory, while the linked list follows one
+/// pointer per element.
+fn iteration(c: &mut Criterion) {
+    let (unrolled, linked) = lists();
+    let mut group = c.benchmark_group("iterate_100k");
+
+    group.bench_function("unrolled_linked_list", |b| {
+        b.iter(|| black_box(unrolled.iter().sum::<u64>()))
+    });
+
+    group.bench_function("linked_list", |b| {
+        b.iter(|| black_box(linked.iter().sum::<u64>()))
+    });
+
+    group.finish();
+}
+
+/// Looks up indices spread across the lists. Both walks are linear, but the
+/// unrolled list skips whole nodes at a time.
+fn indexed_access(c: &mut Criterion) {
+    let (unrolled, linked) = lists();
+    let indices = [0, SIZE / 4, SIZE / 2, SIZE - 1];
+    let mut group = c.benchmark_group("get_100k");
+
+    group.bench_function("unrolled_linked_list", |b| {
+        b.iter(|| {
+            for &index in &indices {
+                black_box(unrolled.get(black_box(index as usize)));
+            }
+        })
+    });
+
+    group.bench_function("linked_list", |b| {
+        b.iter(|| {
+            for &index in &indices {
+                black_box(linked.get(black_box(index as usize)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, iteration, indexed_access);
+criterion_main!(benches);
@@ -0,0 +1,786 @@
+use list_common::linear::LinearList;
+use list_common::node::{allocate, free};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::slice;
+
+/// The most elements a node holds.
+pub const NODE_CAPACITY: usize = 16;
+
+/// The fewest elements a node keeps after a removal while it has a successor to
+/// merge with or borrow from. A full node is split into two halves of this size.
+const MIN_NODE_LENGTH: usize = NODE_CAPACITY / 2;
+
+/// A node in the unrolled linked list.
+///
+/// The list owns every node. They are made by [`list_common::node::allocate`] so
+/// that `tail` can keep pointing at the last one while the chain is relinked.
+#[derive(Debug)]
+struct Node<T> {
+    /// The elements of the node, in order. Allocated once with room for
+    /// `NODE_CAPACITY` elements and never grown past it.
+    elements: Vec<T>,
+    /// The next node in the list.
+    next: Option<NonNull<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    /// Creates a new node with no elements.
+    fn new() -> Self {
+        Node {
+            elements: Vec::with_capacity(NODE_CAPACITY),
+            next: None,
+        }
+    }
+
+    /// Returns the node after this one.
+    fn next_node(&self) -> Option<&Node<T>> {
+        // SAFETY: a node is only freed when a removal merges it into its
+        // predecessor, which takes `&mut` to the list and so cannot overlap this
+        // borrow.
+        self.next.map(|next| unsafe { &*next.as_ptr() })
+    }
+
+    /// Returns `true` if the node has no room for another element.
+    fn is_full(&self) -> bool {
+        self.elements.len() == NODE_CAPACITY
+    }
+}
+
+/// A singly linked list whose nodes each hold up to [`NODE_CAPACITY`] elements.
+///
+/// Storing a run of elements contiguously in each node means walking the list
+/// follows one pointer per run instead of one per element, which makes iteration
+/// and indexed access much friendlier to the cache than in the one-element nodes
+/// of `linked_list`'s `KolzoLinkedList`.
+///
+/// Inserting into a full node splits it into two half-full nodes. When a removal
+/// leaves a node less than half full, the node absorbs its successor if both fit
+/// in one node, and otherwise borrows the successor's first element. A node that
+/// becomes empty is unlinked. Nodes at the ends may be sparse, since pushes only
+/// start a new node when the end node is full.
+pub struct KolzoUnrolledLinkedList<T> {
+    /// The first node of the list.
+    head: Option<NonNull<Node<T>>>,
+    /// The last node of the list, kept for efficient appending.
+    tail: Option<NonNull<Node<T>>>,
+    /// The number of elements in the list.
+    length: usize,
+    /// The list owns boxed nodes holding `T`.
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: every node, and so every element, is owned by exactly one list and only
+// reachable through it; `tail` is an alias of the last link. The list is therefore
+// as thread-safe as the `Vec<T>`s that store its elements.
+unsafe impl<T: Send> Send for KolzoUnrolledLinkedList<T> {}
+unsafe impl<T: Sync> Sync for KolzoUnrolledLinkedList<T> {}
+
+impl<T> KolzoUnrolledLinkedList<T> {
+    /// Creates a new empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let list: KolzoUnrolledLinkedList<i32> = KolzoUnrolledLinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoUnrolledLinkedList {
+            head: None,
+            tail: None,
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds a value at the end of the list in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let mut list = KolzoUnrolledLinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.get(1), Some(&2));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        let mut tail_pointer = match self.tail {
+            // SAFETY: `tail` always points at the last node, which the list owns.
+            Some(tail_pointer) if unsafe { !tail_pointer.as_ref().is_full() } => tail_pointer,
+            _ => self.push_node_back(),
+        };
+
+        // SAFETY: as above, `tail_pointer` is the last node of the list, and nothing
+        // else borrows the list while we hold `&mut self`.
+        unsafe { tail_pointer.as_mut().elements.push(value) };
+        self.length += 1;
+    }
+
+    /// Adds a value at the start of the list, shifting at most a node's worth of
+    /// elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let mut list = KolzoUnrolledLinkedList::new();
+    /// list.push_front(2);
+    /// list.push_front(1);
+    /// assert_eq!(list.get(0), Some(&1));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if self.head_node().is_none_or(Node::is_full) {
+            let mut new_node = allocate(Node::new());
+            // SAFETY: the node was just allocated and is not linked anywhere yet.
+            unsafe { new_node.as_mut().next = self.head };
+            if self.tail.is_none() {
+                self.tail = Some(new_node);
+            }
+            self.head = Some(new_node);
+        }
+
+        let mut head = self.head.expect("a head node was just ensured");
+        // SAFETY: `head` points at a node the list owns, and nothing else borrows
+        // the list while we hold `&mut self`.
+        unsafe { head.as_mut() }.elements.insert(0, value);
+        self.length += 1;
+    }
+
+    /// Returns a reference to the value at `index`, or `None` if the index is out
+    /// of range.
+    ///
+    /// This walks one node per [`NODE_CAPACITY`] elements at most, then indexes
+    /// into the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let list: KolzoUnrolledLinkedList<i32> = (0..100).collect();
+    /// assert_eq!(list.get(42), Some(&42));
+    /// assert_eq!(list.get(100), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut index = index;
+        let mut current = self.head_node();
+        while let Some(node) = current {
+            if index < node.elements.len() {
+                return Some(&node.elements[index]);
+            }
+            index -= node.elements.len();
+            current = node.next_node();
+        }
+        None
+    }
+
+    /// Returns a mutable reference to the value at `index`, or `None` if the index
+    /// is out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut index = index;
+        let mut current = self.head;
+        while let Some(node_pointer) = current {
+            // SAFETY: links point at live nodes the list owns, and the reference
+            // mutably borrows `self`.
+            let node = unsafe { &mut *node_pointer.as_ptr() };
+            if index < node.elements.len() {
+                return Some(&mut node.elements[index]);
+            }
+            index -= node.elements.len();
+            current = node.next;
+        }
+        None
+    }
+
+    /// Inserts a value at `index`, shifting every later value one place right.
+    ///
+    /// If the node the value lands in is full, it is first split into two nodes of
+    /// [`NODE_CAPACITY`]` / 2` elements each.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position the value will have. May equal `len()`.
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let mut list: KolzoUnrolledLinkedList<i32> = [1, 3].into_iter().collect();
+    /// list.insert(1, 2);
+    /// list.insert(3, 4);
+    /// assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than `len()`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(
+            index <= self.length,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.length
+        );
+        if index == self.length {
+            self.push_back(value);
+            return;
+        }
+
+        let mut index = index;
+        // SAFETY: links point at live nodes the list owns, and nothing else borrows
+        // the list while we hold `&mut self`.
+        let mut node = unsafe { &mut *self.head.expect("the list is not empty").as_ptr() };
+        while index >= node.elements.len() {
+            index -= node.elements.len();
+            node = unsafe { &mut *node.next.expect("the index is in range").as_ptr() };
+        }
+
+        if node.is_full() {
+            let mut upper_pointer = allocate(Node::new());
+            // SAFETY: the upper half was just allocated and is not linked anywhere yet.
+            let upper = unsafe { upper_pointer.as_mut() };
+            upper
+                .elements
+                .extend(node.elements.drain(MIN_NODE_LENGTH..));
+            upper.next = node.next;
+            if upper.next.is_none() {
+                self.tail = Some(upper_pointer);
+            }
+            node.next = Some(upper_pointer);
+
+            if index >= MIN_NODE_LENGTH {
+                index -= MIN_NODE_LENGTH;
+                node = upper;
+            }
+        }
+
+        node.elements.insert(index, value);
+        self.length += 1;
+    }
+
+    /// Removes and returns the value at `index`, or returns `None` if the index is
+    /// out of range.
+    ///
+    /// If the node drops below half full, it merges with or borrows from the next
+    /// node; if it becomes empty, it is unlinked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let mut list: KolzoUnrolledLinkedList<i32> = [1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.remove(1), Some(2));
+    /// assert_eq!(list.remove(5), None);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let mut index = index;
+        let mut previous_pointer: Option<NonNull<Node<T>>> = None;
+        let mut node_pointer = self.head.expect("the list is not empty");
+        // SAFETY: links point at live nodes the list owns, and the list is not
+        // modified until the walk ends.
+        unsafe {
+            while index >= node_pointer.as_ref().elements.len() {
+                index -= node_pointer.as_ref().elements.len();
+                previous_pointer = Some(node_pointer);
+                node_pointer = node_pointer.as_ref().next.expect("the index is in range");
+            }
+        }
+
+        // SAFETY: `node_pointer` points at a live node of the list, and nothing
+        // else borrows it.
+        let node = unsafe { &mut *node_pointer.as_ptr() };
+        let value = node.elements.remove(index);
+        self.length -= 1;
+
+        if node.elements.len() < MIN_NODE_LENGTH {
+            match node.next {
+                Some(next_pointer) => {
+                    // SAFETY: the successor is a live node of the list, distinct from
+                    // `node`.
+                    let next = unsafe { &mut *next_pointer.as_ptr() };
+                    if node.elements.len() + next.elements.len() <= NODE_CAPACITY {
+                        node.elements.append(&mut next.elements);
+                        node.next = next.next;
+                        if node.next.is_none() {
+                            self.tail = Some(node_pointer);
+                        }
+                        // SAFETY: the successor is unlinked now.
+                        unsafe { free(next_pointer) };
+                    } else {
+                        node.elements.push(next.elements.remove(0));
+                    }
+                }
+                None if node.elements.is_empty() => {
+                    // The last node is empty, so its predecessor becomes the tail.
+                    match previous_pointer {
+                        // SAFETY: the predecessor is a live node of the list.
+                        Some(mut previous_pointer) => unsafe {
+                            previous_pointer.as_mut().next = None
+                        },
+                        None => self.head = None,
+                    }
+                    self.tail = previous_pointer;
+                    // SAFETY: the node is unlinked now.
+                    unsafe { free(node_pointer) };
+                }
+                None => {}
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an iterator over references to the elements, from first to last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+    ///
+    /// let list: KolzoUnrolledLinkedList<i32> = (1..=3).collect();
+    /// assert_eq!(list.iter().sum::<i32>(), 6);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next_node: self.head_node(),
+            elements: [].iter(),
+            remaining: self.length,
+        }
+    }
+
+    /// Returns the first node.
+    fn head_node(&self) -> Option<&Node<T>> {
+        // SAFETY: `head` points at a live node the list owns, and the reference
+        // borrows `self`.
+        self.head.map(|head| unsafe { &*head.as_ptr() })
+    }
+
+    /// Links a new empty node after the tail and returns a pointer to it.
+    fn push_node_back(&mut self) -> NonNull<Node<T>> {
+        let new_node = allocate(Node::new());
+
+        match self.tail {
+            // SAFETY: `tail` always points at the last node, which the list owns.
+            Some(mut tail_pointer) => unsafe {
+                tail_pointer.as_mut().next = Some(new_node);
+            },
+            None => {
+                self.head = Some(new_node);
+            }
+        }
+
+        self.tail = Some(new_node);
+        new_node
+    }
+}
+
+impl<T> Default for KolzoUnrolledLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoUnrolledLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Drop for KolzoUnrolledLinkedList<T> {
+    /// Frees the nodes one at a time, following the links from the head.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            // SAFETY: every node is freed exactly once, and the list is not used
+            // again after `drop`.
+            current = unsafe { free(node) }.next;
+        }
+    }
+}
+
+impl<T> Extend<T> for KolzoUnrolledLinkedList<T> {
+    /// Pushes every value of the iterator onto the back of the list.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for KolzoUnrolledLinkedList<T> {
+    /// Builds a list holding the values of the iterator in order, with every node
+    /// but the last one full.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = KolzoUnrolledLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoUnrolledLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> LinearList<T> for KolzoUnrolledLinkedList<T> {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+/// An iterator over references to the elements of a [`KolzoUnrolledLinkedList`].
+///
+/// Created by [`KolzoUnrolledLinkedList::iter`].
+pub struct Iter<'a, T> {
+    /// The node after the one being iterated.
+    next_node: Option<&'a Node<T>>,
+    /// The elements of the current node not yet yielded.
+    elements: slice::Iter<'a, T>,
+    /// The number of elements not yet yielded.
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.elements.next() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+            let node = self.next_node?;
+            self.elements = node.elements.iter();
+            self.next_node = node.next_node();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// SAFETY: the iterator reads node slices of a shared-borrowed list, like the
+// `slice::Iter` it wraps.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p unrolled_linked_list`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    /// Returns the number of elements in each node, from head to tail.
+    fn node_lengths<T>(list: &KolzoUnrolledLinkedList<T>) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        let mut current = list.head_node();
+        while let Some(node) = current {
+            lengths.push(node.elements.len());
+            current = node.next_node();
+        }
+        lengths
+    }
+
+    /// Checks the links and bookkeeping that every operation must preserve.
+    fn validate<T>(list: &KolzoUnrolledLinkedList<T>) {
+        let lengths = node_lengths(list);
+        assert_eq!(lengths.iter().sum::<usize>(), list.len());
+        assert!(lengths
+            .iter()
+            .all(|&length| length > 0 && length <= NODE_CAPACITY));
+
+        let mut last: Option<&Node<T>> = None;
+        let mut current = list.head_node();
+        while let Some(node) = current {
+            assert!(node.elements.capacity() <= NODE_CAPACITY);
+            last = Some(node);
+            current = node.next_node();
+        }
+        assert_eq!(
+            last.map(|node| node as *const Node<T>),
+            list.tail.map(|tail| tail.as_ptr() as *const _)
+        );
+    }
+
+    #[test]
+    fn test_push_back_fills_nodes() {
+        let list: KolzoUnrolledLinkedList<usize> = (0..40).collect();
+
+        assert_eq!(list.len(), 40);
+        assert_eq!(node_lengths(&list), vec![16, 16, 8]);
+        assert!((0..40).all(|index| list.get(index) == Some(&index)));
+        assert_eq!(list.get(40), None);
+        validate(&list);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut list = KolzoUnrolledLinkedList::new();
+        for value in (0..20).rev() {
+            list.push_front(value);
+        }
+
+        // The first node filled up, so a new head started in front of it.
+        assert_eq!(node_lengths(&list), vec![4, 16]);
+        assert!(list.iter().copied().eq(0..20));
+        list.push_back(20);
+        assert_eq!(list.get(20), Some(&20));
+        validate(&list);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list: KolzoUnrolledLinkedList<i32> = (0..20).collect();
+        *list.get_mut(17).unwrap() = 100;
+
+        assert_eq!(list.get(17), Some(&100));
+        assert_eq!(list.get_mut(20), None);
+    }
+
+    #[test]
+    fn test_insert_splits_a_full_node() {
+        let mut list: KolzoUnrolledLinkedList<i32> = (0..32).collect();
+        assert_eq!(node_lengths(&list), vec![16, 16]);
+
+        // Into the lower half of the first node.
+        list.insert(3, -1);
+        assert_eq!(node_lengths(&list), vec![9, 8, 16]);
+        assert_eq!(list.get(3), Some(&-1));
+
+        // Into the upper half of the last node, which moves the tail.
+        list.insert(30, -2);
+        assert_eq!(node_lengths(&list), vec![9, 8, 8, 9]);
+        assert_eq!(list.get(30), Some(&-2));
+
+        let mut expected: Vec<i32> = (0..32).collect();
+        expected.insert(3, -1);
+        expected.insert(30, -2);
+        assert!(list.iter().copied().eq(expected));
+        validate(&list);
+
+        list.push_back(32);
+        assert_eq!(node_lengths(&list), vec![9, 8, 8, 10]);
+    }
+
+    #[test]
+    fn test_insert_at_the_ends() {
+        let mut list = KolzoUnrolledLinkedList::new();
+        list.insert(0, 2);
+        list.insert(0, 1);
+        list.insert(2, 3);
+
+        assert!(list.iter().copied().eq([1, 2, 3]));
+        validate(&list);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
+    fn test_insert_out_of_range_panics() {
+        let mut list: KolzoUnrolledLinkedList<i32> = (0..3).collect();
+        list.insert(4, 0);
+    }
+
+    #[test]
+    fn test_remove_merges_with_the_next_node() {
+        let mut list: KolzoUnrolledLinkedList<usize> = (0..24).collect();
+        assert_eq!(node_lengths(&list), vec![16, 8]);
+
+        // Removing down to half full changes nothing yet.
+        for _ in 0..8 {
+            list.remove(0);
+        }
+        assert_eq!(node_lengths(&list), vec![8, 8]);
+
+        // One more leaves 7 + 8 elements, which fit in a single node.
+        assert_eq!(list.remove(0), Some(8));
+        assert_eq!(node_lengths(&list), vec![15]);
+        assert!(list.iter().copied().eq(9..24));
+        validate(&list);
+    }
+
+    #[test]
+    fn test_remove_borrows_from_a_full_next_node() {
+        let mut list: KolzoUnrolledLinkedList<usize> = (0..32).collect();
+        for _ in 0..9 {
+            list.remove(0);
+        }
+
+        // 7 + 16 elements do not fit in one node, so one element moves over.
+        assert_eq!(node_lengths(&list), vec![8, 15]);
+        assert!(list.iter().copied().eq(9..32));
+        validate(&list);
+    }
+
+    #[test]
+    fn test_remove_emptying_the_last_node() {
+        let mut list: KolzoUnrolledLinkedList<usize> = (0..17).collect();
+        assert_eq!(node_lengths(&list), vec![16, 1]);
+
+        assert_eq!(list.remove(16), Some(16));
+        assert_eq!(node_lengths(&list), vec![16]);
+        validate(&list);
+
+        // The tail moved back to the first node, so appending must start a new one.
+        list.push_back(100);
+        assert_eq!(node_lengths(&list), vec![16, 1]);
+        assert_eq!(list.get(16), Some(&100));
+        validate(&list);
+    }
+
+    #[test]
+    fn test_remove_emptying_the_only_node() {
+        let mut list = KolzoUnrolledLinkedList::new();
+        list.push_back(1);
+
+        assert_eq!(list.remove(0), Some(1));
+        assert!(list.is_empty());
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        assert_eq!(list.remove(0), None);
+
+        list.push_back(2);
+        list.push_front(1);
+        assert!(list.iter().copied().eq([1, 2]));
+        validate(&list);
+    }
+
+    #[test]
+    fn test_remove_emptying_a_middle_node() {
+        let mut list: KolzoUnrolledLinkedList<i32> = (1..=16).collect();
+        list.push_front(0);
+        assert_eq!(node_lengths(&list), vec![1, 16]);
+
+        // The head empties and takes over the elements of the node behind it.
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(node_lengths(&list), vec![16]);
+        assert!(list.iter().copied().eq(1..=16));
+        validate(&list);
+    }
+
+    #[test]
+    fn test_iter() {
+        let list: KolzoUnrolledLinkedList<i32> = KolzoUnrolledLinkedList::new();
+        assert_eq!(list.iter().next(), None);
+
+        let list: KolzoUnrolledLinkedList<i32> = (0..50).collect();
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 50);
+        assert_eq!(iter.nth(20), Some(&20));
+        assert_eq!(iter.len(), 29);
+        assert!((&list).into_iter().copied().eq(0..50));
+        assert_eq!(list.elements().len(), 50);
+    }
+
+    #[test]
+    fn test_matches_vec() {
+        let mut rng = StdRng::seed_from_u64(234);
+        let mut list = KolzoUnrolledLinkedList::new();
+        let mut model = Vec::new();
+
+        for _ in 0..20_000 {
+            match rng.gen_range(0..6) {
+                0 => {
+                    let value: i32 = rng.gen();
+                    list.push_back(value);
+                    model.push(value);
+                }
+                1 => {
+                    let value: i32 = rng.gen();
+                    list.push_front(value);
+                    model.insert(0, value);
+                }
+                2 => {
+                    let index = rng.gen_range(0..=model.len());
+                    let value: i32 = rng.gen();
+                    list.insert(index, value);
+                    model.insert(index, value);
+                }
+                3 | 4 => {
+                    let index = rng.gen_range(0..=model.len());
+                    let expected = (index < model.len()).then(|| model.remove(index));
+                    assert_eq!(list.remove(index), expected);
+                }
+                _ => {
+                    let index = rng.gen_range(0..=model.len());
+                    assert_eq!(list.get(index), model.get(index));
+                }
+            }
+            assert_eq!(list.len(), model.len());
+        }
+
+        validate(&list);
+        assert!(list.iter().eq(model.iter()));
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let list: KolzoUnrolledLinkedList<usize> = (0..size(1_000_000)).collect();
+        drop(list);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send::<KolzoUnrolledLinkedList<String>>();
+        assert_sync::<KolzoUnrolledLinkedList<String>>();
+        assert_send::<Iter<'static, String>>();
+        assert_sync::<Iter<'static, String>>();
+    }
+}
@@ -0,0 +1,11 @@
+use unrolled_linked_list::algorithm::KolzoUnrolledLinkedList;
+
+fn main() {
+    let mut list: KolzoUnrolledLinkedList<i32> = (1..=40).collect();
+    list.push_front(0);
+    list.insert(20, 100);
+    println!("LENGTH {}", list.len());
+    println!("GET 20 {:?}", list.get(20));
+    println!("REMOVE 0 {:?}", list.remove(0));
+    println!("FIRST FIVE {:?}", list.iter().take(5).collect::<Vec<_>>());
+}
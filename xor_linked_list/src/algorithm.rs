@@ -0,0 +1,626 @@
+//! A doubly linked list that stores one link per node instead of two.
+//!
+//! Each node keeps `address(previous) ^ address(next)`, with a missing neighbor
+//! counting as address 0. Knowing the address of either neighbor recovers the
+//! other, so a walk from the head can go forward and a walk from the tail can go
+//! backward, as long as it carries the address of the node it came from.
+//!
+//! # Provenance
+//!
+//! A pointer is more than its address: it also carries the provenance of the
+//! allocation it may access, and XOR-ing addresses throws that away. Every node is
+//! therefore allocated through `allocate`, which calls
+//! [`expose_provenance`](pointer::expose_provenance) on the new pointer, and every
+//! pointer rebuilt from a link goes through `node_at`, which uses
+//! [`ptr::with_exposed_provenance_mut`] to pick up the exposed provenance again.
+//! This is the exposed-provenance model the standard library documents for
+//! pointer-integer round trips; the strict-provenance APIs cannot express a link
+//! that mixes two addresses.
+//!
+//! The tests run under Miri with `cargo +nightly miri test -p xor_linked_list`.
+//! Miri reports each integer-to-pointer cast as a warning, which
+//! `MIRIFLAGS=-Zmiri-permissive-provenance` silences.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr;
+
+/// A node in the XOR linked list.
+struct Node<T> {
+    /// The data stored in the node.
+    data: T,
+    /// The address of the previous node XOR the address of the next node.
+    link: usize,
+}
+
+/// Moves `data` into a new heap node with an empty link and exposes the node's
+/// provenance, so [`node_at`] can rebuild a usable pointer from its address.
+fn allocate<T>(data: T) -> *mut Node<T> {
+    let pointer = Box::into_raw(Box::new(Node { data, link: 0 }));
+    pointer.expose_provenance();
+    pointer
+}
+
+/// Turns an address taken from a link back into a node pointer. Address 0 gives
+/// the null pointer.
+fn node_at<T>(address: usize) -> *mut Node<T> {
+    ptr::with_exposed_provenance_mut(address)
+}
+
+/// A doubly linked list whose nodes store a single XOR-ed link.
+///
+/// This saves one pointer per node compared to `double_linked_list`'s
+/// `KolzoDoublyLinkedList`, at the price of being impossible to enter in the
+/// middle: a node alone does not say where its neighbors are, so every operation
+/// starts from one of the ends. It is kept as a study of the technique rather than
+/// as a practical list.
+pub struct KolzoXorLinkedList<T> {
+    /// The first node of the list, or null if the list is empty.
+    head: *mut Node<T>,
+    /// The last node of the list, or null if the list is empty.
+    tail: *mut Node<T>,
+    /// The number of elements in the list.
+    length: usize,
+    /// The list owns its nodes.
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> KolzoXorLinkedList<T> {
+    /// Creates a new empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let list: KolzoXorLinkedList<i32> = KolzoXorLinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        KolzoXorLinkedList {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds a value at the end of the list in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let mut list = KolzoXorLinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        let new_node = allocate(value);
+        // SAFETY: `new_node` was just allocated, and `tail` is either null or the
+        // live last node of the list.
+        unsafe {
+            // The new node has no next node, so its link is just the old tail.
+            (*new_node).link = self.tail.addr();
+            if self.tail.is_null() {
+                self.head = new_node;
+            } else {
+                // The old tail had no next node; now its next node is `new_node`.
+                (*self.tail).link ^= new_node.addr();
+            }
+        }
+        self.tail = new_node;
+        self.length += 1;
+    }
+
+    /// Adds a value at the start of the list in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let mut list = KolzoXorLinkedList::new();
+    /// list.push_front(2);
+    /// list.push_front(1);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        let new_node = allocate(value);
+        // SAFETY: `new_node` was just allocated, and `head` is either null or the
+        // live first node of the list.
+        unsafe {
+            (*new_node).link = self.head.addr();
+            if self.head.is_null() {
+                self.tail = new_node;
+            } else {
+                (*self.head).link ^= new_node.addr();
+            }
+        }
+        self.head = new_node;
+        self.length += 1;
+    }
+
+    /// Removes the last value of the list in O(1) and returns it, or `None` if the
+    /// list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let mut list: KolzoXorLinkedList<i32> = (1..=2).collect();
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+
+        let old_tail = self.tail;
+        // SAFETY: `old_tail` is the live last node. Its link is the address of its
+        // previous node, which is either 0 or a live node of the list.
+        unsafe {
+            let previous = node_at::<T>((*old_tail).link);
+            if previous.is_null() {
+                self.head = ptr::null_mut();
+            } else {
+                (*previous).link ^= old_tail.addr();
+            }
+            self.tail = previous;
+            self.length -= 1;
+            Some(Box::from_raw(old_tail).data)
+        }
+    }
+
+    /// Removes the first value of the list in O(1) and returns it, or `None` if
+    /// the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let mut list: KolzoXorLinkedList<i32> = (1..=2).collect();
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+
+        let old_head = self.head;
+        // SAFETY: as in `pop_back`, with the roles of the ends swapped.
+        unsafe {
+            let next = node_at::<T>((*old_head).link);
+            if next.is_null() {
+                self.tail = ptr::null_mut();
+            } else {
+                (*next).link ^= old_head.addr();
+            }
+            self.head = next;
+            self.length -= 1;
+            Some(Box::from_raw(old_head).data)
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an iterator over references to the elements, from head to tail.
+    ///
+    /// The iterator is double-ended, so `iter().rev()` walks from tail to head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let list: KolzoXorLinkedList<i32> = (1..=3).collect();
+    /// assert!(list.iter().eq([1, 2, 3].iter()));
+    /// assert!(list.iter().rev().eq([3, 2, 1].iter()));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head,
+            before_front: 0,
+            back: self.tail,
+            after_back: 0,
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+
+    /// Walks the list in both directions and checks that the links agree.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if walking from the head reaches exactly `len()` nodes and ends
+    ///   at the tail, and walking from the tail visits the same nodes in reverse.
+    /// * `Err(String)` describing the first violation found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xor_linked_list::algorithm::KolzoXorLinkedList;
+    ///
+    /// let mut list: KolzoXorLinkedList<i32> = (0..10).collect();
+    /// list.pop_front();
+    /// list.push_front(-1);
+    /// assert_eq!(list.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.head.is_null() != self.tail.is_null() {
+            return Err("exactly one of the head and the tail is null".to_string());
+        }
+
+        let forward = self.walk_from(self.head, "head")?;
+        let backward = self.walk_from(self.tail, "tail")?;
+        if forward.len() != self.length {
+            return Err(format!(
+                "the list holds {} nodes but its length is {}",
+                forward.len(),
+                self.length
+            ));
+        }
+        if forward.last().copied().unwrap_or(0) != self.tail.addr() {
+            return Err("walking from the head does not end at the tail".to_string());
+        }
+        if !forward.iter().eq(backward.iter().rev()) {
+            return Err(
+                "walking from the tail does not retrace the walk from the head".to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the addresses of the nodes reached by walking from `start`, one of
+    /// the ends, away from the null neighbor. Fails instead of walking more than
+    /// `len()` nodes, so a cycle cannot hang the check.
+    fn walk_from(&self, start: *mut Node<T>, end: &str) -> Result<Vec<usize>, String> {
+        let mut addresses = Vec::with_capacity(self.length);
+        let mut previous = 0;
+        let mut current = start;
+        while !current.is_null() {
+            if addresses.len() == self.length {
+                return Err(format!(
+                    "walking from the {end} reaches more than {} nodes",
+                    self.length
+                ));
+            }
+            addresses.push(current.addr());
+            // SAFETY: `current` is the start of the walk or was rebuilt from the
+            // link of a node already visited, and the links have been consistent so
+            // far, so it is a live node of the list.
+            let next = unsafe { (*current).link } ^ previous;
+            previous = current.addr();
+            current = node_at(next);
+        }
+        Ok(addresses)
+    }
+}
+
+impl<T> Default for KolzoXorLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for KolzoXorLinkedList<T> {
+    /// Frees the nodes one at a time from the head. The nodes were leaked from
+    /// their boxes when allocated, so nothing else would free them.
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Extend<T> for KolzoXorLinkedList<T> {
+    /// Pushes every value of the iterator onto the back of the list.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for KolzoXorLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = KolzoXorLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T> IntoIterator for &'a KolzoXorLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KolzoXorLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A double-ended iterator over references to the elements of a
+/// [`KolzoXorLinkedList`].
+///
+/// Each end carries the address of the node it last yielded, which is what turns
+/// the next node's link into the address of the node after it.
+///
+/// Created by [`KolzoXorLinkedList::iter`].
+pub struct Iter<'a, T> {
+    /// The next node to yield from the front.
+    front: *mut Node<T>,
+    /// The address of the node before `front`, or 0.
+    before_front: usize,
+    /// The next node to yield from the back.
+    back: *mut Node<T>,
+    /// The address of the node after `back`, or 0.
+    after_back: usize,
+    /// The number of elements not yet yielded from either end.
+    remaining: usize,
+    /// The iterator borrows the list.
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.front;
+        // SAFETY: elements remain, so `front` is a live node of the borrowed list.
+        let node = unsafe { &*node };
+        let next = node.link ^ self.before_front;
+        self.before_front = self.front.addr();
+        self.front = node_at(next);
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: elements remain, so `back` is a live node of the borrowed list.
+        let node = unsafe { &*self.back };
+        let previous = node.link ^ self.after_back;
+        self.after_back = self.back.addr();
+        self.back = node_at(previous);
+        Some(&node.data)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// The unsafe code is checked by running these tests under Miri with
+// `cargo +nightly miri test -p xor_linked_list`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cell::Cell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    /// A payload that counts how many times it has been dropped.
+    struct DropCounter {
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    /// Scales a test size down when running under Miri, which is much slower.
+    fn size(native: usize) -> usize {
+        if cfg!(miri) {
+            native / 100
+        } else {
+            native
+        }
+    }
+
+    #[test]
+    fn test_push_back_and_pop_back() {
+        let mut list: KolzoXorLinkedList<i32> = KolzoXorLinkedList::new();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.length, 3);
+        assert_eq!(list.validate(), Ok(()));
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.length, 2);
+
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.length, 1);
+
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.length, 0);
+
+        assert_eq!(list.pop_back(), None);
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut list = KolzoXorLinkedList::new();
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.length, 3);
+        assert!(list.iter().eq([3, 2, 1].iter()));
+
+        list.push_back(0);
+        assert!(list.iter().eq([3, 2, 1, 0].iter()));
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut list: KolzoXorLinkedList<i32> = KolzoXorLinkedList::new();
+
+        assert_eq!(list.pop_front(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.length, 0);
+
+        assert_eq!(list.pop_front(), None);
+
+        list.push_back(4);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list: KolzoXorLinkedList<i32> = KolzoXorLinkedList::new();
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.iter().next_back(), None);
+        assert!(list.is_empty());
+
+        for value in 1..=5 {
+            list.push_back(value);
+        }
+        assert_eq!(list.len(), 5);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let values: Vec<_> = (&list).into_iter().copied().collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        assert!(list.iter().rev().eq([5, 4, 3, 2, 1].iter()));
+        assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+    }
+
+    #[test]
+    fn test_validate_detects_a_wrong_length() {
+        let mut list: KolzoXorLinkedList<i32> = (0..3).collect();
+
+        list.length = 2;
+        assert_eq!(
+            list.validate(),
+            Err("walking from the head reaches more than 2 nodes".to_string())
+        );
+
+        list.length = 4;
+        assert_eq!(
+            list.validate(),
+            Err("the list holds 3 nodes but its length is 4".to_string())
+        );
+        list.length = 3;
+    }
+
+    #[test]
+    fn test_drop_frees_every_node() {
+        let drops = Rc::new(Cell::new(0));
+        let mut list: KolzoXorLinkedList<DropCounter> = (0..10)
+            .map(|_| DropCounter {
+                drops: Rc::clone(&drops),
+            })
+            .collect();
+
+        drop(list.pop_front());
+        drop(list.pop_back());
+        assert_eq!(drops.get(), 2);
+
+        drop(list);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let list: KolzoXorLinkedList<usize> = (0..size(1_000_000)).collect();
+        drop(list);
+    }
+
+    #[test]
+    fn test_matches_vec_deque() {
+        let mut rng = StdRng::seed_from_u64(235);
+        let mut list = KolzoXorLinkedList::new();
+        let mut model = VecDeque::new();
+
+        for _ in 0..size(10_000) {
+            match rng.gen_range(0..5) {
+                0 => {
+                    let value: i32 = rng.gen();
+                    list.push_back(value);
+                    model.push_back(value);
+                }
+                1 => {
+                    let value: i32 = rng.gen();
+                    list.push_front(value);
+                    model.push_front(value);
+                }
+                2 => assert_eq!(list.pop_back(), model.pop_back()),
+                3 => assert_eq!(list.pop_front(), model.pop_front()),
+                _ => {
+                    assert!(list.iter().eq(model.iter()));
+                    assert!(list.iter().rev().eq(model.iter().rev()));
+                }
+            }
+            assert_eq!(list.len(), model.len());
+        }
+
+        assert_eq!(list.validate(), Ok(()));
+    }
+}
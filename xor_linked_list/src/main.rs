@@ -0,0 +1,16 @@
+use xor_linked_list::algorithm::KolzoXorLinkedList;
+
+fn main() {
+    let mut list = KolzoXorLinkedList::new();
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    println!("FORWARD {:?}", list.iter().collect::<Vec<_>>());
+    println!("BACKWARD {:?}", list.iter().rev().collect::<Vec<_>>());
+    println!(
+        "POP FRONT {:?} POP BACK {:?}",
+        list.pop_front(),
+        list.pop_back()
+    );
+    println!("LENGTH {} VALID {:?}", list.len(), list.validate());
+}